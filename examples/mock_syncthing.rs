@@ -0,0 +1,43 @@
+//! Fake Syncthing for local development
+//!
+//! Serves a YAML fixture over the Syncthing REST API so stui can run without
+//! a real instance:
+//!
+//! ```sh
+//! cargo run --example mock_syncthing -- tests/fixtures/mock_syncthing.yaml
+//! ```
+
+use anyhow::Result;
+use clap::Parser;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use stui::mock::{Fixture, MockServer};
+
+#[derive(Parser, Debug)]
+#[command(about = "Serve a fixture over the Syncthing REST API")]
+struct Args {
+    /// Fixture file (YAML)
+    fixture: PathBuf,
+
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8385)]
+    port: u16,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let fixture = Fixture::load(&args.fixture)?;
+    let server = MockServer::bind(fixture, SocketAddr::from(([127, 0, 0, 1], args.port))).await?;
+
+    println!("Mock Syncthing listening on {}", server.url());
+    println!();
+    println!("Point stui at it with:");
+    println!("  base_url: \"{}\"", server.url());
+    println!("  api_key: \"{}\"", server.api_key());
+    println!();
+    println!("Press Ctrl-C to stop");
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
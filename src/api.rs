@@ -41,6 +41,124 @@ pub struct Device {
     pub name: String,
 }
 
+/// Full device configuration entry from /rest/config/devices
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DeviceConfig {
+    #[serde(rename = "deviceID")]
+    pub device_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub addresses: Vec<String>,
+    #[serde(default)]
+    pub compression: String, // "metadata", "always", "never"
+    #[serde(default)]
+    pub introducer: bool,
+    #[serde(default)]
+    pub paused: bool,
+    /// KiB/s, 0 = unlimited (only read here; set with `set_device_rate_limits`)
+    #[serde(rename = "maxSendKbps", default, skip_serializing)]
+    pub max_send_kbps: u64,
+    /// KiB/s, 0 = unlimited (only read here; set with `set_device_rate_limits`)
+    #[serde(rename = "maxRecvKbps", default, skip_serializing)]
+    pub max_recv_kbps: u64,
+}
+
+impl DeviceConfig {
+    /// Bandwidth limits of this device
+    pub fn rate_limits(&self) -> RateLimits {
+        RateLimits {
+            max_send_kbps: self.max_send_kbps,
+            max_recv_kbps: self.max_recv_kbps,
+        }
+    }
+}
+
+/// Bandwidth limits in KiB/s (0 = unlimited)
+///
+/// Used for `options` (global) and for single devices; both name the fields
+/// `maxSendKbps`/`maxRecvKbps`.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RateLimits {
+    #[serde(rename = "maxSendKbps", default)]
+    pub max_send_kbps: u64,
+    #[serde(rename = "maxRecvKbps", default)]
+    pub max_recv_kbps: u64,
+}
+
+impl RateLimits {
+    /// Whether either direction is limited
+    pub fn is_limited(&self) -> bool {
+        self.max_send_kbps > 0 || self.max_recv_kbps > 0
+    }
+}
+
+/// A device that tried to connect but is not in our configuration
+///
+/// Values of the map returned by /rest/cluster/pending/devices (keyed by device ID)
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PendingDevice {
+    #[serde(default)]
+    pub time: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub address: String,
+}
+
+/// A folder offered to us by one or more remote devices
+///
+/// Values of the map returned by /rest/cluster/pending/folders (keyed by folder ID)
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PendingFolder {
+    #[serde(rename = "offeredBy", default)]
+    pub offered_by: std::collections::HashMap<String, PendingFolderOffer>,
+}
+
+/// A single remote device's offer of a pending folder
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PendingFolderOffer {
+    #[serde(default)]
+    pub time: String,
+    #[serde(default)]
+    pub label: String,
+}
+
+/// Archived version of a file (from /rest/folder/versions)
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FileVersion {
+    #[serde(rename = "versionTime")]
+    pub version_time: String, // RFC 3339, when the version was archived
+    #[serde(rename = "modTime")]
+    pub mod_time: String, // RFC 3339, modification time of the archived content
+    #[serde(default)]
+    pub size: u64,
+}
+
+/// Folder versioning settings (the `versioning` object of a folder config)
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct VersioningConfig {
+    #[serde(rename = "type", default)]
+    pub versioning_type: String, // "", "simple", "staggered", "trashcan", "external"
+    #[serde(rename = "fsPath", default)]
+    pub fs_path: String, // Empty means .stversions inside the folder
+}
+
+/// A folder's `.stignore` as returned by /rest/db/ignores
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct Ignores {
+    /// The lines of `.stignore` itself
+    #[serde(
+        rename = "ignore",
+        default,
+        deserialize_with = "deserialize_null_default"
+    )]
+    pub lines: Vec<String>,
+    /// Every pattern in effect, with `#include` files read in
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub expanded: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ConfigResponse {
     folders: Vec<Folder>,
@@ -67,6 +185,7 @@ pub enum SyncState {
     RemoteOnly, // ☁️ Only on remote devices
     Ignored,    // 🚫 In .stignore
     Syncing,    // 🔄 Currently syncing
+    Conflict,   // 💥 Sync-conflict copy left by Syncthing
     Unknown,    // ❓ Not yet determined
 }
 
@@ -127,6 +246,45 @@ pub struct NeedResponse {
     pub perpage: u32,
 }
 
+/// Progress of one file being pulled (from DownloadProgress events)
+///
+/// Block counts, except `bytes_total`/`bytes_done`.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DownloadProgress {
+    pub total: u64,
+    /// Blocks being fetched from the network right now
+    pub pulling: u64,
+    pub copied_from_origin: u64,
+    pub copied_from_elsewhere: u64,
+    /// Blocks already present in the old version of the file
+    pub reused: u64,
+    /// Blocks fetched from the network
+    pub pulled: u64,
+    pub bytes_total: u64,
+    pub bytes_done: u64,
+}
+
+/// Sync state of a folder on a remote device (from /rest/db/completion)
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Completion {
+    /// Percent of the global state the device has
+    pub completion: f64,
+    pub global_bytes: u64,
+    pub global_items: u64,
+    pub need_bytes: u64,
+    pub need_items: u64,
+    pub need_deletes: u64,
+    pub remote_state: String, // "valid", "paused", "notSharing" or "unknown"
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteNeedResponse {
+    #[serde(default)]
+    files: Option<Vec<FileInfo>>, // null when the device needs nothing
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
 pub struct DeviceAvailability {
@@ -153,7 +311,7 @@ pub struct FileDetails {
     pub availability: Vec<DeviceAvailability>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FolderStatus {
     pub state: String,
@@ -211,6 +369,40 @@ pub struct FolderStatus {
     pub errors: u64,
 }
 
+/// Item that failed to sync (from /rest/folder/errors)
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FolderError {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FolderErrorsResponse {
+    #[serde(default)]
+    errors: Option<Vec<FolderError>>, // null when the folder has no errors
+}
+
+/// Entry from /rest/system/log or /rest/system/error
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct LogEntry {
+    pub when: String, // RFC 3339
+    pub message: String,
+    #[serde(default)]
+    pub level: Option<i32>, // Only reported by newer Syncthing versions
+}
+
+#[derive(Debug, Deserialize)]
+struct LogResponse {
+    #[serde(default)]
+    messages: Option<Vec<LogEntry>>, // null when the buffer is empty
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Option<Vec<LogEntry>>, // null when there are no errors
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemStatus {
@@ -222,6 +414,25 @@ pub struct SystemStatus {
     pub start_time: String,
 }
 
+/// Syncthing build information from /rest/system/version
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemVersion {
+    pub version: String,
+    pub os: String,
+    pub arch: String,
+    #[serde(default)]
+    pub codename: String,
+    #[serde(default)]
+    pub long_version: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RestartRequired {
+    requires_restart: bool,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectionTotal {
@@ -240,11 +451,8 @@ pub struct ConnectionStats {
 #[serde(rename_all = "camelCase")]
 pub struct ConnectionInfo {
     pub connected: bool,
-    #[allow(dead_code)]
     pub address: String,
-    #[allow(dead_code)]
     pub in_bytes_total: u64,
-    #[allow(dead_code)]
     pub out_bytes_total: u64,
     #[allow(dead_code)]
     pub paused: bool,
@@ -289,6 +497,11 @@ impl SyncthingClient {
         }
     }
 
+    /// URL of the Syncthing instance this client talks to
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Fetch system config (consolidates get_folders and get_device_name)
     async fn get_system_config(&self) -> Result<ConfigResponse> {
         let url = format!("{}/rest/system/config", self.base_url);
@@ -435,6 +648,36 @@ impl SyncthingClient {
         Ok(())
     }
 
+    /// Rescan only the given paths of a folder
+    ///
+    /// Uses POST /rest/db/scan with one `sub` parameter per path, so Syncthing
+    /// scans them in a single request instead of walking the whole folder.
+    pub async fn rescan_paths(&self, folder_id: &str, paths: &[String]) -> Result<()> {
+        let mut url = format!(
+            "{}/rest/db/scan?folder={}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+        for path in paths {
+            url.push_str(&format!("&sub={}", urlencoding::encode(path)));
+        }
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to trigger rescan")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to trigger rescan: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
     pub async fn revert_folder(&self, folder_id: &str) -> Result<()> {
         let url = format!("{}/rest/db/revert?folder={}", self.base_url, folder_id);
         self.client
@@ -447,6 +690,33 @@ impl SyncthingClient {
         Ok(())
     }
 
+    /// Override remote changes in a send-only folder
+    ///
+    /// Uses POST /rest/db/override: the local state becomes the newest version
+    /// of every item the folder needs, so other devices take it.
+    pub async fn override_folder(&self, folder_id: &str) -> Result<()> {
+        let url = format!(
+            "{}/rest/db/override?folder={}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to override folder")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to override folder: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
     pub async fn get_local_changed_files(&self, folder_id: &str) -> Result<Vec<String>> {
         let url = format!(
             "{}/rest/db/localchanged?folder={}",
@@ -569,27 +839,121 @@ impl SyncthingClient {
             .context("Failed to parse need response")
     }
 
-    pub async fn get_ignore_patterns(&self, folder_id: &str) -> Result<Vec<String>> {
-        let url = format!("{}/rest/db/ignores?folder={}", self.base_url, folder_id);
+    /// Move a needed file to the front of the download queue
+    ///
+    /// Returns the first page of the reordered need list.
+    pub async fn prioritize_file(&self, folder_id: &str, file_path: &str) -> Result<NeedResponse> {
+        let url = format!(
+            "{}/rest/db/prio?folder={}&file={}",
+            self.base_url,
+            urlencoding::encode(folder_id),
+            urlencoding::encode(file_path)
+        );
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to prioritize file")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to prioritize file: {} - {}", status, text);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse need response")
+    }
+
+    /// Get how far a remote device is with a folder
+    ///
+    /// Uses GET /rest/db/completion, which answers from the local index of
+    /// what the device has announced.
+    pub async fn get_completion(&self, folder_id: &str, device_id: &str) -> Result<Completion> {
+        let url = format!(
+            "{}/rest/db/completion?folder={}&device={}",
+            self.base_url,
+            urlencoding::encode(folder_id),
+            urlencoding::encode(device_id)
+        );
         let response = self
             .client
             .get(&url)
             .header("X-API-Key", &self.api_key)
             .send()
             .await
-            .context("Failed to fetch ignore patterns")?;
+            .context("Failed to get completion")?;
 
-        #[derive(Deserialize)]
-        struct IgnoresResponse {
-            ignore: Option<Vec<String>>,
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to get completion: {} - {}", status, text);
+        }
+
+        response.json().await.context("Failed to parse completion")
+    }
+
+    /// Get a page of the files a remote device still needs in a folder
+    pub async fn get_remote_need(
+        &self,
+        folder_id: &str,
+        device_id: &str,
+        page: usize,
+        per_page: usize,
+    ) -> Result<Vec<FileInfo>> {
+        let url = format!(
+            "{}/rest/db/remoteneed?folder={}&device={}&page={}&perpage={}",
+            self.base_url,
+            urlencoding::encode(folder_id),
+            urlencoding::encode(device_id),
+            page,
+            per_page
+        );
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to get remote need")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to get remote need: {} - {}", status, text);
         }
 
-        let data: IgnoresResponse = response
+        let need: RemoteNeedResponse = response
             .json()
             .await
-            .context("Failed to parse ignore patterns")?;
+            .context("Failed to parse remote need response")?;
+
+        Ok(need.files.unwrap_or_default())
+    }
+
+    pub async fn get_ignore_patterns(&self, folder_id: &str) -> Result<Vec<String>> {
+        Ok(self.get_ignores(folder_id).await?.lines)
+    }
+
+    /// Get a folder's `.stignore` lines along with the expanded patterns
+    pub async fn get_ignores(&self, folder_id: &str) -> Result<Ignores> {
+        let url = format!("{}/rest/db/ignores?folder={}", self.base_url, folder_id);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch ignore patterns")?;
 
-        Ok(data.ignore.unwrap_or_default())
+        response
+            .json()
+            .await
+            .context("Failed to parse ignore patterns")
     }
 
     pub async fn set_ignore_patterns(&self, folder_id: &str, patterns: Vec<String>) -> Result<()> {
@@ -602,7 +966,8 @@ impl SyncthingClient {
 
         let request_body = IgnoresRequest { ignore: patterns };
 
-        self.client
+        let response = self
+            .client
             .post(&url)
             .header("X-API-Key", &self.api_key)
             .json(&request_body)
@@ -610,6 +975,12 @@ impl SyncthingClient {
             .await
             .context("Failed to set ignore patterns")?;
 
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to set ignore patterns: {} - {}", status, text);
+        }
+
         Ok(())
     }
 
@@ -631,185 +1002,921 @@ impl SyncthingClient {
         Ok(status)
     }
 
-    pub async fn get_connection_stats(&self) -> Result<ConnectionStats> {
-        let url = format!("{}/rest/system/connections", self.base_url);
+    /// Get one page of a folder's failed items
+    ///
+    /// Uses GET /rest/folder/errors?folder={id}&page={page}&perpage={per_page} (pages start at 1)
+    pub async fn get_folder_errors(
+        &self,
+        folder_id: &str,
+        page: usize,
+        per_page: usize,
+    ) -> Result<Vec<FolderError>> {
+        let url = format!(
+            "{}/rest/folder/errors?folder={}&page={}&perpage={}",
+            self.base_url,
+            urlencoding::encode(folder_id),
+            page,
+            per_page
+        );
         let response = self
             .client
             .get(&url)
             .header("X-API-Key", &self.api_key)
             .send()
             .await
-            .context("Failed to fetch connection stats")?;
+            .context("Failed to fetch folder errors")?;
 
-        let stats: ConnectionStats = response
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch folder errors: {} - {}", status, text);
+        }
+
+        let errors: FolderErrorsResponse = response
             .json()
             .await
-            .context("Failed to parse connection stats")?;
+            .context("Failed to parse folder errors")?;
 
-        Ok(stats)
+        Ok(errors.errors.unwrap_or_default())
     }
 
-    /// Get system connections (device connectivity and transfer stats)
-    pub async fn get_system_connections(&self) -> Result<ConnectionsResponse> {
-        let url = format!("{}/rest/system/connections", self.base_url);
+    /// Get recent log messages
+    ///
+    /// Uses GET /rest/system/log; `since` (RFC 3339) limits the result to newer entries.
+    pub async fn get_system_log(&self, since: Option<&str>) -> Result<Vec<LogEntry>> {
+        let mut url = format!("{}/rest/system/log", self.base_url);
+        if let Some(since) = since {
+            url.push_str(&format!("?since={}", urlencoding::encode(since)));
+        }
+
         let response = self
             .client
             .get(&url)
             .header("X-API-Key", &self.api_key)
             .send()
             .await
-            .context("Failed to send connections request")?;
+            .context("Failed to fetch system log")?;
 
-        let connections: ConnectionsResponse = response
+        let log: LogResponse = response
             .json()
             .await
-            .context("Failed to parse connections response")?;
+            .context("Failed to parse system log")?;
 
-        Ok(connections)
+        Ok(log.messages.unwrap_or_default())
     }
 
-    /// Fetch folder statistics to get last updated file per folder
+    /// Get the errors Syncthing reports in its GUI
     ///
-    /// Returns HashMap of folder_id -> (timestamp, filename)
-    /// Uses /rest/stats/folder which matches what Syncthing web GUI displays
-    pub async fn get_folder_stats(
-        &self,
-    ) -> Result<std::collections::HashMap<String, (std::time::SystemTime, String)>> {
-        use std::collections::HashMap;
-
-        let url = format!("{}/rest/stats/folder", self.base_url);
+    /// Uses GET /rest/system/error
+    pub async fn get_system_errors(&self) -> Result<Vec<LogEntry>> {
+        let url = format!("{}/rest/system/error", self.base_url);
         let response = self
             .client
             .get(&url)
             .header("X-API-Key", &self.api_key)
             .send()
             .await
-            .context("Failed to fetch folder stats")?;
+            .context("Failed to fetch system errors")?;
 
-        let stats: HashMap<String, FolderStats> = response
+        let errors: ErrorResponse = response
             .json()
             .await
-            .context("Failed to parse folder stats")?;
+            .context("Failed to parse system errors")?;
 
-        let mut updates: HashMap<String, (std::time::SystemTime, String)> = HashMap::new();
+        Ok(errors.errors.unwrap_or_default())
+    }
 
-        for (folder_id, folder_stats) in stats {
-            // Parse the timestamp
-            let timestamp =
-                crate::services::events::parse_event_time_public(&folder_stats.last_file.at);
+    /// Clear the reported errors
+    ///
+    /// Uses POST /rest/system/error/clear
+    pub async fn clear_system_errors(&self) -> Result<()> {
+        let url = format!("{}/rest/system/error/clear", self.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to clear system errors")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to clear system errors: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Get the Syncthing version, OS and architecture
+    ///
+    /// Uses GET /rest/system/version
+    pub async fn get_system_version(&self) -> Result<SystemVersion> {
+        let url = format!("{}/rest/system/version", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch system version")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch system version: {} - {}", status, text);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse system version")
+    }
+
+    /// Whether saved config changes only take effect after a restart
+    ///
+    /// Uses GET /rest/config/restart-required
+    pub async fn get_restart_required(&self) -> Result<bool> {
+        let url = format!("{}/rest/config/restart-required", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch restart status")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch restart status: {} - {}", status, text);
+        }
+
+        let restart: RestartRequired = response
+            .json()
+            .await
+            .context("Failed to parse restart status")?;
+        Ok(restart.requires_restart)
+    }
+
+    /// Restart Syncthing
+    ///
+    /// Uses POST /rest/system/restart; the API is unreachable until it is back up
+    pub async fn restart(&self) -> Result<()> {
+        let url = format!("{}/rest/system/restart", self.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to restart Syncthing")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to restart Syncthing: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Shut Syncthing down
+    ///
+    /// Uses POST /rest/system/shutdown
+    pub async fn shutdown(&self) -> Result<()> {
+        let url = format!("{}/rest/system/shutdown", self.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to shut down Syncthing")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to shut down Syncthing: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_connection_stats(&self) -> Result<ConnectionStats> {
+        let url = format!("{}/rest/system/connections", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch connection stats")?;
+
+        let stats: ConnectionStats = response
+            .json()
+            .await
+            .context("Failed to parse connection stats")?;
+
+        Ok(stats)
+    }
+
+    /// Get system connections (device connectivity and transfer stats)
+    pub async fn get_system_connections(&self) -> Result<ConnectionsResponse> {
+        let url = format!("{}/rest/system/connections", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to send connections request")?;
+
+        let connections: ConnectionsResponse = response
+            .json()
+            .await
+            .context("Failed to parse connections response")?;
+
+        Ok(connections)
+    }
+
+    /// Fetch folder statistics to get last updated file per folder
+    ///
+    /// Returns HashMap of folder_id -> (timestamp, filename)
+    /// Uses /rest/stats/folder which matches what Syncthing web GUI displays
+    pub async fn get_folder_stats(
+        &self,
+    ) -> Result<std::collections::HashMap<String, (std::time::SystemTime, String)>> {
+        use std::collections::HashMap;
+
+        let url = format!("{}/rest/stats/folder", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch folder stats")?;
+
+        let stats: HashMap<String, FolderStats> = response
+            .json()
+            .await
+            .context("Failed to parse folder stats")?;
+
+        let mut updates: HashMap<String, (std::time::SystemTime, String)> = HashMap::new();
+
+        for (folder_id, folder_stats) in stats {
+            // Parse the timestamp
+            let timestamp =
+                crate::services::events::parse_event_time_public(&folder_stats.last_file.at);
+
+            // Include all files, even deleted ones - matches Syncthing web GUI behavior
+            // The "deleted" flag just indicates the last operation was a deletion
+            updates.insert(folder_id, (timestamp, folder_stats.last_file.filename));
+        }
+
+        Ok(updates)
+    }
+
+    /// Fetch folder events from Syncthing event stream
+    ///
+    /// Returns events starting from `since` event ID, limited to `limit` events.
+    /// Uses /rest/events endpoint to get historical sync events.
+    ///
+    /// # Arguments
+    /// * `since` - Event ID to start from (0 for all history, or recent ID for recent events)
+    /// * `limit` - Maximum number of events to fetch
+    ///
+    /// # Returns
+    /// Vec of SyncthingEvent objects containing event metadata and data
+    #[allow(dead_code)] // Used in folder history feature (Task 4)
+    pub async fn get_folder_events(&self, since: u64, limit: usize) -> Result<Vec<SyncthingEvent>> {
+        let url = format!(
+            "{}/rest/events?since={}&limit={}",
+            self.base_url, since, limit
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch folder events")?;
+
+        let events: Vec<SyncthingEvent> = response
+            .json()
+            .await
+            .context("Failed to parse folder events")?;
+
+        Ok(events)
+    }
+
+    /// Pause or resume a folder
+    ///
+    /// Uses PATCH /rest/config/folders/{id} to set the paused state
+    pub async fn set_folder_paused(&self, folder_id: &str, paused: bool) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/folders/{}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+
+        let payload = serde_json::json!({
+            "paused": paused
+        });
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .context("Failed to set folder paused state")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Failed to {} folder: {} - {}",
+                if paused { "pause" } else { "resume" },
+                status,
+                text
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Change folder type
+    ///
+    /// Uses PATCH /rest/config/folders/{id} to set the folder type
+    /// Valid types: "sendonly", "sendreceive", "receiveonly"
+    pub async fn set_folder_type(&self, folder_id: &str, folder_type: &str) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/folders/{}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+
+        let payload = serde_json::json!({
+            "type": folder_type
+        });
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .context("Failed to set folder type")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to set folder type: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Get the full configuration object of a folder
+    ///
+    /// Uses GET /rest/config/folders/{id}. Kept as raw JSON so that fields stui
+    /// doesn't know about survive a round trip through `update_folder_config`.
+    pub async fn get_folder_config(&self, folder_id: &str) -> Result<serde_json::Value> {
+        let url = format!(
+            "{}/rest/config/folders/{}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch folder config")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch folder config: {} - {}", status, text);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse folder config")
+    }
+
+    /// Replace the configuration object of a folder
+    ///
+    /// Uses PUT /rest/config/folders/{id} with the full object, as loaded by
+    /// `get_folder_config` and then edited
+    pub async fn update_folder_config(
+        &self,
+        folder_id: &str,
+        config: &serde_json::Value,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/folders/{}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+
+        let response = self
+            .client
+            .put(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(config)
+            .send()
+            .await
+            .context("Failed to update folder config")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to update folder config: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Get full configuration for all devices
+    ///
+    /// Uses GET /rest/config/devices (includes addresses, compression, introducer, paused)
+    pub async fn get_config_devices(&self) -> Result<Vec<DeviceConfig>> {
+        let url = format!("{}/rest/config/devices", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch device config")?;
+
+        response
+            .json()
+            .await
+            .context("Failed to parse device config")
+    }
+
+    /// Add a new remote device
+    ///
+    /// Uses POST /rest/config/devices. Fields not present in the payload are
+    /// filled in by Syncthing from its device defaults.
+    pub async fn add_device(&self, device: &DeviceConfig) -> Result<()> {
+        let url = format!("{}/rest/config/devices", self.base_url);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(device)
+            .send()
+            .await
+            .context("Failed to add device")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to add device: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Update an existing device (name, addresses, compression, introducer, paused)
+    ///
+    /// Uses PATCH /rest/config/devices/{id} so unrelated settings are preserved
+    pub async fn update_device(&self, device: &DeviceConfig) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/devices/{}",
+            self.base_url,
+            urlencoding::encode(&device.device_id)
+        );
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(device)
+            .send()
+            .await
+            .context("Failed to update device")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to update device: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Pause or resume a device
+    ///
+    /// Uses PATCH /rest/config/devices/{id} to set the paused state
+    pub async fn set_device_paused(&self, device_id: &str, paused: bool) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/devices/{}",
+            self.base_url,
+            urlencoding::encode(device_id)
+        );
+
+        let payload = serde_json::json!({
+            "paused": paused
+        });
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(&payload)
+            .send()
+            .await
+            .context("Failed to set device paused state")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Failed to {} device: {} - {}",
+                if paused { "pause" } else { "resume" },
+                status,
+                text
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Set the bandwidth limits of a device
+    ///
+    /// Uses PATCH /rest/config/devices/{id} so unrelated settings are preserved
+    pub async fn set_device_rate_limits(&self, device_id: &str, limits: &RateLimits) -> Result<()> {
+        let url = format!(
+            "{}/rest/config/devices/{}",
+            self.base_url,
+            urlencoding::encode(device_id)
+        );
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(limits)
+            .send()
+            .await
+            .context("Failed to set device rate limits")?;
 
-            // Include all files, even deleted ones - matches Syncthing web GUI behavior
-            // The "deleted" flag just indicates the last operation was a deletion
-            updates.insert(folder_id, (timestamp, folder_stats.last_file.filename));
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to set device rate limits: {} - {}", status, text);
         }
 
-        Ok(updates)
+        Ok(())
     }
 
-    /// Fetch folder events from Syncthing event stream
+    /// Get the global bandwidth limits
     ///
-    /// Returns events starting from `since` event ID, limited to `limit` events.
-    /// Uses /rest/events endpoint to get historical sync events.
+    /// Uses GET /rest/config/options (other options are ignored)
+    pub async fn get_global_rate_limits(&self) -> Result<RateLimits> {
+        let url = format!("{}/rest/config/options", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch options")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch options: {} - {}", status, text);
+        }
+
+        response.json().await.context("Failed to parse options")
+    }
+
+    /// Set the global bandwidth limits
     ///
-    /// # Arguments
-    /// * `since` - Event ID to start from (0 for all history, or recent ID for recent events)
-    /// * `limit` - Maximum number of events to fetch
+    /// Uses PATCH /rest/config/options so other options are preserved
+    pub async fn set_global_rate_limits(&self, limits: &RateLimits) -> Result<()> {
+        let url = format!("{}/rest/config/options", self.base_url);
+
+        let response = self
+            .client
+            .patch(&url)
+            .header("X-API-Key", &self.api_key)
+            .header("Content-Type", "application/json")
+            .json(limits)
+            .send()
+            .await
+            .context("Failed to set global rate limits")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to set global rate limits: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a device from the configuration
     ///
-    /// # Returns
-    /// Vec of SyncthingEvent objects containing event metadata and data
-    #[allow(dead_code)] // Used in folder history feature (Task 4)
-    pub async fn get_folder_events(&self, since: u64, limit: usize) -> Result<Vec<SyncthingEvent>> {
+    /// Uses DELETE /rest/config/devices/{id}
+    pub async fn remove_device(&self, device_id: &str) -> Result<()> {
         let url = format!(
-            "{}/rest/events?since={}&limit={}",
-            self.base_url, since, limit
+            "{}/rest/config/devices/{}",
+            self.base_url,
+            urlencoding::encode(device_id)
         );
 
+        let response = self
+            .client
+            .delete(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to remove device")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to remove device: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Get devices that tried to connect but are not configured
+    ///
+    /// Uses GET /rest/cluster/pending/devices (map of device ID → details)
+    pub async fn get_pending_devices(
+        &self,
+    ) -> Result<std::collections::HashMap<String, PendingDevice>> {
+        let url = format!("{}/rest/cluster/pending/devices", self.base_url);
         let response = self
             .client
             .get(&url)
             .header("X-API-Key", &self.api_key)
             .send()
             .await
-            .context("Failed to fetch folder events")?;
+            .context("Failed to fetch pending devices")?;
 
-        let events: Vec<SyncthingEvent> = response
+        response
             .json()
             .await
-            .context("Failed to parse folder events")?;
+            .context("Failed to parse pending devices")
+    }
 
-        Ok(events)
+    /// Get folders offered by remote devices that are not configured locally
+    ///
+    /// Uses GET /rest/cluster/pending/folders (map of folder ID → offering devices)
+    pub async fn get_pending_folders(
+        &self,
+    ) -> Result<std::collections::HashMap<String, PendingFolder>> {
+        let url = format!("{}/rest/cluster/pending/folders", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch pending folders")?;
+
+        response
+            .json()
+            .await
+            .context("Failed to parse pending folders")
     }
 
-    /// Pause or resume a folder
+    /// Dismiss a pending device (it is recorded again if it reconnects)
     ///
-    /// Uses PATCH /rest/config/folders/{id} to set the paused state
-    pub async fn set_folder_paused(&self, folder_id: &str, paused: bool) -> Result<()> {
+    /// Uses DELETE /rest/cluster/pending/devices?device={id}
+    pub async fn dismiss_pending_device(&self, device_id: &str) -> Result<()> {
         let url = format!(
-            "{}/rest/config/folders/{}",
+            "{}/rest/cluster/pending/devices?device={}",
             self.base_url,
-            urlencoding::encode(folder_id)
+            urlencoding::encode(device_id)
         );
 
-        let payload = serde_json::json!({
-            "paused": paused
-        });
+        let response = self
+            .client
+            .delete(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to dismiss pending device")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to dismiss pending device: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Dismiss a pending folder offer from a specific device
+    ///
+    /// Uses DELETE /rest/cluster/pending/folders?folder={id}&device={id}
+    pub async fn dismiss_pending_folder(&self, folder_id: &str, device_id: &str) -> Result<()> {
+        let url = format!(
+            "{}/rest/cluster/pending/folders?folder={}&device={}",
+            self.base_url,
+            urlencoding::encode(folder_id),
+            urlencoding::encode(device_id)
+        );
 
         let response = self
             .client
-            .patch(&url)
+            .delete(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to dismiss pending folder")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to dismiss pending folder: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
+
+    /// Add a new folder to the configuration
+    ///
+    /// Uses POST /rest/config/folders. Fields not present in the payload are
+    /// filled in by Syncthing from its folder defaults; the local device is
+    /// added to the share list automatically.
+    pub async fn add_folder(&self, folder: &Folder) -> Result<()> {
+        let url = format!("{}/rest/config/folders", self.base_url);
+
+        let response = self
+            .client
+            .post(&url)
             .header("X-API-Key", &self.api_key)
             .header("Content-Type", "application/json")
-            .json(&payload)
+            .json(folder)
             .send()
             .await
-            .context("Failed to set folder paused state")?;
+            .context("Failed to add folder")?;
 
         if !response.status().is_success() {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
-            anyhow::bail!(
-                "Failed to {} folder: {} - {}",
-                if paused { "pause" } else { "resume" },
-                status,
-                text
-            );
+            anyhow::bail!("Failed to add folder: {} - {}", status, text);
         }
 
         Ok(())
     }
 
-    /// Change folder type
+    /// Remove a folder from the configuration
     ///
-    /// Uses PATCH /rest/config/folders/{id} to set the folder type
-    /// Valid types: "sendonly", "sendreceive", "receiveonly"
-    pub async fn set_folder_type(&self, folder_id: &str, folder_type: &str) -> Result<()> {
+    /// Uses DELETE /rest/config/folders/{id}. Files on disk are left alone.
+    pub async fn remove_folder(&self, folder_id: &str) -> Result<()> {
         let url = format!(
             "{}/rest/config/folders/{}",
             self.base_url,
             urlencoding::encode(folder_id)
         );
 
-        let payload = serde_json::json!({
-            "type": folder_type
-        });
+        let response = self
+            .client
+            .delete(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to remove folder")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to remove folder: {} - {}", status, text);
+        }
+
+        Ok(())
+    }
 
+    /// Get archived versions of all files in a folder
+    ///
+    /// Uses GET /rest/folder/versions (map of file path → versions)
+    pub async fn get_file_versions(
+        &self,
+        folder_id: &str,
+    ) -> Result<std::collections::HashMap<String, Vec<FileVersion>>> {
+        let url = format!(
+            "{}/rest/folder/versions?folder={}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
         let response = self
             .client
-            .patch(&url)
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch file versions")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch file versions: {} - {}", status, text);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse file versions")
+    }
+
+    /// Restore archived file versions
+    ///
+    /// Uses POST /rest/folder/versions with a map of file path → versionTime.
+    /// Returns a map of file path → error for files that could not be restored.
+    pub async fn restore_file_versions(
+        &self,
+        folder_id: &str,
+        versions: &std::collections::HashMap<String, String>,
+    ) -> Result<std::collections::HashMap<String, String>> {
+        let url = format!(
+            "{}/rest/folder/versions?folder={}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+
+        let response = self
+            .client
+            .post(&url)
             .header("X-API-Key", &self.api_key)
             .header("Content-Type", "application/json")
-            .json(&payload)
+            .json(versions)
             .send()
             .await
-            .context("Failed to set folder type")?;
+            .context("Failed to restore file versions")?;
 
         if !response.status().is_success() {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to set folder type: {} - {}", status, text);
+            anyhow::bail!("Failed to restore file versions: {} - {}", status, text);
         }
 
-        Ok(())
+        response
+            .json()
+            .await
+            .context("Failed to parse restore result")
+    }
+
+    /// Get the versioning settings of a folder
+    ///
+    /// Uses GET /rest/config/folders/{id} and extracts the `versioning` object
+    pub async fn get_folder_versioning(&self, folder_id: &str) -> Result<VersioningConfig> {
+        #[derive(Deserialize)]
+        struct FolderVersioningResponse {
+            #[serde(default)]
+            versioning: VersioningConfig,
+        }
+
+        let url = format!(
+            "{}/rest/config/folders/{}",
+            self.base_url,
+            urlencoding::encode(folder_id)
+        );
+        let response = self
+            .client
+            .get(&url)
+            .header("X-API-Key", &self.api_key)
+            .send()
+            .await
+            .context("Failed to fetch folder config")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to fetch folder config: {} - {}", status, text);
+        }
+
+        let config: FolderVersioningResponse = response
+            .json()
+            .await
+            .context("Failed to parse folder config")?;
+        Ok(config.versioning)
     }
 }
 
@@ -876,6 +1983,117 @@ mod tests {
         // Real testing will happen in integration tests
     }
 
+    #[test]
+    fn test_device_config_parsing() {
+        // Test parsing /rest/config/devices response (extra fields are ignored)
+        let devices_json = r#"[
+            {
+                "deviceID": "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH",
+                "name": "laptop",
+                "addresses": ["dynamic", "tcp://192.168.1.10:22000"],
+                "compression": "metadata",
+                "introducer": true,
+                "paused": false,
+                "autoAcceptFolders": false
+            },
+            {
+                "deviceID": "IIIIIII-JJJJJJJ-KKKKKKK-LLLLLLL-MMMMMMM-NNNNNNN-OOOOOOO-PPPPPPP"
+            }
+        ]"#;
+
+        let devices: Vec<DeviceConfig> =
+            serde_json::from_str(devices_json).expect("Failed to parse device config");
+
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].name, "laptop");
+        assert_eq!(devices[0].addresses.len(), 2);
+        assert_eq!(devices[0].compression, "metadata");
+        assert!(devices[0].introducer);
+        assert!(!devices[0].paused);
+
+        // Missing fields fall back to defaults
+        assert!(devices[1].name.is_empty());
+        assert!(devices[1].addresses.is_empty());
+        assert!(!devices[1].introducer);
+
+        // Serialized payload uses Syncthing's field names
+        let payload = serde_json::to_value(&devices[0]).unwrap();
+        assert_eq!(
+            payload["deviceID"],
+            "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH"
+        );
+        assert_eq!(payload["introducer"], true);
+    }
+
+    #[test]
+    fn test_file_versions_parsing() {
+        // Test parsing /rest/folder/versions response
+        let versions_json = r#"{
+            "docs/report.txt": [
+                {
+                    "versionTime": "2025-01-15T10:30:00+01:00",
+                    "modTime": "2025-01-15T10:29:55+01:00",
+                    "size": 1024
+                },
+                {
+                    "versionTime": "2025-01-14T08:00:00+01:00",
+                    "modTime": "2025-01-14T07:59:00+01:00",
+                    "size": 980
+                }
+            ]
+        }"#;
+
+        let versions: std::collections::HashMap<String, Vec<FileVersion>> =
+            serde_json::from_str(versions_json).expect("Failed to parse file versions");
+        let report = versions.get("docs/report.txt").unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].version_time, "2025-01-15T10:30:00+01:00");
+        assert_eq!(report[0].size, 1024);
+
+        // Versioning config with defaults for missing fields
+        let versioning: VersioningConfig =
+            serde_json::from_str(r#"{"type": "staggered", "params": {}}"#).unwrap();
+        assert_eq!(versioning.versioning_type, "staggered");
+        assert!(versioning.fs_path.is_empty());
+    }
+
+    #[test]
+    fn test_pending_devices_and_folders_parsing() {
+        // Test parsing /rest/cluster/pending/{devices,folders} responses
+        let devices_json = r#"{
+            "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH": {
+                "time": "2025-01-15T10:30:00Z",
+                "name": "phone",
+                "address": "192.168.1.20:22000"
+            }
+        }"#;
+        let devices: std::collections::HashMap<String, PendingDevice> =
+            serde_json::from_str(devices_json).expect("Failed to parse pending devices");
+        let device = devices
+            .get("AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH")
+            .unwrap();
+        assert_eq!(device.name, "phone");
+        assert_eq!(device.address, "192.168.1.20:22000");
+
+        let folders_json = r#"{
+            "abcd-1234": {
+                "offeredBy": {
+                    "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH": {
+                        "time": "2025-01-15T10:31:00Z",
+                        "label": "Photos",
+                        "receiveEncrypted": false,
+                        "remoteEncrypted": false
+                    }
+                }
+            }
+        }"#;
+        let folders: std::collections::HashMap<String, PendingFolder> =
+            serde_json::from_str(folders_json).expect("Failed to parse pending folders");
+        let folder = folders.get("abcd-1234").unwrap();
+        assert_eq!(folder.offered_by.len(), 1);
+        assert_eq!(folder.offered_by.values().next().unwrap().label, "Photos");
+    }
+
     #[test]
     fn test_folder_stats_parsing() {
         // Test parsing /rest/stats/folder response
@@ -918,13 +2136,13 @@ mod tests {
             .expect("Should have folder-abc-123");
         assert_eq!(folder_abc.last_file.filename, ".thumbnails/7726.jpg");
         assert_eq!(folder_abc.last_file.at, "2025-11-09T23:24:15Z");
-        assert_eq!(folder_abc.last_file.deleted, false);
+        assert!(!folder_abc.last_file.deleted);
 
         let folder_deleted = stats
             .get("folder-deleted-789")
             .expect("Should have folder-deleted-789");
-        assert_eq!(
-            folder_deleted.last_file.deleted, true,
+        assert!(
+            folder_deleted.last_file.deleted,
             "Deleted flag should be true"
         );
 
@@ -1111,13 +2329,13 @@ mod tests {
         let parsed: serde_json::Value = serde_json::from_str(json).unwrap();
         let connections = parsed.get("connections").unwrap();
         let device1 = connections.get("DEVICE123-ABC").unwrap();
-        assert_eq!(device1.get("connected").unwrap().as_bool().unwrap(), true);
+        assert!(device1.get("connected").unwrap().as_bool().unwrap());
         assert_eq!(
             device1.get("address").unwrap().as_str().unwrap(),
             "192.168.1.10:22000"
         );
 
         let device2 = connections.get("DEVICE456-DEF").unwrap();
-        assert_eq!(device2.get("connected").unwrap().as_bool().unwrap(), false);
+        assert!(!device2.get("connected").unwrap().as_bool().unwrap());
     }
 }
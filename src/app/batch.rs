@@ -0,0 +1,383 @@
+//! Batch operations on marked items
+//!
+//! Items of the focused breadcrumb level can be marked with Space, by range
+//! (`v`) or all at once for the active search (`*`). While marks exist:
+//! - Ignore, ignore+delete and delete are confirmed once for the whole batch
+//! - Copy path, open and rescan apply to every marked item immediately
+
+use crate::model::{BatchItem, BatchOperation, ConfirmAction};
+use crate::{App, SyncState, log_debug, logic, services};
+use anyhow::Result;
+use std::path::PathBuf;
+
+impl App {
+    /// Index of the focused breadcrumb level, if it has marked items
+    fn marked_level_index(&self) -> Option<usize> {
+        let level_idx = self.model.navigation.focus_level.checked_sub(1)?;
+        self.model
+            .navigation
+            .breadcrumb_trail
+            .get(level_idx)
+            .filter(|level| !level.marked_items().is_empty())
+            .map(|_| level_idx)
+    }
+
+    /// Whether actions should apply to marked items instead of the selection
+    pub(crate) fn has_marked_items(&self) -> bool {
+        self.marked_level_index().is_some()
+    }
+
+    /// Toggle the mark on the selected item of the focused level
+    pub(crate) fn toggle_mark(&mut self) {
+        let Some(level_idx) = self.model.navigation.focus_level.checked_sub(1) else {
+            return;
+        };
+        if let Some(level) = self.model.navigation.breadcrumb_trail.get_mut(level_idx) {
+            level.toggle_mark();
+        }
+    }
+
+    /// Mark everything between the last toggled item and the selection
+    pub(crate) fn mark_range(&mut self) {
+        let Some(level_idx) = self.model.navigation.focus_level.checked_sub(1) else {
+            return;
+        };
+        if let Some(level) = self.model.navigation.breadcrumb_trail.get_mut(level_idx) {
+            level.mark_range();
+            let count = level.marked.len();
+            self.model.ui.show_toast(format!("{} marked", count));
+        }
+    }
+
+    /// Mark every item shown in the focused level (all search matches)
+    pub(crate) fn mark_all_matching(&mut self) {
+        let Some(level_idx) = self.model.navigation.focus_level.checked_sub(1) else {
+            return;
+        };
+        let query = self.model.ui.search_query.clone();
+        if let Some(level) = self.model.navigation.breadcrumb_trail.get_mut(level_idx) {
+            let added = level.mark_all_displayed();
+            let message = if query.is_empty() {
+                format!("Marked {} items", added)
+            } else {
+                format!("Marked {} items matching '{}'", added, query)
+            };
+            self.model.ui.show_toast(message);
+        }
+    }
+
+    /// Clear the marks of the focused level; returns whether any were cleared
+    pub(crate) fn clear_marks(&mut self) -> bool {
+        let Some(level_idx) = self.marked_level_index() else {
+            return false;
+        };
+        self.model.navigation.breadcrumb_trail[level_idx].clear_marks();
+        self.model.ui.show_toast("Marks cleared".to_string());
+        true
+    }
+
+    /// Snapshot the marked items of a level with their paths
+    fn batch_items(&self, level_idx: usize) -> Vec<BatchItem> {
+        let level = &self.model.navigation.breadcrumb_trail[level_idx];
+        level
+            .marked_items()
+            .into_iter()
+            .map(|item| {
+                // translated_base_path already includes the full path to this directory level
+                let host_path = format!(
+                    "{}/{}",
+                    level.translated_base_path.trim_end_matches('/'),
+                    item.name
+                );
+                let path = std::path::Path::new(&host_path);
+                BatchItem {
+                    name: item.name.clone(),
+                    relative_path: level.relative_path(&item.name),
+                    is_dir: path.is_dir() || item.item_type == "FILE_INFO_TYPE_DIRECTORY",
+                    exists: path.exists(),
+                    host_path,
+                }
+            })
+            .collect()
+    }
+
+    /// Ask for confirmation of a batch operation on the marked items
+    pub(crate) fn request_batch(&mut self, operation: BatchOperation) {
+        let Some(level_idx) = self.marked_level_index() else {
+            return;
+        };
+        let level = &self.model.navigation.breadcrumb_trail[level_idx];
+        let folder_id = level.folder_id.clone();
+
+        let items: Vec<BatchItem> = self
+            .batch_items(level_idx)
+            .into_iter()
+            .filter(|item| match operation {
+                BatchOperation::Ignore => {
+                    level.get_sync_state(&item.name) != Some(SyncState::Ignored)
+                }
+                BatchOperation::IgnoreDelete => true,
+                BatchOperation::Delete => item.exists,
+            })
+            .collect();
+
+        if items.is_empty() {
+            let message = match operation {
+                BatchOperation::Ignore => "All marked items are already ignored",
+                BatchOperation::IgnoreDelete | BatchOperation::Delete => {
+                    "None of the marked items exist on disk"
+                }
+            };
+            self.model.ui.show_toast(message.to_string());
+            return;
+        }
+
+        self.model.ui.confirm_action = Some(ConfirmAction::Batch {
+            folder_id,
+            operation,
+            items,
+        });
+    }
+
+    /// Apply a confirmed batch operation
+    ///
+    /// Ignore patterns are written with a single `set_ignore_patterns` call and
+    /// the folder is rescanned once at the end.
+    pub(crate) async fn execute_batch(
+        &mut self,
+        folder_id: &str,
+        operation: BatchOperation,
+        items: &[BatchItem],
+    ) -> Result<()> {
+        if matches!(
+            operation,
+            BatchOperation::Ignore | BatchOperation::IgnoreDelete
+        ) {
+            let patterns = self.client.get_ignore_patterns(folder_id).await?;
+            let paths: Vec<String> = items.iter().map(|i| i.relative_path.clone()).collect();
+            let (updated, added) = logic::ignore::add_ignore_patterns(&patterns, &paths);
+            if added > 0 {
+                self.client.set_ignore_patterns(folder_id, updated).await?;
+            }
+        }
+
+        let mut deleted: Vec<&BatchItem> = Vec::new();
+        let mut failed = 0;
+        if operation != BatchOperation::Ignore {
+            for item in items.iter().filter(|item| item.exists) {
+                let path_buf = PathBuf::from(&item.host_path);
+                if operation == BatchOperation::IgnoreDelete {
+                    // Block un-ignore until Syncthing has seen the deletion
+                    self.add_pending_delete(folder_id.to_string(), path_buf.clone());
+                }
+
+                let result = if item.is_dir {
+                    std::fs::remove_dir_all(&item.host_path)
+                } else {
+                    std::fs::remove_file(&item.host_path)
+                };
+
+                match result {
+                    Ok(()) => deleted.push(item),
+                    Err(e) => {
+                        log_debug(&format!(
+                            "Batch delete failed for {}: {}",
+                            item.host_path, e
+                        ));
+                        if operation == BatchOperation::IgnoreDelete {
+                            self.remove_pending_delete(folder_id, &path_buf);
+                        }
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        self.apply_batch_to_level(folder_id, operation, items, &deleted);
+
+        if operation == BatchOperation::IgnoreDelete
+            && let Some(pending_info) = self
+                .model
+                .performance
+                .pending_ignore_deletes
+                .get_mut(folder_id)
+        {
+            pending_info.rescan_triggered = true;
+        }
+
+        let _ = self.api_tx.send(services::api::ApiRequest::RescanFolder {
+            folder_id: folder_id.to_string(),
+        });
+
+        let done = match operation {
+            BatchOperation::Ignore => format!("Ignored {} items", items.len()),
+            BatchOperation::IgnoreDelete => {
+                format!("Ignored {} items, deleted {}", items.len(), deleted.len())
+            }
+            BatchOperation::Delete => format!("Deleted {} items", deleted.len()),
+        };
+        let message = if failed > 0 {
+            format!("{} ({} failed to delete)", done, failed)
+        } else {
+            done
+        };
+        self.model.ui.show_toast(message);
+
+        Ok(())
+    }
+
+    /// Reflect a finished batch in the focused level and the cache, then clear marks
+    fn apply_batch_to_level(
+        &mut self,
+        folder_id: &str,
+        operation: BatchOperation,
+        items: &[BatchItem],
+        deleted: &[&BatchItem],
+    ) {
+        let Some(level_idx) = self.model.navigation.focus_level.checked_sub(1) else {
+            return;
+        };
+        if self
+            .model
+            .navigation
+            .breadcrumb_trail
+            .get(level_idx)
+            .is_none_or(|level| level.folder_id != folder_id)
+        {
+            return;
+        }
+
+        match operation {
+            BatchOperation::Ignore | BatchOperation::IgnoreDelete => {
+                for item in items {
+                    self.model.navigation.breadcrumb_trail[level_idx]
+                        .file_sync_states
+                        .insert(item.name.clone(), SyncState::Ignored);
+                    self.update_ignored_exists_for_file(level_idx, &item.name, SyncState::Ignored);
+
+                    // Update cache immediately so browse refresh doesn't overwrite with stale data
+                    let _ = self.cache.save_sync_state(
+                        folder_id,
+                        &item.relative_path,
+                        SyncState::Ignored,
+                        0,
+                    );
+                }
+            }
+            BatchOperation::Delete => {
+                for item in deleted {
+                    if item.is_dir {
+                        let _ = self
+                            .cache
+                            .invalidate_directory(folder_id, &item.relative_path);
+                    } else {
+                        let _ = self
+                            .cache
+                            .invalidate_single_file(folder_id, &item.relative_path);
+                    }
+                }
+
+                let level = &mut self.model.navigation.breadcrumb_trail[level_idx];
+                let removed = |name: &String| deleted.iter().any(|item| &item.name == name);
+                level.items.retain(|item| !removed(&item.name));
+                if let Some(ref mut filtered) = level.filtered_items {
+                    filtered.retain(|item| !removed(&item.name));
+                }
+                for item in deleted {
+                    level.file_sync_states.remove(&item.name);
+                }
+
+                // Keep the selection within the shortened list
+                let display_len = level.display_items().len();
+                level.selected_index = match level.selected_index {
+                    _ if display_len == 0 => None,
+                    Some(idx) => Some(idx.min(display_len - 1)),
+                    None => None,
+                };
+
+                let browse_key = format!("{}:{}", folder_id, level.prefix.as_deref().unwrap_or(""));
+                self.model.performance.loading_browse.remove(&browse_key);
+            }
+        }
+
+        self.model.navigation.breadcrumb_trail[level_idx].clear_marks();
+    }
+
+    /// Copy the host paths of all marked items, one per line
+    pub(crate) fn copy_marked_paths(&mut self) {
+        let Some(level_idx) = self.marked_level_index() else {
+            return;
+        };
+        let paths: Vec<String> = self
+            .batch_items(level_idx)
+            .into_iter()
+            .map(|item| item.host_path)
+            .collect();
+
+        let summary = format!("{} paths", paths.len());
+        self.copy_text_to_clipboard(&paths.join("\n"), &summary);
+    }
+
+    /// Ask Syncthing to rescan just the marked items, in one request
+    pub(crate) fn rescan_marked_items(&mut self) {
+        let Some(level_idx) = self.marked_level_index() else {
+            return;
+        };
+        let folder_id = self.model.navigation.breadcrumb_trail[level_idx]
+            .folder_id
+            .clone();
+        let paths: Vec<String> = self
+            .batch_items(level_idx)
+            .into_iter()
+            .map(|item| item.relative_path)
+            .collect();
+
+        let count = paths.len();
+        let _ = self
+            .api_tx
+            .send(services::api::ApiRequest::RescanPaths { folder_id, paths });
+        self.model
+            .ui
+            .show_toast(format!("Rescanning {} items...", count));
+    }
+
+    /// Open every marked item that exists on disk with the configured command
+    pub(crate) fn open_marked_items(&mut self) {
+        let Some(ref open_cmd) = self.open_command else {
+            self.model
+                .ui
+                .show_toast("Error: open_command not configured".to_string());
+            return;
+        };
+        let Some(level_idx) = self.marked_level_index() else {
+            return;
+        };
+
+        let mut opened = 0;
+        for item in self.batch_items(level_idx).iter().filter(|i| i.exists) {
+            // Spawn without waiting, like a single open
+            let result = std::process::Command::new(open_cmd)
+                .arg(&item.host_path)
+                .stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .spawn();
+
+            match result {
+                Ok(_child) => opened += 1,
+                Err(e) => {
+                    log_debug(&format!(
+                        "Failed to execute open_command '{}': {}",
+                        open_cmd, e
+                    ));
+                    self.model
+                        .ui
+                        .show_toast(format!("Error: Failed to open with '{}'", open_cmd));
+                    return;
+                }
+            }
+        }
+
+        self.model.ui.show_toast(format!("Opened {} items", opened));
+    }
+}
@@ -0,0 +1,98 @@
+//! Live config reload
+//!
+//! Syncthing emits ConfigSaved whenever its configuration changes, including
+//! edits made in the web UI. Folders, devices and rate limits are reloaded in
+//! place so the change shows up without restarting stui, and the restart
+//! banner follows changes that only apply after restarting Syncthing.
+
+use crate::{App, api, log_debug, services};
+use anyhow::Result;
+
+impl App {
+    /// Reload folders and devices after a ConfigSaved event
+    ///
+    /// Keeps the selected folder selected (by ID) when the list changes.
+    pub async fn reload_config(&mut self) {
+        let devices = self.client.get_devices().await;
+        let folders = self.client.get_folders().await;
+        if !self.update_config(devices, folders) {
+            return;
+        }
+
+        self.refresh_device_count().await;
+        self.refresh_rate_limits().await;
+        self.refresh_restart_required().await;
+
+        if self.model.ui.device_details_modal.is_some() {
+            self.refresh_devices_modal().await;
+        }
+    }
+
+    /// Request a config reload through the API service
+    pub fn request_config_reload(&self) {
+        let _ = self.api_tx.send(services::api::ApiRequest::ReloadConfig);
+    }
+
+    /// Apply reloaded folders and devices, then request what depends on them
+    pub fn apply_config_reload(
+        &mut self,
+        devices: Result<Vec<api::Device>>,
+        folders: Result<Vec<api::Folder>>,
+    ) {
+        if !self.update_config(devices, folders) {
+            return;
+        }
+
+        for request in [
+            services::api::ApiRequest::GetDeviceCount,
+            services::api::ApiRequest::GetRateLimits,
+            services::api::ApiRequest::GetRestartRequired,
+        ] {
+            let _ = self.api_tx.send(request);
+        }
+        self.request_devices_refresh();
+    }
+
+    /// Replace devices and folders, returning false if the folders failed to load
+    fn update_config(
+        &mut self,
+        devices: Result<Vec<api::Device>>,
+        folders: Result<Vec<api::Folder>>,
+    ) -> bool {
+        match devices {
+            Ok(devices) => self.model.syncthing.devices = devices,
+            Err(e) => log_debug(&format!("Failed to reload devices: {}", e)),
+        }
+
+        let folders = match folders {
+            Ok(folders) => folders,
+            Err(e) => {
+                log_debug(&format!("Failed to reload folders: {}", e));
+                return false;
+            }
+        };
+        let _ = self.cache.save_folders(&folders);
+
+        let selected_id = self
+            .model
+            .navigation
+            .folders_state_selection
+            .and_then(|i| self.model.syncthing.folders.get(i))
+            .map(|f| f.id.clone());
+        let selection = selected_id
+            .and_then(|id| folders.iter().position(|f| f.id == id))
+            .or_else(|| (!folders.is_empty()).then_some(0));
+
+        // Drop live state of removed folders
+        let sync = &mut self.model.syncthing;
+        sync.scan_progress
+            .retain(|id, _| folders.iter().any(|f| &f.id == id));
+        sync.remote_completion
+            .retain(|id, _| folders.iter().any(|f| &f.id == id));
+
+        self.model.syncthing.folders = folders;
+        self.model.navigation.folders_state_selection = selection;
+        self.refresh_folder_statuses_nonblocking();
+        true
+    }
+}
@@ -0,0 +1,282 @@
+//! Sync conflict resolution
+//!
+//! Methods for the conflicts modal:
+//! - Scan a folder's host directory for `.sync-conflict-` copies
+//! - Preview the original and the conflict copy side by side
+//! - Resolve a conflict on the host filesystem and rescan the folder
+
+use crate::{App, log_debug, logic, model};
+use std::path::Path;
+
+/// Directories Syncthing manages inside a folder root (never contain user conflicts)
+const SKIPPED_DIRS: [&str; 2] = [".stfolder", logic::versions::DEFAULT_VERSIONS_DIR];
+
+impl App {
+    /// Open the conflicts modal for the current folder
+    ///
+    /// Uses the selected folder in folder view, or the browsed folder in breadcrumb view.
+    pub async fn open_conflicts(&mut self) {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return;
+        };
+
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+        let conflicts = self.scan_conflicts(&folder).await;
+
+        if conflicts.is_empty() {
+            self.model
+                .ui
+                .show_toast(format!("No sync conflicts in {}", label));
+            return;
+        }
+
+        self.model.ui.conflicts_modal = Some(model::ConflictsModal {
+            folder_id: folder.id.clone(),
+            folder_label: label,
+            conflicts,
+            selected_index: 0,
+            original_preview: Err("Loading...".to_string()),
+            conflict_preview: Err("Loading...".to_string()),
+            scroll_offset: 0,
+        });
+
+        self.load_conflict_previews().await;
+    }
+
+    /// Close the conflicts modal
+    pub fn close_conflicts(&mut self) {
+        self.model.ui.conflicts_modal = None;
+    }
+
+    /// Move the selection in the conflicts list and reload the previews
+    pub async fn select_conflict(&mut self, index: usize) {
+        let Some(modal) = &mut self.model.ui.conflicts_modal else {
+            return;
+        };
+
+        let index = index.min(modal.conflicts.len().saturating_sub(1));
+        if index == modal.selected_index {
+            return;
+        }
+        modal.selected_index = index;
+        modal.scroll_offset = 0;
+
+        self.load_conflict_previews().await;
+    }
+
+    /// Resolve a conflict on the host filesystem (called after confirmation)
+    pub async fn resolve_conflict(
+        &mut self,
+        folder_id: &str,
+        conflict_path: &str,
+        original_path: &str,
+        resolution: model::ConflictResolution,
+    ) {
+        let Some(folder) = self.model.syncthing.get_folder(folder_id).cloned() else {
+            return;
+        };
+
+        let host_conflict =
+            logic::path::translate_path(&folder.path, conflict_path, &self.path_map);
+        let host_original =
+            logic::path::translate_path(&folder.path, original_path, &self.path_map);
+
+        let result = match resolution {
+            model::ConflictResolution::Original => std::fs::remove_file(&host_conflict)
+                .map(|_| format!("Kept {}, removed conflict copy", original_path)),
+            model::ConflictResolution::Conflict => std::fs::rename(&host_conflict, &host_original)
+                .map(|_| format!("Replaced {} with conflict copy", original_path)),
+            model::ConflictResolution::Both => {
+                let file_name = conflict_path.rsplit('/').next().unwrap_or(conflict_path);
+                match logic::conflicts::parse_conflict_name(file_name) {
+                    Some(parsed) => {
+                        let device_name = logic::conflicts::conflict_device_name(
+                            parsed.device_short_id.as_deref(),
+                            &self.model.syncthing.devices,
+                        );
+                        let parent = Path::new(&host_conflict)
+                            .parent()
+                            .map(Path::to_path_buf)
+                            .unwrap_or_default();
+                        let new_name =
+                            logic::conflicts::keep_both_name(&parsed, &device_name, |name| {
+                                parent.join(name).exists()
+                            });
+                        std::fs::rename(&host_conflict, parent.join(&new_name)).map(|_| {
+                            format!("Kept both files, conflict copy saved as {}", new_name)
+                        })
+                    }
+                    None => Err(std::io::Error::other("not a conflict file")),
+                }
+            }
+        };
+
+        match result {
+            Ok(message) => {
+                let _ = self.cache.invalidate_single_file(folder_id, conflict_path);
+                let _ = self.cache.invalidate_single_file(folder_id, original_path);
+                let _ = self.force_refresh_folder(folder_id);
+                self.model.ui.show_toast(message);
+                self.refresh_conflicts_modal().await;
+            }
+            Err(e) => {
+                log_debug(&format!(
+                    "Failed to resolve conflict {} ({:?}): {}",
+                    host_conflict, resolution, e
+                ));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to resolve conflict: {}", e));
+            }
+        }
+    }
+
+    /// Rescan the open conflicts modal after a resolution, closing it when none remain
+    async fn refresh_conflicts_modal(&mut self) {
+        let Some(modal) = &self.model.ui.conflicts_modal else {
+            return;
+        };
+        let Some(folder) = self.model.syncthing.get_folder(&modal.folder_id).cloned() else {
+            return;
+        };
+        let selected = modal.selected_index;
+
+        let conflicts = self.scan_conflicts(&folder).await;
+        if conflicts.is_empty() {
+            self.model.ui.conflicts_modal = None;
+            return;
+        }
+
+        if let Some(modal) = &mut self.model.ui.conflicts_modal {
+            modal.selected_index = selected.min(conflicts.len() - 1);
+            modal.conflicts = conflicts;
+            modal.scroll_offset = 0;
+        }
+        self.load_conflict_previews().await;
+    }
+
+    /// Read the selected conflict's original and conflict copy into the previews
+    async fn load_conflict_previews(&mut self) {
+        let Some(modal) = &self.model.ui.conflicts_modal else {
+            return;
+        };
+        let Some(conflict) = modal.selected_conflict() else {
+            return;
+        };
+        let Some(folder) = self.model.syncthing.get_folder(&modal.folder_id) else {
+            return;
+        };
+
+        let (original_preview, _, _) =
+            Self::read_file_content_static(&self.path_map, folder, &conflict.original_path).await;
+        let (conflict_preview, _, _) =
+            Self::read_file_content_static(&self.path_map, folder, &conflict.conflict_path).await;
+
+        if let Some(modal) = &mut self.model.ui.conflicts_modal {
+            modal.original_preview = original_preview;
+            modal.conflict_preview = conflict_preview;
+        }
+    }
+
+    /// Find all conflict copies in a folder's host directory (newest first)
+    async fn scan_conflicts(&self, folder: &crate::Folder) -> Vec<model::ConflictEntry> {
+        let root = logic::path::translate_path(&folder.path, "", &self.path_map);
+
+        let files = match tokio::task::spawn_blocking(move || find_conflict_files(Path::new(&root)))
+            .await
+        {
+            Ok(files) => files,
+            Err(e) => {
+                log_debug(&format!("Conflict scan failed for {}: {}", folder.id, e));
+                Vec::new()
+            }
+        };
+
+        let mut conflicts: Vec<model::ConflictEntry> = files
+            .into_iter()
+            .filter_map(|file| {
+                let file_name = file.relative_path.rsplit('/').next()?;
+                let parsed = logic::conflicts::parse_conflict_name(file_name)?;
+                let original_path = logic::conflicts::original_path(&file.relative_path)?;
+
+                let host_original =
+                    logic::path::translate_path(&folder.path, &original_path, &self.path_map);
+                let original_meta = std::fs::metadata(&host_original).ok();
+
+                Some(model::ConflictEntry {
+                    device_name: logic::conflicts::conflict_device_name(
+                        parsed.device_short_id.as_deref(),
+                        &self.model.syncthing.devices,
+                    ),
+                    timestamp: parsed.timestamp,
+                    conflict_path: file.relative_path,
+                    original_path,
+                    conflict_size: file.size,
+                    conflict_modified: file.modified,
+                    original_size: original_meta.as_ref().map(|m| m.len()),
+                    original_modified: original_meta
+                        .as_ref()
+                        .map(modified_rfc3339)
+                        .unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        conflicts.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| a.conflict_path.cmp(&b.conflict_path))
+        });
+        conflicts
+    }
+}
+
+/// Conflict copy found on disk
+struct ConflictFile {
+    relative_path: String,
+    size: u64,
+    modified: String,
+}
+
+/// Walk a folder root for conflict copies (symlinks are not followed)
+fn find_conflict_files(root: &Path) -> Vec<ConflictFile> {
+    let mut found = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), String::new())];
+
+    while let Some((dir, prefix)) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+
+            if file_type.is_dir() {
+                if prefix.is_empty() && SKIPPED_DIRS.contains(&name.as_str()) {
+                    continue;
+                }
+                pending.push((entry.path(), format!("{}{}/", prefix, name)));
+            } else if file_type.is_file() && logic::conflicts::is_conflict_file(&name) {
+                let metadata = entry.metadata().ok();
+                found.push(ConflictFile {
+                    relative_path: format!("{}{}", prefix, name),
+                    size: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+                    modified: metadata.as_ref().map(modified_rfc3339).unwrap_or_default(),
+                });
+            }
+        }
+    }
+
+    found
+}
+
+/// Modification time of a file as RFC 3339 (empty if unavailable)
+fn modified_rfc3339(metadata: &std::fs::Metadata) -> String {
+    metadata
+        .modified()
+        .map(|time| chrono::DateTime::<chrono::Local>::from(time).to_rfc3339())
+        .unwrap_or_default()
+}
@@ -0,0 +1,303 @@
+//! Remote device management
+//!
+//! Methods for the devices modal:
+//! - Load device config + live connection info
+//! - Add / edit devices via the editor form
+//! - Pause / resume and remove devices
+
+use crate::{App, api, log_debug, logic, model, services};
+use anyhow::Result;
+use std::time::Instant;
+
+impl App {
+    /// Open the device management modal
+    pub async fn open_devices_modal(&mut self) {
+        log_debug("Opening devices modal");
+
+        match self.fetch_device_infos().await {
+            Ok((configs, connections)) => {
+                let devices = self.build_device_infos(configs, &connections, None);
+                self.model.ui.device_details_modal = Some(model::DeviceDetailsModal {
+                    devices,
+                    selected_index: 0,
+                    refreshed_at: Instant::now(),
+                    editor: None,
+                });
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to load devices: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load devices: {}", e));
+            }
+        }
+    }
+
+    /// Close the device management modal
+    pub fn close_devices_modal(&mut self) {
+        log_debug("Closing devices modal");
+        self.model.ui.device_details_modal = None;
+    }
+
+    /// Reload device list (keeps selection and open editor, updates rates)
+    pub async fn refresh_devices_modal(&mut self) {
+        if self.model.ui.device_details_modal.is_none() {
+            return;
+        }
+
+        let infos = self.fetch_device_infos().await;
+        self.apply_device_infos(infos);
+    }
+
+    /// Request a device list reload through the API service
+    pub fn request_devices_refresh(&self) {
+        if self.model.ui.device_details_modal.is_some() {
+            let _ = self.api_tx.send(services::api::ApiRequest::GetDeviceInfos);
+        }
+    }
+
+    /// Update the open modal from fetched device config and connections
+    pub fn apply_device_infos(
+        &mut self,
+        infos: Result<(Vec<api::DeviceConfig>, api::ConnectionsResponse)>,
+    ) {
+        let Some(previous) = &self.model.ui.device_details_modal else {
+            return;
+        };
+
+        match infos {
+            Ok((configs, connections)) => {
+                let devices = self.build_device_infos(configs, &connections, Some(previous));
+                if let Some(modal) = &mut self.model.ui.device_details_modal {
+                    modal.selected_index =
+                        modal.selected_index.min(devices.len().saturating_sub(1));
+                    modal.devices = devices;
+                    modal.refreshed_at = Instant::now();
+                }
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to refresh devices: {}", e));
+            }
+        }
+    }
+
+    /// Fetch device config and live connections
+    async fn fetch_device_infos(
+        &self,
+    ) -> Result<(Vec<api::DeviceConfig>, api::ConnectionsResponse)> {
+        let configs = self.client.get_config_devices().await?;
+        let connections = self.client.get_system_connections().await?;
+        Ok((configs, connections))
+    }
+
+    /// Merge device config and connections into display rows
+    ///
+    /// The local device is excluded. If a previous snapshot is given, per-device
+    /// rates are calculated from the byte counter deltas since that snapshot.
+    fn build_device_infos(
+        &self,
+        configs: Vec<api::DeviceConfig>,
+        connections: &api::ConnectionsResponse,
+        previous: Option<&model::DeviceDetailsModal>,
+    ) -> Vec<model::DeviceInfo> {
+        let my_device_id = self
+            .model
+            .syncthing
+            .system_status
+            .as_ref()
+            .map(|s| s.my_id.as_str());
+        let folders = &self.model.syncthing.folders;
+        let elapsed = previous
+            .map(|p| p.refreshed_at.elapsed().as_secs_f64())
+            .unwrap_or(0.0);
+
+        configs
+            .into_iter()
+            .filter(|config| Some(config.device_id.as_str()) != my_device_id)
+            .map(|config| {
+                let conn = connections.connections.get(&config.device_id);
+                let (in_bytes, out_bytes) = conn
+                    .map(|c| (c.in_bytes_total, c.out_bytes_total))
+                    .unwrap_or((0, 0));
+
+                let prev = previous
+                    .and_then(|p| p.devices.iter().find(|d| d.device_id == config.device_id));
+                let (download_rate, upload_rate) = match prev {
+                    Some(prev) if elapsed > 0.0 => (
+                        Some(logic::devices::calculate_rate(
+                            prev.in_bytes_total,
+                            in_bytes,
+                            elapsed,
+                        )),
+                        Some(logic::devices::calculate_rate(
+                            prev.out_bytes_total,
+                            out_bytes,
+                            elapsed,
+                        )),
+                    ),
+                    _ => (None, None),
+                };
+
+                let device_name = if config.name.is_empty() {
+                    logic::devices::short_device_id(&config.device_id).to_string()
+                } else {
+                    config.name.clone()
+                };
+
+                model::DeviceInfo {
+                    shared_folder_count: logic::devices::count_shared_folders(
+                        &config.device_id,
+                        folders,
+                    ),
+                    total_folder_count: folders.len(),
+                    device_name,
+                    connected: conn.map(|c| c.connected).unwrap_or(false),
+                    address: conn.map(|c| c.address.clone()).unwrap_or_default(),
+                    download_rate,
+                    upload_rate,
+                    addresses: config.addresses,
+                    compression: config.compression,
+                    introducer: config.introducer,
+                    paused: config.paused,
+                    in_bytes_total: in_bytes,
+                    out_bytes_total: out_bytes,
+                    device_id: config.device_id,
+                }
+            })
+            .collect()
+    }
+
+    /// Open the editor form for a new device
+    pub fn start_add_device(&mut self) {
+        if let Some(modal) = &mut self.model.ui.device_details_modal {
+            modal.editor = Some(model::DeviceEditorState::new_device());
+        }
+    }
+
+    /// Open the editor form for the selected device
+    pub fn start_edit_device(&mut self) {
+        if let Some(modal) = &mut self.model.ui.device_details_modal
+            && let Some(device) = modal.selected_device()
+        {
+            modal.editor = Some(model::DeviceEditorState::edit_device(device));
+        }
+    }
+
+    /// Validate and submit the editor form (add or update)
+    ///
+    /// Keeps the form open and shows a toast if validation or the API call fails.
+    pub async fn save_device_editor(&mut self) {
+        let Some(editor) = self
+            .model
+            .ui
+            .device_details_modal
+            .as_ref()
+            .and_then(|m| m.editor.clone())
+        else {
+            return;
+        };
+
+        let device_id = match &editor.original_device_id {
+            Some(id) => id.clone(),
+            None => match logic::devices::normalize_device_id(&editor.device_id) {
+                Ok(id) => id,
+                Err(e) => {
+                    self.model.ui.show_toast(e);
+                    return;
+                }
+            },
+        };
+
+        let device = api::DeviceConfig {
+            device_id,
+            name: editor.name.trim().to_string(),
+            addresses: logic::devices::parse_addresses(&editor.addresses),
+            compression: editor.compression.clone(),
+            introducer: editor.introducer,
+            paused: editor.paused,
+            // Not sent; rate limits are changed from the rate limit popup
+            max_send_kbps: 0,
+            max_recv_kbps: 0,
+        };
+
+        let result = if editor.is_new() {
+            self.client.add_device(&device).await
+        } else {
+            self.client.update_device(&device).await
+        };
+
+        let display_name = if device.name.is_empty() {
+            logic::devices::short_device_id(&device.device_id).to_string()
+        } else {
+            device.name.clone()
+        };
+
+        match result {
+            Ok(()) => {
+                let action = if editor.is_new() { "added" } else { "updated" };
+                self.model
+                    .ui
+                    .show_toast(format!("Device {} {}", display_name, action));
+                if let Some(modal) = &mut self.model.ui.device_details_modal {
+                    modal.editor = None;
+                }
+                self.reload_devices_after_change().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to save device: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+
+    /// Pause or resume a device (called after confirmation)
+    pub async fn set_device_paused(&mut self, device_id: &str, name: &str, paused: bool) {
+        match self.client.set_device_paused(device_id, paused).await {
+            Ok(()) => {
+                let action = if paused { "paused" } else { "resumed" };
+                self.model
+                    .ui
+                    .show_toast(format!("Device {} {}", name, action));
+                self.reload_devices_after_change().await;
+            }
+            Err(e) => {
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to pause/resume device: {}", e));
+            }
+        }
+    }
+
+    /// Remove a device from the configuration (called after confirmation)
+    pub async fn remove_device(&mut self, device_id: &str, name: &str) {
+        match self.client.remove_device(device_id).await {
+            Ok(()) => {
+                self.model.ui.show_toast(format!("Device {} removed", name));
+                self.reload_devices_after_change().await;
+            }
+            Err(e) => {
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to remove device: {}", e));
+            }
+        }
+    }
+
+    /// Refresh everything that depends on the device list after a config change
+    async fn reload_devices_after_change(&mut self) {
+        // Device names are used by the file info popup
+        match self.client.get_devices().await {
+            Ok(devices) => self.model.syncthing.devices = devices,
+            Err(e) => log_debug(&format!("Failed to reload devices: {}", e)),
+        }
+
+        // Removing a device also unshares it from folders
+        match self.client.get_folders().await {
+            Ok(folders) => self.model.syncthing.folders = folders,
+            Err(e) => log_debug(&format!("Failed to reload folders: {}", e)),
+        }
+
+        self.refresh_devices_modal().await;
+        self.refresh_device_count().await;
+    }
+}
@@ -0,0 +1,171 @@
+//! Failed items view
+//!
+//! Methods for the failed items modal:
+//! - Page through a folder's `/rest/folder/errors`
+//! - Reload the loaded pages when a `FolderErrors` event arrives
+//! - Jump to a failed file in the breadcrumb view
+
+use crate::{App, api, log_debug, logic, model, services};
+use anyhow::Result;
+
+impl App {
+    /// Open the failed items modal for the current folder
+    ///
+    /// Uses the selected folder in folder view, or the browsed folder in breadcrumb view.
+    pub async fn open_failed_items(&mut self) {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return;
+        };
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+
+        let page_size = logic::folder_errors::FAILED_ITEMS_PAGE_SIZE;
+        let items = match self
+            .client
+            .get_folder_errors(&folder.id, 1, page_size)
+            .await
+        {
+            Ok(items) => items,
+            Err(e) => {
+                log_debug(&format!("Failed to load folder errors: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load failed items: {}", e));
+                return;
+            }
+        };
+
+        if items.is_empty() {
+            self.model
+                .ui
+                .show_toast(format!("No failed items in {}", label));
+            return;
+        }
+
+        self.model.ui.failed_items_modal = Some(model::FailedItemsModal {
+            folder_id: folder.id.clone(),
+            folder_label: label,
+            has_more: logic::folder_errors::has_more_pages(items.len()),
+            items,
+            selected_index: 0,
+            pages_loaded: 1,
+        });
+    }
+
+    /// Close the failed items modal
+    pub fn close_failed_items(&mut self) {
+        self.model.ui.failed_items_modal = None;
+    }
+
+    /// Move the selection, loading the next page when nearing the end
+    pub async fn select_failed_item(&mut self, index: usize) {
+        let Some(modal) = &mut self.model.ui.failed_items_modal else {
+            return;
+        };
+        modal.selected_index = index.min(modal.items.len().saturating_sub(1));
+
+        if !logic::folder_errors::should_load_more(
+            modal.selected_index,
+            modal.items.len(),
+            modal.has_more,
+        ) {
+            return;
+        }
+
+        let folder_id = modal.folder_id.clone();
+        let page = modal.pages_loaded + 1;
+        let page_size = logic::folder_errors::FAILED_ITEMS_PAGE_SIZE;
+
+        match self
+            .client
+            .get_folder_errors(&folder_id, page, page_size)
+            .await
+        {
+            Ok(items) => {
+                if let Some(modal) = &mut self.model.ui.failed_items_modal {
+                    modal.has_more = logic::folder_errors::has_more_pages(items.len());
+                    modal.pages_loaded = page;
+                    modal.items.extend(items);
+                }
+            }
+            Err(e) => {
+                log_debug(&format!(
+                    "Failed to load folder errors page {}: {}",
+                    page, e
+                ));
+                if let Some(modal) = &mut self.model.ui.failed_items_modal {
+                    modal.has_more = false;
+                }
+            }
+        }
+    }
+
+    /// Request the pages loaded so far again (after a FolderErrors event)
+    pub fn request_failed_items_refresh(&self) {
+        let Some(modal) = &self.model.ui.failed_items_modal else {
+            return;
+        };
+        let pages = modal.pages_loaded.max(1);
+
+        let _ = self.api_tx.send(services::api::ApiRequest::GetFailedItems {
+            folder_id: modal.folder_id.clone(),
+            count: pages * logic::folder_errors::FAILED_ITEMS_PAGE_SIZE,
+        });
+    }
+
+    /// Replace the modal's items with the first `count` fetched ones
+    pub fn apply_failed_items(
+        &mut self,
+        folder_id: &str,
+        count: usize,
+        items: Result<Vec<api::FolderError>>,
+    ) {
+        let Some(modal) = &mut self.model.ui.failed_items_modal else {
+            return;
+        };
+        if modal.folder_id != folder_id {
+            return;
+        }
+
+        match items {
+            Ok(items) => {
+                modal.has_more = items.len() == count;
+                modal.selected_index = modal.selected_index.min(items.len().saturating_sub(1));
+                modal.items = items;
+            }
+            Err(e) => log_debug(&format!("Failed to refresh folder errors: {}", e)),
+        }
+    }
+
+    /// Close the modal and navigate to the selected failed item
+    pub async fn jump_to_failed_item(&mut self) {
+        let Some((folder_id, file_path)) =
+            self.model.ui.failed_items_modal.as_ref().and_then(|modal| {
+                Some((modal.folder_id.clone(), modal.selected_item()?.path.clone()))
+            })
+        else {
+            return;
+        };
+
+        self.close_failed_items();
+
+        // Navigate back to folder view, then enter the folder and jump like folder history does
+        while self.model.navigation.focus_level > 0 {
+            self.go_back();
+        }
+
+        match self.enter_folder(&folder_id).await {
+            Ok(()) => {
+                if let Err(e) = self.jump_to_file(&file_path).await {
+                    self.model
+                        .ui
+                        .show_toast(format!("Failed to navigate: {}", e));
+                }
+            }
+            Err(e) => {
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to enter folder: {}", e));
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //! - Delete files/directories
 //! - Rescan folders
 //! - Restore deleted files (receive-only folders)
+//! - Override remote changes (send-only folders)
 //! - Open files/directories with external commands
 //! - Copy paths to clipboard
 
@@ -173,6 +174,72 @@ impl App {
         Ok(())
     }
 
+    /// Ask to override the remote changes of the current send-only folder
+    ///
+    /// Uses the selected folder in folder view, or the browsed folder in breadcrumb view.
+    pub(crate) async fn override_remote_changes(&mut self) -> Result<()> {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return Ok(());
+        };
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+        let status = self.model.syncthing.folder_statuses.get(&folder.id);
+
+        if folder.folder_type != "sendonly" {
+            self.model
+                .ui
+                .show_toast(format!("{} is not a send-only folder", label));
+            return Ok(());
+        }
+        if !logic::folder::has_remote_changes(&folder.folder_type, status) {
+            self.model
+                .ui
+                .show_toast(format!("No remote changes to override in {}", label));
+            return Ok(());
+        }
+        let total = status.map(|s| s.need_total_items).unwrap_or(0);
+
+        // The items the override will replace with the local version
+        let need = self
+            .client
+            .get_needed_files(&folder.id, Some(1), Some(100))
+            .await?;
+        let changed_files = need
+            .progress
+            .iter()
+            .chain(&need.queued)
+            .chain(&need.rest)
+            .map(|file| file.name.clone())
+            .collect();
+
+        self.model.ui.confirm_action = Some(crate::model::ConfirmAction::Override {
+            folder_id: folder.id,
+            folder_label: label,
+            changed_files,
+            total,
+        });
+        Ok(())
+    }
+
+    /// Push the local state of a send-only folder over its remote changes
+    pub(crate) async fn override_folder(&mut self, folder_id: &str, folder_label: &str) {
+        match self.client.override_folder(folder_id).await {
+            Ok(()) => {
+                // The need list is about to change; don't filter on the old one
+                let _ = self.cache.invalidate_out_of_sync_categories(folder_id);
+                self.model
+                    .ui
+                    .show_toast(format!("Overriding remote changes in {}", folder_label));
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to override {}: {}", folder_id, e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to override {}: {}", folder_label, e));
+            }
+        }
+        self.refresh_folder_statuses_nonblocking();
+    }
+
     pub(crate) async fn delete_file(&mut self) -> Result<()> {
         // Only works when focused on a breadcrumb level (not folder list)
         if !logic::folder::can_delete_file(
@@ -401,77 +468,84 @@ impl App {
 
         // Copy to clipboard if we have text
         if let Some(text) = text_to_copy {
-            // Always log clipboard operations (not just in debug mode) since they can fail silently
-            use std::io::Write;
-            let log_file = crate::utils::get_debug_log_path();
-
-            if let Some(ref clipboard_cmd) = self.clipboard_command {
-                // Use user-configured clipboard command (text sent via stdin)
-                // Spawn in background and write to stdin without waiting
-                let result = std::process::Command::new(clipboard_cmd)
-                    .stdin(std::process::Stdio::piped())
-                    .stdout(std::process::Stdio::null())
-                    .stderr(std::process::Stdio::null())
-                    .spawn()
-                    .and_then(|mut child| {
-                        if let Some(mut stdin) = child.stdin.take() {
-                            stdin.write_all(text.as_bytes())?;
-                            // Close stdin to signal EOF
-                            drop(stdin);
-                        }
-                        Ok(())
-                    });
-
-                match result {
-                    Ok(_) => {
-                        let _ = std::fs::OpenOptions::new()
-                            .create(true)
-                            .append(true)
-                            .open(log_file)
-                            .and_then(|mut f| {
-                                writeln!(f, "Copied to clipboard via {}: {}", clipboard_cmd, text)
-                            });
-                        // Show toast notification with full path
-                        let toast_msg = format!("Copied to clipboard: {}", text);
-                        self.model.ui.toast_message = Some((toast_msg, Instant::now()));
-                    }
-                    Err(e) => {
-                        let _ = std::fs::OpenOptions::new()
-                            .create(true)
-                            .append(true)
-                            .open(log_file)
-                            .and_then(|mut f| {
-                                writeln!(
-                                    f,
-                                    "ERROR: Failed to execute clipboard command '{}': {}",
-                                    clipboard_cmd, e
-                                )
-                            });
-                        // Show error toast
-                        let toast_msg = format!("Error: Failed to copy with '{}'", clipboard_cmd);
-                        self.model.ui.toast_message = Some((toast_msg, Instant::now()));
+            self.copy_text_to_clipboard(&text, &text);
+        }
+
+        Ok(())
+    }
+
+    /// Send text to the configured clipboard command
+    ///
+    /// `summary` is shown in the toast (the text itself for a single path).
+    pub(crate) fn copy_text_to_clipboard(&mut self, text: &str, summary: &str) {
+        // Always log clipboard operations (not just in debug mode) since they can fail silently
+        use std::io::Write;
+        let log_file = crate::utils::get_debug_log_path();
+
+        if let Some(ref clipboard_cmd) = self.clipboard_command {
+            // Use user-configured clipboard command (text sent via stdin)
+            // Spawn in background and write to stdin without waiting
+            let result = std::process::Command::new(clipboard_cmd)
+                .stdin(std::process::Stdio::piped())
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .spawn()
+                .and_then(|mut child| {
+                    if let Some(mut stdin) = child.stdin.take() {
+                        stdin.write_all(text.as_bytes())?;
+                        // Close stdin to signal EOF
+                        drop(stdin);
                     }
+                    Ok(())
+                });
+
+            match result {
+                Ok(_) => {
+                    let _ = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(log_file)
+                        .and_then(|mut f| {
+                            writeln!(f, "Copied to clipboard via {}: {}", clipboard_cmd, text)
+                        });
+                    // Show toast notification with full path
+                    let toast_msg = format!("Copied to clipboard: {}", summary);
+                    self.model.ui.toast_message = Some((toast_msg, Instant::now()));
+                }
+                Err(e) => {
+                    let _ = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(log_file)
+                        .and_then(|mut f| {
+                            writeln!(
+                                f,
+                                "ERROR: Failed to execute clipboard command '{}': {}",
+                                clipboard_cmd, e
+                            )
+                        });
+                    // Show error toast
+                    let toast_msg = format!("Error: Failed to copy with '{}'", clipboard_cmd);
+                    self.model.ui.toast_message = Some((toast_msg, Instant::now()));
                 }
-            } else {
-                // No clipboard command configured - log message
-                let _ = std::fs::OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(log_file)
-                    .and_then(|mut f| {
-                        writeln!(
-                            f,
-                            "No clipboard_command configured - set clipboard_command in config.yaml"
-                        )
-                    });
-                // Show error toast
-                self.model.ui.toast_message = Some((
-                    "Error: clipboard_command not configured".to_string(),
-                    Instant::now(),
-                ));
             }
+        } else {
+            // No clipboard command configured - log message
+            let _ = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(log_file)
+                .and_then(|mut f| {
+                    writeln!(
+                        f,
+                        "No clipboard_command configured - set clipboard_command in config.yaml"
+                    )
+                });
+            // Show error toast
+            self.model.ui.toast_message = Some((
+                "Error: clipboard_command not configured".to_string(),
+                Instant::now(),
+            ));
         }
-
-        Ok(())
     }
 }
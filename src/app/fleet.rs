@@ -0,0 +1,150 @@
+//! Fleet overview
+//!
+//! Read-only summary of every configured profile:
+//! - Poll system status, folder statuses and connections of all instances
+//!   concurrently, in background tasks (results arrive via `fleet_update_rx`)
+//! - Re-poll periodically while the overview is open
+//! - Open the selected instance in the normal browser
+
+use crate::api::SyncthingClient;
+use crate::model::{FleetOverviewModal, FleetRow, FleetStatus, InstanceSummary};
+use crate::{App, log_debug, logic};
+use anyhow::Result;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+/// Give up on an instance after this long (shown as unreachable)
+const INSTANCE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the overview re-polls while open
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+impl App {
+    /// Open the fleet overview and start polling every profile
+    pub fn open_fleet_overview(&mut self) {
+        log_debug("Opening fleet overview");
+
+        let selected_index = self
+            .profiles
+            .iter()
+            .position(|p| p.name == self.active_profile)
+            .unwrap_or(0);
+
+        self.model.ui.fleet_overview = Some(FleetOverviewModal {
+            rows: self
+                .profiles
+                .iter()
+                .map(|p| FleetRow {
+                    profile: p.name.clone(),
+                    status: FleetStatus::Polling,
+                })
+                .collect(),
+            selected_index,
+            active: self.active_profile.clone(),
+            polled_at: Instant::now(),
+        });
+
+        self.poll_fleet();
+    }
+
+    /// Close the fleet overview
+    pub fn close_fleet_overview(&mut self) {
+        self.model.ui.fleet_overview = None;
+    }
+
+    /// Poll every profile concurrently (one background task per instance)
+    pub fn poll_fleet(&mut self) {
+        let Some(overview) = &mut self.model.ui.fleet_overview else {
+            return;
+        };
+        overview.polled_at = Instant::now();
+
+        for profile in &self.profiles {
+            let client = SyncthingClient::new(profile.base_url.clone(), profile.api_key.clone());
+            let name = profile.name.clone();
+            let tx = self.fleet_update_tx.clone();
+
+            tokio::spawn(async move {
+                let status = match tokio::time::timeout(INSTANCE_TIMEOUT, poll_instance(&client))
+                    .await
+                {
+                    Ok(Ok(summary)) => FleetStatus::Reachable(summary),
+                    Ok(Err(e)) => FleetStatus::Unreachable(logic::errors::format_error_message(&e)),
+                    Err(_) => FleetStatus::Unreachable("Timed out".to_string()),
+                };
+                let _ = tx.send((name, status));
+            });
+        }
+    }
+
+    /// Re-poll if the overview is open and the last poll is old enough
+    pub fn poll_fleet_if_due(&mut self) {
+        if self
+            .model
+            .ui
+            .fleet_overview
+            .as_ref()
+            .is_some_and(|overview| overview.polled_at.elapsed() >= POLL_INTERVAL)
+        {
+            self.poll_fleet();
+        }
+    }
+
+    /// Store the poll result of one instance
+    pub fn apply_fleet_update(&mut self, profile: &str, status: FleetStatus) {
+        let Some(overview) = &mut self.model.ui.fleet_overview else {
+            return;
+        };
+        if let Some(row) = overview.rows.iter_mut().find(|row| row.profile == profile) {
+            row.status = status;
+        }
+    }
+
+    /// Close the overview and browse the selected instance
+    pub async fn open_selected_instance(&mut self) {
+        let Some(name) = self
+            .model
+            .ui
+            .fleet_overview
+            .as_ref()
+            .and_then(|overview| overview.selected_row())
+            .map(|row| row.profile.clone())
+        else {
+            return;
+        };
+
+        self.close_fleet_overview();
+        if let Err(e) = self.switch_profile(&name).await {
+            self.model
+                .ui
+                .show_toast(format!("Failed to switch profile: {}", e));
+        }
+    }
+}
+
+/// Fetch and summarize one instance
+async fn poll_instance(client: &SyncthingClient) -> Result<InstanceSummary> {
+    let (status, connections, folders) = tokio::join!(
+        client.get_system_status(),
+        client.get_system_connections(),
+        client.get_folders()
+    );
+    let (status, connections, folders) = (status?, connections?, folders?);
+
+    let mut requests = JoinSet::new();
+    for folder in folders {
+        let client = client.clone();
+        requests.spawn(async move {
+            let folder_status = client.get_folder_status(&folder.id).await.ok();
+            (folder.paused, folder_status)
+        });
+    }
+    let folder_statuses = requests.join_all().await;
+
+    Ok(logic::fleet::summarize_instance(
+        &status.my_id,
+        status.uptime,
+        &folder_statuses,
+        &connections,
+    ))
+}
@@ -0,0 +1,92 @@
+//! Folder settings
+//!
+//! Methods for the folder settings form:
+//! - Load the folder's full config object into the form
+//! - Validate and confirm the changed settings
+//! - Write the edited object back with PUT /rest/config/folders/{id}
+
+use crate::{App, log_debug, logic, model};
+
+impl App {
+    /// Open the settings form for the selected folder
+    pub async fn open_folder_settings(&mut self) {
+        let Some(folder_id) = self.model.selected_folder().map(|f| f.id.clone()) else {
+            return;
+        };
+
+        match self.client.get_folder_config(&folder_id).await {
+            Ok(config) => {
+                self.model.ui.folder_settings =
+                    Some(logic::folder_settings::settings_form(&folder_id, config));
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to load folder config: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load folder settings: {}", e));
+            }
+        }
+    }
+
+    /// Close the settings form without saving
+    pub fn close_folder_settings(&mut self) {
+        self.model.ui.folder_settings = None;
+    }
+
+    /// Validate the form and ask to confirm the changed settings
+    pub fn request_save_folder_settings(&mut self) {
+        let Some(form) = &self.model.ui.folder_settings else {
+            return;
+        };
+
+        let updated = match logic::folder_settings::apply_settings(form) {
+            Ok(updated) => updated,
+            Err(e) => {
+                self.model.ui.show_toast(e);
+                return;
+            }
+        };
+
+        let changes = logic::folder_settings::diff_settings(&form.config, &updated);
+        if changes.is_empty() {
+            self.model.ui.show_toast("No changes to save".to_string());
+            return;
+        }
+
+        self.model.ui.confirm_action = Some(model::ConfirmAction::SaveFolderSettings {
+            folder_id: form.folder_id.clone(),
+            changes,
+        });
+    }
+
+    /// Save the settings form (called after confirmation)
+    ///
+    /// Keeps the form open and shows a toast if the API call fails.
+    pub async fn save_folder_settings(&mut self, folder_id: &str) {
+        let Some(form) = &self.model.ui.folder_settings else {
+            return;
+        };
+        let updated = match logic::folder_settings::apply_settings(form) {
+            Ok(updated) => updated,
+            Err(e) => {
+                self.model.ui.show_toast(e);
+                return;
+            }
+        };
+
+        match self.client.update_folder_config(folder_id, &updated).await {
+            Ok(()) => {
+                self.model.ui.folder_settings = None;
+                self.model
+                    .ui
+                    .show_toast("Folder settings saved".to_string());
+                // Labels and types show up in the folder list right away
+                self.reload_config().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to save folder settings: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+}
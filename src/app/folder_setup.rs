@@ -0,0 +1,229 @@
+//! Folder setup
+//!
+//! Methods for adding, sharing and removing folders:
+//! - New folder form (generated ID, host path mapped through path_map, type, devices)
+//! - Sharing checklist written back with PUT /rest/config/folders/{id}
+//! - Removal behind a typed-name confirmation
+
+use crate::{App, log_debug, logic, model};
+use std::hash::{BuildHasher, Hasher, RandomState};
+
+impl App {
+    /// Host paths of all configured folders (without trailing slash)
+    pub(crate) fn existing_host_paths(&self) -> Vec<String> {
+        self.model
+            .syncthing
+            .folders
+            .iter()
+            .map(|f| {
+                logic::path::translate_path(&f.path, "", &self.path_map)
+                    .trim_end_matches('/')
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Remote devices as a checklist, with the given ones checked
+    fn sharing_choices(
+        &self,
+        shared_with: &[crate::api::FolderDevice],
+    ) -> Vec<model::DeviceChoice> {
+        let my_id = self
+            .model
+            .syncthing
+            .system_status
+            .as_ref()
+            .map(|s| s.my_id.as_str());
+        logic::folder_setup::device_choices(&self.model.syncthing.devices, my_id, shared_with)
+    }
+
+    // ========================================
+    // NEW FOLDER
+    // ========================================
+
+    /// Open the new folder form with a generated folder ID
+    pub fn start_new_folder(&mut self) {
+        let seed = RandomState::new().build_hasher().finish();
+        let folder_id = logic::folder_setup::generate_folder_id(seed);
+        let path = logic::pending::suggest_folder_path(&self.existing_host_paths(), "", &folder_id);
+
+        self.model.ui.new_folder = Some(model::NewFolderForm {
+            label: String::new(),
+            folder_id,
+            path,
+            path_edited: false,
+            folder_type: logic::pending::FOLDER_TYPES[0].to_string(),
+            devices: self.sharing_choices(&[]),
+            device_index: 0,
+            focused_field: model::NewFolderField::Label,
+        });
+    }
+
+    /// Close the new folder form without adding anything
+    pub fn close_new_folder(&mut self) {
+        self.model.ui.new_folder = None;
+    }
+
+    /// Re-suggest the path from the label until the path is edited by hand
+    pub fn update_new_folder_path(&mut self) {
+        let existing = self.existing_host_paths();
+        if let Some(form) = &mut self.model.ui.new_folder
+            && !form.path_edited
+        {
+            form.path =
+                logic::pending::suggest_folder_path(&existing, &form.label, &form.folder_id);
+        }
+    }
+
+    /// Submit the new folder form (creates the folder config entry)
+    ///
+    /// Keeps the form open and shows a toast if validation or the API call fails.
+    pub async fn submit_new_folder(&mut self) {
+        let Some(form) = &self.model.ui.new_folder else {
+            return;
+        };
+
+        let folder = match logic::folder_setup::validate_new_folder(
+            form,
+            &self.model.syncthing.folders,
+            &self.path_map,
+        ) {
+            Ok(folder) => folder,
+            Err(e) => {
+                self.model.ui.show_toast(e);
+                return;
+            }
+        };
+
+        match self.client.add_folder(&folder).await {
+            Ok(()) => {
+                let name = folder
+                    .label
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or(folder.id);
+                self.model.ui.new_folder = None;
+                self.model.ui.show_toast(format!("Folder {} added", name));
+                self.reload_config().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to add folder: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+
+    // ========================================
+    // SHARING
+    // ========================================
+
+    /// Open the sharing checklist for the selected folder
+    pub fn open_folder_sharing(&mut self) {
+        let Some(folder) = self.model.selected_folder().cloned() else {
+            return;
+        };
+
+        let devices = self.sharing_choices(&folder.devices);
+        if devices.is_empty() {
+            self.model
+                .ui
+                .show_toast("No remote devices to share with".to_string());
+            return;
+        }
+
+        self.model.ui.folder_sharing = Some(model::FolderSharingModal {
+            label: folder.label.clone().unwrap_or_default(),
+            folder_id: folder.id,
+            devices,
+            selected_index: 0,
+        });
+    }
+
+    /// Close the sharing checklist without saving
+    pub fn close_folder_sharing(&mut self) {
+        self.model.ui.folder_sharing = None;
+    }
+
+    /// Save the sharing checklist into the folder's device list
+    ///
+    /// Round-trips the full folder config so per-device settings are kept.
+    /// Keeps the checklist open and shows a toast if an API call fails.
+    pub async fn save_folder_sharing(&mut self) {
+        let Some(modal) = &self.model.ui.folder_sharing else {
+            return;
+        };
+        let folder_id = modal.folder_id.clone();
+
+        let result = match self.client.get_folder_config(&folder_id).await {
+            Ok(config) => {
+                let updated = logic::folder_setup::apply_sharing(&config, &modal.devices);
+                self.client.update_folder_config(&folder_id, &updated).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                self.model.ui.folder_sharing = None;
+                self.model.ui.show_toast("Sharing updated".to_string());
+                self.reload_config().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to update sharing: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to update sharing: {}", e));
+            }
+        }
+    }
+
+    // ========================================
+    // REMOVAL
+    // ========================================
+
+    /// Ask to type the selected folder's name before removing it
+    pub fn start_remove_folder(&mut self) {
+        if let Some(folder) = self.model.selected_folder() {
+            self.model.ui.remove_folder = Some(logic::folder_setup::remove_folder_prompt(folder));
+        }
+    }
+
+    /// Close the removal prompt without removing anything
+    pub fn close_remove_folder(&mut self) {
+        self.model.ui.remove_folder = None;
+    }
+
+    /// Remove the folder once its name has been typed
+    ///
+    /// Only the config entry is removed; files on disk stay where they are.
+    pub async fn confirm_remove_folder(&mut self) {
+        let Some(prompt) = &self.model.ui.remove_folder else {
+            return;
+        };
+        if !logic::folder_setup::removal_confirmed(prompt) {
+            self.model
+                .ui
+                .show_toast(format!("Type \"{}\" to remove the folder", prompt.name));
+            return;
+        }
+        let prompt = prompt.clone();
+
+        match self.client.remove_folder(&prompt.folder_id).await {
+            Ok(()) => {
+                self.model.ui.remove_folder = None;
+                let _ = self.cache.invalidate_folder(&prompt.folder_id);
+                self.model
+                    .syncthing
+                    .folder_statuses
+                    .remove(&prompt.folder_id);
+                self.model
+                    .ui
+                    .show_toast(format!("Folder {} removed", prompt.name));
+                self.reload_config().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to remove folder: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+}
@@ -3,9 +3,11 @@
 //! Methods for managing .stignore patterns:
 //! - Toggle ignore state (add/remove patterns)
 //! - Ignore and delete (immediate action)
+//! - Edit the whole `.stignore` in the editor modal
 
 use crate::{App, SyncState, log_debug, logic, model, services};
 use anyhow::Result;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::Instant;
 
@@ -51,8 +53,10 @@ impl App {
             item.name.clone()
         };
 
-        // Get current ignore patterns
-        let patterns = self.client.get_ignore_patterns(&folder_id).await?;
+        // Get current ignore patterns (and those read through #include lines)
+        let ignores = self.client.get_ignores(&folder_id).await?;
+        let included = logic::ignore::included_patterns(&ignores.lines, &ignores.expanded);
+        let patterns = ignores.lines;
 
         if sync_state == SyncState::Ignored {
             // File is ignored - check if un-ignore is allowed (not pending deletion)
@@ -79,22 +83,38 @@ impl App {
                 return Ok(());
             }
 
-            // File is ignored - find matching patterns and remove them
-            let file_path = format!("/{}", relative_path);
-            let matching_patterns = logic::ignore::find_matching_patterns(&patterns, &file_path);
-
-            if matching_patterns.is_empty() {
-                return Ok(()); // No patterns match (shouldn't happen)
+            let matcher = logic::ignore::IgnoreMatcher::with_includes(&patterns, &included);
+            if let Some(verdict) = matcher.match_path(&relative_path)
+                && verdict.ignored
+                && let Some(include) = &verdict.include
+            {
+                // Included files can't be edited through the REST API
+                self.model.ui.show_toast(format!(
+                    "{} is ignored by {} from {} (line {})",
+                    relative_path, verdict.pattern, include, verdict.line
+                ));
+                return Ok(());
             }
 
-            if matching_patterns.len() == 1 {
-                // Only one pattern - remove it directly
-                let pattern_to_remove = &matching_patterns[0];
-                let updated_patterns: Vec<String> = patterns
+            // File is ignored - find the lines ignoring it (deciding line first)
+            let matches: Vec<_> =
+                logic::ignore::find_matching_patterns(&patterns, &included, &relative_path)
                     .into_iter()
-                    .filter(|p| p != pattern_to_remove)
+                    .filter(|m| m.include.is_none())
                     .collect();
 
+            if matches.is_empty() {
+                self.model
+                    .ui
+                    .show_toast(format!("No .stignore line ignores {}", relative_path));
+                return Ok(());
+            }
+
+            if matches.len() == 1 {
+                // Only one pattern - remove it directly
+                let updated_patterns = logic::ignore::remove_ignore_line(&patterns, &matches[0]);
+                self.toast_if_still_ignored(&updated_patterns, &included, &relative_path);
+
                 self.client
                     .set_ignore_patterns(&folder_id, updated_patterns)
                     .await?;
@@ -144,7 +164,8 @@ impl App {
                 self.model.ui.pattern_selection = Some(model::PatternSelectionState {
                     folder_id,
                     item_name,
-                    patterns: matching_patterns,
+                    relative_path,
+                    patterns: matches,
                     selected_index: Some(0),
                 });
             }
@@ -197,6 +218,28 @@ impl App {
         Ok(())
     }
 
+    /// Warn when removing a pattern leaves a path ignored by a later line
+    pub(crate) fn toast_if_still_ignored(
+        &mut self,
+        patterns: &[String],
+        included: &HashMap<String, Vec<String>>,
+        relative_path: &str,
+    ) {
+        if let Some(verdict) = logic::ignore::IgnoreMatcher::with_includes(patterns, included)
+            .match_path(relative_path)
+            .filter(|m| m.ignored)
+        {
+            let source = match &verdict.include {
+                Some(include) => format!(" ({})", include),
+                None => String::new(),
+            };
+            self.model.ui.show_toast(format!(
+                "Still ignored by line {}: {}{}",
+                verdict.line, verdict.pattern, source
+            ));
+        }
+    }
+
     pub(crate) async fn ignore_and_delete(&mut self) -> Result<()> {
         // Only works when focused on a breadcrumb level (not folder list)
         if self.model.navigation.focus_level == 0
@@ -346,4 +389,141 @@ impl App {
 
         Ok(())
     }
+
+    /// Open the `.stignore` editor for the selected/current folder
+    pub async fn open_ignore_editor(&mut self) {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return;
+        };
+
+        let lines = match self.client.get_ignore_patterns(&folder.id).await {
+            Ok(lines) => lines,
+            Err(e) => {
+                log_debug(&format!("Failed to load ignore patterns: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load .stignore: {}", e));
+                return;
+            }
+        };
+
+        // Every cached file and directory in the folder feeds the match preview
+        let folder_sequence = self
+            .model
+            .syncthing
+            .folder_statuses
+            .get(&folder.id)
+            .map(|status| status.sequence)
+            .unwrap_or(0);
+        let mut cached_paths: Vec<String> = self
+            .cache
+            .get_all_browse_items(&folder.id, folder_sequence)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(path, item)| {
+                let path = path.replace("//", "/");
+                if item.item_type == "FILE_INFO_TYPE_DIRECTORY" {
+                    format!("{}/", path)
+                } else {
+                    path
+                }
+            })
+            .collect();
+        cached_paths.sort();
+
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+        self.model.ui.ignore_editor = Some(model::IgnoreEditorModal::new(
+            folder.id.clone(),
+            label,
+            lines,
+            cached_paths,
+        ));
+        self.refresh_ignore_preview();
+    }
+
+    /// Recompute which cached paths the editor's current line affects
+    pub fn refresh_ignore_preview(&mut self) {
+        let Some(editor) = &mut self.model.ui.ignore_editor else {
+            return;
+        };
+
+        let key = (editor.cursor_line, editor.lines.clone());
+        if editor.preview_for.as_ref() == Some(&key) {
+            return;
+        }
+
+        let (ignored, unignored) =
+            logic::ignore::line_effect(&editor.lines, editor.cursor_line, &editor.cached_paths);
+        editor.preview_ignored = ignored;
+        editor.preview_unignored = unignored;
+        editor.preview_for = Some(key);
+    }
+
+    /// Close the `.stignore` editor, asking for a second Esc if there are unsaved changes
+    pub fn close_ignore_editor(&mut self) {
+        let Some(editor) = &mut self.model.ui.ignore_editor else {
+            return;
+        };
+
+        if editor.is_modified() && !editor.discard_armed {
+            editor.discard_armed = true;
+            self.model
+                .ui
+                .show_toast("Unsaved .stignore changes - press Esc again to discard".to_string());
+            return;
+        }
+
+        self.model.ui.ignore_editor = None;
+    }
+
+    /// Validate the edited `.stignore` and ask for confirmation with a diff
+    pub fn request_save_ignore_editor(&mut self) {
+        let Some(editor) = &self.model.ui.ignore_editor else {
+            return;
+        };
+
+        if let Some((line_no, err)) = editor.lines.iter().enumerate().find_map(|(i, line)| {
+            logic::ignore::validate_ignore_line(line)
+                .err()
+                .map(|e| (i, e))
+        }) {
+            self.model
+                .ui
+                .show_toast(format!("Line {}: {}", line_no + 1, err));
+            return;
+        }
+
+        if !editor.is_modified() {
+            self.model.ui.show_toast("No changes to save".to_string());
+            return;
+        }
+
+        self.model.ui.confirm_action = Some(model::ConfirmAction::SaveIgnorePatterns {
+            folder_id: editor.folder_id.clone(),
+            diff: logic::ignore::diff_lines(&editor.saved_lines, &editor.lines_to_save()),
+        });
+    }
+
+    /// Save the edited `.stignore` (called after confirmation)
+    pub async fn save_ignore_editor(&mut self, folder_id: &str) {
+        let Some(editor) = &self.model.ui.ignore_editor else {
+            return;
+        };
+        let lines = editor.lines_to_save();
+
+        match self.client.set_ignore_patterns(folder_id, lines).await {
+            Ok(()) => {
+                self.model.ui.ignore_editor = None;
+                // Ignore states of cached files are stale now
+                let _ = self.force_refresh_folder(folder_id);
+                self.model.ui.show_toast("Saved .stignore".to_string());
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to save ignore patterns: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to save .stignore: {}", e));
+            }
+        }
+    }
 }
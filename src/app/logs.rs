@@ -0,0 +1,127 @@
+//! Syncthing log viewer
+//!
+//! Methods for the log viewer modal and the system bar error badge:
+//! - Load `/rest/system/log` and `/rest/system/error` into the viewer
+//! - Poll for new log lines while follow mode is on
+//! - Poll errors in the background and clear them
+
+use crate::{App, api, log_debug, logic, model, services};
+use anyhow::Result;
+
+impl App {
+    /// Open the log viewer (following the newest lines)
+    pub async fn open_log_viewer(&mut self) {
+        log_debug("Opening log viewer");
+
+        let log = match self.client.get_system_log(None).await {
+            Ok(log) => log,
+            Err(e) => {
+                log_debug(&format!("Failed to load system log: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load system log: {}", e));
+                return;
+            }
+        };
+
+        let mut lines = logic::logs::to_log_lines(&log, false);
+        if let Ok(errors) = self.client.get_system_errors().await {
+            self.model.syncthing.system_errors = errors;
+        }
+        logic::logs::merge_log_lines(
+            &mut lines,
+            logic::logs::to_log_lines(&self.model.syncthing.system_errors, true),
+        );
+
+        self.model.ui.log_viewer = Some(model::LogViewerModal {
+            lines,
+            scroll_offset: 0,
+            follow: true,
+            min_level: model::LogLevel::Info,
+            search_query: String::new(),
+            search_active: false,
+        });
+        self.mark_errors_seen();
+    }
+
+    /// Close the log viewer
+    pub fn close_log_viewer(&mut self) {
+        self.model.ui.log_viewer = None;
+    }
+
+    /// Request log lines newer than the newest one shown
+    pub fn request_log_refresh(&self) {
+        let Some(viewer) = &self.model.ui.log_viewer else {
+            return;
+        };
+        let since = viewer.lines.last().map(|line| line.when.clone());
+        let _ = self
+            .api_tx
+            .send(services::api::ApiRequest::GetSystemLog { since });
+    }
+
+    /// Append fetched log lines to the viewer
+    pub fn apply_system_log(&mut self, entries: Result<Vec<api::LogEntry>>) {
+        match entries {
+            Ok(entries) => {
+                if let Some(viewer) = &mut self.model.ui.log_viewer {
+                    logic::logs::merge_log_lines(
+                        &mut viewer.lines,
+                        logic::logs::to_log_lines(&entries, false),
+                    );
+                }
+            }
+            Err(e) => log_debug(&format!("Failed to refresh system log: {}", e)),
+        }
+    }
+
+    /// Store polled errors (raises the system bar badge for new ones)
+    pub fn apply_system_errors(&mut self, errors: Result<Vec<api::LogEntry>>) {
+        match errors {
+            Ok(errors) => {
+                self.model.syncthing.system_errors = errors;
+
+                // Errors arriving while the viewer is open are seen right away
+                if let Some(viewer) = &mut self.model.ui.log_viewer {
+                    logic::logs::merge_log_lines(
+                        &mut viewer.lines,
+                        logic::logs::to_log_lines(&self.model.syncthing.system_errors, true),
+                    );
+                    self.mark_errors_seen();
+                }
+            }
+            Err(e) => log_debug(&format!("Failed to poll system errors: {}", e)),
+        }
+    }
+
+    /// Clear Syncthing's error list
+    pub async fn clear_system_errors(&mut self) {
+        match self.client.clear_system_errors().await {
+            Ok(()) => {
+                self.model.syncthing.system_errors.clear();
+                self.model.ui.show_toast("Errors cleared".to_string());
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to clear system errors: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to clear errors: {}", e));
+            }
+        }
+    }
+
+    /// Number of errors not yet seen in the log viewer (system bar badge)
+    pub fn unseen_error_count(&self) -> usize {
+        logic::logs::count_new_errors(
+            &self.model.syncthing.system_errors,
+            self.model.ui.errors_seen_until.as_deref(),
+        )
+    }
+
+    /// Remember the newest error as seen
+    fn mark_errors_seen(&mut self) {
+        if let Some(newest) = logic::logs::newest_when(&self.model.syncthing.system_errors) {
+            self.model.ui.errors_seen_until = Some(newest);
+        }
+    }
+}
@@ -11,11 +11,28 @@
 //! Methods are kept as `impl App` but organized by functional domain
 //! for better discoverability and maintainability.
 
+pub(crate) mod batch;
+pub(crate) mod config_reload;
+pub(crate) mod conflicts;
+pub(crate) mod devices;
+pub(crate) mod failed_items;
 pub(crate) mod file_ops;
 pub(crate) mod filters;
+pub(crate) mod fleet;
 pub(crate) mod folder_history;
+pub(crate) mod folder_settings;
+pub(crate) mod folder_setup;
 pub(crate) mod ignore;
+pub(crate) mod logs;
 pub(crate) mod navigation;
+pub(crate) mod pending;
 pub(crate) mod preview;
+pub(crate) mod profiles;
+pub(crate) mod rate_limits;
+pub(crate) mod recording;
+pub(crate) mod remote_completion;
 pub(crate) mod sorting;
 pub(crate) mod sync_states;
+pub(crate) mod system;
+pub(crate) mod transfers;
+pub(crate) mod versions;
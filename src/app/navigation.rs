@@ -7,6 +7,7 @@
 
 use crate::{App, SyncState, log_debug, logic, model, services};
 use anyhow::Result;
+use std::collections::HashSet;
 use std::time::Instant;
 
 impl App {
@@ -136,6 +137,8 @@ impl App {
                 file_sync_states,
                 ignored_exists,
                 filtered_items: None,
+                marked: HashSet::new(),
+                mark_anchor: None,
             }];
 
             // Only change focus if not in preview mode
@@ -332,6 +335,8 @@ impl App {
                     file_sync_states,
                     ignored_exists,
                     filtered_items: None,
+                    marked: HashSet::new(),
+                    mark_anchor: None,
                 });
 
             self.model.navigation.focus_level += 1;
@@ -486,6 +491,8 @@ impl App {
                 file_sync_states,
                 ignored_exists,
                 filtered_items: None,
+                marked: HashSet::new(),
+                mark_anchor: None,
             });
 
         self.model.navigation.focus_level = 1;
@@ -0,0 +1,341 @@
+//! Pending invitations inbox
+//!
+//! Methods for the pending invitations modal:
+//! - Load pending device and folder offers
+//! - Accept a device (adds it to the config) or a folder (via the accept form,
+//!   or by sharing a folder we already have with the offering device)
+//! - Dismiss an offer
+
+use crate::services::api::PendingOffers;
+use crate::{App, api, log_debug, logic, model, services};
+use anyhow::Result;
+
+impl App {
+    /// Open the pending invitations inbox
+    pub async fn open_pending_inbox(&mut self) {
+        log_debug("Opening pending invitations inbox");
+
+        match self.fetch_pending_invitations().await {
+            Ok((devices, folders)) => {
+                let invitations = logic::pending::build_invitations(
+                    &devices,
+                    &folders,
+                    &self.model.syncthing.devices,
+                );
+                self.model.ui.pending_inbox = Some(model::PendingInboxModal {
+                    invitations,
+                    selected_index: 0,
+                    accept_form: None,
+                });
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to load pending invitations: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load pending invitations: {}", e));
+            }
+        }
+    }
+
+    /// Close the pending invitations inbox
+    pub fn close_pending_inbox(&mut self) {
+        log_debug("Closing pending invitations inbox");
+        self.model.ui.pending_inbox = None;
+    }
+
+    /// Reload the invitation list (keeps selection and open accept form)
+    pub async fn refresh_pending_inbox(&mut self) {
+        if self.model.ui.pending_inbox.is_none() {
+            return;
+        }
+
+        let invitations = self.fetch_pending_invitations().await;
+        self.apply_pending_invitations(invitations);
+    }
+
+    /// Request an invitation list reload through the API service
+    pub fn request_pending_refresh(&self) {
+        if self.model.ui.pending_inbox.is_some() {
+            let _ = self
+                .api_tx
+                .send(services::api::ApiRequest::GetPendingInvitations);
+        }
+    }
+
+    /// Update the open inbox from fetched pending devices and folders
+    pub fn apply_pending_invitations(&mut self, invitations: Result<PendingOffers>) {
+        match invitations {
+            Ok((devices, folders)) => {
+                let invitations = logic::pending::build_invitations(
+                    &devices,
+                    &folders,
+                    &self.model.syncthing.devices,
+                );
+                if let Some(inbox) = &mut self.model.ui.pending_inbox {
+                    inbox.selected_index = inbox
+                        .selected_index
+                        .min(invitations.len().saturating_sub(1));
+                    inbox.invitations = invitations;
+                }
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to refresh pending invitations: {}", e));
+            }
+        }
+    }
+
+    /// Fetch pending devices and folders
+    async fn fetch_pending_invitations(&self) -> Result<PendingOffers> {
+        let devices = self.client.get_pending_devices().await?;
+        let folders = self.client.get_pending_folders().await?;
+        Ok((devices, folders))
+    }
+
+    /// Accept the selected invitation
+    ///
+    /// Devices are added right away; new folders open the accept form so the
+    /// user can choose a local path and folder type first. Offers for folders
+    /// that already exist only add the offering device to them.
+    pub async fn accept_selected_invitation(&mut self) {
+        let Some(invitation) = self
+            .model
+            .ui
+            .pending_inbox
+            .as_ref()
+            .and_then(|inbox| inbox.selected_invitation().cloned())
+        else {
+            return;
+        };
+
+        match invitation {
+            model::PendingInvitation::Device {
+                device_id, name, ..
+            } => {
+                let device = api::DeviceConfig {
+                    device_id,
+                    name,
+                    addresses: vec!["dynamic".to_string()],
+                    compression: "metadata".to_string(),
+                    introducer: false,
+                    paused: false,
+                    max_send_kbps: 0,
+                    max_recv_kbps: 0,
+                };
+                let display_name = if device.name.is_empty() {
+                    logic::devices::short_device_id(&device.device_id).to_string()
+                } else {
+                    device.name.clone()
+                };
+
+                match self.client.add_device(&device).await {
+                    Ok(()) => {
+                        self.model
+                            .ui
+                            .show_toast(format!("Device {} added", display_name));
+                        self.reload_after_invitation_change().await;
+                    }
+                    Err(e) => {
+                        log_debug(&format!("Failed to accept device: {}", e));
+                        self.model
+                            .ui
+                            .show_toast(format!("Failed to accept device: {}", e));
+                    }
+                }
+            }
+            model::PendingInvitation::Folder {
+                folder_id,
+                label,
+                device_id,
+                device_name,
+                ..
+            } => {
+                // Offer for a folder we already have: only start sharing it
+                if self.model.syncthing.get_folder(&folder_id).is_some() {
+                    self.share_existing_folder(&folder_id, &device_id, &device_name)
+                        .await;
+                    return;
+                }
+
+                let existing_paths = self.existing_host_paths();
+                let path = logic::pending::suggest_folder_path(&existing_paths, &label, &folder_id);
+
+                if let Some(inbox) = &mut self.model.ui.pending_inbox {
+                    inbox.accept_form = Some(model::AcceptFolderForm {
+                        folder_id,
+                        label,
+                        device_id,
+                        path,
+                        folder_type: logic::pending::FOLDER_TYPES[0].to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Submit the folder accept form (creates the folder config entry)
+    ///
+    /// Keeps the form open and shows a toast if the API call fails.
+    pub async fn submit_accept_folder_form(&mut self) {
+        let Some(form) = self
+            .model
+            .ui
+            .pending_inbox
+            .as_ref()
+            .and_then(|inbox| inbox.accept_form.clone())
+        else {
+            return;
+        };
+
+        // The folder may have been added since the form was opened
+        if self.model.syncthing.get_folder(&form.folder_id).is_some() {
+            let device_name = logic::devices::short_device_id(&form.device_id).to_string();
+            self.share_existing_folder(&form.folder_id, &form.device_id, &device_name)
+                .await;
+            return;
+        }
+
+        let host_path = form.path.trim();
+        if host_path.is_empty() {
+            self.model
+                .ui
+                .show_toast("Folder path is required".to_string());
+            return;
+        }
+
+        // Syncthing may run in a container, so map the host path back
+        let container_path = logic::path::reverse_translate_path(host_path, &self.path_map);
+
+        let folder = api::Folder {
+            id: form.folder_id.clone(),
+            label: Some(form.label.clone()),
+            path: container_path,
+            paused: false,
+            folder_type: form.folder_type.clone(),
+            devices: vec![api::FolderDevice {
+                device_id: form.device_id.clone(),
+            }],
+        };
+
+        let display_name = if form.label.is_empty() {
+            form.folder_id.clone()
+        } else {
+            form.label.clone()
+        };
+
+        match self.client.add_folder(&folder).await {
+            Ok(()) => {
+                self.model
+                    .ui
+                    .show_toast(format!("Folder {} added", display_name));
+                if let Some(inbox) = &mut self.model.ui.pending_inbox {
+                    inbox.accept_form = None;
+                }
+                self.reload_after_invitation_change().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to accept folder: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+
+    /// Accept an offer for a folder that already exists locally
+    ///
+    /// Adds the offering device to the folder's config; the path, type and
+    /// the devices it is already shared with are kept.
+    async fn share_existing_folder(&mut self, folder_id: &str, device_id: &str, device_name: &str) {
+        let choice = model::DeviceChoice {
+            device_id: device_id.to_string(),
+            name: device_name.to_string(),
+            selected: true,
+        };
+
+        let result = match self.client.get_folder_config(folder_id).await {
+            Ok(config) => {
+                let updated = logic::folder_setup::apply_sharing(&config, &[choice]);
+                self.client.update_folder_config(folder_id, &updated).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                self.model.ui.show_toast(format!(
+                    "Folder {} is now shared with {}",
+                    folder_id, device_name
+                ));
+                if let Some(inbox) = &mut self.model.ui.pending_inbox {
+                    inbox.accept_form = None;
+                }
+                self.reload_after_invitation_change().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to share existing folder: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to share folder: {}", e));
+            }
+        }
+    }
+
+    /// Dismiss the selected invitation
+    pub async fn dismiss_selected_invitation(&mut self) {
+        let Some(invitation) = self
+            .model
+            .ui
+            .pending_inbox
+            .as_ref()
+            .and_then(|inbox| inbox.selected_invitation().cloned())
+        else {
+            return;
+        };
+
+        let result = match &invitation {
+            model::PendingInvitation::Device { device_id, .. } => {
+                self.client.dismiss_pending_device(device_id).await
+            }
+            model::PendingInvitation::Folder {
+                folder_id,
+                device_id,
+                ..
+            } => {
+                self.client
+                    .dismiss_pending_folder(folder_id, device_id)
+                    .await
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                self.model.ui.show_toast("Invitation dismissed".to_string());
+                self.refresh_pending_inbox().await;
+            }
+            Err(e) => {
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to dismiss invitation: {}", e));
+            }
+        }
+    }
+
+    /// Refresh everything that depends on the config after accepting an offer
+    async fn reload_after_invitation_change(&mut self) {
+        match self.client.get_devices().await {
+            Ok(devices) => self.model.syncthing.devices = devices,
+            Err(e) => log_debug(&format!("Failed to reload devices: {}", e)),
+        }
+
+        // Accepted folders show up in the folder list right away
+        match self.client.get_folders().await {
+            Ok(folders) => {
+                let _ = self.cache.save_folders(&folders);
+                self.model.syncthing.folders = folders;
+                self.refresh_folder_statuses_nonblocking();
+            }
+            Err(e) => log_debug(&format!("Failed to reload folders: {}", e)),
+        }
+
+        self.refresh_pending_inbox().await;
+        self.refresh_device_count().await;
+    }
+}
@@ -78,9 +78,9 @@ impl App {
             let host_path_buf = std::path::PathBuf::from(&host_path);
             let exists = tokio::fs::metadata(&host_path_buf).await.is_ok();
 
-            if exists && self.image_picker.is_some() {
+            if let (true, Some(picker)) = (exists, self.image_picker.as_ref()) {
                 // Spawn background task to load image
-                let picker = self.image_picker.as_ref().unwrap().clone();
+                let picker = picker.clone();
                 let image_tx = self.image_update_tx.clone();
                 let image_file_path = file_path.clone();
 
@@ -309,7 +309,7 @@ impl App {
         Ok((protocol, metadata))
     }
 
-    async fn read_file_content_static(
+    pub(crate) async fn read_file_content_static(
         path_map: &HashMap<String, String>,
         folder: &Folder,
         relative_path: &str,
@@ -0,0 +1,200 @@
+//! Syncthing instance profiles
+//!
+//! Methods for switching between the instances configured in `profiles`:
+//! - Start the client, cache and background services of an instance
+//! - Open the profile switcher
+//! - Tear down the current instance and connect to another one
+
+use crate::api::SyncthingClient;
+use crate::cache::CacheDb;
+use crate::config::Profile;
+use crate::services::api::{ApiRequest, ApiResponse};
+use crate::services::events::CacheInvalidation;
+use crate::{App, log_debug, model, services};
+use anyhow::{Result, anyhow};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+/// Client, cache and running background services of one instance
+pub(crate) struct InstanceServices {
+    pub client: SyncthingClient,
+    pub cache: CacheDb,
+    pub api_tx: UnboundedSender<ApiRequest>,
+    pub api_rx: UnboundedReceiver<ApiResponse>,
+    pub invalidation_rx: UnboundedReceiver<CacheInvalidation>,
+    pub event_id_rx: UnboundedReceiver<u64>,
+    pub event_listener: JoinHandle<()>,
+}
+
+impl InstanceServices {
+    /// Open the instance's cache and spawn its API service and event listener
+    pub(crate) async fn start(profile: &Profile) -> Result<Self> {
+        let client = SyncthingClient::new(profile.base_url.clone(), profile.api_key.clone());
+        let cache = CacheDb::for_instance(&client, profile.top_level).await?;
+
+        // Event IDs are per instance, so they live in the instance's cache
+        let last_event_id = cache.get_last_event_id().unwrap_or(0);
+
+        Ok(Self::spawn(profile, client, cache, last_event_id))
+    }
+
+    /// Connect to a replay server with a throwaway cache
+    ///
+    /// The replayed events start from the beginning of the recording and must
+    /// not touch the recorded instance's cache.
+    pub(crate) fn start_replay(profile: &Profile) -> Result<Self> {
+        let client = SyncthingClient::new(profile.base_url.clone(), profile.api_key.clone());
+        let cache = CacheDb::new_in_memory()?;
+        Ok(Self::spawn(profile, client, cache, 0))
+    }
+
+    fn spawn(
+        profile: &Profile,
+        client: SyncthingClient,
+        cache: CacheDb,
+        last_event_id: u64,
+    ) -> Self {
+        // Spawn API service worker
+        let (api_tx, api_rx) = services::api::spawn_api_service(client.clone());
+
+        let (invalidation_tx, invalidation_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (event_id_tx, event_id_rx) = tokio::sync::mpsc::unbounded_channel();
+        let event_listener = services::events::spawn_event_listener(
+            profile.base_url.clone(),
+            profile.api_key.clone(),
+            last_event_id,
+            invalidation_tx,
+            event_id_tx,
+        );
+
+        Self {
+            client,
+            cache,
+            api_tx,
+            api_rx,
+            invalidation_rx,
+            event_id_rx,
+            event_listener,
+        }
+    }
+}
+
+impl App {
+    /// Open the profile switcher
+    pub fn open_profile_switcher(&mut self) {
+        if self.event_session.is_some() {
+            self.model.ui.show_toast(
+                "Profile switching is disabled while recording or replaying events".to_string(),
+            );
+            return;
+        }
+        if self.profiles.len() < 2 {
+            self.model.ui.show_toast(
+                "Only one Syncthing instance configured - add profiles to config.yaml".to_string(),
+            );
+            return;
+        }
+
+        let selected_index = self
+            .profiles
+            .iter()
+            .position(|p| p.name == self.active_profile)
+            .unwrap_or(0);
+
+        self.model.ui.profile_switcher = Some(model::ProfileSwitcherModal {
+            profiles: self
+                .profiles
+                .iter()
+                .map(|p| model::ProfileEntry {
+                    name: p.name.clone(),
+                    base_url: p.base_url.clone(),
+                })
+                .collect(),
+            selected_index,
+            active: self.active_profile.clone(),
+        });
+    }
+
+    /// Close the profile switcher
+    pub fn close_profile_switcher(&mut self) {
+        self.model.ui.profile_switcher = None;
+    }
+
+    /// Disconnect from the current instance and connect to another profile
+    ///
+    /// The API service stops once its request sender is dropped and the event
+    /// listener is aborted. View preferences (sort, display mode) are kept;
+    /// everything else is reloaded from the new instance.
+    pub async fn switch_profile(&mut self, name: &str) -> Result<()> {
+        if name == self.active_profile {
+            return Ok(());
+        }
+        let profile = self
+            .profiles
+            .iter()
+            .find(|p| p.name == name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown profile '{}'", name))?;
+
+        log_debug(&format!(
+            "Switching profile: {} -> {} ({})",
+            self.active_profile, profile.name, profile.base_url
+        ));
+
+        // Writes belong to the old instance's cache
+        self.flush_pending_db_writes();
+
+        let instance = InstanceServices::start(&profile).await?;
+
+        if let Some(listener) = self.event_listener.take() {
+            listener.abort();
+        }
+        self.client = instance.client;
+        self.cache = instance.cache;
+        self.api_tx = instance.api_tx;
+        self.api_rx = instance.api_rx;
+        self.invalidation_rx = instance.invalidation_rx;
+        self.event_id_rx = instance.event_id_rx;
+        self.event_listener = Some(instance.event_listener);
+
+        self.path_map = profile.path_map;
+        self.open_command = profile.open_command;
+        self.clipboard_command = profile.clipboard_command;
+        self.base_url = profile.base_url;
+        self.active_profile = profile.name;
+
+        // Fresh model for the new instance, keeping view preferences
+        let old_ui = &self.model.ui;
+        let mut model = model::Model::new(old_ui.vim_mode);
+        model.ui.sort_mode = old_ui.sort_mode;
+        model.ui.sort_reverse = old_ui.sort_reverse;
+        model.ui.display_mode = old_ui.display_mode;
+        model.ui.config_path = old_ui.config_path.clone();
+        model.ui.image_font_size = old_ui.image_font_size;
+        model.ui.keymap = old_ui.keymap.clone();
+        model.ui.active_profile = Some(self.active_profile.clone());
+        self.model = model;
+        self.image_state_map.clear();
+
+        let now = Instant::now();
+        self.last_status_update = now;
+        self.last_system_status_update = now;
+        self.last_connection_stats_fetch = now;
+        self.last_error_poll = now;
+        self.last_log_poll = now;
+        self.last_directory_update = now;
+        self.last_reconnect_attempt = now;
+        self.reconnect_delay = Duration::from_secs(5);
+
+        self.load_instance().await;
+        self.refresh_device_count().await;
+        self.refresh_rate_limits().await;
+        self.refresh_restart_required().await;
+
+        self.model
+            .ui
+            .show_toast(format!("Connected to profile '{}'", self.active_profile));
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Rate limits
+//!
+//! Methods for the bandwidth limit popup:
+//! - Load global (options) and per-device limits
+//! - Apply a preset or custom limit to the selected row
+//! - Keep the system bar indicator up to date
+
+use crate::{App, api, log_debug, logic, model};
+use anyhow::Result;
+
+impl App {
+    /// Fetch global limits and device configs, and update the indicator
+    ///
+    /// Refreshes the popup rows too (keeping the selection) when it is open.
+    pub async fn refresh_rate_limits(&mut self) {
+        let limits = self.fetch_rate_limits().await;
+        self.apply_rate_limits(limits);
+    }
+
+    /// Update the indicator (and the open popup) from fetched limits
+    pub fn apply_rate_limits(&mut self, limits: Result<(api::RateLimits, Vec<api::DeviceConfig>)>) {
+        match limits {
+            Ok((global, devices)) => {
+                let (rows, limited_devices) = self.rate_limit_rows(global, &devices);
+                self.model.syncthing.global_rate_limits = rows[0].limits;
+                self.model.syncthing.rate_limited_devices = limited_devices;
+                if let Some(modal) = &mut self.model.ui.rate_limits {
+                    modal.selected_index = modal.selected_index.min(rows.len() - 1);
+                    modal.rows = rows;
+                }
+            }
+            Err(e) => log_debug(&format!("Failed to load rate limits: {}", e)),
+        }
+    }
+
+    /// Fetch global limits and device configs
+    async fn fetch_rate_limits(&self) -> Result<(api::RateLimits, Vec<api::DeviceConfig>)> {
+        let global = self.client.get_global_rate_limits().await?;
+        let devices = self.client.get_config_devices().await?;
+        Ok((global, devices))
+    }
+
+    /// Popup rows (global row first) and the number of limited devices
+    fn rate_limit_rows(
+        &self,
+        global: api::RateLimits,
+        devices: &[api::DeviceConfig],
+    ) -> (Vec<model::RateLimitRow>, usize) {
+        let my_id = self
+            .model
+            .syncthing
+            .system_status
+            .as_ref()
+            .map(|s| s.my_id.as_str());
+        (
+            logic::rate_limits::build_rows(global, devices, my_id),
+            logic::rate_limits::count_limited_devices(devices, my_id),
+        )
+    }
+
+    /// Open the rate limit popup
+    pub async fn open_rate_limits(&mut self) {
+        match self.fetch_rate_limits().await {
+            Ok((global, devices)) => {
+                let (rows, limited_devices) = self.rate_limit_rows(global, &devices);
+                self.model.syncthing.global_rate_limits = rows[0].limits;
+                self.model.syncthing.rate_limited_devices = limited_devices;
+                self.model.ui.rate_limits = Some(model::RateLimitModal {
+                    rows,
+                    selected_index: 0,
+                    direction: model::RateLimitDirection::Both,
+                    custom_input: None,
+                });
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to load rate limits: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load rate limits: {}", e));
+            }
+        }
+    }
+
+    /// Close the rate limit popup
+    pub fn close_rate_limits(&mut self) {
+        self.model.ui.rate_limits = None;
+    }
+
+    /// Set the selected row's limit (KiB/s, 0 = unlimited) in the chosen direction
+    pub async fn set_rate_limit(&mut self, kbps: u64) {
+        let Some(modal) = &self.model.ui.rate_limits else {
+            return;
+        };
+        let Some(row) = modal.selected_row().cloned() else {
+            return;
+        };
+        let limits = logic::rate_limits::apply_limit(row.limits, modal.direction, kbps);
+
+        let result = match &row.device_id {
+            Some(device_id) => self.client.set_device_rate_limits(device_id, &limits).await,
+            None => self.client.set_global_rate_limits(&limits).await,
+        };
+
+        match result {
+            Ok(()) => {
+                let symbols = self.icon_renderer.symbols();
+                self.model.ui.show_toast(format!(
+                    "{}: {}{} {}{}",
+                    row.name,
+                    symbols.download,
+                    logic::rate_limits::format_limit(limits.max_recv_kbps, &symbols),
+                    symbols.upload,
+                    logic::rate_limits::format_limit(limits.max_send_kbps, &symbols)
+                ));
+                self.refresh_rate_limits().await;
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to set rate limit: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+
+    /// Apply the custom limit being typed
+    pub async fn submit_custom_rate_limit(&mut self) {
+        let Some(input) = self
+            .model
+            .ui
+            .rate_limits
+            .as_mut()
+            .and_then(|modal| modal.custom_input.take())
+        else {
+            return;
+        };
+
+        match logic::rate_limits::parse_custom_limit(&input) {
+            Ok(kbps) => self.set_rate_limit(kbps).await,
+            Err(e) => {
+                // Keep the input so it can be corrected
+                if let Some(modal) = &mut self.model.ui.rate_limits {
+                    modal.custom_input = Some(input);
+                }
+                self.model.ui.show_toast(e);
+            }
+        }
+    }
+}
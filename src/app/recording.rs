@@ -0,0 +1,107 @@
+//! Event recording and replay
+//!
+//! `--record-events` puts a recording proxy between stui and the instance,
+//! `--replay-events` connects to a replay server instead of the instance.
+//! Either one lasts for the whole session, so profile switching is disabled.
+
+use crate::App;
+use crate::config::Profile;
+use anyhow::Result;
+use std::path::PathBuf;
+use stui::mock::{Recorder, Recording, RecordingProxy, ReplayServer};
+
+/// How the session talks to Syncthing
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SessionMode {
+    Live,
+    Record(PathBuf),
+    Replay { path: PathBuf, speed: f64 },
+}
+
+/// A recording or replay in progress (dropping it stops the proxy/server)
+pub(crate) enum EventSession {
+    Recording {
+        _proxy: RecordingProxy,
+        path: PathBuf,
+    },
+    Replay {
+        server: ReplayServer,
+        path: PathBuf,
+        speed: f64,
+        /// Set once the end of the replay was announced
+        finished: bool,
+    },
+}
+
+impl EventSession {
+    /// Start recording or replaying and point `profile` at the proxy/server
+    pub(crate) async fn start(mode: SessionMode, profile: &mut Profile) -> Result<Option<Self>> {
+        match mode {
+            SessionMode::Live => Ok(None),
+            SessionMode::Record(path) => {
+                let recorder = Recorder::create(&path)?;
+                let proxy = RecordingProxy::start(&profile.base_url, recorder).await?;
+                profile.base_url = proxy.url();
+                Ok(Some(Self::Recording {
+                    _proxy: proxy,
+                    path,
+                }))
+            }
+            SessionMode::Replay { path, speed } => {
+                let recording = Recording::load(&path)?;
+                let server = ReplayServer::start(recording, speed).await?;
+                profile.base_url = server.url();
+                Ok(Some(Self::Replay {
+                    server,
+                    path,
+                    speed,
+                    finished: false,
+                }))
+            }
+        }
+    }
+
+    pub(crate) fn is_replay(&self) -> bool {
+        matches!(self, Self::Replay { .. })
+    }
+
+    /// Toast shown when the session starts
+    pub(crate) fn description(&self) -> String {
+        match self {
+            Self::Recording { path, .. } => {
+                format!("Recording events to {}", path.display())
+            }
+            Self::Replay { path, speed, .. } => {
+                format!("Replaying {} at {}x", path.display(), speed)
+            }
+        }
+    }
+
+    /// True exactly once: the first time a replay has released all of its events
+    fn take_replay_finished(&mut self) -> bool {
+        match self {
+            Self::Replay {
+                server, finished, ..
+            } if !*finished && server.is_finished() => {
+                *finished = true;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl App {
+    /// Announce the end of a replay (the UI keeps running on the last responses)
+    pub fn check_replay_finished(&mut self) {
+        if self
+            .event_session
+            .as_mut()
+            .is_some_and(|session| session.take_replay_finished())
+        {
+            self.model
+                .ui
+                .show_toast("Replay finished - all recorded events delivered".to_string());
+        }
+    }
+}
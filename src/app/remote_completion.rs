@@ -0,0 +1,296 @@
+//! Remote device completion
+//!
+//! Methods for the remote completion modal:
+//! - Fetch `/rest/db/completion` for every device a folder is shared with
+//! - Reload when a `FolderCompletion` event arrives for the folder
+//! - Drill into a device to page through `/rest/db/remoteneed`
+
+use crate::api::{FileInfo, Folder};
+use crate::logic::completion::{
+    DeviceCompletion, REMOTE_NEED_PAGE_SIZE, folder_devices, has_more_pages,
+};
+use crate::logic::folder_errors::should_load_more;
+use crate::services::api::{ApiRequest, ApiService};
+use crate::{App, log_debug, model};
+use anyhow::Result;
+
+impl App {
+    /// Open the remote completion modal for the current folder
+    ///
+    /// Uses the selected folder in folder view, or the browsed folder in breadcrumb view.
+    pub async fn open_remote_completion(&mut self) {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return;
+        };
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+
+        let devices = match self.load_device_completions(&folder).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                log_debug(&format!("Failed to load device completion: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load device completion: {}", e));
+                return;
+            }
+        };
+
+        if devices.is_empty() {
+            self.model
+                .ui
+                .show_toast(format!("{} is not shared with any device", label));
+            return;
+        }
+
+        self.model.ui.remote_completion = Some(model::RemoteCompletionModal {
+            folder_id: folder.id,
+            folder_label: label,
+            devices,
+            selected_index: 0,
+            remote_need: None,
+        });
+    }
+
+    /// Close the remote completion modal
+    pub fn close_remote_completion(&mut self) {
+        self.model.ui.remote_completion = None;
+    }
+
+    /// Move the device selection
+    pub fn select_remote_device(&mut self, index: usize) {
+        if let Some(modal) = &mut self.model.ui.remote_completion {
+            modal.selected_index = index.min(modal.devices.len().saturating_sub(1));
+        }
+    }
+
+    /// Reload the modal on the next loop iteration if it shows `folder_id`
+    pub fn flag_remote_completion_refresh(&mut self, folder_id: &str) {
+        if self
+            .model
+            .ui
+            .remote_completion
+            .as_ref()
+            .is_some_and(|modal| modal.folder_id == folder_id)
+        {
+            self.model.ui.needs_remote_completion_refresh = true;
+        }
+    }
+
+    /// Request the completion of every device, and the open remote-need list
+    pub fn request_remote_completion_refresh(&mut self) {
+        let Some(modal) = &self.model.ui.remote_completion else {
+            return;
+        };
+        let Some(folder) = self
+            .model
+            .syncthing
+            .folders
+            .iter()
+            .find(|f| f.id == modal.folder_id)
+        else {
+            // Folder was removed
+            self.close_remote_completion();
+            return;
+        };
+
+        let _ = self.api_tx.send(ApiRequest::GetRemoteCompletion {
+            folder_id: folder.id.clone(),
+            devices: self.sharing_devices(folder),
+        });
+        if let Some(list) = &modal.remote_need {
+            let _ = self.api_tx.send(ApiRequest::GetRemoteNeed {
+                folder_id: modal.folder_id.clone(),
+                device_id: list.device_id.clone(),
+                count: list.pages_loaded.max(1) * REMOTE_NEED_PAGE_SIZE,
+            });
+        }
+    }
+
+    /// Update the card and the open modal from fetched device completions
+    pub fn apply_remote_completion(
+        &mut self,
+        folder_id: &str,
+        devices: Result<Vec<DeviceCompletion>>,
+    ) {
+        match devices {
+            Ok(devices) => {
+                self.update_card_completion(folder_id, &devices);
+                if let Some(modal) = &mut self.model.ui.remote_completion
+                    && modal.folder_id == folder_id
+                {
+                    modal.selected_index =
+                        modal.selected_index.min(devices.len().saturating_sub(1));
+                    modal.devices = devices;
+                }
+            }
+            Err(e) => log_debug(&format!("Failed to refresh device completion: {}", e)),
+        }
+    }
+
+    /// List the files the selected device still needs
+    pub async fn open_remote_need(&mut self) {
+        let Some((folder_id, device)) =
+            self.model.ui.remote_completion.as_ref().and_then(|modal| {
+                Some((modal.folder_id.clone(), modal.selected_device()?.clone()))
+            })
+        else {
+            return;
+        };
+
+        let files = match self
+            .client
+            .get_remote_need(&folder_id, &device.device_id, 1, REMOTE_NEED_PAGE_SIZE)
+            .await
+        {
+            Ok(files) => files,
+            Err(e) => {
+                log_debug(&format!("Failed to load remote need: {}", e));
+                self.model.ui.show_toast(format!(
+                    "Failed to load files needed by {}: {}",
+                    device.name, e
+                ));
+                return;
+            }
+        };
+
+        if files.is_empty() {
+            self.model
+                .ui
+                .show_toast(format!("{} doesn't need any files", device.name));
+            return;
+        }
+
+        if let Some(modal) = &mut self.model.ui.remote_completion {
+            modal.remote_need = Some(model::RemoteNeedList {
+                device_id: device.device_id,
+                device_name: device.name,
+                has_more: has_more_pages(files.len()),
+                files,
+                selected_index: 0,
+                pages_loaded: 1,
+            });
+        }
+    }
+
+    /// Go back from the remote-need list to the devices
+    pub fn close_remote_need(&mut self) {
+        if let Some(modal) = &mut self.model.ui.remote_completion {
+            modal.remote_need = None;
+        }
+    }
+
+    /// Move the remote-need selection, loading the next page when nearing the end
+    pub async fn select_remote_need_file(&mut self, index: usize) {
+        let Some(modal) = &mut self.model.ui.remote_completion else {
+            return;
+        };
+        let folder_id = modal.folder_id.clone();
+        let Some(list) = &mut modal.remote_need else {
+            return;
+        };
+        list.selected_index = index.min(list.files.len().saturating_sub(1));
+
+        if !should_load_more(list.selected_index, list.files.len(), list.has_more) {
+            return;
+        }
+
+        let device_id = list.device_id.clone();
+        let page = list.pages_loaded + 1;
+        let result = self
+            .client
+            .get_remote_need(&folder_id, &device_id, page, REMOTE_NEED_PAGE_SIZE)
+            .await;
+
+        let Some(list) = self
+            .model
+            .ui
+            .remote_completion
+            .as_mut()
+            .and_then(|modal| modal.remote_need.as_mut())
+        else {
+            return;
+        };
+        match result {
+            Ok(files) => {
+                list.has_more = has_more_pages(files.len());
+                list.pages_loaded = page;
+                list.files.extend(files);
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to load remote need page {}: {}", page, e));
+                list.has_more = false;
+            }
+        }
+    }
+
+    /// Replace the open remote-need list with the first `count` fetched files
+    pub fn apply_remote_need(
+        &mut self,
+        folder_id: &str,
+        device_id: &str,
+        count: usize,
+        files: Result<Vec<FileInfo>>,
+    ) {
+        let Some(list) = self
+            .model
+            .ui
+            .remote_completion
+            .as_mut()
+            .filter(|modal| modal.folder_id == folder_id)
+            .and_then(|modal| modal.remote_need.as_mut())
+            .filter(|list| list.device_id == device_id)
+        else {
+            return;
+        };
+
+        match files {
+            Ok(files) => {
+                list.has_more = files.len() == count;
+                list.selected_index = list.selected_index.min(files.len().saturating_sub(1));
+                list.files = files;
+            }
+            Err(e) => log_debug(&format!("Failed to refresh remote need: {}", e)),
+        }
+    }
+
+    /// Fetch the completion of every remote device sharing `folder`
+    ///
+    /// A device whose completion can't be fetched is listed without one. The
+    /// results also update the remote completion shown on the folder card.
+    async fn load_device_completions(&mut self, folder: &Folder) -> Result<Vec<DeviceCompletion>> {
+        let devices = self.sharing_devices(folder);
+        let completions =
+            ApiService::get_device_completions(&self.client, &folder.id, devices).await?;
+        self.update_card_completion(&folder.id, &completions);
+        Ok(completions)
+    }
+
+    /// Remote devices `folder` is shared with, as (device ID, name) pairs
+    fn sharing_devices(&self, folder: &Folder) -> Vec<(String, String)> {
+        let my_device_id = self
+            .model
+            .syncthing
+            .system_status
+            .as_ref()
+            .map(|s| s.my_id.as_str());
+        folder_devices(folder, my_device_id, &self.model.syncthing.devices)
+    }
+
+    /// Store fetched completions for the folder card
+    fn update_card_completion(&mut self, folder_id: &str, completions: &[DeviceCompletion]) {
+        let card = self
+            .model
+            .syncthing
+            .remote_completion
+            .entry(folder_id.to_string())
+            .or_default();
+        for device in completions {
+            match &device.completion {
+                Some(completion) if completion.remote_state != "notSharing" => {
+                    card.insert(device.device_id.clone(), completion.completion);
+                }
+                _ => {}
+            }
+        }
+    }
+}
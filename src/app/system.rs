@@ -0,0 +1,123 @@
+//! System control
+//!
+//! Methods for the system menu:
+//! - Show the Syncthing version, OS and architecture
+//! - Restart or shut down Syncthing (after a confirmation)
+//! - Track whether saved config changes wait for a restart
+
+use crate::{App, log_debug, model};
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+/// Retry delay while waiting for Syncthing to come back after a restart
+const RESTART_RETRY: Duration = Duration::from_secs(2);
+
+impl App {
+    /// Check whether saved config changes only take effect after a restart
+    pub async fn refresh_restart_required(&mut self) {
+        let required = self.client.get_restart_required().await;
+        self.apply_restart_required(required);
+    }
+
+    /// Store a fetched restart-required flag (drives the restart banner)
+    pub fn apply_restart_required(&mut self, required: Result<bool>) {
+        match required {
+            Ok(required) => self.model.syncthing.restart_required = required,
+            Err(e) => log_debug(&format!("Failed to check restart status: {}", e)),
+        }
+    }
+
+    /// Open the system menu
+    pub async fn open_system_menu(&mut self) {
+        let version = match self.client.get_system_version().await {
+            Ok(version) => Some(version),
+            Err(e) => {
+                log_debug(&format!("Failed to load system version: {}", e));
+                None
+            }
+        };
+        self.refresh_restart_required().await;
+
+        self.model.ui.system_menu = Some(model::SystemMenu {
+            version,
+            selected_index: 0,
+        });
+    }
+
+    /// Close the system menu
+    pub fn close_system_menu(&mut self) {
+        self.model.ui.system_menu = None;
+    }
+
+    /// Ask to confirm the selected system menu entry
+    pub fn select_system_menu_item(&mut self) {
+        let Some(item) = self
+            .model
+            .ui
+            .system_menu
+            .as_ref()
+            .and_then(|menu| menu.selected_item())
+        else {
+            return;
+        };
+
+        let device_name = self
+            .model
+            .syncthing
+            .device_name
+            .clone()
+            .unwrap_or_else(|| "this device".to_string());
+        self.model.ui.system_menu = None;
+        self.model.ui.confirm_action = Some(match item {
+            model::SystemMenuItem::Restart => {
+                model::ConfirmAction::RestartSyncthing { device_name }
+            }
+            model::SystemMenuItem::Shutdown => {
+                model::ConfirmAction::ShutdownSyncthing { device_name }
+            }
+        });
+    }
+
+    /// Restart Syncthing and wait for it to come back
+    ///
+    /// The background reconnection takes over from here: it retries every
+    /// two seconds and the system bar shows "Restarting" instead of errors
+    /// until the new process answers.
+    pub async fn restart_syncthing(&mut self) {
+        match self.client.restart().await {
+            Ok(()) => {
+                self.model.syncthing.begin_restart(RESTART_RETRY.as_secs());
+                self.reconnect_delay = RESTART_RETRY;
+                self.last_reconnect_attempt = Instant::now();
+                self.model
+                    .ui
+                    .show_toast("Restarting Syncthing...".to_string());
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to restart Syncthing: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+
+    /// Shut Syncthing down
+    ///
+    /// stui keeps running and reconnects if Syncthing is started again.
+    pub async fn shutdown_syncthing(&mut self) {
+        match self.client.shutdown().await {
+            Ok(()) => {
+                self.model.syncthing.connection_state =
+                    model::syncthing::ConnectionState::Disconnected {
+                        error_type: crate::logic::errors::ErrorType::Other,
+                        message: "Syncthing was shut down".to_string(),
+                    };
+                self.last_reconnect_attempt = Instant::now();
+                self.model.ui.show_toast("Syncthing shut down".to_string());
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to shut down Syncthing: {}", e));
+                self.model.ui.show_toast(format!("{}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Transfer queue
+//!
+//! Methods for the transfer queue modal:
+//! - Load a folder's needed files in download order from `/rest/db/need`
+//! - Reload the queue when files start or finish syncing
+//! - Move a file to the front of the queue with `/rest/db/prio`
+//!
+//! Per-file progress comes from DownloadProgress events (see the event handler).
+
+use crate::logic::transfers::{
+    TRANSFER_QUEUE_PAGE_SIZE, TransferEntry, TransferSection, queue_entries,
+};
+use crate::{App, api, log_debug, model, services};
+use anyhow::Result;
+
+impl App {
+    /// Open the transfer queue for the current folder
+    ///
+    /// Uses the selected folder in folder view, or the browsed folder in breadcrumb view.
+    pub async fn open_transfer_queue(&mut self) {
+        let Some(folder) = self.model.current_folder().cloned() else {
+            return;
+        };
+        let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
+
+        let need = match self
+            .client
+            .get_needed_files(&folder.id, Some(1), Some(TRANSFER_QUEUE_PAGE_SIZE))
+            .await
+        {
+            Ok(need) => need,
+            Err(e) => {
+                log_debug(&format!("Failed to load transfer queue: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load transfer queue: {}", e));
+                return;
+            }
+        };
+
+        let entries = queue_entries(&need);
+        if entries.is_empty() {
+            self.model
+                .ui
+                .show_toast(format!("Nothing to download in {}", label));
+            return;
+        }
+
+        self.model.ui.transfer_queue = Some(model::TransferQueueModal {
+            need_total: self.need_total(&folder.id, entries.len()),
+            folder_id: folder.id,
+            folder_label: label,
+            entries,
+            selected_index: 0,
+        });
+    }
+
+    /// Close the transfer queue
+    pub fn close_transfer_queue(&mut self) {
+        self.model.ui.transfer_queue = None;
+    }
+
+    /// Move the selection
+    pub fn select_transfer(&mut self, index: usize) {
+        if let Some(modal) = &mut self.model.ui.transfer_queue {
+            modal.selected_index = index.min(modal.entries.len().saturating_sub(1));
+        }
+    }
+
+    /// Reload the queue on the next loop iteration if it shows `folder_id`
+    pub fn flag_transfer_queue_refresh(&mut self, folder_id: &str) {
+        if self
+            .model
+            .ui
+            .transfer_queue
+            .as_ref()
+            .is_some_and(|modal| modal.folder_id == folder_id)
+        {
+            self.model.ui.needs_transfer_queue_refresh = true;
+        }
+    }
+
+    /// Request a queue reload through the API service
+    pub fn request_transfer_queue_refresh(&self) {
+        if let Some(modal) = &self.model.ui.transfer_queue {
+            let _ = self
+                .api_tx
+                .send(services::api::ApiRequest::GetTransferQueue {
+                    folder_id: modal.folder_id.clone(),
+                });
+        }
+    }
+
+    /// Replace the queue, keeping the selected file selected
+    pub fn apply_transfer_queue(&mut self, folder_id: &str, need: Result<api::NeedResponse>) {
+        if self
+            .model
+            .ui
+            .transfer_queue
+            .as_ref()
+            .is_none_or(|modal| modal.folder_id != folder_id)
+        {
+            return;
+        }
+
+        match need {
+            Ok(need) => self.replace_transfer_queue(queue_entries(&need)),
+            Err(e) => log_debug(&format!("Failed to refresh transfer queue: {}", e)),
+        }
+    }
+
+    /// Move the selected file to the front of the download queue
+    pub async fn prioritize_selected_transfer(&mut self) {
+        let Some((folder_id, entry)) = self
+            .model
+            .ui
+            .transfer_queue
+            .as_ref()
+            .and_then(|modal| Some((modal.folder_id.clone(), modal.selected_entry()?.clone())))
+        else {
+            return;
+        };
+
+        if entry.section == TransferSection::InProgress {
+            self.model
+                .ui
+                .show_toast(format!("{} is already downloading", entry.path));
+            return;
+        }
+
+        match self.client.prioritize_file(&folder_id, &entry.path).await {
+            Ok(need) => {
+                self.replace_transfer_queue(queue_entries(&need));
+                self.model
+                    .ui
+                    .show_toast(format!("Moved {} to the front of the queue", entry.path));
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to prioritize {}: {}", entry.path, e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to prioritize file: {}", e));
+            }
+        }
+    }
+
+    /// Swap in new entries, following the selected file to its new position
+    fn replace_transfer_queue(&mut self, entries: Vec<TransferEntry>) {
+        let Some(modal) = &self.model.ui.transfer_queue else {
+            return;
+        };
+        let need_total = self.need_total(&modal.folder_id, entries.len());
+
+        if let Some(modal) = &mut self.model.ui.transfer_queue {
+            let selected_path = modal.selected_entry().map(|entry| entry.path.clone());
+            modal.selected_index = selected_path
+                .and_then(|path| entries.iter().position(|entry| entry.path == path))
+                .unwrap_or(modal.selected_index)
+                .min(entries.len().saturating_sub(1));
+            modal.entries = entries;
+            modal.need_total = need_total;
+        }
+    }
+
+    /// Needed items of a folder (at least the number of loaded entries)
+    fn need_total(&self, folder_id: &str, loaded: usize) -> u64 {
+        self.model
+            .syncthing
+            .get_folder_status(folder_id)
+            .map(|status| status.need_total_items)
+            .unwrap_or(0)
+            .max(loaded as u64)
+    }
+}
@@ -0,0 +1,274 @@
+//! File version browser
+//!
+//! Methods for the file versions modal:
+//! - List archived versions of the marked files, or the selected file
+//!   (`/rest/folder/versions`)
+//! - Preview a version's content through the regular preview pipeline
+//! - Restore the marked versions (or the selected one) in a single request
+//!   and invalidate the cached file states
+
+use crate::{App, log_debug, logic, model};
+use std::collections::HashMap;
+
+impl App {
+    /// Open the versions modal for the marked files (or the selected file)
+    /// in the breadcrumb view
+    pub async fn open_file_versions(&mut self) {
+        let Some((folder_id, file_paths)) = self.selected_files_for_versions() else {
+            return;
+        };
+
+        let Some(folder) = self
+            .model
+            .syncthing
+            .folders
+            .iter()
+            .find(|f| f.id == folder_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        let versioning = match self.client.get_folder_versioning(&folder_id).await {
+            Ok(versioning) => versioning,
+            Err(e) => {
+                log_debug(&format!("Failed to load versioning config: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load versioning config: {}", e));
+                return;
+            }
+        };
+
+        if !logic::versions::supports_version_browsing(&versioning.versioning_type) {
+            let message = if versioning.versioning_type.is_empty() {
+                "File versioning is not enabled for this folder".to_string()
+            } else {
+                format!(
+                    "{} versioning does not support browsing versions",
+                    versioning.versioning_type
+                )
+            };
+            self.model.ui.show_toast(message);
+            return;
+        }
+
+        let mut all = match self.client.get_file_versions(&folder_id).await {
+            Ok(all) => all,
+            Err(e) => {
+                log_debug(&format!("Failed to load file versions: {}", e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to load file versions: {}", e));
+                return;
+            }
+        };
+
+        let mut versions = Vec::new();
+        for file_path in &file_paths {
+            let mut file_versions = all.remove(file_path).unwrap_or_default();
+            // RFC 3339 timestamps from one device sort chronologically as strings
+            file_versions.sort_by(|a, b| b.version_time.cmp(&a.version_time));
+            versions.extend(file_versions.into_iter().map(|v| model::FileVersionEntry {
+                file_path: file_path.clone(),
+                version_time: v.version_time,
+                mod_time: v.mod_time,
+                size: v.size,
+            }));
+        }
+
+        if versions.is_empty() {
+            let message = match file_paths.as_slice() {
+                [file_path] => format!("No archived versions of {}", file_path),
+                _ => "No archived versions of the marked files".to_string(),
+            };
+            self.model.ui.show_toast(message);
+            return;
+        }
+
+        self.model.ui.file_versions_modal = Some(model::FileVersionsModal {
+            folder_id,
+            file_paths,
+            versioning_type: versioning.versioning_type,
+            versions_root: logic::versions::versions_root(&folder.path, &versioning.fs_path),
+            versions,
+            selected_index: 0,
+            marked: HashMap::new(),
+            preview: Err("Loading...".to_string()),
+            preview_is_binary: false,
+            scroll_offset: 0,
+        });
+
+        self.load_version_preview().await;
+    }
+
+    /// Close the versions modal
+    pub fn close_file_versions(&mut self) {
+        self.model.ui.file_versions_modal = None;
+    }
+
+    /// Move the selection in the versions list and reload the preview
+    pub async fn select_file_version(&mut self, index: usize) {
+        let Some(modal) = &mut self.model.ui.file_versions_modal else {
+            return;
+        };
+
+        let index = index.min(modal.versions.len().saturating_sub(1));
+        if index == modal.selected_index {
+            return;
+        }
+        modal.selected_index = index;
+        modal.scroll_offset = 0;
+
+        self.load_version_preview().await;
+    }
+
+    /// Read the selected version from the versions directory into the preview
+    async fn load_version_preview(&mut self) {
+        let Some(modal) = &self.model.ui.file_versions_modal else {
+            return;
+        };
+        let Some(version) = modal.selected_version() else {
+            return;
+        };
+
+        let archived_path = logic::versions::archived_version_path(
+            &version.file_path,
+            &version.version_time,
+            &modal.versioning_type,
+        );
+
+        let (preview, is_binary) = match archived_path {
+            Some(path) => {
+                // The versions directory acts as the "folder" for path translation
+                let versions_folder = crate::Folder {
+                    id: modal.folder_id.clone(),
+                    label: None,
+                    path: modal.versions_root.clone(),
+                    paused: false,
+                    folder_type: String::new(),
+                    devices: vec![],
+                };
+                let (content, _exists, is_binary) =
+                    Self::read_file_content_static(&self.path_map, &versions_folder, &path).await;
+                (content, is_binary)
+            }
+            None => (Err("Cannot locate this version on disk".to_string()), false),
+        };
+
+        if let Some(modal) = &mut self.model.ui.file_versions_modal {
+            modal.preview = preview;
+            modal.preview_is_binary = is_binary;
+        }
+    }
+
+    /// Mark or unmark the selected version for restore
+    pub fn toggle_version_mark(&mut self) {
+        if let Some(modal) = &mut self.model.ui.file_versions_modal {
+            modal.toggle_mark();
+        }
+    }
+
+    /// Restore file versions in one request (called after confirmation)
+    ///
+    /// `versions` holds (file path, version time) pairs, at most one per file.
+    pub async fn restore_file_versions(&mut self, folder_id: &str, versions: &[(String, String)]) {
+        let request: HashMap<String, String> = versions.iter().cloned().collect();
+
+        let errors = match self.client.restore_file_versions(folder_id, &request).await {
+            Ok(errors) => errors,
+            Err(e) => {
+                log_debug(&format!("Failed to restore {:?}: {}", request, e));
+                self.model
+                    .ui
+                    .show_toast(format!("Failed to restore version: {}", e));
+                return;
+            }
+        };
+
+        for (file_path, _) in versions.iter().filter(|(p, _)| !errors.contains_key(p)) {
+            let _ = self.cache.invalidate_single_file(folder_id, file_path);
+        }
+        if errors.len() < versions.len() {
+            self.invalidate_folder_caches(folder_id);
+        }
+
+        let Some((failed_path, message)) = errors.iter().min_by_key(|(path, _)| *path) else {
+            self.model.ui.file_versions_modal = None;
+            let message = match versions {
+                [(file_path, version_time)] => format!(
+                    "Restored {} from {}",
+                    file_path,
+                    logic::formatting::format_datetime(version_time)
+                ),
+                _ => format!("Restored {} files", versions.len()),
+            };
+            self.model.ui.show_toast(message);
+            return;
+        };
+
+        log_debug(&format!("Failed to restore versions: {:?}", errors));
+        let message = if versions.len() == 1 {
+            format!("Failed to restore version: {}", message)
+        } else {
+            format!(
+                "Restored {} of {} files; {}: {}",
+                versions.len() - errors.len(),
+                versions.len(),
+                failed_path,
+                message
+            )
+        };
+        self.model.ui.show_toast(message);
+
+        // Keep only the failed versions marked so they can be retried
+        if let Some(modal) = &mut self.model.ui.file_versions_modal {
+            modal.marked.retain(|path, _| errors.contains_key(path));
+        }
+    }
+
+    /// Folder ID and paths of the marked files in the breadcrumb view, or of
+    /// the selected file (directories are skipped)
+    fn selected_files_for_versions(&mut self) -> Option<(String, Vec<String>)> {
+        if self.model.navigation.focus_level == 0 {
+            return None;
+        }
+
+        let level = self
+            .model
+            .navigation
+            .breadcrumb_trail
+            .get(self.model.navigation.focus_level - 1)?;
+        let path_of = |name: &str| match &level.prefix {
+            Some(prefix) => format!("{}{}", prefix, name),
+            None => name.to_string(),
+        };
+
+        if !level.marked.is_empty() {
+            let mut file_paths: Vec<String> = level
+                .marked_items()
+                .into_iter()
+                .filter(|item| item.item_type != "FILE_INFO_TYPE_DIRECTORY")
+                .map(|item| path_of(&item.name))
+                .collect();
+            if file_paths.is_empty() {
+                self.model
+                    .ui
+                    .show_toast("Versions are only available for files".to_string());
+                return None;
+            }
+            file_paths.sort();
+            return Some((level.folder_id.clone(), file_paths));
+        }
+
+        let item = level.selected_item()?;
+        if item.item_type == "FILE_INFO_TYPE_DIRECTORY" {
+            self.model
+                .ui
+                .show_toast("Versions are only available for files".to_string());
+            return None;
+        }
+
+        Some((level.folder_id.clone(), vec![path_of(&item.name)]))
+    }
+}
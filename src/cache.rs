@@ -1,12 +1,12 @@
 use anyhow::Result;
-use rusqlite::{Connection, params};
+use rusqlite::{Connection, OptionalExtension, params};
 use std::collections::HashMap;
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
 
-use crate::api::{BrowseItem, FolderStatus, NeedResponse, SyncState};
+use crate::api::{BrowseItem, FolderStatus, NeedResponse, SyncState, SyncthingClient};
 use crate::model::types::FolderSyncBreakdown;
 use crate::utils;
 
@@ -29,12 +29,143 @@ pub struct CacheDb {
     conn: Connection,
 }
 
+/// Database file of one Syncthing instance's cache
+///
+/// Keyed by the first group of the instance's device ID when known, otherwise
+/// by the base URL, so two instances never share cached data.
+///
+/// # Examples
+/// ```
+/// use stui::cache::instance_cache_file;
+///
+/// assert_eq!(
+///     instance_cache_file(Some("ABCDEFG-HIJKLMN-OPQRSTU"), "http://nas:8384"),
+///     "cache-ABCDEFG.db"
+/// );
+/// assert_eq!(
+///     instance_cache_file(None, "http://nas:8384"),
+///     "cache-nas_8384.db"
+/// );
+/// ```
+pub fn instance_cache_file(device_id: Option<&str>, base_url: &str) -> String {
+    let short_id = device_id
+        .and_then(|id| id.split('-').next())
+        .filter(|id| !id.is_empty());
+
+    let key = match short_id {
+        Some(id) => id.to_string(),
+        None => {
+            let host = base_url
+                .split_once("://")
+                .map_or(base_url, |(_, rest)| rest)
+                .trim_end_matches('/');
+            host.chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect()
+        }
+    };
+
+    format!("cache-{}.db", key)
+}
+
+/// Hand the pre-profile `cache.db` to the top-level instance
+///
+/// Before profiles the top-level `base_url` was the only instance, so its
+/// cache becomes that instance's file (SQLite's `-wal`/`-shm` files move
+/// along). If the instance already has a cache of its own, the old file is
+/// deleted instead.
+fn migrate_legacy_cache(cache_dir: &Path, db_path: &Path) {
+    let legacy = cache_dir.join("cache.db");
+    if !legacy.exists() {
+        return;
+    }
+
+    let keep = !db_path.exists();
+    for suffix in ["", "-wal", "-shm"] {
+        let from = PathBuf::from(format!("{}{}", legacy.display(), suffix));
+        if !from.exists() {
+            continue;
+        }
+        let result = if keep {
+            std::fs::rename(&from, format!("{}{}", db_path.display(), suffix))
+        } else {
+            std::fs::remove_file(&from)
+        };
+        if let Err(e) = result {
+            log_debug(&format!(
+                "Failed to migrate legacy cache {}: {}",
+                from.display(),
+                e
+            ));
+        }
+    }
+    if keep {
+        log_debug(&format!("Moved legacy cache.db to {}", db_path.display()));
+    } else {
+        log_debug("Removed legacy cache.db");
+    }
+}
+
 impl CacheDb {
-    pub fn new() -> Result<Self> {
+    /// Open the cache of the Syncthing instance behind `client`
+    ///
+    /// The instance is identified by its device ID. While it is unreachable,
+    /// the device ID last seen at the same URL is used, so the cached folders
+    /// still show up on an offline start.
+    ///
+    /// `owns_legacy_cache` is set for the top-level instance only: the
+    /// pre-profile `cache.db` belongs to it and is taken over once its device
+    /// ID is known.
+    pub async fn for_instance(client: &SyncthingClient, owns_legacy_cache: bool) -> Result<Self> {
+        let status = client.get_system_status().await;
+
         let cache_dir = Self::get_cache_dir()?;
         std::fs::create_dir_all(&cache_dir)?;
 
-        let db_path = cache_dir.join("cache.db");
+        let registry = Connection::open(cache_dir.join("instances.db"))?;
+        registry.execute(
+            "CREATE TABLE IF NOT EXISTS instances (
+                base_url TEXT PRIMARY KEY,
+                device_id TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        let device_id = match status {
+            Ok(status) => {
+                registry.execute(
+                    "INSERT OR REPLACE INTO instances (base_url, device_id) VALUES (?1, ?2)",
+                    params![client.base_url(), status.my_id],
+                )?;
+                Some(status.my_id)
+            }
+            Err(e) => {
+                log_debug(&format!(
+                    "Instance at {} unreachable ({}), using last known device ID",
+                    client.base_url(),
+                    e
+                ));
+                registry
+                    .query_row(
+                        "SELECT device_id FROM instances WHERE base_url = ?1",
+                        params![client.base_url()],
+                        |row| row.get(0),
+                    )
+                    .optional()?
+            }
+        };
+
+        let file_name = instance_cache_file(device_id.as_deref(), client.base_url());
+        log_debug(&format!("Using cache database {}", file_name));
+        let db_path = cache_dir.join(file_name);
+        // A URL-keyed file would be abandoned once the device ID is known
+        if owns_legacy_cache && device_id.is_some() {
+            migrate_legacy_cache(&cache_dir, &db_path);
+        }
+        Self::open(db_path)
+    }
+
+    fn open(db_path: PathBuf) -> Result<Self> {
         let conn = Connection::open(db_path)?;
 
         // Enable Write-Ahead Logging for better concurrency
@@ -49,8 +180,7 @@ impl CacheDb {
         Ok(cache)
     }
 
-    /// Create an in-memory cache for testing
-    #[allow(dead_code)]
+    /// Create an in-memory cache (tests and replays)
     pub fn new_in_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
         let mut cache = CacheDb { conn };
@@ -603,6 +733,7 @@ impl CacheDb {
             SyncState::RemoteOnly => "RemoteOnly".to_string(),
             SyncState::Ignored => "Ignored".to_string(),
             SyncState::Syncing => "Syncing".to_string(),
+            SyncState::Conflict => "Conflict".to_string(),
             SyncState::Unknown => "Unknown".to_string(),
         }
     }
@@ -614,6 +745,7 @@ impl CacheDb {
             "LocalOnly" => SyncState::LocalOnly,
             "RemoteOnly" => SyncState::RemoteOnly,
             "Ignored" => SyncState::Ignored,
+            "Conflict" => SyncState::Conflict,
             // Syncing is transient - never persist across app restarts
             "Syncing" => {
                 log_debug("WARNING: Found stale 'Syncing' state in cache, converting to Unknown");
@@ -821,6 +953,36 @@ impl CacheDb {
         Ok(())
     }
 
+    /// Cache the need of a send-only folder
+    ///
+    /// Send-only folders never pull, so every needed file is a remote change
+    /// that can only be overridden, whichever part of the need list it is in.
+    pub fn cache_remote_changed_files(
+        &self,
+        folder_id: &str,
+        need_response: &NeedResponse,
+    ) -> Result<()> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs() as i64;
+
+        let files = need_response
+            .progress
+            .iter()
+            .chain(&need_response.queued)
+            .chain(&need_response.rest);
+        for file in files {
+            self.conn.execute(
+                "INSERT OR REPLACE INTO sync_states
+                 (folder_id, file_path, file_sequence, sync_state, need_category, need_cached_at)
+                 VALUES (?1, ?2, 0, ?3, ?4, ?5)",
+                params![folder_id, &file.name, "OutOfSync", "remote_changed", now],
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn cache_local_changed_files(&self, folder_id: &str, file_paths: &[String]) -> Result<()> {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)?
@@ -883,6 +1045,7 @@ impl CacheDb {
                 "remote_only" => breakdown.remote_only = count,
                 "modified" => breakdown.modified = count,
                 "local_only" => breakdown.local_only = count,
+                "remote_changed" => breakdown.remote_changed = count,
                 _ => {}
             }
         }
@@ -1006,6 +1169,61 @@ mod tests {
     use super::*;
     use crate::api::{FileInfo, NeedResponse};
 
+    #[test]
+    fn test_instance_cache_file_uses_short_device_id() {
+        let id = "ABCDEFG-HIJKLMN-OPQRSTU-VWXYZ23-4567ABC-DEFGHIJ-KLMNOPQ-RSTUVWX";
+        assert_eq!(
+            instance_cache_file(Some(id), "http://127.0.0.1:8384"),
+            "cache-ABCDEFG.db"
+        );
+        // Same instance behind another URL shares the cache
+        assert_eq!(
+            instance_cache_file(Some(id), "https://nas.lan:8384/"),
+            "cache-ABCDEFG.db"
+        );
+    }
+
+    #[test]
+    fn test_instance_cache_file_falls_back_to_url() {
+        assert_eq!(
+            instance_cache_file(None, "http://127.0.0.1:8384"),
+            "cache-127_0_0_1_8384.db"
+        );
+        assert_eq!(
+            instance_cache_file(Some(""), "https://nas.lan:8384/"),
+            "cache-nas_lan_8384.db"
+        );
+    }
+
+    #[test]
+    fn test_migrate_legacy_cache() {
+        let dir = std::env::temp_dir().join(format!("stui-cache-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let first = dir.join("cache-AAAAAAA.db");
+        let second = dir.join("cache-BBBBBBB.db");
+
+        // The first instance takes over the old cache and its WAL file
+        std::fs::write(dir.join("cache.db"), "legacy").unwrap();
+        std::fs::write(dir.join("cache.db-wal"), "wal").unwrap();
+        migrate_legacy_cache(&dir, &first);
+        assert_eq!(std::fs::read_to_string(&first).unwrap(), "legacy");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("cache-AAAAAAA.db-wal")).unwrap(),
+            "wal"
+        );
+        assert!(!dir.join("cache.db").exists());
+        assert!(!dir.join("cache.db-wal").exists());
+
+        // A leftover next to an existing cache is deleted, not merged
+        std::fs::write(dir.join("cache.db"), "stale").unwrap();
+        std::fs::write(&second, "current").unwrap();
+        migrate_legacy_cache(&dir, &second);
+        assert_eq!(std::fs::read_to_string(&second).unwrap(), "current");
+        assert!(!dir.join("cache.db").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_cache_needed_files_stores_categories() {
         let cache = CacheDb::new_in_memory().unwrap();
@@ -1141,6 +1359,35 @@ mod tests {
         assert_eq!(breakdown.local_only, 0);
     }
 
+    #[test]
+    fn test_cache_remote_changed_files_counts_whole_need_as_remote_changed() {
+        let cache = CacheDb::new_in_memory().unwrap();
+
+        let file = |name: &str| FileInfo {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let need_response = NeedResponse {
+            progress: vec![file("edited.txt")],
+            queued: vec![],
+            rest: vec![file("added.txt"), file("deleted.txt")],
+            page: 1,
+            perpage: 100,
+        };
+
+        cache
+            .cache_remote_changed_files("send-folder", &need_response)
+            .unwrap();
+
+        let breakdown = cache.get_folder_sync_breakdown("send-folder").unwrap();
+        assert_eq!(breakdown.remote_changed, 3);
+        assert_eq!(breakdown.downloading, 0);
+        assert_eq!(breakdown.remote_only, 0);
+
+        let items = cache.get_out_of_sync_items("send-folder").unwrap();
+        assert_eq!(items.get("edited.txt"), Some(&"remote_changed".to_string()));
+    }
+
     #[test]
     fn test_invalidate_out_of_sync_categories_clears_data() {
         let cache = CacheDb::new_in_memory().unwrap();
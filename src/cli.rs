@@ -0,0 +1,695 @@
+//! Headless subcommands
+//!
+//! `stui <command>` prints the data the TUI shows without starting it: aligned
+//! tables by default, JSON with `--json`. Commands that report sync state exit
+//! with the codes from `logic::health` so they can drive cron health checks.
+
+use crate::api::{Folder, SyncthingClient};
+use crate::cache::CacheDb;
+use crate::logic;
+use crate::logic::health::{EXIT_OK, EXIT_OUT_OF_SYNC};
+use anyhow::{Result, anyhow};
+use clap::Subcommand;
+use serde::Serialize;
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Show this device and the health of every folder
+    Status,
+    /// List folders with type, path and health
+    Folders,
+    /// List a directory of a folder with cached sync states
+    Ls {
+        /// Folder ID or label
+        folder: String,
+        /// Directory inside the folder (default: folder root)
+        path: Option<String>,
+    },
+    /// List files the folder still needs from remote devices
+    Need {
+        /// Folder ID or label
+        folder: String,
+    },
+    /// Add or remove .stignore lines
+    Ignore {
+        #[command(subcommand)]
+        action: IgnoreAction,
+    },
+    /// Rescan a folder (all folders when omitted)
+    Rescan {
+        /// Folder ID or label
+        folder: Option<String>,
+    },
+    /// Pause a folder
+    Pause {
+        /// Folder ID or label
+        folder: String,
+    },
+    /// Resume a paused folder
+    Resume {
+        /// Folder ID or label
+        folder: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum IgnoreAction {
+    /// Ignore a path (adds an anchored /path line)
+    Add {
+        /// Folder ID or label
+        folder: String,
+        /// Path relative to the folder root
+        path: String,
+    },
+    /// Stop ignoring a path (removes its anchored /path line)
+    Rm {
+        /// Folder ID or label
+        folder: String,
+        /// Path relative to the folder root
+        path: String,
+        /// Also remove broader lines that ignore the path, such as `*.log`
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+/// Folder row shared by `status` and `folders`
+#[derive(Serialize, Debug)]
+struct FolderReport {
+    id: String,
+    label: String,
+    #[serde(rename = "type")]
+    folder_type: String,
+    path: String,
+    paused: bool,
+    state: Option<String>,
+    health: &'static str,
+    need_items: u64,
+    need_bytes: u64,
+    local_changes: u64,
+    errors: u64,
+}
+
+#[derive(Serialize, Debug)]
+struct StatusReport {
+    device_name: String,
+    device_id: String,
+    uptime_seconds: u64,
+    folders: Vec<FolderReport>,
+}
+
+#[derive(Serialize, Debug)]
+struct LsEntry {
+    name: String,
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    size: u64,
+    mod_time: String,
+    sync_state: Option<String>, // From the TUI cache, when available
+}
+
+#[derive(Serialize, Debug)]
+struct NeedEntry {
+    name: String,
+    category: &'static str,
+    size: u64,
+    modified: String,
+}
+
+#[derive(Serialize, Debug)]
+struct IgnoreReport {
+    folder: String,
+    path: String,
+    added: Vec<String>,
+    removed: Vec<String>,
+    still_ignored_by: Option<String>,
+}
+
+/// Run a subcommand and return the process exit code
+pub async fn run(command: Command, client: &SyncthingClient, json: bool) -> Result<i32> {
+    match command {
+        Command::Status => status(client, json).await,
+        Command::Folders => folders(client, json).await,
+        Command::Ls { folder, path } => ls(client, &folder, path.as_deref(), json).await,
+        Command::Need { folder } => need(client, &folder, json).await,
+        Command::Ignore { action } => ignore(client, action, json).await,
+        Command::Rescan { folder } => rescan(client, folder.as_deref(), json).await,
+        Command::Pause { folder } => set_paused(client, &folder, true, json).await,
+        Command::Resume { folder } => set_paused(client, &folder, false, json).await,
+    }
+}
+
+/// Look up a folder by ID or label
+async fn resolve_folder(client: &SyncthingClient, query: &str) -> Result<Folder> {
+    let folders = client.get_folders().await?;
+    logic::folder::find_folder(&folders, query)
+        .cloned()
+        .ok_or_else(|| anyhow!("No folder with ID or label '{}'", query))
+}
+
+/// Fetch every folder with its status and health
+async fn folder_reports(client: &SyncthingClient) -> Result<(Vec<FolderReport>, i32)> {
+    let folders = client.get_folders().await?;
+    let mut reports = Vec::new();
+    let mut healths = Vec::new();
+
+    for folder in folders {
+        let status = if folder.paused {
+            None
+        } else {
+            client.get_folder_status(&folder.id).await.ok()
+        };
+        let health = logic::health::folder_health(folder.paused, status.as_ref());
+        healths.push(health);
+
+        reports.push(FolderReport {
+            label: folder.label.clone().unwrap_or_else(|| folder.id.clone()),
+            id: folder.id,
+            folder_type: folder.folder_type,
+            path: folder.path,
+            paused: folder.paused,
+            state: status.as_ref().map(|s| s.state.clone()),
+            health: health.as_str(),
+            need_items: status.as_ref().map_or(0, |s| s.need_total_items),
+            need_bytes: status.as_ref().map_or(0, |s| s.need_bytes),
+            local_changes: status.as_ref().map_or(0, |s| s.receive_only_total_items),
+            errors: status.as_ref().map_or(0, |s| s.errors),
+        });
+    }
+
+    Ok((reports, logic::health::exit_code(&healths)))
+}
+
+async fn status(client: &SyncthingClient, json: bool) -> Result<i32> {
+    let system = client.get_system_status().await?;
+    let device_name = client
+        .get_device_name()
+        .await
+        .unwrap_or_else(|_| "Unknown".to_string());
+    let (folders, code) = folder_reports(client).await?;
+
+    if json {
+        print_json(&StatusReport {
+            device_name,
+            device_id: system.my_id,
+            uptime_seconds: system.uptime,
+            folders,
+        })?;
+        return Ok(code);
+    }
+
+    println!(
+        "{} ({})  up {}",
+        device_name,
+        system.my_id.split('-').next().unwrap_or(&system.my_id),
+        logic::formatting::format_uptime(system.uptime)
+    );
+    println!();
+    let rows = folders
+        .iter()
+        .map(|f| {
+            vec![
+                f.label.clone(),
+                f.state.clone().unwrap_or_else(|| "-".to_string()),
+                f.health.to_string(),
+                f.need_items.to_string(),
+                logic::formatting::format_human_size(f.need_bytes),
+                f.local_changes.to_string(),
+                f.errors.to_string(),
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        format_table(
+            &[
+                "FOLDER",
+                "STATE",
+                "HEALTH",
+                "NEED",
+                "NEED SIZE",
+                "LOCAL",
+                "ERRORS"
+            ],
+            rows
+        )
+    );
+    Ok(code)
+}
+
+async fn folders(client: &SyncthingClient, json: bool) -> Result<i32> {
+    let (folders, code) = folder_reports(client).await?;
+
+    if json {
+        print_json(&folders)?;
+        return Ok(code);
+    }
+
+    let rows = folders
+        .into_iter()
+        .map(|f| vec![f.id, f.label, f.folder_type, f.health.to_string(), f.path])
+        .collect();
+    print!(
+        "{}",
+        format_table(&["ID", "LABEL", "TYPE", "HEALTH", "PATH"], rows)
+    );
+    Ok(code)
+}
+
+async fn ls(
+    client: &SyncthingClient,
+    folder_query: &str,
+    path: Option<&str>,
+    json: bool,
+) -> Result<i32> {
+    let folder = resolve_folder(client, folder_query).await?;
+
+    // Browse prefixes end with '/', like the breadcrumb levels
+    let prefix = path
+        .map(|p| p.trim_matches('/'))
+        .filter(|p| !p.is_empty())
+        .map(|p| format!("{}/", p));
+
+    let mut items = client.browse_folder(&folder.id, prefix.as_deref()).await?;
+    items.sort_by(|a, b| {
+        let a_dir = a.item_type == "FILE_INFO_TYPE_DIRECTORY";
+        let b_dir = b.item_type == "FILE_INFO_TYPE_DIRECTORY";
+        b_dir.cmp(&a_dir).then_with(|| a.name.cmp(&b.name))
+    });
+
+    // Sync states come from the TUI's cache; without one the column stays empty.
+    // The legacy cache is left for the TUI to migrate.
+    let cache = CacheDb::for_instance(client, false).await.ok();
+    let entries: Vec<LsEntry> = items
+        .into_iter()
+        .map(|item| {
+            let relative_path = format!("{}{}", prefix.as_deref().unwrap_or(""), item.name);
+            let sync_state = cache
+                .as_ref()
+                .and_then(|c| {
+                    c.get_sync_state_unvalidated(&folder.id, &relative_path)
+                        .ok()
+                })
+                .flatten()
+                .map(|state| format!("{:?}", state));
+            LsEntry {
+                item_type: if item.item_type == "FILE_INFO_TYPE_DIRECTORY" {
+                    "directory"
+                } else {
+                    "file"
+                },
+                name: item.name,
+                size: item.size,
+                mod_time: item.mod_time,
+                sync_state,
+            }
+        })
+        .collect();
+
+    if json {
+        print_json(&entries)?;
+        return Ok(EXIT_OK);
+    }
+
+    let rows = entries
+        .into_iter()
+        .map(|e| {
+            let name = if e.item_type == "directory" {
+                format!("{}/", e.name)
+            } else {
+                e.name
+            };
+            vec![
+                e.sync_state.unwrap_or_else(|| "-".to_string()),
+                logic::formatting::format_human_size(e.size),
+                logic::formatting::format_datetime(&e.mod_time),
+                name,
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        format_table(&["STATE", "SIZE", "MODIFIED", "NAME"], rows)
+    );
+    Ok(EXIT_OK)
+}
+
+async fn need(client: &SyncthingClient, folder_query: &str, json: bool) -> Result<i32> {
+    let folder = resolve_folder(client, folder_query).await?;
+    let response = client.get_needed_files(&folder.id, None, None).await?;
+
+    let entries: Vec<NeedEntry> = [
+        ("downloading", response.progress),
+        ("queued", response.queued),
+        ("remote", response.rest),
+    ]
+    .into_iter()
+    .flat_map(|(category, files)| {
+        files.into_iter().map(move |file| NeedEntry {
+            name: file.name,
+            category,
+            size: file.size,
+            modified: file.modified,
+        })
+    })
+    .collect();
+
+    let code = if entries.is_empty() {
+        EXIT_OK
+    } else {
+        EXIT_OUT_OF_SYNC
+    };
+
+    if json {
+        print_json(&entries)?;
+        return Ok(code);
+    }
+
+    let rows = entries
+        .into_iter()
+        .map(|e| {
+            vec![
+                e.category.to_string(),
+                logic::formatting::format_human_size(e.size),
+                e.name,
+            ]
+        })
+        .collect();
+    print!("{}", format_table(&["CATEGORY", "SIZE", "NAME"], rows));
+    Ok(code)
+}
+
+async fn ignore(client: &SyncthingClient, action: IgnoreAction, json: bool) -> Result<i32> {
+    let (folder_query, path, adding, all) = match action {
+        IgnoreAction::Add { folder, path } => (folder, path, true, false),
+        IgnoreAction::Rm { folder, path, all } => (folder, path, false, all),
+    };
+    let folder = resolve_folder(client, &folder_query).await?;
+    let path = path.trim_matches('/').to_string();
+    let ignores = client.get_ignores(&folder.id).await?;
+    let included = logic::ignore::included_patterns(&ignores.lines, &ignores.expanded);
+    let patterns = ignores.lines;
+
+    let mut report = IgnoreReport {
+        folder: folder.id.clone(),
+        path: path.clone(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        still_ignored_by: None,
+    };
+
+    let updated = if adding {
+        let (updated, added) =
+            logic::ignore::add_ignore_patterns(&patterns, std::slice::from_ref(&path));
+        if added > 0 {
+            report.added.push(format!("/{}", path));
+        }
+        updated
+    } else {
+        let matches = logic::ignore::find_matching_patterns(&patterns, &included, &path);
+        let matches = lines_to_remove(matches, &path, all).map_err(|e| anyhow!(e))?;
+
+        // Remove from the bottom up so earlier line numbers stay valid
+        let mut updated = patterns.clone();
+        let mut by_line = matches.clone();
+        by_line.sort_by_key(|m| std::cmp::Reverse(m.line));
+        for m in &by_line {
+            updated = logic::ignore::remove_ignore_line(&updated, m);
+        }
+        report.removed = matches.into_iter().map(|m| m.pattern).collect();
+
+        report.still_ignored_by = logic::ignore::IgnoreMatcher::with_includes(&updated, &included)
+            .match_path(&path)
+            .filter(|m| m.ignored)
+            .map(|m| m.pattern);
+        updated
+    };
+
+    if updated != patterns {
+        client.set_ignore_patterns(&folder.id, updated).await?;
+    }
+
+    if json {
+        print_json(&report)?;
+        return Ok(EXIT_OK);
+    }
+
+    if adding && report.added.is_empty() {
+        println!("{} already has /{}", folder.id, path);
+    }
+    for line in &report.added {
+        println!("Added {} to {}", line, folder.id);
+    }
+    for line in &report.removed {
+        println!("Removed {} from {}", line, folder.id);
+    }
+    if let Some(pattern) = &report.still_ignored_by {
+        println!("Still ignored by: {}", pattern);
+    }
+    Ok(EXIT_OK)
+}
+
+async fn rescan(client: &SyncthingClient, folder_query: Option<&str>, json: bool) -> Result<i32> {
+    let folders = match folder_query {
+        Some(query) => vec![resolve_folder(client, query).await?],
+        None => client.get_folders().await?,
+    };
+
+    let mut rescanned = Vec::new();
+    for folder in folders.iter().filter(|f| !f.paused) {
+        client.rescan_folder(&folder.id).await?;
+        rescanned.push(folder.id.clone());
+    }
+
+    if json {
+        print_json(&serde_json::json!({ "rescanned": rescanned }))?;
+    } else {
+        for id in &rescanned {
+            println!("Rescanning {}", id);
+        }
+    }
+    Ok(EXIT_OK)
+}
+
+async fn set_paused(
+    client: &SyncthingClient,
+    folder_query: &str,
+    paused: bool,
+    json: bool,
+) -> Result<i32> {
+    let folder = resolve_folder(client, folder_query).await?;
+    client.set_folder_paused(&folder.id, paused).await?;
+
+    if json {
+        print_json(&serde_json::json!({ "folder": folder.id, "paused": paused }))?;
+    } else {
+        let verb = if paused { "Paused" } else { "Resumed" };
+        println!("{} {}", verb, folder.id);
+    }
+    Ok(EXIT_OK)
+}
+
+/// Pick the matching lines `ignore rm` removes
+///
+/// Only the path's own anchored `/path` line goes (with any `(?i)` / `(?d)`
+/// modifiers, in any order), unless `all` is set: broader lines such as `*.log` also ignore unrelated
+/// files, so they are listed in the error instead.
+fn lines_to_remove(
+    matches: Vec<logic::ignore::IgnoreMatch>,
+    path: &str,
+    all: bool,
+) -> Result<Vec<logic::ignore::IgnoreMatch>, String> {
+    // Included files can't be edited through the REST API
+    if let Some(m) = matches.first()
+        && let Some(include) = &m.include
+    {
+        return Err(format!(
+            "{} is ignored by {} from {} (line {}), which stui cannot edit",
+            path, m.pattern, include, m.line
+        ));
+    }
+    let matches: Vec<_> = matches
+        .into_iter()
+        .filter(|m| m.include.is_none())
+        .collect();
+
+    if matches.is_empty() {
+        return Err(format!("No .stignore line ignores {}", path));
+    }
+    if all {
+        return Ok(matches);
+    }
+
+    let anchored = format!("/{}", path);
+    let is_own_line = |m: &logic::ignore::IgnoreMatch| {
+        logic::ignore::parse_ignore_line(&m.pattern).is_some_and(|line| {
+            if line.case_insensitive {
+                line.pattern.eq_ignore_ascii_case(&anchored)
+            } else {
+                line.pattern == anchored
+            }
+        })
+    };
+    let (exact, broader): (Vec<_>, Vec<_>) = matches.into_iter().partition(is_own_line);
+    if exact.is_empty() {
+        let lines: Vec<String> = broader
+            .iter()
+            .map(|m| format!("{} (line {})", m.pattern, m.line))
+            .collect();
+        return Err(format!(
+            "{} is ignored by {}; use --all to remove {}",
+            path,
+            lines.join(", "),
+            if lines.len() == 1 { "it" } else { "them" }
+        ));
+    }
+    Ok(exact)
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Left-aligned columns separated by two spaces, header first
+fn format_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            if let Some(width) = widths.get_mut(i) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+    }
+
+    let header_row: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+    std::iter::once(header_row)
+        .chain(rows)
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    let pad = widths[i].saturating_sub(cell.chars().count());
+                    format!("{}{}", cell, " ".repeat(pad))
+                })
+                .collect();
+            format!("{}\n", cells.join("  ").trim_end())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_format_table_aligns_columns() {
+        let table = format_table(
+            &["ID", "HEALTH"],
+            vec![
+                vec!["photos".to_string(), "up-to-date".to_string()],
+                vec!["a".to_string(), "error".to_string()],
+            ],
+        );
+
+        assert_eq!(table, "ID      HEALTH\nphotos  up-to-date\na       error\n");
+    }
+
+    #[test]
+    fn test_format_table_without_rows_prints_header() {
+        assert_eq!(format_table(&["NAME"], vec![]), "NAME\n");
+    }
+
+    #[test]
+    fn test_ignore_rm_only_removes_anchored_line() {
+        let lines: Vec<String> = ["*.log", "(?d)/logs/app.log", "/logs"]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        let matches =
+            || logic::ignore::find_matching_patterns(&lines, &HashMap::new(), "logs/app.log");
+
+        let removed = lines_to_remove(matches(), "logs/app.log", false).unwrap();
+        let removed: Vec<&str> = removed.iter().map(|m| m.pattern.as_str()).collect();
+        assert_eq!(removed, vec!["(?d)/logs/app.log"]);
+
+        assert_eq!(
+            lines_to_remove(matches(), "logs/app.log", true)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    /// Lines `ignore rm logs/app.log` removes from `*.log` followed by `line`
+    fn removed_own_line(line: &str) -> Vec<String> {
+        let lines = vec!["*.log".to_string(), line.to_string()];
+        let matches =
+            logic::ignore::find_matching_patterns(&lines, &HashMap::new(), "logs/app.log");
+        lines_to_remove(matches, "logs/app.log", false)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.pattern)
+            .collect()
+    }
+
+    #[test]
+    fn test_ignore_rm_strips_deletable_prefix() {
+        assert_eq!(removed_own_line("(?d)/logs/app.log"), ["(?d)/logs/app.log"]);
+    }
+
+    #[test]
+    fn test_ignore_rm_strips_case_insensitive_prefix() {
+        assert_eq!(removed_own_line("(?i)/logs/app.log"), ["(?i)/logs/app.log"]);
+        // The path's own line even when written in another case
+        assert_eq!(removed_own_line("(?i)/Logs/App.log"), ["(?i)/Logs/App.log"]);
+    }
+
+    #[test]
+    fn test_ignore_rm_strips_combined_prefixes() {
+        assert_eq!(
+            removed_own_line("(?d)(?i)/logs/app.log"),
+            ["(?d)(?i)/logs/app.log"]
+        );
+        assert_eq!(
+            removed_own_line("(?i)(?d)/logs/app.log"),
+            ["(?i)(?d)/logs/app.log"]
+        );
+    }
+
+    #[test]
+    fn test_ignore_rm_lists_broader_lines() {
+        let lines = vec!["*.log".to_string()];
+        let matches = logic::ignore::find_matching_patterns(&lines, &HashMap::new(), "debug.log");
+
+        assert_eq!(
+            lines_to_remove(matches, "debug.log", false).unwrap_err(),
+            "debug.log is ignored by *.log (line 1); use --all to remove it"
+        );
+        assert_eq!(
+            lines_to_remove(Vec::new(), "debug.log", false).unwrap_err(),
+            "No .stignore line ignores debug.log"
+        );
+    }
+
+    #[test]
+    fn test_ignore_rm_reports_included_pattern() {
+        let lines: Vec<String> = ["#include .stglobalignore", "/debug.log"]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        let included = HashMap::from([(
+            "#include .stglobalignore".to_string(),
+            vec!["**/*.log".to_string()],
+        )]);
+        let matches = logic::ignore::find_matching_patterns(&lines, &included, "debug.log");
+
+        assert_eq!(
+            lines_to_remove(matches, "debug.log", true).unwrap_err(),
+            "debug.log is ignored by **/*.log from #include .stglobalignore (line 1), \
+             which stui cannot edit"
+        );
+    }
+}
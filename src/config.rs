@@ -1,11 +1,18 @@
+use crate::logic::keymap::{Action, KeyBinding, KeyContext, Keymap};
+use anyhow::{Result, anyhow, bail};
 use serde::Deserialize;
 use std::collections::HashMap;
 
+/// Name of the profile built from the top-level `base_url`/`api_key`
+pub const DEFAULT_PROFILE: &str = "default";
+
 #[derive(Debug, Deserialize)]
 pub struct Config {
+    #[serde(default)]
     pub api_key: String,
+    #[serde(default)]
     pub base_url: String,
-    #[allow(dead_code)]
+    #[serde(default)]
     pub path_map: HashMap<String, String>,
     #[serde(default)]
     pub vim_mode: bool,
@@ -19,10 +26,172 @@ pub struct Config {
     pub image_preview_enabled: bool,
     #[serde(default = "default_image_protocol")]
     pub image_protocol: String,
+    /// Built-in theme name or path to a theme file (default: dark)
+    #[serde(default)]
+    pub theme: Option<String>,
+    /// Additional named Syncthing instances
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    /// Profile used when `--profile` is not given (default: the first one)
+    #[serde(default)]
+    pub default_profile: Option<String>,
+    /// Key binding overrides: context -> action -> key(s)
+    #[serde(default)]
+    pub keymap: HashMap<String, HashMap<String, KeyList>>,
+}
+
+/// One key or a list of keys (an empty list unbinds the action)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Connection settings of one Syncthing instance
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub base_url: String,
+    pub api_key: String,
+    #[serde(default)]
+    pub path_map: HashMap<String, String>,
+    #[serde(default)]
+    pub open_command: Option<String>,
+    #[serde(default)]
+    pub clipboard_command: Option<String>,
+    /// Built from the top-level `base_url`/`api_key`, the only instance
+    /// before profiles existed
+    #[serde(skip)]
+    pub top_level: bool,
+}
+
+impl Config {
+    /// All configured instances in config order
+    ///
+    /// The top-level `base_url`/`api_key` (if set) come first as the `default`
+    /// profile. Named profiles without their own open or clipboard command
+    /// inherit the top-level one.
+    pub fn profiles(&self) -> Vec<Profile> {
+        let mut profiles = Vec::new();
+
+        if !self.base_url.is_empty() {
+            profiles.push(Profile {
+                name: DEFAULT_PROFILE.to_string(),
+                base_url: self.base_url.clone(),
+                api_key: self.api_key.clone(),
+                path_map: self.path_map.clone(),
+                open_command: self.open_command.clone(),
+                clipboard_command: self.clipboard_command.clone(),
+                top_level: true,
+            });
+        }
+
+        profiles.extend(self.profiles.iter().map(|profile| {
+            Profile {
+                open_command: profile
+                    .open_command
+                    .clone()
+                    .or_else(|| self.open_command.clone()),
+                clipboard_command: profile
+                    .clipboard_command
+                    .clone()
+                    .or_else(|| self.clipboard_command.clone()),
+                ..profile.clone()
+            }
+        }));
+
+        profiles
+    }
+
+    /// Pick the profile to connect to
+    ///
+    /// Uses `name` (from `--profile`), then `default_profile`, then the first
+    /// configured instance.
+    pub fn resolve_profile(&self, name: Option<&str>) -> Result<Profile> {
+        let profiles = self.profiles();
+        if profiles.is_empty() {
+            bail!("No Syncthing instance configured: set base_url and api_key or add profiles");
+        }
+
+        let Some(name) = name.or(self.default_profile.as_deref()) else {
+            return Ok(profiles[0].clone());
+        };
+
+        match profiles.iter().find(|profile| profile.name == name) {
+            Some(profile) => Ok(profile.clone()),
+            None => {
+                let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+                bail!(
+                    "Unknown profile '{}' (available: {})",
+                    name,
+                    names.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Default key bindings with the `keymap` overrides applied
+    pub fn keymap(&self, vim_mode: bool) -> Result<Keymap> {
+        let mut keymap = Keymap::defaults(vim_mode);
+
+        // Sorted so the first reported error is stable
+        let mut contexts: Vec<_> = self.keymap.iter().collect();
+        contexts.sort_by_key(|(name, _)| name.as_str());
+
+        for (context_name, actions) in contexts {
+            let context = KeyContext::from_name(context_name).ok_or_else(|| {
+                anyhow!(
+                    "keymap.{}: unknown context (expected folder_list, breadcrumb, preview or modal)",
+                    context_name
+                )
+            })?;
+
+            let mut actions: Vec<_> = actions.iter().collect();
+            actions.sort_by_key(|(name, _)| name.as_str());
+
+            for (action_name, keys) in actions {
+                let action = Action::from_name(action_name).ok_or_else(|| {
+                    anyhow!("keymap.{}.{}: unknown action", context_name, action_name)
+                })?;
+                // List modals only understand navigation and their own actions
+                if context == KeyContext::Modal && !action.is_modal() {
+                    return Err(anyhow!(
+                        "keymap.modal.{}: not available in modals (expected close, up, down, select or a modal's own action)",
+                        action_name
+                    ));
+                }
+                if context != KeyContext::Modal
+                    && action.list_modal().is_some()
+                    && action != Action::Search
+                {
+                    return Err(anyhow!(
+                        "keymap.{}.{}: only available in modals",
+                        context_name,
+                        action_name
+                    ));
+                }
+                let keys = match keys {
+                    KeyList::One(key) => vec![key.clone()],
+                    KeyList::Many(keys) => keys.clone(),
+                };
+                let bindings = keys
+                    .iter()
+                    .map(|key| KeyBinding::parse(key))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| anyhow!("keymap.{}.{}: {}", context_name, action_name, e))?;
+                keymap.bind(context, action, bindings);
+            }
+        }
+
+        Ok(keymap)
+    }
 }
 
 fn default_icon_mode() -> String {
-    "nerdfont".to_string()
+    "auto".to_string()
 }
 
 fn default_image_preview_enabled() -> bool {
@@ -32,3 +201,164 @@ fn default_image_preview_enabled() -> bool {
 fn default_image_protocol() -> String {
     "auto".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> Config {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    // ========================================
+    // PROFILES
+    // ========================================
+
+    #[test]
+    fn test_single_instance_config_is_default_profile() {
+        let config =
+            parse("api_key: key\nbase_url: http://localhost:8384\npath_map:\n  /data: /mnt/data\n");
+
+        let profile = config.resolve_profile(None).unwrap();
+        assert_eq!(profile.name, DEFAULT_PROFILE);
+        assert_eq!(profile.base_url, "http://localhost:8384");
+        assert_eq!(profile.path_map.get("/data").unwrap(), "/mnt/data");
+    }
+
+    #[test]
+    fn test_named_profiles_inherit_commands() {
+        let config = parse(
+            "open_command: xdg-open\n\
+             clipboard_command: wl-copy\n\
+             profiles:\n\
+             - name: nas\n\
+             \x20 base_url: http://nas:8384\n\
+             \x20 api_key: nas-key\n\
+             \x20 open_command: nas-open\n\
+             - name: laptop\n\
+             \x20 base_url: http://laptop:8384\n\
+             \x20 api_key: laptop-key\n",
+        );
+
+        let profiles = config.profiles();
+        assert_eq!(profiles.len(), 2, "no top-level instance configured");
+        assert_eq!(profiles[0].open_command.as_deref(), Some("nas-open"));
+        assert_eq!(profiles[1].open_command.as_deref(), Some("xdg-open"));
+        assert_eq!(profiles[1].clipboard_command.as_deref(), Some("wl-copy"));
+    }
+
+    #[test]
+    fn test_resolve_profile_order() {
+        let mut config = parse(
+            "api_key: key\n\
+             base_url: http://localhost:8384\n\
+             profiles:\n\
+             - name: nas\n\
+             \x20 base_url: http://nas:8384\n\
+             \x20 api_key: nas-key\n",
+        );
+
+        assert_eq!(config.resolve_profile(None).unwrap().name, "default");
+        assert_eq!(config.resolve_profile(Some("nas")).unwrap().name, "nas");
+        // Only the top-level instance takes over the pre-profile cache
+        assert!(config.resolve_profile(None).unwrap().top_level);
+        assert!(!config.resolve_profile(Some("nas")).unwrap().top_level);
+
+        config.default_profile = Some("nas".to_string());
+        assert_eq!(config.resolve_profile(None).unwrap().name, "nas");
+        assert_eq!(
+            config.resolve_profile(Some("default")).unwrap().name,
+            "default"
+        );
+    }
+
+    #[test]
+    fn test_resolve_profile_errors() {
+        let config = parse("profiles:\n- name: nas\n  base_url: http://nas:8384\n  api_key: k\n");
+        let err = config.resolve_profile(Some("server")).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown profile 'server' (available: nas)");
+
+        let empty = parse("vim_mode: true\n");
+        assert!(empty.resolve_profile(None).is_err());
+    }
+
+    // ========================================
+    // KEYMAP
+    // ========================================
+
+    #[test]
+    fn test_keymap_overrides() {
+        let config = parse(
+            "keymap:\n\
+             \x20 breadcrumb:\n\
+             \x20   delete: x\n\
+             \x20   search: [\"/\", \"Ctrl-f\"]\n\
+             \x20 folder_list:\n\
+             \x20   quit: []\n",
+        );
+        let keymap = config.keymap(false).unwrap();
+
+        assert_eq!(
+            keymap
+                .key_labels(KeyContext::Breadcrumb, Action::Delete)
+                .unwrap(),
+            "x"
+        );
+        assert_eq!(
+            keymap
+                .key_labels(KeyContext::Breadcrumb, Action::Search)
+                .unwrap(),
+            "//^f"
+        );
+        assert_eq!(keymap.key_label(KeyContext::FolderList, Action::Quit), None);
+        assert_eq!(
+            keymap
+                .key_label(KeyContext::Breadcrumb, Action::Quit)
+                .unwrap(),
+            "q"
+        );
+    }
+
+    #[test]
+    fn test_keymap_errors_name_the_entry() {
+        let err = parse("keymap:\n  breadcrumb:\n    delete: Hyper-x\n")
+            .keymap(false)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "keymap.breadcrumb.delete: unknown modifier 'Hyper' in 'Hyper-x'"
+        );
+
+        let err = parse("keymap:\n  breadcrumb:\n    explode: x\n")
+            .keymap(false)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "keymap.breadcrumb.explode: unknown action");
+
+        assert!(
+            parse("keymap:\n  sidebar:\n    quit: x\n")
+                .keymap(false)
+                .is_err()
+        );
+
+        let err = parse("keymap:\n  modal:\n    delete: x\n")
+            .keymap(false)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "keymap.modal.delete: not available in modals (expected close, up, down, select or a modal's own action)"
+        );
+
+        let err = parse("keymap:\n  folder_list:\n    add_device: x\n")
+            .keymap(false)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "keymap.folder_list.add_device: only available in modals"
+        );
+
+        let keymap = parse("keymap:\n  modal:\n    restore_version: R\n")
+            .keymap(false)
+            .unwrap();
+        assert_eq!(keymap.modal_label(Action::RestoreVersion), "R");
+    }
+}
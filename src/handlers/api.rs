@@ -22,6 +22,8 @@ use crate::services::api::{ApiRequest, ApiResponse, Priority};
 /// - RescanResult: Result of folder rescan operation
 /// - SystemStatusResult: System info (uptime, device name)
 /// - ConnectionStatsResult: Transfer statistics
+/// - ConfigResult ... RemoteNeedResult: Event-driven and periodic refreshes,
+///   applied by the matching `App::apply_*` method
 pub fn handle_api_response(app: &mut App, response: ApiResponse) {
     match response {
         ApiResponse::BrowseResult {
@@ -49,7 +51,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
             };
 
             // Successful API call - mark as connected
-            app.model.syncthing.connection_state = ConnectionState::Connected;
+            app.model.syncthing.mark_connected(None);
 
             // Check if this response is still relevant to current navigation
             // We allow caching for subdirectories of the current folder (prefetch),
@@ -339,7 +341,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
             };
 
             // Successful API call - mark as connected
-            app.model.syncthing.connection_state = ConnectionState::Connected;
+            app.model.syncthing.mark_connected(None);
 
             // Check if this response is still relevant to current navigation
             let is_relevant = if app.model.navigation.focus_level != 0
@@ -370,7 +372,10 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                 .map(|f| f.sequence)
                 .unwrap_or(0);
 
-            let state = file_details.determine_sync_state();
+            let state = crate::logic::conflicts::apply_conflict_state(
+                &file_path,
+                file_details.determine_sync_state(),
+            );
             crate::log_debug(&format!(
                 "DEBUG [FileInfoResult]: folder={} path={} state={:?} seq={}",
                 folder_id, file_path, state, file_sequence
@@ -470,7 +475,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                 app.model.syncthing.connection_state,
                 ConnectionState::Connected
             );
-            app.model.syncthing.connection_state = ConnectionState::Connected;
+            app.model.syncthing.mark_connected(None);
 
             // If we just reconnected, immediately fetch system status for responsive UI
             // SystemStatus will handle setting needs_folder_refresh if folders are empty
@@ -591,6 +596,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                     ));
 
                     // Store system status first (needed for DevicesResult handler)
+                    let uptime = sys_status.uptime;
                     app.model.syncthing.system_status = Some(sys_status);
 
                     // Check if we just reconnected
@@ -629,8 +635,12 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                         ));
                     }
 
-                    // Successful API call - mark as connected
-                    app.model.syncthing.connection_state = ConnectionState::Connected;
+                    // Successful API call - mark as connected (this ends a restart)
+                    if app.model.syncthing.mark_connected(Some(uptime)) {
+                        app.model.ui.show_toast("Syncthing restarted".to_string());
+                        // Pick up config changes that needed the restart
+                        app.model.ui.needs_config_reload = true;
+                    }
                 }
                 Err(e) => {
                     // SystemStatus is semi-critical: if we're idle and it fails, this likely indicates disconnection
@@ -698,7 +708,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                 }
 
                 // Successful API call - mark as connected
-                app.model.syncthing.connection_state = ConnectionState::Connected;
+                app.model.syncthing.mark_connected(None);
             }
             Err(e) => {
                 // NOTE: Connection stats failures don't mark the entire connection as Disconnected
@@ -747,7 +757,7 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                 }
 
                 // Successful API call - mark as connected
-                app.model.syncthing.connection_state = ConnectionState::Connected;
+                app.model.syncthing.mark_connected(None);
             }
             Err(e) => {
                 // Device list fetch failed - log but don't change connection state
@@ -763,8 +773,19 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
             folder_id,
             response,
         } => {
-            // Cache the response
-            if let Err(e) = app.cache.cache_needed_files(&folder_id, &response) {
+            // Cache the response (send-only folders never pull what they need)
+            let is_send_only = app
+                .model
+                .syncthing
+                .folders
+                .iter()
+                .any(|f| f.id == folder_id && f.folder_type == "sendonly");
+            let cached = if is_send_only {
+                app.cache.cache_remote_changed_files(&folder_id, &response)
+            } else {
+                app.cache.cache_needed_files(&folder_id, &response)
+            };
+            if let Err(e) = cached {
                 crate::log_debug(&format!(
                     "Failed to cache needed files for {}: {}",
                     folder_id, e
@@ -841,5 +862,62 @@ pub fn handle_api_response(app: &mut App, response: ApiResponse) {
                 }
             }
         }
+
+        ApiResponse::ConfigResult { devices, folders } => {
+            app.apply_config_reload(devices, folders);
+        }
+
+        ApiResponse::DeviceCountResult { connections } => {
+            app.apply_device_count(connections);
+        }
+
+        ApiResponse::DeviceInfosResult { infos } => {
+            app.apply_device_infos(infos);
+        }
+
+        ApiResponse::RateLimitsResult { limits } => {
+            app.apply_rate_limits(limits);
+        }
+
+        ApiResponse::RestartRequiredResult { required } => {
+            app.apply_restart_required(required);
+        }
+
+        ApiResponse::SystemErrorsResult { errors } => {
+            app.apply_system_errors(errors);
+        }
+
+        ApiResponse::SystemLogResult { entries } => {
+            app.apply_system_log(entries);
+        }
+
+        ApiResponse::PendingInvitationsResult { invitations } => {
+            app.apply_pending_invitations(invitations);
+        }
+
+        ApiResponse::FailedItemsResult {
+            folder_id,
+            count,
+            items,
+        } => {
+            app.apply_failed_items(&folder_id, count, items);
+        }
+
+        ApiResponse::TransferQueueResult { folder_id, need } => {
+            app.apply_transfer_queue(&folder_id, need);
+        }
+
+        ApiResponse::RemoteCompletionResult { folder_id, devices } => {
+            app.apply_remote_completion(&folder_id, devices);
+        }
+
+        ApiResponse::RemoteNeedResult {
+            folder_id,
+            device_id,
+            count,
+            files,
+        } => {
+            app.apply_remote_need(&folder_id, &device_id, count, files);
+        }
     }
 }
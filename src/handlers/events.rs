@@ -4,7 +4,8 @@
 //! These events tell us when files/directories change so we can refresh the UI.
 
 use crate::App;
-use crate::services::api::{ApiRequest, Priority};
+use crate::logic::keymap::{Action, KeyContext};
+use crate::services::api::{ApiRequest, ApiResponse, Priority};
 use crate::services::events::CacheInvalidation;
 
 /// Handle cache invalidation messages from event listener
@@ -17,6 +18,17 @@ use crate::services::events::CacheInvalidation;
 /// - Directory: Directory changed (affects all children)
 /// - ItemStarted: File started syncing (currently skipped for performance)
 /// - ItemFinished: File finished syncing (state updated by LocalIndexUpdated)
+/// - Activity: Latest activity message per folder for the status bar
+/// - FolderErrors: Failed items changed (folder status + failed items refresh)
+/// - PendingChanged: New device/folder offers (toast + inbox refresh)
+/// - DeviceConnection: Device connected/disconnected (connected device count)
+/// - FolderSummary: Pushed folder status (handled like a status poll result)
+/// - FolderState: Folder state changed (card state, clears scan progress)
+/// - FolderCompletion: Remote device completion for a folder
+/// - FolderPaused: Folder paused/resumed
+/// - ConfigSaved: Config changed (folders and devices reloaded)
+/// - ScanProgress: Scan percentage shown on the folder card
+/// - DownloadProgress: Per-file progress and rates for the transfer queue
 pub fn handle_cache_invalidation(app: &mut App, invalidation: CacheInvalidation) {
     match invalidation {
         CacheInvalidation::File {
@@ -190,6 +202,9 @@ pub fn handle_cache_invalidation(app: &mut App, invalidation: CacheInvalidation)
                 "DEBUG [Event]: ItemStarted: folder={} path={} (skipped UI update)",
                 folder_id, file_path
             ));
+
+            // The file moves to the in-progress section of an open transfer queue
+            app.flag_transfer_queue_refresh(&folder_id);
         }
         CacheInvalidation::ItemFinished {
             folder_id,
@@ -201,6 +216,8 @@ pub fn handle_cache_invalidation(app: &mut App, invalidation: CacheInvalidation)
                 folder_id, file_path
             ));
 
+            app.flag_transfer_queue_refresh(&folder_id);
+
             // Invalidate out-of-sync cache and refresh summary modal if open
             // File just finished syncing, so need_category may have changed
             app.invalidate_and_refresh_out_of_sync_summary(&folder_id);
@@ -239,6 +256,175 @@ pub fn handle_cache_invalidation(app: &mut App, invalidation: CacheInvalidation)
                 ));
             }
         }
+        CacheInvalidation::FolderErrors { folder_id } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Failed items changed: folder={}",
+                folder_id
+            ));
+
+            // Update the error count shown on the folder card
+            let _ = app
+                .api_tx
+                .send(crate::services::api::ApiRequest::GetFolderStatus {
+                    folder_id: folder_id.clone(),
+                });
+
+            // Reload the failed items on the next loop iteration if they're shown
+            if app
+                .model
+                .ui
+                .failed_items_modal
+                .as_ref()
+                .is_some_and(|modal| modal.folder_id == folder_id)
+            {
+                app.model.ui.needs_failed_items_refresh = true;
+            }
+        }
+        CacheInvalidation::PendingChanged { message } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Pending invitations changed: {:?}",
+                message
+            ));
+
+            if let Some(message) = message {
+                let message = match app
+                    .model
+                    .ui
+                    .keymap
+                    .key_label(KeyContext::FolderList, Action::Pending)
+                {
+                    Some(key) => format!("{} - press {} to review", message, key),
+                    None => message,
+                };
+                app.model.ui.show_toast(message);
+            }
+
+            // Reload the inbox on the next loop iteration if it's open
+            if app.model.ui.pending_inbox.is_some() {
+                app.model.ui.needs_pending_refresh = true;
+            }
+        }
+        CacheInvalidation::DeviceConnection {
+            device_id,
+            connected,
+        } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Device {}: {}",
+                if connected {
+                    "connected"
+                } else {
+                    "disconnected"
+                },
+                device_id
+            ));
+
+            // Adjust the system bar count right away; the periodic
+            // /rest/system/connections poll corrects any drift
+            if let Some(count) = app.model.syncthing.connected_device_count.as_mut() {
+                *count = if connected {
+                    count.saturating_add(1)
+                } else {
+                    count.saturating_sub(1)
+                };
+            }
+        }
+        CacheInvalidation::FolderSummary { folder_id, status } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Folder summary: folder={} state={} need={}",
+                folder_id, status.state, status.need_total_items
+            ));
+
+            // Same data as /rest/db/status, so reuse the poll result handling
+            // (sequence change detection, cache write, card update)
+            crate::handlers::api::handle_api_response(
+                app,
+                ApiResponse::FolderStatusResult {
+                    folder_id,
+                    status: Ok(*status),
+                },
+            );
+        }
+        CacheInvalidation::FolderState { folder_id, state } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Folder state changed: folder={} state={}",
+                folder_id, state
+            ));
+
+            if state != "scanning" {
+                app.model.syncthing.scan_progress.remove(&folder_id);
+            }
+            if let Some(status) = app.model.syncthing.folder_statuses.get_mut(&folder_id) {
+                status.state = state;
+            }
+        }
+        CacheInvalidation::FolderCompletion {
+            folder_id,
+            device_id,
+            completion,
+        } => {
+            app.flag_remote_completion_refresh(&folder_id);
+            app.model
+                .syncthing
+                .remote_completion
+                .entry(folder_id)
+                .or_default()
+                .insert(device_id, completion);
+        }
+        CacheInvalidation::FolderPaused { folder_id, paused } => {
+            crate::log_debug(&format!(
+                "DEBUG [Event]: Folder {}: {}",
+                if paused { "paused" } else { "resumed" },
+                folder_id
+            ));
+
+            if let Some(folder) = app
+                .model
+                .syncthing
+                .folders
+                .iter_mut()
+                .find(|f| f.id == folder_id)
+            {
+                folder.paused = paused;
+            }
+            if !paused {
+                let _ = app.api_tx.send(ApiRequest::GetFolderStatus { folder_id });
+            }
+        }
+        CacheInvalidation::ConfigSaved => {
+            crate::log_debug("DEBUG [Event]: Config saved, reloading folders and devices");
+
+            // Reload on the next loop iteration (needs async API calls)
+            app.model.ui.needs_config_reload = true;
+        }
+        CacheInvalidation::ScanProgress {
+            folder_id,
+            current,
+            total,
+        } => {
+            // Empty scans report total 0 - nothing to show
+            if let Some(percent) = (current.min(total) * 100).checked_div(total) {
+                app.model
+                    .syncthing
+                    .scan_progress
+                    .insert(folder_id, percent as u8);
+            }
+        }
+        CacheInvalidation::DownloadProgress { folders, timestamp } => {
+            // Each event lists every file in progress, so folders missing
+            // from it have nothing downloading anymore
+            let previous = std::mem::take(&mut app.model.syncthing.transfers);
+            app.model.syncthing.transfers = folders
+                .into_iter()
+                .map(|(folder_id, files)| {
+                    let transfers = crate::logic::transfers::update_transfers(
+                        previous.get(&folder_id),
+                        files,
+                        timestamp,
+                    );
+                    (folder_id, transfers)
+                })
+                .collect();
+        }
     }
 }
 
@@ -268,6 +454,10 @@ mod tests {
             clipboard_command: None,
             image_preview_enabled: false,
             image_protocol: "auto".to_string(),
+            profiles: Vec::new(),
+            default_profile: None,
+            keymap: Default::default(),
+            theme: None,
         };
 
         let client = SyncthingClient::new(config.api_key.clone(), config.base_url.clone());
@@ -277,6 +467,7 @@ mod tests {
         let (_invalidation_tx, invalidation_rx) = tokio::sync::mpsc::unbounded_channel();
         let (_event_id_tx, event_id_rx) = tokio::sync::mpsc::unbounded_channel();
         let (image_update_tx, image_update_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (fleet_update_tx, fleet_update_rx) = tokio::sync::mpsc::unbounded_channel();
 
         App {
             model: Model::new(config.vim_mode),
@@ -287,16 +478,25 @@ mod tests {
             invalidation_rx,
             event_id_rx,
             icon_renderer: IconRenderer::new(IconMode::Emoji, IconTheme::default()),
+            theme: crate::ui::theme::Theme::default(),
             image_picker: None,
             image_update_tx,
             image_update_rx,
+            fleet_update_tx,
+            fleet_update_rx,
             path_map: config.path_map,
             open_command: config.open_command,
             clipboard_command: config.clipboard_command,
             base_url: config.base_url,
+            profiles: Vec::new(),
+            active_profile: crate::config::DEFAULT_PROFILE.to_string(),
+            event_listener: None,
+            event_session: None,
             last_status_update: std::time::Instant::now(),
             last_system_status_update: std::time::Instant::now(),
             last_connection_stats_fetch: std::time::Instant::now(),
+            last_error_poll: std::time::Instant::now(),
+            last_log_poll: std::time::Instant::now(),
             last_directory_update: std::time::Instant::now(),
             last_db_flush: std::time::Instant::now(),
             last_reconnect_attempt: std::time::Instant::now(),
@@ -410,4 +610,244 @@ mod tests {
             time2
         );
     }
+
+    #[test]
+    fn test_pending_changed_shows_toast_and_flags_open_inbox() {
+        let mut app = create_test_app();
+
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::PendingChanged {
+                message: Some("Device phone wants to connect".to_string()),
+            },
+        );
+        assert_eq!(
+            app.model
+                .ui
+                .toast_message
+                .as_ref()
+                .map(|(msg, _)| msg.as_str()),
+            Some("Device phone wants to connect - press P to review")
+        );
+        // Inbox closed - nothing to refresh
+        assert!(!app.model.ui.needs_pending_refresh);
+
+        // The hint follows the keymap and is left out when the action is unbound
+        app.model
+            .ui
+            .keymap
+            .bind(KeyContext::FolderList, Action::Pending, vec![]);
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::PendingChanged {
+                message: Some("Device phone wants to connect".to_string()),
+            },
+        );
+        assert_eq!(
+            app.model
+                .ui
+                .toast_message
+                .as_ref()
+                .map(|(msg, _)| msg.as_str()),
+            Some("Device phone wants to connect")
+        );
+
+        app.model.ui.pending_inbox = Some(crate::model::PendingInboxModal {
+            invitations: vec![],
+            selected_index: 0,
+            accept_form: None,
+        });
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::PendingChanged { message: None },
+        );
+        assert!(app.model.ui.needs_pending_refresh);
+    }
+
+    #[test]
+    fn test_device_connection_adjusts_count() {
+        let mut app = create_test_app();
+
+        // Unknown count stays unknown until the connections poll fills it in
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::DeviceConnection {
+                device_id: "NAS0000".to_string(),
+                connected: true,
+            },
+        );
+        assert_eq!(app.model.syncthing.connected_device_count, None);
+
+        app.model.syncthing.connected_device_count = Some(1);
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::DeviceConnection {
+                device_id: "PHONE00".to_string(),
+                connected: true,
+            },
+        );
+        assert_eq!(app.model.syncthing.connected_device_count, Some(2));
+
+        for _ in 0..3 {
+            handle_cache_invalidation(
+                &mut app,
+                CacheInvalidation::DeviceConnection {
+                    device_id: "PHONE00".to_string(),
+                    connected: false,
+                },
+            );
+        }
+        assert_eq!(app.model.syncthing.connected_device_count, Some(0));
+    }
+
+    #[test]
+    fn test_folder_state_and_scan_progress() {
+        let mut app = create_test_app();
+
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::ScanProgress {
+                folder_id: "docs".to_string(),
+                current: 150,
+                total: 200,
+            },
+        );
+        assert_eq!(app.model.syncthing.scan_progress.get("docs"), Some(&75));
+
+        // Empty scans don't divide by zero
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::ScanProgress {
+                folder_id: "photos".to_string(),
+                current: 0,
+                total: 0,
+            },
+        );
+        assert!(!app.model.syncthing.scan_progress.contains_key("photos"));
+
+        // Leaving the scanning state clears the progress
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::FolderState {
+                folder_id: "docs".to_string(),
+                state: "idle".to_string(),
+            },
+        );
+        assert!(app.model.syncthing.scan_progress.is_empty());
+    }
+
+    #[test]
+    fn test_folder_paused_and_config_saved() {
+        let mut app = create_test_app();
+        app.model.syncthing.folders = vec![crate::api::Folder {
+            id: "docs".to_string(),
+            label: None,
+            path: "/docs".to_string(),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: vec![],
+        }];
+
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::FolderPaused {
+                folder_id: "docs".to_string(),
+                paused: true,
+            },
+        );
+        assert!(app.model.syncthing.folders[0].paused);
+
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::FolderCompletion {
+                folder_id: "docs".to_string(),
+                device_id: "NAS0000".to_string(),
+                completion: 42.0,
+            },
+        );
+        assert_eq!(
+            app.model.syncthing.remote_completion["docs"].get("NAS0000"),
+            Some(&42.0)
+        );
+
+        assert!(!app.model.ui.needs_config_reload);
+        handle_cache_invalidation(&mut app, CacheInvalidation::ConfigSaved);
+        assert!(app.model.ui.needs_config_reload);
+    }
+
+    #[test]
+    fn test_folder_completion_refreshes_open_remote_completion() {
+        let mut app = create_test_app();
+        app.model.ui.remote_completion = Some(crate::model::RemoteCompletionModal {
+            folder_id: "docs".to_string(),
+            folder_label: "Documents".to_string(),
+            devices: vec![],
+            selected_index: 0,
+            remote_need: None,
+        });
+        let completion = |folder: &str| CacheInvalidation::FolderCompletion {
+            folder_id: folder.to_string(),
+            device_id: "NAS0000".to_string(),
+            completion: 50.0,
+        };
+
+        handle_cache_invalidation(&mut app, completion("photos"));
+        assert!(!app.model.ui.needs_remote_completion_refresh);
+
+        handle_cache_invalidation(&mut app, completion("docs"));
+        assert!(app.model.ui.needs_remote_completion_refresh);
+    }
+
+    #[test]
+    fn test_download_progress_tracks_rates_per_folder() {
+        use crate::api::DownloadProgress;
+        use std::collections::HashMap;
+
+        let mut app = create_test_app();
+        let progress = |bytes_done| DownloadProgress {
+            total: 10,
+            bytes_total: 10_000,
+            bytes_done,
+            ..Default::default()
+        };
+        let sample = |folder: &str, bytes_done| {
+            HashMap::from([(
+                folder.to_string(),
+                HashMap::from([("big.iso".to_string(), progress(bytes_done))]),
+            )])
+        };
+        let start = SystemTime::now();
+
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::DownloadProgress {
+                folders: sample("docs", 1_000),
+                timestamp: start,
+            },
+        );
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::DownloadProgress {
+                folders: sample("docs", 3_000),
+                timestamp: start + Duration::from_secs(2),
+            },
+        );
+        let transfer = &app.model.syncthing.transfers["docs"]["big.iso"];
+        assert_eq!(transfer.progress.bytes_done, 3_000);
+        assert_eq!(transfer.rate, Some(1_000.0));
+
+        // A folder missing from the next event has finished downloading
+        handle_cache_invalidation(
+            &mut app,
+            CacheInvalidation::DownloadProgress {
+                folders: sample("photos", 500),
+                timestamp: start + Duration::from_secs(4),
+            },
+        );
+        assert!(!app.model.syncthing.transfers.contains_key("docs"));
+        assert_eq!(
+            app.model.syncthing.transfers["photos"]["big.iso"].rate,
+            None
+        );
+    }
 }
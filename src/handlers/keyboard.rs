@@ -9,6 +9,7 @@ use std::time::Instant;
 
 use crate::App;
 use crate::api::SyncState;
+use crate::logic::keymap::{Action, KeyChord, KeyContext};
 use crate::model::{self, ConfirmAction};
 
 /// Check if folder history modal should load more files
@@ -129,6 +130,13 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                         let _ = app.client.revert_folder(&folder_id).await;
                         app.refresh_folder_statuses_nonblocking();
                     }
+                    ConfirmAction::Override {
+                        folder_id,
+                        folder_label,
+                        ..
+                    } => {
+                        app.override_folder(&folder_id, &folder_label).await;
+                    }
                     ConfirmAction::Delete { path, is_dir, .. } => {
                         // Delete file or directory
                         let delete_result = if is_dir {
@@ -271,6 +279,59 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                         // Handled above (needs y/f/n instead of just y/n)
                         unreachable!("Rescan should be handled earlier")
                     }
+                    ConfirmAction::PauseResumeDevice {
+                        device_id,
+                        name,
+                        is_paused,
+                    } => {
+                        app.set_device_paused(&device_id, &name, !is_paused).await;
+                    }
+                    ConfirmAction::RemoveDevice { device_id, name } => {
+                        app.remove_device(&device_id, &name).await;
+                    }
+                    ConfirmAction::RestoreVersion {
+                        folder_id,
+                        versions,
+                    } => {
+                        app.restore_file_versions(&folder_id, &versions).await;
+                    }
+                    ConfirmAction::ResolveConflict {
+                        folder_id,
+                        conflict_path,
+                        original_path,
+                        resolution,
+                    } => {
+                        app.resolve_conflict(
+                            &folder_id,
+                            &conflict_path,
+                            &original_path,
+                            resolution,
+                        )
+                        .await;
+                    }
+                    ConfirmAction::SaveIgnorePatterns { folder_id, .. } => {
+                        app.save_ignore_editor(&folder_id).await;
+                    }
+                    ConfirmAction::SaveFolderSettings { folder_id, .. } => {
+                        app.save_folder_settings(&folder_id).await;
+                    }
+                    ConfirmAction::Batch {
+                        folder_id,
+                        operation,
+                        items,
+                    } => {
+                        if let Err(e) = app.execute_batch(&folder_id, operation, &items).await {
+                            app.model
+                                .ui
+                                .show_toast(format!("{} failed: {}", operation.label(), e));
+                        }
+                    }
+                    ConfirmAction::RestartSyncthing { .. } => {
+                        app.restart_syncthing().await;
+                    }
+                    ConfirmAction::ShutdownSyncthing { .. } => {
+                        app.shutdown_syncthing().await;
+                    }
                 }
 
                 return Ok(());
@@ -287,6 +348,30 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
         }
     }
 
+    // List modals: translate remapped `modal` keys into the keys the modal
+    // handlers below understand (Esc, Up, Down, Enter). Modal actions of
+    // their own, and the key that opened the modal (it closes it again), are
+    // matched by action.
+    let (modal_action, opener) = if app.model.ui.has_list_modal() {
+        let keymap = &app.model.ui.keymap;
+        let chord = KeyChord::from_event(&key);
+        let nav_context = if app.model.navigation.focus_level == 0 {
+            KeyContext::FolderList
+        } else {
+            KeyContext::Breadcrumb
+        };
+        match keymap.modal_action(app.model.ui.list_modal(), &chord) {
+            Some(action) => (Some(action), None),
+            None => (None, keymap.action(nav_context, &chord)),
+        }
+    } else {
+        (None, None)
+    };
+    let key = modal_action
+        .and_then(|action| action.modal_key())
+        .map(|code| KeyEvent::new(code, KeyModifiers::NONE))
+        .unwrap_or(key);
+
     // Handle summary modal closing (process before other keys)
     if app.model.ui.out_of_sync_summary.is_some() {
         match key.code {
@@ -352,154 +437,1029 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                 modal_state.selected_index = modal_state.selected_index.saturating_sub(5);
                 return Ok(());
             }
-            KeyCode::Char('u') => {
+            _ if opener == Some(Action::UpdateHistory) => {
                 // Toggle - close modal with same key
                 app.close_folder_history_modal();
                 return Ok(());
             }
-            KeyCode::Up | KeyCode::Char('k') if app.model.ui.vim_mode => {
-                // Navigate up in history list
-                if modal_state.selected_index > 0 {
-                    modal_state.selected_index -= 1;
-                }
-                return Ok(());
+            KeyCode::Up => {
+                // Navigate up in history list
+                if modal_state.selected_index > 0 {
+                    modal_state.selected_index -= 1;
+                }
+                return Ok(());
+            }
+            KeyCode::Down => {
+                // Navigate down in history list
+                if modal_state.selected_index + 1 < modal_state.entries.len() {
+                    modal_state.selected_index += 1;
+                }
+
+                // Auto-load more if near bottom
+                if should_load_more_history(modal_state) {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::PageUp => {
+                // Page up (10 items)
+                modal_state.selected_index = modal_state.selected_index.saturating_sub(10);
+                return Ok(());
+            }
+            KeyCode::PageDown => {
+                // Page down (10 items)
+                modal_state.selected_index = (modal_state.selected_index + 10)
+                    .min(modal_state.entries.len().saturating_sub(1));
+
+                // Auto-load more if near bottom
+                if should_load_more_history(modal_state) {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::Home | KeyCode::Char('g')
+                if app.model.ui.vim_mode
+                    && app.model.ui.vim_command_state
+                        == crate::model::VimCommandState::WaitingForSecondG =>
+            {
+                // Jump to first (gg in vim mode)
+                modal_state.selected_index = 0;
+                app.model.ui.vim_command_state = crate::model::VimCommandState::None;
+                return Ok(());
+            }
+            KeyCode::Home if !app.model.ui.vim_mode => {
+                // Jump to first (non-vim mode)
+                modal_state.selected_index = 0;
+                return Ok(());
+            }
+            KeyCode::End | KeyCode::Char('G') if app.model.ui.vim_mode => {
+                // Jump to last (G in vim mode)
+                if !modal_state.entries.is_empty() {
+                    modal_state.selected_index = modal_state.entries.len() - 1;
+                }
+
+                // Immediate load if at end and more available (fast jump)
+                if modal_state.has_more && !modal_state.loading {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::End if !app.model.ui.vim_mode => {
+                // Jump to last (non-vim mode)
+                if !modal_state.entries.is_empty() {
+                    modal_state.selected_index = modal_state.entries.len() - 1;
+                }
+
+                // Immediate load if at end and more available (fast jump)
+                if modal_state.has_more && !modal_state.loading {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::Char('d')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
+            {
+                // Half page down (Ctrl-d in vim mode)
+                modal_state.selected_index = (modal_state.selected_index + 5)
+                    .min(modal_state.entries.len().saturating_sub(1));
+
+                // Auto-load more if near bottom
+                if should_load_more_history(modal_state) {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::Char('f')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
+            {
+                // Full page down (Ctrl-f in vim mode)
+                modal_state.selected_index = (modal_state.selected_index + 10)
+                    .min(modal_state.entries.len().saturating_sub(1));
+
+                // Auto-load more if near bottom
+                if should_load_more_history(modal_state) {
+                    let _ = app.load_next_history_batch().await;
+                }
+
+                return Ok(());
+            }
+            KeyCode::Char('b')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
+            {
+                // Full page up (Ctrl-b in vim mode)
+                modal_state.selected_index = modal_state.selected_index.saturating_sub(10);
+                return Ok(());
+            }
+            KeyCode::Char('g') if app.model.ui.vim_mode => {
+                // First g in gg sequence
+                app.model.ui.vim_command_state = crate::model::VimCommandState::WaitingForSecondG;
+                return Ok(());
+            }
+            _ => {
+                // Ignore other keys while modal is open
+                return Ok(());
+            }
+        }
+    }
+
+    // ========================================
+    // DEVICES MODAL HANDLERS
+    // ========================================
+
+    // Handle device add/edit form (text input takes priority over list keys)
+    if let Some(editor) = app
+        .model
+        .ui
+        .device_details_modal
+        .as_mut()
+        .and_then(|m| m.editor.as_mut())
+    {
+        use crate::model::DeviceEditorField;
+
+        match key.code {
+            KeyCode::Esc => {
+                if let Some(modal) = &mut app.model.ui.device_details_modal {
+                    modal.editor = None;
+                }
+            }
+            KeyCode::Enter => {
+                app.save_device_editor().await;
+            }
+            KeyCode::Tab | KeyCode::Down => editor.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => editor.focus_prev(),
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right
+                if editor.focused_field == DeviceEditorField::Compression =>
+            {
+                editor.compression =
+                    crate::logic::devices::next_compression(&editor.compression).to_string();
+            }
+            KeyCode::Char(' ') if editor.focused_field == DeviceEditorField::Introducer => {
+                editor.introducer = !editor.introducer;
+            }
+            KeyCode::Backspace => {
+                if let Some(text) = editor.focused_text_mut() {
+                    text.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(text) = editor.focused_text_mut() {
+                    text.push(c);
+                }
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle devices modal list (process before other keys)
+    if let Some(ref mut modal_state) = app.model.ui.device_details_modal {
+        match key.code {
+            _ if modal_action == Some(Action::AddDevice) => {
+                app.start_add_device();
+            }
+            _ if modal_action == Some(Action::EditDevice) => {
+                app.start_edit_device();
+            }
+            _ if modal_action == Some(Action::PauseDevice) => {
+                if let Some(device) = modal_state.selected_device() {
+                    app.model.ui.confirm_action = Some(ConfirmAction::PauseResumeDevice {
+                        device_id: device.device_id.clone(),
+                        name: device.device_name.clone(),
+                        is_paused: device.paused,
+                    });
+                }
+            }
+            _ if modal_action == Some(Action::RemoveDevice) => {
+                if let Some(device) = modal_state.selected_device() {
+                    app.model.ui.confirm_action = Some(ConfirmAction::RemoveDevice {
+                        device_id: device.device_id.clone(),
+                        name: device.device_name.clone(),
+                    });
+                }
+            }
+            KeyCode::Esc => {
+                app.close_devices_modal();
+            }
+            _ if opener == Some(Action::Devices) => {
+                app.close_devices_modal();
+            }
+            KeyCode::Up => {
+                modal_state.selected_index = modal_state.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                modal_state.selected_index = (modal_state.selected_index + 1)
+                    .min(modal_state.devices.len().saturating_sub(1));
+            }
+            KeyCode::Home => {
+                modal_state.selected_index = 0;
+            }
+            KeyCode::End => {
+                modal_state.selected_index = modal_state.devices.len().saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                app.start_edit_device();
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // FILE VERSIONS HANDLERS
+    // ========================================
+
+    // Handle file versions modal (process before other keys)
+    if let Some(ref mut versions_modal) = app.model.ui.file_versions_modal {
+        let selected = versions_modal.selected_index;
+        match key.code {
+            _ if modal_action == Some(Action::RestoreVersion) || key.code == KeyCode::Enter => {
+                let versions = versions_modal.versions_to_restore();
+                if !versions.is_empty() {
+                    app.model.ui.confirm_action = Some(ConfirmAction::RestoreVersion {
+                        folder_id: versions_modal.folder_id.clone(),
+                        versions,
+                    });
+                }
+            }
+            _ if modal_action == Some(Action::MarkVersion) => {
+                app.toggle_version_mark();
+            }
+            KeyCode::Esc => {
+                app.close_file_versions();
+            }
+            _ if opener == Some(Action::Versions) => {
+                app.close_file_versions();
+            }
+            KeyCode::Up => {
+                app.select_file_version(selected.saturating_sub(1)).await;
+            }
+            KeyCode::Down => {
+                app.select_file_version(selected + 1).await;
+            }
+            KeyCode::Home => {
+                app.select_file_version(0).await;
+            }
+            KeyCode::End => {
+                app.select_file_version(usize::MAX).await;
+            }
+            KeyCode::PageDown => {
+                versions_modal.scroll_offset = versions_modal.scroll_offset.saturating_add(10);
+            }
+            KeyCode::PageUp => {
+                versions_modal.scroll_offset = versions_modal.scroll_offset.saturating_sub(10);
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // FOLDER SETTINGS HANDLERS
+    // ========================================
+
+    // Handle folder settings form (text input takes priority over everything below)
+    if let Some(form) = app.model.ui.folder_settings.as_mut() {
+        use crate::logic::folder_settings::{
+            DISK_FREE_UNITS, PULL_ORDERS, VERSIONING_TYPES, cycle_option,
+        };
+        use crate::model::FolderSettingsField;
+
+        let forward = key.code != KeyCode::Left;
+        match key.code {
+            KeyCode::Esc => app.close_folder_settings(),
+            KeyCode::Enter => app.request_save_folder_settings(),
+            KeyCode::Tab | KeyCode::Down => form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus_prev(),
+            KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right
+                if form.focused_text_mut().is_none() =>
+            {
+                match form.focused_field {
+                    FolderSettingsField::FsWatcher => {
+                        form.fs_watcher_enabled = !form.fs_watcher_enabled
+                    }
+                    FolderSettingsField::IgnorePerms => form.ignore_perms = !form.ignore_perms,
+                    FolderSettingsField::IgnoreDelete => form.ignore_delete = !form.ignore_delete,
+                    FolderSettingsField::PullOrder => {
+                        form.order = cycle_option(&PULL_ORDERS, &form.order, forward).to_string();
+                    }
+                    FolderSettingsField::MinDiskFreeUnit => {
+                        form.min_disk_free_unit =
+                            cycle_option(&DISK_FREE_UNITS, &form.min_disk_free_unit, forward)
+                                .to_string();
+                    }
+                    FolderSettingsField::Versioning => {
+                        form.versioning_type =
+                            cycle_option(&VERSIONING_TYPES, &form.versioning_type, forward)
+                                .to_string();
+                    }
+                    _ => {}
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.push(c);
+                }
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // FOLDER SETUP HANDLERS
+    // ========================================
+
+    // Handle folder removal prompt (the folder name has to be typed)
+    if let Some(prompt) = app.model.ui.remove_folder.as_mut() {
+        match key.code {
+            KeyCode::Esc => app.close_remove_folder(),
+            KeyCode::Enter => app.confirm_remove_folder().await,
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Char(c) => prompt.input.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle new folder form (text input takes priority over everything below)
+    if let Some(form) = app.model.ui.new_folder.as_mut() {
+        use crate::model::NewFolderField;
+
+        let in_devices = form.focused_field == NewFolderField::Devices;
+        match key.code {
+            KeyCode::Esc => app.close_new_folder(),
+            KeyCode::Enter => app.submit_new_folder().await,
+            KeyCode::Tab => form.focus_next(),
+            KeyCode::BackTab => form.focus_prev(),
+            KeyCode::Down if in_devices && form.device_index + 1 < form.devices.len() => {
+                form.device_index += 1;
+            }
+            KeyCode::Up if in_devices && form.device_index > 0 => {
+                form.device_index -= 1;
+            }
+            KeyCode::Down => form.focus_next(),
+            KeyCode::Up => form.focus_prev(),
+            KeyCode::Char(' ') if in_devices => {
+                if let Some(device) = form.devices.get_mut(form.device_index) {
+                    device.selected = !device.selected;
+                }
+            }
+            KeyCode::Char(' ') | KeyCode::Right
+                if form.focused_field == NewFolderField::FolderType =>
+            {
+                form.folder_type =
+                    crate::logic::pending::next_folder_type(&form.folder_type).to_string();
+            }
+            KeyCode::Left if form.focused_field == NewFolderField::FolderType => {
+                form.folder_type =
+                    crate::logic::pending::prev_folder_type(&form.folder_type).to_string();
+            }
+            KeyCode::Backspace => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.pop();
+                }
+                app.update_new_folder_path();
+            }
+            KeyCode::Char(c) => {
+                if let Some(text) = form.focused_text_mut() {
+                    text.push(c);
+                }
+                app.update_new_folder_path();
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle folder sharing checklist (process before other keys)
+    if let Some(ref mut modal) = app.model.ui.folder_sharing {
+        match key.code {
+            KeyCode::Esc => app.close_folder_sharing(),
+            KeyCode::Up => {
+                modal.selected_index = modal.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                modal.selected_index =
+                    (modal.selected_index + 1).min(modal.devices.len().saturating_sub(1));
+            }
+            KeyCode::Home => {
+                modal.selected_index = 0;
+            }
+            KeyCode::End => {
+                modal.selected_index = modal.devices.len().saturating_sub(1);
+            }
+            KeyCode::Char(' ') => {
+                if let Some(device) = modal.devices.get_mut(modal.selected_index) {
+                    device.selected = !device.selected;
+                }
+            }
+            KeyCode::Enter => app.save_folder_sharing().await,
+            _ => {
+                // Ignore other keys while the checklist is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // RATE LIMIT HANDLERS
+    // ========================================
+
+    // Handle custom rate limit input (text input takes priority over list keys)
+    if let Some(input) = app
+        .model
+        .ui
+        .rate_limits
+        .as_mut()
+        .and_then(|modal| modal.custom_input.as_mut())
+    {
+        match key.code {
+            KeyCode::Esc => {
+                if let Some(modal) = &mut app.model.ui.rate_limits {
+                    modal.custom_input = None;
+                }
+            }
+            KeyCode::Enter => app.submit_custom_rate_limit().await,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => input.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle rate limit popup (process before other keys)
+    if let Some(ref mut modal) = app.model.ui.rate_limits {
+        use crate::logic::rate_limits::{RATE_PRESETS, next_direction};
+
+        match key.code {
+            _ if modal_action == Some(Action::CustomLimit) || key.code == KeyCode::Enter => {
+                modal.custom_input = Some(String::new());
+            }
+            KeyCode::Esc => app.close_rate_limits(),
+            _ if opener == Some(Action::RateLimits) => app.close_rate_limits(),
+            KeyCode::Up => {
+                modal.selected_index = modal.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                modal.selected_index =
+                    (modal.selected_index + 1).min(modal.rows.len().saturating_sub(1));
+            }
+            KeyCode::Home => {
+                modal.selected_index = 0;
+            }
+            KeyCode::End => {
+                modal.selected_index = modal.rows.len().saturating_sub(1);
+            }
+            KeyCode::Tab => {
+                modal.direction = next_direction(modal.direction);
+            }
+            KeyCode::Char(c @ '0'..='9') => {
+                if let Some((_, kbps)) = c.to_digit(10).and_then(|i| RATE_PRESETS.get(i as usize)) {
+                    app.set_rate_limit(*kbps).await;
+                }
+            }
+            _ => {
+                // Ignore other keys while the popup is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // SYSTEM MENU HANDLERS
+    // ========================================
+
+    // Handle system menu (process before other keys)
+    if let Some(ref mut menu) = app.model.ui.system_menu {
+        use crate::model::SystemMenuItem;
+
+        match key.code {
+            _ if modal_action == Some(Action::Restart) => {
+                menu.selected_index = 0;
+                app.select_system_menu_item();
+            }
+            _ if modal_action == Some(Action::Shutdown) => {
+                menu.selected_index = 1;
+                app.select_system_menu_item();
+            }
+            KeyCode::Esc => app.close_system_menu(),
+            _ if opener == Some(Action::System) => app.close_system_menu(),
+            KeyCode::Up => {
+                menu.selected_index = menu.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                menu.selected_index = (menu.selected_index + 1).min(SystemMenuItem::ALL.len() - 1);
+            }
+            KeyCode::Enter => app.select_system_menu_item(),
+            _ => {
+                // Ignore other keys while the menu is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // IGNORE EDITOR HANDLERS
+    // ========================================
+
+    // Handle .stignore editor (text input takes priority over everything below)
+    if let Some(editor) = app.model.ui.ignore_editor.as_mut() {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+
+        if key.code != KeyCode::Esc {
+            editor.discard_armed = false;
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                app.close_ignore_editor();
+                return Ok(());
+            }
+            KeyCode::Char('s') if ctrl => {
+                app.request_save_ignore_editor();
+                return Ok(());
+            }
+            KeyCode::Char('k') if ctrl => editor.delete_line(),
+            KeyCode::Char('t') if ctrl => editor.toggle_comment(),
+            KeyCode::Up if alt => editor.move_line(true),
+            KeyCode::Down if alt => editor.move_line(false),
+            KeyCode::Up => editor.move_cursor_line(true),
+            KeyCode::Down => editor.move_cursor_line(false),
+            KeyCode::Left => editor.move_cursor_left(),
+            KeyCode::Right => editor.move_cursor_right(),
+            KeyCode::Home => editor.move_cursor_home(),
+            KeyCode::End => editor.move_cursor_end(),
+            KeyCode::Enter => editor.split_line(),
+            KeyCode::Backspace => editor.backspace(),
+            KeyCode::Char(c) if !ctrl => editor.insert_char(c),
+            _ => {
+                // Ignore other keys while editing
+            }
+        }
+
+        app.refresh_ignore_preview();
+        return Ok(());
+    }
+
+    // ========================================
+    // SYNC CONFLICTS HANDLERS
+    // ========================================
+
+    // Handle sync conflicts modal (process before other keys)
+    if let Some(ref mut conflicts_modal) = app.model.ui.conflicts_modal {
+        let selected = conflicts_modal.selected_index;
+        let resolution = match modal_action {
+            Some(Action::KeepOriginal) => Some(crate::model::ConflictResolution::Original),
+            Some(Action::KeepConflict) => Some(crate::model::ConflictResolution::Conflict),
+            Some(Action::KeepBoth) => Some(crate::model::ConflictResolution::Both),
+            _ => None,
+        };
+
+        if let Some(resolution) = resolution {
+            if let Some(conflict) = conflicts_modal.selected_conflict() {
+                app.model.ui.confirm_action = Some(ConfirmAction::ResolveConflict {
+                    folder_id: conflicts_modal.folder_id.clone(),
+                    conflict_path: conflict.conflict_path.clone(),
+                    original_path: conflict.original_path.clone(),
+                    resolution,
+                });
+            }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                app.close_conflicts();
+            }
+            _ if opener == Some(Action::Conflicts) => {
+                app.close_conflicts();
+            }
+            KeyCode::Up => {
+                app.select_conflict(selected.saturating_sub(1)).await;
+            }
+            KeyCode::Down => {
+                app.select_conflict(selected + 1).await;
+            }
+            KeyCode::Home => {
+                app.select_conflict(0).await;
+            }
+            KeyCode::End => {
+                app.select_conflict(usize::MAX).await;
+            }
+            KeyCode::PageDown => {
+                conflicts_modal.scroll_offset = conflicts_modal.scroll_offset.saturating_add(10);
+            }
+            KeyCode::PageUp => {
+                conflicts_modal.scroll_offset = conflicts_modal.scroll_offset.saturating_sub(10);
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // FAILED ITEMS HANDLERS
+    // ========================================
+
+    // Handle failed items modal
+    if let Some(modal) = &app.model.ui.failed_items_modal {
+        let selected = modal.selected_index;
+        match key.code {
+            KeyCode::Esc => {
+                app.close_failed_items();
+            }
+            _ if opener == Some(Action::FailedItems) => {
+                app.close_failed_items();
+            }
+            KeyCode::Enter => {
+                app.jump_to_failed_item().await;
+            }
+            KeyCode::Up => {
+                app.select_failed_item(selected.saturating_sub(1)).await;
+            }
+            KeyCode::Down => {
+                app.select_failed_item(selected + 1).await;
+            }
+            KeyCode::PageUp => {
+                app.select_failed_item(selected.saturating_sub(10)).await;
+            }
+            KeyCode::PageDown => {
+                app.select_failed_item(selected + 10).await;
+            }
+            KeyCode::Home => {
+                app.select_failed_item(0).await;
+            }
+            KeyCode::End => {
+                app.select_failed_item(usize::MAX).await;
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // TRANSFER QUEUE HANDLERS
+    // ========================================
+
+    // Handle transfer queue modal
+    if let Some(modal) = &app.model.ui.transfer_queue {
+        let selected = modal.selected_index;
+        match key.code {
+            _ if modal_action == Some(Action::Prioritize) => {
+                app.prioritize_selected_transfer().await;
+            }
+            KeyCode::Esc => {
+                app.close_transfer_queue();
+            }
+            _ if opener == Some(Action::Transfers) => {
+                app.close_transfer_queue();
+            }
+            KeyCode::Up => {
+                app.select_transfer(selected.saturating_sub(1));
+            }
+            KeyCode::Down => {
+                app.select_transfer(selected + 1);
+            }
+            KeyCode::PageUp => {
+                app.select_transfer(selected.saturating_sub(10));
+            }
+            KeyCode::PageDown => {
+                app.select_transfer(selected + 10);
+            }
+            KeyCode::Home => {
+                app.select_transfer(0);
+            }
+            KeyCode::End => {
+                app.select_transfer(usize::MAX);
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // REMOTE COMPLETION HANDLERS
+    // ========================================
+
+    // Handle the files needed by a remote device (drilled into from the device list)
+    if let Some(list) = app
+        .model
+        .ui
+        .remote_completion
+        .as_ref()
+        .and_then(|modal| modal.remote_need.as_ref())
+    {
+        let selected = list.selected_index;
+        match key.code {
+            KeyCode::Esc | KeyCode::Left | KeyCode::Backspace => {
+                app.close_remote_need();
+            }
+            _ if opener == Some(Action::RemoteCompletion) => {
+                app.close_remote_completion();
+            }
+            KeyCode::Up => {
+                app.select_remote_need_file(selected.saturating_sub(1))
+                    .await;
+            }
+            KeyCode::Down => {
+                app.select_remote_need_file(selected + 1).await;
+            }
+            KeyCode::PageUp => {
+                app.select_remote_need_file(selected.saturating_sub(10))
+                    .await;
+            }
+            KeyCode::PageDown => {
+                app.select_remote_need_file(selected + 10).await;
+            }
+            KeyCode::Home => {
+                app.select_remote_need_file(0).await;
+            }
+            KeyCode::End => {
+                app.select_remote_need_file(usize::MAX).await;
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // Handle remote completion modal
+    if let Some(modal) = &app.model.ui.remote_completion {
+        let selected = modal.selected_index;
+        match key.code {
+            KeyCode::Esc => {
+                app.close_remote_completion();
+            }
+            _ if opener == Some(Action::RemoteCompletion) => {
+                app.close_remote_completion();
+            }
+            KeyCode::Enter | KeyCode::Right => {
+                app.open_remote_need().await;
+            }
+            KeyCode::Up => {
+                app.select_remote_device(selected.saturating_sub(1));
+            }
+            KeyCode::Down => {
+                app.select_remote_device(selected + 1);
+            }
+            KeyCode::Home => {
+                app.select_remote_device(0);
+            }
+            KeyCode::End => {
+                app.select_remote_device(usize::MAX);
+            }
+            _ => {
+                // Ignore other keys while modal is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // LOG VIEWER HANDLERS
+    // ========================================
+
+    // Handle log viewer search input (typing takes priority over viewer keys)
+    if let Some(viewer) = app
+        .model
+        .ui
+        .log_viewer
+        .as_mut()
+        .filter(|viewer| viewer.search_active)
+    {
+        match key.code {
+            KeyCode::Esc => {
+                viewer.search_query.clear();
+                viewer.search_active = false;
+            }
+            KeyCode::Enter => {
+                viewer.search_active = false;
+            }
+            KeyCode::Backspace => {
+                viewer.search_query.pop();
+            }
+            KeyCode::Char(c) => {
+                viewer.search_query.push(c);
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle log viewer
+    if let Some(viewer) = &mut app.model.ui.log_viewer {
+        match key.code {
+            KeyCode::Esc if !viewer.search_query.is_empty() => {
+                viewer.search_query.clear();
+            }
+            _ if modal_action == Some(Action::ToggleFollow) => {
+                viewer.follow = !viewer.follow;
+            }
+            _ if modal_action == Some(Action::CycleLevel) => {
+                viewer.min_level = crate::logic::logs::next_min_level(viewer.min_level);
+            }
+            _ if modal_action == Some(Action::Search) => {
+                viewer.search_active = true;
+            }
+            _ if modal_action == Some(Action::ClearErrors) => {
+                app.clear_system_errors().await;
+            }
+            KeyCode::Esc => {
+                app.close_log_viewer();
+            }
+            _ if opener == Some(Action::Logs) => {
+                app.close_log_viewer();
+            }
+            KeyCode::Up => {
+                viewer.follow = false;
+                viewer.scroll_offset = viewer.scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                viewer.scroll_offset = viewer.scroll_offset.saturating_add(1);
+            }
+            KeyCode::PageUp => {
+                viewer.follow = false;
+                viewer.scroll_offset = viewer.scroll_offset.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                viewer.scroll_offset = viewer.scroll_offset.saturating_add(10);
+            }
+            KeyCode::Home | KeyCode::Char('g') => {
+                viewer.follow = false;
+                viewer.scroll_offset = 0;
+            }
+            KeyCode::End | KeyCode::Char('G') => {
+                viewer.follow = true;
+            }
+            _ => {
+                // Ignore other keys while viewer is open
+            }
+        }
+        return Ok(());
+    }
+
+    // ========================================
+    // PENDING INVITATIONS HANDLERS
+    // ========================================
+
+    // Handle accept-folder form (text input takes priority over list keys)
+    if let Some(form) = app
+        .model
+        .ui
+        .pending_inbox
+        .as_mut()
+        .and_then(|inbox| inbox.accept_form.as_mut())
+    {
+        match key.code {
+            KeyCode::Esc => {
+                if let Some(inbox) = &mut app.model.ui.pending_inbox {
+                    inbox.accept_form = None;
+                }
+            }
+            KeyCode::Enter => {
+                app.submit_accept_folder_form().await;
+            }
+            KeyCode::Tab | KeyCode::Right => {
+                form.folder_type =
+                    crate::logic::pending::next_folder_type(&form.folder_type).to_string();
+            }
+            KeyCode::BackTab | KeyCode::Left => {
+                form.folder_type =
+                    crate::logic::pending::prev_folder_type(&form.folder_type).to_string();
+            }
+            KeyCode::Backspace => {
+                form.path.pop();
+            }
+            KeyCode::Char(c) => {
+                form.path.push(c);
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    // Handle pending invitations list (process before other keys)
+    if let Some(ref mut inbox) = app.model.ui.pending_inbox {
+        match key.code {
+            _ if modal_action == Some(Action::Accept) || key.code == KeyCode::Enter => {
+                app.accept_selected_invitation().await;
+            }
+            _ if modal_action == Some(Action::Dismiss) => {
+                app.dismiss_selected_invitation().await;
+            }
+            KeyCode::Esc => {
+                app.close_pending_inbox();
+            }
+            _ if opener == Some(Action::Pending) => {
+                app.close_pending_inbox();
+            }
+            KeyCode::Up => {
+                inbox.selected_index = inbox.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                inbox.selected_index =
+                    (inbox.selected_index + 1).min(inbox.invitations.len().saturating_sub(1));
             }
-            KeyCode::Down | KeyCode::Char('j') if app.model.ui.vim_mode => {
-                // Navigate down in history list
-                if modal_state.selected_index + 1 < modal_state.entries.len() {
-                    modal_state.selected_index += 1;
-                }
-
-                // Auto-load more if near bottom
-                if should_load_more_history(modal_state) {
-                    let _ = app.load_next_history_batch().await;
-                }
-
-                return Ok(());
+            KeyCode::Home => {
+                inbox.selected_index = 0;
             }
-            KeyCode::Up if !app.model.ui.vim_mode => {
-                // Navigate up (non-vim mode)
-                if modal_state.selected_index > 0 {
-                    modal_state.selected_index -= 1;
-                }
-                return Ok(());
+            KeyCode::End => {
+                inbox.selected_index = inbox.invitations.len().saturating_sub(1);
             }
-            KeyCode::Down if !app.model.ui.vim_mode => {
-                // Navigate down (non-vim mode)
-                if modal_state.selected_index + 1 < modal_state.entries.len() {
-                    modal_state.selected_index += 1;
-                }
-
-                // Auto-load more if near bottom
-                if should_load_more_history(modal_state) {
-                    let _ = app.load_next_history_batch().await;
-                }
+            _ => {
+                // Ignore other keys while inbox is open
+            }
+        }
+        return Ok(());
+    }
 
-                return Ok(());
+    // Handle fleet overview (process before other keys)
+    if let Some(ref mut overview) = app.model.ui.fleet_overview {
+        match key.code {
+            _ if modal_action == Some(Action::Refresh) => {
+                app.poll_fleet();
             }
-            KeyCode::PageUp => {
-                // Page up (10 items)
-                modal_state.selected_index = modal_state.selected_index.saturating_sub(10);
-                return Ok(());
+            KeyCode::Esc => {
+                app.close_fleet_overview();
             }
-            KeyCode::PageDown => {
-                // Page down (10 items)
-                modal_state.selected_index = (modal_state.selected_index + 10)
-                    .min(modal_state.entries.len().saturating_sub(1));
-
-                // Auto-load more if near bottom
-                if should_load_more_history(modal_state) {
-                    let _ = app.load_next_history_batch().await;
-                }
-
-                return Ok(());
+            _ if opener == Some(Action::Overview) => {
+                app.close_fleet_overview();
             }
-            KeyCode::Home | KeyCode::Char('g')
-                if app.model.ui.vim_mode
-                    && app.model.ui.vim_command_state
-                        == crate::model::VimCommandState::WaitingForSecondG =>
-            {
-                // Jump to first (gg in vim mode)
-                modal_state.selected_index = 0;
-                app.model.ui.vim_command_state = crate::model::VimCommandState::None;
-                return Ok(());
+            KeyCode::Up => {
+                overview.selected_index = overview.selected_index.saturating_sub(1);
             }
-            KeyCode::Home if !app.model.ui.vim_mode => {
-                // Jump to first (non-vim mode)
-                modal_state.selected_index = 0;
-                return Ok(());
+            KeyCode::Down => {
+                overview.selected_index =
+                    (overview.selected_index + 1).min(overview.rows.len().saturating_sub(1));
             }
-            KeyCode::End | KeyCode::Char('G') if app.model.ui.vim_mode => {
-                // Jump to last (G in vim mode)
-                if !modal_state.entries.is_empty() {
-                    modal_state.selected_index = modal_state.entries.len() - 1;
-                }
-
-                // Immediate load if at end and more available (fast jump)
-                if modal_state.has_more && !modal_state.loading {
-                    let _ = app.load_next_history_batch().await;
-                }
-
-                return Ok(());
+            KeyCode::Enter => {
+                app.open_selected_instance().await;
             }
-            KeyCode::End if !app.model.ui.vim_mode => {
-                // Jump to last (non-vim mode)
-                if !modal_state.entries.is_empty() {
-                    modal_state.selected_index = modal_state.entries.len() - 1;
-                }
-
-                // Immediate load if at end and more available (fast jump)
-                if modal_state.has_more && !modal_state.loading {
-                    let _ = app.load_next_history_batch().await;
-                }
-
-                return Ok(());
+            _ => {
+                // Ignore other keys while the overview is open
             }
-            KeyCode::Char('d')
-                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
-            {
-                // Half page down (Ctrl-d in vim mode)
-                modal_state.selected_index = (modal_state.selected_index + 5)
-                    .min(modal_state.entries.len().saturating_sub(1));
-
-                // Auto-load more if near bottom
-                if should_load_more_history(modal_state) {
-                    let _ = app.load_next_history_batch().await;
-                }
+        }
+        return Ok(());
+    }
 
-                return Ok(());
+    // Handle profile switcher (process before other keys)
+    if let Some(ref mut switcher) = app.model.ui.profile_switcher {
+        match key.code {
+            KeyCode::Esc => {
+                app.close_profile_switcher();
             }
-            KeyCode::Char('f')
-                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
-            {
-                // Full page down (Ctrl-f in vim mode)
-                modal_state.selected_index = (modal_state.selected_index + 10)
-                    .min(modal_state.entries.len().saturating_sub(1));
-
-                // Auto-load more if near bottom
-                if should_load_more_history(modal_state) {
-                    let _ = app.load_next_history_batch().await;
-                }
-
-                return Ok(());
+            _ if opener == Some(Action::Profiles) => {
+                app.close_profile_switcher();
             }
-            KeyCode::Char('b')
-                if key.modifiers.contains(KeyModifiers::CONTROL) && app.model.ui.vim_mode =>
-            {
-                // Full page up (Ctrl-b in vim mode)
-                modal_state.selected_index = modal_state.selected_index.saturating_sub(10);
-                return Ok(());
+            KeyCode::Up => {
+                switcher.selected_index = switcher.selected_index.saturating_sub(1);
             }
-            KeyCode::Char('g') if app.model.ui.vim_mode => {
-                // First g in gg sequence
-                app.model.ui.vim_command_state = crate::model::VimCommandState::WaitingForSecondG;
-                return Ok(());
+            KeyCode::Down => {
+                switcher.selected_index =
+                    (switcher.selected_index + 1).min(switcher.profiles.len().saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                let name = switcher.selected_profile().map(|p| p.name.clone());
+                app.close_profile_switcher();
+                if let Some(name) = name
+                    && let Err(e) = app.switch_profile(&name).await
+                {
+                    app.model
+                        .ui
+                        .show_toast(format!("Failed to switch profile: {}", e));
+                }
             }
             _ => {
-                // Ignore other keys while modal is open
-                return Ok(());
+                // Ignore other keys while the switcher is open
             }
         }
+        return Ok(());
     }
 
     // Handle pattern selection menu
@@ -526,14 +1486,18 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                     let pattern_to_remove = pattern_state.patterns[selected].clone();
                     let folder_id = pattern_state.folder_id.clone();
                     let item_name = pattern_state.item_name.clone();
+                    let relative_path = pattern_state.relative_path.clone();
                     app.model.ui.pattern_selection = None;
 
                     // Get all patterns and remove the selected one
-                    let all_patterns = app.client.get_ignore_patterns(&folder_id).await?;
-                    let updated_patterns: Vec<String> = all_patterns
-                        .into_iter()
-                        .filter(|p| p != &pattern_to_remove)
-                        .collect();
+                    let ignores = app.client.get_ignores(&folder_id).await?;
+                    let included =
+                        crate::logic::ignore::included_patterns(&ignores.lines, &ignores.expanded);
+                    let updated_patterns = crate::logic::ignore::remove_ignore_line(
+                        &ignores.lines,
+                        &pattern_to_remove,
+                    );
+                    app.toast_if_still_ignored(&updated_patterns, &included, &relative_path);
 
                     app.client
                         .set_ignore_patterns(&folder_id, updated_patterns)
@@ -693,81 +1657,51 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
 
     // Handle file info popup
     if let Some(popup_state) = &mut app.model.ui.file_info_popup {
-        match key.code {
-            KeyCode::Esc | KeyCode::Char('?') | KeyCode::Left | KeyCode::Backspace => {
+        let (action, vim_command_state) = app.model.ui.keymap.resolve(
+            KeyContext::Preview,
+            &KeyChord::from_event(&key),
+            app.model.ui.vim_command_state,
+        );
+        app.model.ui.vim_command_state = vim_command_state;
+
+        match action {
+            Some(Action::Close) => {
                 // Close popup and trigger sixel cleanup if it was an image (terminal.clear once)
                 if popup_state.is_image {
                     app.model.ui.sixel_cleanup_frames = 1;
                 }
                 app.model.ui.file_info_popup = None;
-                return Ok(());
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                // Scroll down
+            Some(Action::Down) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_add(1);
-                return Ok(());
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                // Scroll up
+            Some(Action::Up) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_sub(1);
-                return Ok(());
-            }
-            KeyCode::PageDown => {
-                // Scroll down by page (10 lines)
-                popup_state.scroll_offset = popup_state.scroll_offset.saturating_add(10);
-                return Ok(());
-            }
-            KeyCode::PageUp => {
-                // Scroll up by page (10 lines)
-                popup_state.scroll_offset = popup_state.scroll_offset.saturating_sub(10);
-                return Ok(());
             }
-            // Vim keybindings for scrolling
-            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl-d: Half page down
+            Some(Action::HalfPageDown) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_add(10);
-                return Ok(());
             }
-            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl-u: Half page up
+            Some(Action::HalfPageUp) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_sub(10);
-                return Ok(());
             }
-            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl-f: Full page down
+            Some(Action::PageDown) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_add(20);
-                return Ok(());
             }
-            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl-b: Full page up
+            Some(Action::PageUp) => {
                 popup_state.scroll_offset = popup_state.scroll_offset.saturating_sub(20);
-                return Ok(());
             }
-            KeyCode::Char('g') => {
-                // First 'g' in 'gg' sequence - need to track this
-                if app.model.ui.vim_command_state == model::VimCommandState::WaitingForSecondG {
-                    // This is the second 'g' - go to top
-                    popup_state.scroll_offset = 0;
-                    app.model.ui.vim_command_state = model::VimCommandState::None;
-                } else {
-                    // First 'g' - wait for second one
-                    app.model.ui.vim_command_state = model::VimCommandState::WaitingForSecondG;
-                }
-                return Ok(());
+            Some(Action::First) => {
+                popup_state.scroll_offset = 0;
             }
-            KeyCode::Char('G') => {
-                // Go to bottom (set to a very large number, will be clamped by rendering)
+            Some(Action::Last) => {
+                // Very large offset, clamped by rendering
                 popup_state.scroll_offset = u16::MAX;
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-                return Ok(());
             }
             _ => {
-                // Reset 'gg' sequence on any other key
-                app.model.ui.vim_command_state = model::VimCommandState::None;
                 // Ignore other keys while popup is showing
-                return Ok(());
             }
         }
+        return Ok(());
     }
 
     // Handle search input mode (process before other keys)
@@ -866,80 +1800,116 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
             app.refresh_all_breadcrumbs().await?;
             return Ok(());
         }
+
+        // Then drop multi-select marks
+        if app.clear_marks() {
+            return Ok(());
+        }
     }
 
-    match key.code {
-        KeyCode::Char('q') => app.model.ui.should_quit = true,
-        KeyCode::Char('r') => {
-            // Show rescan confirmation dialog
-            if let Some((folder_id, folder_label)) = app.get_rescan_folder_info() {
+    let context = if app.model.navigation.focus_level == 0 {
+        KeyContext::FolderList
+    } else {
+        KeyContext::Breadcrumb
+    };
+    let (action, vim_command_state) = app.model.ui.keymap.resolve(
+        context,
+        &KeyChord::from_event(&key),
+        app.model.ui.vim_command_state,
+    );
+    app.model.ui.vim_command_state = vim_command_state;
+
+    let Some(action) = action else {
+        return Ok(());
+    };
+
+    match action {
+        Action::Quit => app.model.ui.should_quit = true,
+        Action::Rescan => {
+            if app.has_marked_items() {
+                // Rescan only the marked items (one request)
+                app.rescan_marked_items();
+            } else if let Some((folder_id, folder_label)) = app.get_rescan_folder_info() {
+                // Show rescan confirmation dialog
                 app.model.ui.confirm_action = Some(ConfirmAction::Rescan {
                     folder_id,
                     folder_label,
                 });
             }
         }
-        KeyCode::Char('R') => {
+        Action::Restore => {
             // Restore selected file (if remote-only/deleted locally)
             let _ = app.restore_selected_file().await;
         }
-        // Vim keybindings with Ctrl modifiers (check before 'd' and other letters)
-        KeyCode::Char('d')
-            if app.model.ui.vim_mode && key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.half_page_down(20).await; // Use reasonable default, will be more precise with frame height
-        }
-        KeyCode::Char('u')
-            if app.model.ui.vim_mode && key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.half_page_up(20).await;
+        Action::Override => {
+            // Override remote changes in a send-only folder (asks first)
+            if let Err(e) = app.override_remote_changes().await {
+                app.model
+                    .ui
+                    .show_toast(format!("Failed to load remote changes: {}", e));
+            }
         }
-        KeyCode::Char('f')
-            if !app.model.ui.vim_mode && key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            // Ctrl-F: Enter search mode (normal mode only - vim uses / instead)
+        Action::Search => {
             app.enter_search_mode();
         }
-        KeyCode::Char('f')
-            if app.model.ui.vim_mode && key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.page_down(40).await;
-        }
-        KeyCode::Char('b')
-            if app.model.ui.vim_mode && key.modifiers.contains(KeyModifiers::CONTROL) =>
-        {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.page_up(40).await;
-        }
-        KeyCode::Char('d') => {
+        Action::Delete => {
             // Flush pending writes before destructive operation
             app.flush_pending_db_writes();
-            // Delete file from disk (with confirmation)
-            let _ = app.delete_file().await;
+            if app.has_marked_items() {
+                // Delete all marked items (one confirmation)
+                app.request_batch(model::BatchOperation::Delete);
+            } else {
+                // Delete file from disk (with confirmation)
+                let _ = app.delete_file().await;
+            }
         }
-        KeyCode::Char('i') => {
-            // Toggle ignore state (add or remove from .stignore)
-            let _ = app.toggle_ignore().await;
+        Action::ToggleIgnore => {
+            if app.has_marked_items() {
+                // Ignore all marked items (one .stignore write)
+                app.request_batch(model::BatchOperation::Ignore);
+            } else {
+                // Toggle ignore state (add or remove from .stignore)
+                let _ = app.toggle_ignore().await;
+            }
         }
-        KeyCode::Char('I') => {
+        Action::IgnoreDelete => {
             // Flush pending writes before destructive operation
             app.flush_pending_db_writes();
-            // Ignore file AND delete from disk
-            let _ = app.ignore_and_delete().await;
+            if app.has_marked_items() {
+                // Ignore and delete all marked items (one confirmation)
+                app.request_batch(model::BatchOperation::IgnoreDelete);
+            } else {
+                // Ignore file AND delete from disk
+                let _ = app.ignore_and_delete().await;
+            }
         }
-        KeyCode::Char('o') if app.model.navigation.focus_level == 0 => {
+        Action::ToggleMark if app.model.navigation.focus_level > 0 => {
+            // Toggle mark on the selected item and move down
+            app.toggle_mark();
+            app.next_item().await;
+        }
+        Action::MarkRange => {
+            // Mark from the last toggled item to the selection
+            app.mark_range();
+        }
+        Action::MarkMatches => {
+            // Mark every item matching the current search
+            app.mark_all_matching();
+        }
+        Action::OpenWebUi => {
             // Open Syncthing web UI in browser
             let _ = app.open_syncthing_web_ui();
         }
-        KeyCode::Char('o') if app.model.navigation.focus_level > 0 => {
-            // Open file/directory with configured command
-            let _ = app.open_selected_item();
+        Action::OpenItem => {
+            if app.has_marked_items() {
+                app.open_marked_items();
+            } else {
+                // Open file/directory with configured command
+                let _ = app.open_selected_item();
+            }
         }
-        KeyCode::Char('c') if app.model.navigation.focus_level == 0 => {
-            // Change folder type (only in folder view)
+        Action::ChangeType => {
+            // Change folder type of the selected folder
             if let Some(folder) = app.model.selected_folder() {
                 let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
                 // Determine initial selection index based on current type
@@ -958,24 +1928,97 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                     });
             }
         }
-        KeyCode::Char('c') if app.model.navigation.focus_level > 0 => {
-            // Copy file/directory path (breadcrumbs only)
-            let _ = app.copy_to_clipboard();
+        Action::CopyPath => {
+            if app.has_marked_items() {
+                // Copy all marked paths, one per line
+                app.copy_marked_paths();
+            } else {
+                // Copy file/directory path (breadcrumbs only)
+                let _ = app.copy_to_clipboard();
+            }
         }
-        KeyCode::Char('f') if app.model.navigation.focus_level > 0 => {
+        Action::Filter => {
             // Toggle out-of-sync filter (only in breadcrumb view)
             app.activate_out_of_sync_filter();
         }
-        KeyCode::Char('u') if app.model.navigation.focus_level == 0 => {
-            // Open folder update history modal (folder view only)
+        Action::FolderSettings => {
+            // Edit settings of the selected folder
+            app.open_folder_settings().await;
+        }
+        Action::NewFolder => {
+            // Add a new folder
+            app.start_new_folder();
+        }
+        Action::ShareFolder => {
+            // Choose the devices the selected folder is shared with
+            app.open_folder_sharing();
+        }
+        Action::RemoveFolder => {
+            // Remove the selected folder (asks for its name first)
+            app.start_remove_folder();
+        }
+        Action::UpdateHistory => {
+            // Open folder update history modal
             if let Some(folder) = app.model.selected_folder() {
                 let folder_id = folder.id.clone();
                 let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
                 app.open_folder_history_modal(&folder_id, &label).await;
             }
         }
-        KeyCode::Char('p') if app.model.navigation.focus_level == 0 => {
-            // Pause/resume folder (only in folder view)
+        Action::Devices => {
+            // Open device management modal
+            app.open_devices_modal().await;
+        }
+        Action::Pending => {
+            // Open pending invitations inbox
+            app.open_pending_inbox().await;
+        }
+        Action::Overview => {
+            // Fleet overview of all profiles
+            app.open_fleet_overview();
+        }
+        Action::Profiles => {
+            // Switch Syncthing instance profile
+            app.open_profile_switcher();
+        }
+        Action::System => {
+            // Syncthing version, restart and shutdown
+            app.open_system_menu().await;
+        }
+        Action::Conflicts => {
+            // List and resolve sync conflicts in the selected/current folder
+            app.open_conflicts().await;
+        }
+        Action::EditIgnores => {
+            // Edit .stignore of the selected/current folder
+            app.open_ignore_editor().await;
+        }
+        Action::FailedItems => {
+            // List files that failed to sync in the selected/current folder
+            app.open_failed_items().await;
+        }
+        Action::Transfers => {
+            // Download queue of the selected/current folder
+            app.open_transfer_queue().await;
+        }
+        Action::RemoteCompletion => {
+            // How far each remote device is with the selected/current folder
+            app.open_remote_completion().await;
+        }
+        Action::Logs => {
+            // Show the Syncthing log and reported errors
+            app.open_log_viewer().await;
+        }
+        Action::RateLimits => {
+            // Global and per-device bandwidth limits
+            app.open_rate_limits().await;
+        }
+        Action::Versions => {
+            // Browse archived versions of the selected file (breadcrumb view only)
+            app.open_file_versions().await;
+        }
+        Action::PauseResume => {
+            // Pause/resume the selected folder
             if let Some(folder) = app.model.selected_folder() {
                 let label = folder.label.clone().unwrap_or_else(|| folder.id.clone());
                 app.model.ui.confirm_action = Some(crate::model::ConfirmAction::PauseResume {
@@ -985,7 +2028,7 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                 });
             }
         }
-        KeyCode::Char('s') => {
+        Action::CycleSort => {
             // Cycle through sort modes
             app.cycle_sort_mode();
             // Show toast with new sort mode
@@ -993,7 +2036,7 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                 .ui
                 .show_toast(format!("Sort: {}", app.model.ui.sort_mode.as_str()));
         }
-        KeyCode::Char('S') => {
+        Action::ReverseSort => {
             // Toggle reverse sort order
             app.toggle_sort_reverse();
             // Show toast with new sort direction
@@ -1008,122 +2051,57 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                 direction
             ));
         }
-        KeyCode::Char('t') => {
+        Action::CycleDisplay => {
             // Cycle through display modes: Off -> TimestampOnly -> TimestampAndSize -> Off
             app.model.ui.display_mode =
                 crate::logic::ui::cycle_display_mode(app.model.ui.display_mode);
         }
-        KeyCode::Char('/') if app.model.ui.vim_mode => {
-            // /: Enter search mode (vim mode only)
-            app.enter_search_mode();
-        }
-        KeyCode::Char('?') if app.model.navigation.focus_level > 0 => {
-            // Toggle file information popup
-            if let Some(popup_state) = &app.model.ui.file_info_popup {
-                // Close popup and trigger sixel cleanup if it was an image (terminal.clear once)
-                if popup_state.is_image {
-                    app.model.ui.sixel_cleanup_frames = 1;
-                }
-                app.model.ui.file_info_popup = None;
-            } else {
-                // Open popup for selected item
-                if let Some(level) = app
-                    .model
-                    .navigation
-                    .breadcrumb_trail
-                    .get(app.model.navigation.focus_level - 1)
-                    && let Some(selected_idx) = level.selected_index
-                    && let Some(item) = level.display_items().get(selected_idx)
-                {
-                    // Construct full path
-                    let file_path = if let Some(prefix) = &level.prefix {
-                        format!("{}{}", prefix, item.name)
-                    } else {
-                        item.name.clone()
-                    };
+        Action::FileInfo if app.model.navigation.focus_level > 0 => {
+            // Open file information popup for the selected item
+            if let Some(level) = app
+                .model
+                .navigation
+                .breadcrumb_trail
+                .get(app.model.navigation.focus_level - 1)
+                && let Some(selected_idx) = level.selected_index
+                && let Some(item) = level.display_items().get(selected_idx)
+            {
+                // Construct full path
+                let file_path = if let Some(prefix) = &level.prefix {
+                    format!("{}{}", prefix, item.name)
+                } else {
+                    item.name.clone()
+                };
 
-                    // Fetch file info and content (await since it's async)
-                    app.fetch_file_info_and_content(
-                        level.folder_id.clone(),
-                        file_path,
-                        item.clone(),
-                    )
+                // Fetch file info and content (await since it's async)
+                app.fetch_file_info_and_content(level.folder_id.clone(), file_path, item.clone())
                     .await;
-                }
-            }
-        }
-        // Vim keybindings
-        KeyCode::Char('h') if app.model.ui.vim_mode => {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.go_back();
-        }
-        KeyCode::Char('j') if app.model.ui.vim_mode => {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.next_item().await;
-        }
-        KeyCode::Char('k') if app.model.ui.vim_mode => {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.previous_item().await;
-        }
-        KeyCode::Char('l') if app.model.ui.vim_mode => {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            if app.model.navigation.focus_level == 0 {
-                app.load_root_level(false).await?; // Not preview - actually enter folder
-            } else {
-                app.enter_directory().await?;
             }
         }
-        KeyCode::Char('g') if app.model.ui.vim_mode => {
-            let (new_state, should_jump) = crate::logic::ui::next_vim_command_state(
-                app.model.ui.vim_command_state,
-                true, // 'g' key pressed
-            );
-            app.model.ui.vim_command_state = new_state;
-            if should_jump {
-                app.jump_to_first().await;
-            }
+        Action::HalfPageDown => {
+            app.half_page_down(20).await; // Use reasonable default, will be more precise with frame height
         }
-        KeyCode::Char('G') if app.model.ui.vim_mode => {
-            app.model.ui.vim_command_state = model::VimCommandState::None;
-            app.jump_to_last().await;
+        Action::HalfPageUp => {
+            app.half_page_up(20).await;
         }
-        // Standard navigation keys (not advertised)
-        KeyCode::PageDown => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::PageDown => {
             app.page_down(40).await;
         }
-        KeyCode::PageUp => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::PageUp => {
             app.page_up(40).await;
         }
-        KeyCode::Home => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::First => {
             app.jump_to_first().await;
         }
-        KeyCode::End => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::Last => {
             app.jump_to_last().await;
         }
-        KeyCode::Left | KeyCode::Backspace => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::Back => {
             // Flush before navigation to save state
             app.flush_pending_db_writes();
             app.go_back();
         }
-        KeyCode::Right | KeyCode::Enter => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::Select => {
             // Flush before navigation to save state
             app.flush_pending_db_writes();
             if app.model.navigation.focus_level == 0 {
@@ -1139,7 +2117,7 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                     && let Some(item) = level.display_items().get(selected_idx)
                 {
                     if item.item_type != "FILE_INFO_TYPE_DIRECTORY" {
-                        // File - show preview (same logic as '?' key)
+                        // File - show preview (same as the file info key)
                         let file_path = if let Some(prefix) = &level.prefix {
                             format!("{}{}", prefix, item.name)
                         } else {
@@ -1159,24 +2137,33 @@ pub async fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
                 }
             }
         }
-        KeyCode::Up => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::Up => {
             app.previous_item().await;
         }
-        KeyCode::Down => {
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
+        Action::Down => {
             app.next_item().await;
         }
-        _ => {
-            // Reset last_key_was_g on any other key
-            if app.model.ui.vim_mode {
-                app.model.ui.vim_command_state = model::VimCommandState::None;
-            }
-        }
+        // Preview/modal-only actions, or bound where they don't apply
+        Action::Close | Action::ToggleMark | Action::FileInfo => {}
+        Action::AddDevice
+        | Action::EditDevice
+        | Action::PauseDevice
+        | Action::RemoveDevice
+        | Action::RestoreVersion
+        | Action::MarkVersion
+        | Action::KeepOriginal
+        | Action::KeepConflict
+        | Action::KeepBoth
+        | Action::Prioritize
+        | Action::ToggleFollow
+        | Action::CycleLevel
+        | Action::ClearErrors
+        | Action::Accept
+        | Action::Dismiss
+        | Action::Refresh
+        | Action::CustomLimit
+        | Action::Restart
+        | Action::Shutdown => {}
     }
     Ok(())
 }
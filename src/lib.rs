@@ -7,6 +7,7 @@ use std::sync::atomic::AtomicBool;
 pub mod api;
 pub mod cache;
 pub mod logic;
+pub mod mock;
 pub mod model;
 pub mod services;
 pub mod utils;
@@ -0,0 +1,225 @@
+//! Remote device completion logic
+//!
+//! Pure functions for the per-device completion view: which devices share a
+//! folder, how far each one is according to `/rest/db/completion`, and
+//! paging through the files a device still needs (`/rest/db/remoteneed`).
+
+use crate::api::{Completion, Device, Folder};
+use crate::logic::devices::short_device_id;
+
+/// Remote-need files fetched per page
+pub const REMOTE_NEED_PAGE_SIZE: usize = 100;
+
+/// Completion of a folder on one remote device
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceCompletion {
+    pub device_id: String,
+    pub name: String,
+    pub connected: bool,
+    /// None when the completion could not be fetched
+    pub completion: Option<Completion>,
+}
+
+/// How far a device is with a folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionStatus {
+    UpToDate,
+    Syncing,
+    /// Behind, and not connected to catch up
+    Disconnected,
+    Paused,
+    /// Not shared with the device any more (or not accepted yet)
+    NotSharing,
+    Unknown,
+}
+
+impl CompletionStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompletionStatus::UpToDate => "Up to date",
+            CompletionStatus::Syncing => "Syncing",
+            CompletionStatus::Disconnected => "Disconnected",
+            CompletionStatus::Paused => "Paused",
+            CompletionStatus::NotSharing => "Not sharing",
+            CompletionStatus::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Remote devices a folder is shared with, as (device ID, name)
+///
+/// The local device is left out. Devices missing from the config are named
+/// by the first block of their ID.
+pub fn folder_devices(
+    folder: &Folder,
+    my_device_id: Option<&str>,
+    devices: &[Device],
+) -> Vec<(String, String)> {
+    folder
+        .devices
+        .iter()
+        .filter(|device| Some(device.device_id.as_str()) != my_device_id)
+        .map(|device| {
+            let name = devices
+                .iter()
+                .find(|d| d.id == device.device_id && !d.name.is_empty())
+                .map(|d| d.name.clone())
+                .unwrap_or_else(|| short_device_id(&device.device_id).to_string());
+            (device.device_id.clone(), name)
+        })
+        .collect()
+}
+
+/// Status of a device for display
+pub fn completion_status(device: &DeviceCompletion) -> CompletionStatus {
+    let Some(completion) = &device.completion else {
+        return CompletionStatus::Unknown;
+    };
+    match completion.remote_state.as_str() {
+        "paused" => CompletionStatus::Paused,
+        "notSharing" => CompletionStatus::NotSharing,
+        _ if is_up_to_date(completion) => CompletionStatus::UpToDate,
+        _ if !device.connected => CompletionStatus::Disconnected,
+        _ => CompletionStatus::Syncing,
+    }
+}
+
+/// Whether the device has everything
+pub fn is_up_to_date(completion: &Completion) -> bool {
+    completion.need_bytes == 0 && completion.need_items == 0 && completion.need_deletes == 0
+}
+
+/// Completion percentage, only showing 100% when nothing is needed
+///
+/// # Examples
+/// ```
+/// use stui::api::Completion;
+/// use stui::logic::completion::format_percent;
+///
+/// let mut completion = Completion { completion: 99.97, need_items: 1, ..Default::default() };
+/// assert_eq!(format_percent(&completion), "99%");
+/// completion.need_items = 0;
+/// assert_eq!(format_percent(&completion), "100%");
+/// ```
+pub fn format_percent(completion: &Completion) -> String {
+    if is_up_to_date(completion) {
+        return "100%".to_string();
+    }
+    format!("{}%", (completion.completion.floor() as u8).min(99))
+}
+
+/// Whether another page may follow (Syncthing doesn't report a total)
+pub fn has_more_pages(page_len: usize) -> bool {
+    page_len == REMOTE_NEED_PAGE_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::FolderDevice;
+
+    fn completion(percent: f64, need_bytes: u64, remote_state: &str) -> Completion {
+        Completion {
+            completion: percent,
+            need_bytes,
+            need_items: u64::from(need_bytes > 0),
+            remote_state: remote_state.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn device(connected: bool, completion: Option<Completion>) -> DeviceCompletion {
+        DeviceCompletion {
+            device_id: "NAS".to_string(),
+            name: "nas".to_string(),
+            connected,
+            completion,
+        }
+    }
+
+    // ========================================
+    // FOLDER DEVICES
+    // ========================================
+
+    #[test]
+    fn test_folder_devices_skip_local_device_and_name_the_rest() {
+        let folder = Folder {
+            id: "docs".to_string(),
+            label: None,
+            path: "/docs".to_string(),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: ["ME-ID", "LAPTOP-ID", "ABCDEF1-UNKNOWN"]
+                .iter()
+                .map(|id| FolderDevice {
+                    device_id: id.to_string(),
+                })
+                .collect(),
+        };
+        let devices = vec![
+            Device {
+                id: "ME-ID".to_string(),
+                name: "desktop".to_string(),
+            },
+            Device {
+                id: "LAPTOP-ID".to_string(),
+                name: "laptop".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            folder_devices(&folder, Some("ME-ID"), &devices),
+            vec![
+                ("LAPTOP-ID".to_string(), "laptop".to_string()),
+                ("ABCDEF1-UNKNOWN".to_string(), "ABCDEF1".to_string()),
+            ]
+        );
+    }
+
+    // ========================================
+    // STATUS AND PERCENT
+    // ========================================
+
+    #[test]
+    fn test_completion_status() {
+        let done = completion(100.0, 0, "valid");
+        let behind = completion(42.0, 1024, "valid");
+
+        assert_eq!(
+            completion_status(&device(true, Some(done.clone()))),
+            CompletionStatus::UpToDate
+        );
+        // A disconnected device that has everything is still up to date
+        assert_eq!(
+            completion_status(&device(false, Some(done))),
+            CompletionStatus::UpToDate
+        );
+        assert_eq!(
+            completion_status(&device(true, Some(behind.clone()))),
+            CompletionStatus::Syncing
+        );
+        assert_eq!(
+            completion_status(&device(false, Some(behind))),
+            CompletionStatus::Disconnected
+        );
+        assert_eq!(
+            completion_status(&device(true, Some(completion(0.0, 0, "paused")))),
+            CompletionStatus::Paused
+        );
+        assert_eq!(
+            completion_status(&device(true, Some(completion(0.0, 0, "notSharing")))),
+            CompletionStatus::NotSharing
+        );
+        assert_eq!(
+            completion_status(&device(true, None)),
+            CompletionStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_format_percent_never_rounds_up_to_done() {
+        assert_eq!(format_percent(&completion(42.7, 1024, "valid")), "42%");
+        assert_eq!(format_percent(&completion(100.0, 1, "valid")), "99%");
+        assert_eq!(format_percent(&completion(100.0, 0, "valid")), "100%");
+    }
+}
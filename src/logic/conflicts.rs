@@ -0,0 +1,317 @@
+//! Sync conflict logic
+//!
+//! Pure functions for recognising the conflict copies Syncthing leaves behind
+//! (`name.sync-conflict-YYYYMMDD-HHMMSS-DEVICEID.ext`) and for naming the
+//! files produced when resolving them.
+
+use crate::api::{Device, SyncState};
+use crate::logic::devices::short_device_id;
+
+/// Marker Syncthing inserts between a file's stem and its extension
+pub const CONFLICT_MARKER: &str = ".sync-conflict-";
+
+/// A parsed conflict copy file name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictName {
+    /// File name of the original (marker removed, extension kept)
+    pub original: String,
+    /// Conflict time as written by Syncthing (`YYYYMMDD-HHMMSS`)
+    pub timestamp: String,
+    /// Short ID of the device whose change lost the conflict (absent in old Syncthing versions)
+    pub device_short_id: Option<String>,
+}
+
+/// Parse a conflict copy file name (a single path component)
+///
+/// Follows Syncthing's naming, which inserts the marker before the last
+/// extension. Returns None for ordinary file names.
+///
+/// # Example
+/// ```
+/// use stui::logic::conflicts::parse_conflict_name;
+///
+/// let parsed = parse_conflict_name("report.sync-conflict-20250115-103005-ABCDEF1.txt").unwrap();
+/// assert_eq!(parsed.original, "report.txt");
+/// assert_eq!(parsed.timestamp, "20250115-103005");
+/// assert_eq!(parsed.device_short_id.as_deref(), Some("ABCDEF1"));
+/// assert!(parse_conflict_name("report.txt").is_none());
+/// ```
+pub fn parse_conflict_name(name: &str) -> Option<ConflictName> {
+    let marker_idx = name.rfind(CONFLICT_MARKER)?;
+    let stem = &name[..marker_idx];
+    let rest = &name[marker_idx + CONFLICT_MARKER.len()..];
+
+    // YYYYMMDD-HHMMSS
+    let timestamp = rest.get(..15)?;
+    let (date, time) = timestamp.split_at(8);
+    if !date.bytes().all(|b| b.is_ascii_digit())
+        || !time.starts_with('-')
+        || !time[1..].bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let rest = &rest[15..];
+
+    // Optional -DEVICEID, then the original extension (if any)
+    let (device_short_id, ext) = match rest.strip_prefix('-') {
+        Some(after) => {
+            let end = after.find('.').unwrap_or(after.len());
+            let device = &after[..end];
+            if device.is_empty() || !device.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                return None;
+            }
+            (Some(device.to_string()), &after[end..])
+        }
+        None => (None, rest),
+    };
+
+    if !ext.is_empty() && !ext.starts_with('.') {
+        return None;
+    }
+
+    Some(ConflictName {
+        original: format!("{}{}", stem, ext),
+        timestamp: timestamp.to_string(),
+        device_short_id,
+    })
+}
+
+/// Whether a file path (relative or absolute) names a conflict copy
+pub fn is_conflict_file(path: &str) -> bool {
+    parse_conflict_name(file_name(path)).is_some()
+}
+
+/// Get the path of the original file a conflict copy belongs to
+///
+/// # Example
+/// ```
+/// use stui::logic::conflicts::original_path;
+///
+/// assert_eq!(
+///     original_path("docs/a.sync-conflict-20250115-103005-ABCDEF1.md"),
+///     Some("docs/a.md".to_string())
+/// );
+/// assert_eq!(original_path("docs/a.md"), None);
+/// ```
+pub fn original_path(conflict_path: &str) -> Option<String> {
+    let name = file_name(conflict_path);
+    let parsed = parse_conflict_name(name)?;
+    let dir = &conflict_path[..conflict_path.len() - name.len()];
+    Some(format!("{}{}", dir, parsed.original))
+}
+
+/// Format a conflict timestamp for display (`20250115-103005` → `2025-01-15 10:30:05`)
+pub fn format_conflict_time(timestamp: &str) -> String {
+    chrono::NaiveDateTime::parse_from_str(timestamp, "%Y%m%d-%H%M%S")
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+/// Pick the name a conflict copy is renamed to when keeping both files
+///
+/// Produces `stem (conflict DEVICE YYYY-MM-DD HHMMSS).ext`, which no longer
+/// matches the conflict pattern. A counter is appended while `exists`
+/// reports the name as taken.
+///
+/// # Example
+/// ```
+/// use stui::logic::conflicts::{keep_both_name, parse_conflict_name};
+///
+/// let parsed = parse_conflict_name("a.sync-conflict-20250115-103005-ABCDEF1.md").unwrap();
+/// assert_eq!(
+///     keep_both_name(&parsed, "laptop", |_| false),
+///     "a (conflict laptop 2025-01-15 103005).md"
+/// );
+/// ```
+pub fn keep_both_name(
+    conflict: &ConflictName,
+    device_label: &str,
+    exists: impl Fn(&str) -> bool,
+) -> String {
+    let (stem, ext) = match conflict.original.rfind('.') {
+        Some(idx) if idx > 0 => conflict.original.split_at(idx),
+        _ => (conflict.original.as_str(), ""),
+    };
+
+    let date_time =
+        match chrono::NaiveDateTime::parse_from_str(&conflict.timestamp, "%Y%m%d-%H%M%S") {
+            Ok(dt) => dt.format("%Y-%m-%d %H%M%S").to_string(),
+            Err(_) => conflict.timestamp.clone(),
+        };
+
+    // Device names may contain path separators
+    let device_label: String = device_label
+        .chars()
+        .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+        .collect();
+
+    let base = if device_label.is_empty() {
+        format!("{} (conflict {})", stem, date_time)
+    } else {
+        format!("{} (conflict {} {})", stem, device_label, date_time)
+    };
+
+    let mut candidate = format!("{}{}", base, ext);
+    let mut counter = 2;
+    while exists(&candidate) {
+        candidate = format!("{} {}{}", base, counter, ext);
+        counter += 1;
+    }
+    candidate
+}
+
+/// Name the device that produced a conflict copy
+///
+/// Matches the short ID from the file name against configured devices,
+/// falling back to the short ID itself.
+pub fn conflict_device_name(device_short_id: Option<&str>, devices: &[Device]) -> String {
+    let Some(short_id) = device_short_id else {
+        return "unknown device".to_string();
+    };
+
+    devices
+        .iter()
+        .find(|d| short_device_id(&d.id) == short_id && !d.name.is_empty())
+        .map(|d| d.name.clone())
+        .unwrap_or_else(|| short_id.to_string())
+}
+
+/// Override a file's sync state when it is a conflict copy
+///
+/// Remote-only, ignored and unknown files keep their state since there is
+/// nothing on this device to resolve.
+pub fn apply_conflict_state(file_path: &str, state: SyncState) -> SyncState {
+    match state {
+        SyncState::Synced | SyncState::OutOfSync | SyncState::LocalOnly | SyncState::Syncing
+            if is_conflict_file(file_path) =>
+        {
+            SyncState::Conflict
+        }
+        other => other,
+    }
+}
+
+/// Last component of a slash-separated path
+fn file_name(path: &str) -> &str {
+    let trimmed = path.trim_end_matches('/');
+    match trimmed.rfind('/') {
+        Some(idx) => &trimmed[idx + 1..],
+        None => trimmed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================
+    // PARSING
+    // ========================================
+
+    #[test]
+    fn test_parse_conflict_name_without_extension() {
+        let parsed = parse_conflict_name("Makefile.sync-conflict-20250115-103005-ABCDEF1").unwrap();
+        assert_eq!(parsed.original, "Makefile");
+        assert_eq!(parsed.device_short_id.as_deref(), Some("ABCDEF1"));
+    }
+
+    #[test]
+    fn test_parse_conflict_name_keeps_inner_extensions() {
+        let parsed =
+            parse_conflict_name("archive.tar.sync-conflict-20250115-103005-ABCDEF1.gz").unwrap();
+        assert_eq!(parsed.original, "archive.tar.gz");
+    }
+
+    #[test]
+    fn test_parse_conflict_name_legacy_without_device() {
+        let parsed = parse_conflict_name("notes.sync-conflict-20200101-000000.txt").unwrap();
+        assert_eq!(parsed.original, "notes.txt");
+        assert_eq!(parsed.timestamp, "20200101-000000");
+        assert_eq!(parsed.device_short_id, None);
+    }
+
+    #[test]
+    fn test_parse_conflict_name_rejects_malformed() {
+        assert!(parse_conflict_name("a.sync-conflict-2025-ABCDEF1.txt").is_none());
+        assert!(parse_conflict_name("a.sync-conflict-20250115x103005.txt").is_none());
+        assert!(parse_conflict_name("a.sync-conflict-20250115-103005txt").is_none());
+        assert!(parse_conflict_name("a.sync-conflict-20250115-103005-.txt").is_none());
+    }
+
+    #[test]
+    fn test_is_conflict_file_uses_last_component() {
+        assert!(is_conflict_file(
+            "dir/a.sync-conflict-20250115-103005-ABCDEF1.txt"
+        ));
+        assert!(!is_conflict_file(
+            "dir.sync-conflict-20250115-103005-ABCDEF1/a.txt"
+        ));
+    }
+
+    #[test]
+    fn test_format_conflict_time() {
+        assert_eq!(
+            format_conflict_time("20250115-103005"),
+            "2025-01-15 10:30:05"
+        );
+        assert_eq!(format_conflict_time("garbage"), "garbage");
+    }
+
+    // ========================================
+    // KEEP BOTH NAMING
+    // ========================================
+
+    #[test]
+    fn test_keep_both_name_skips_taken_names() {
+        let parsed = parse_conflict_name("a.sync-conflict-20250115-103005-ABCDEF1.md").unwrap();
+        let taken = ["a (conflict laptop 2025-01-15 103005).md".to_string()];
+        assert_eq!(
+            keep_both_name(&parsed, "laptop", |name| taken.iter().any(|t| t == name)),
+            "a (conflict laptop 2025-01-15 103005) 2.md"
+        );
+    }
+
+    #[test]
+    fn test_keep_both_name_is_not_a_conflict_name() {
+        let parsed = parse_conflict_name(".bashrc.sync-conflict-20250115-103005-ABCDEF1").unwrap();
+        let name = keep_both_name(&parsed, "a/b", |_| false);
+        assert_eq!(name, ".bashrc (conflict a_b 2025-01-15 103005)");
+        assert!(!is_conflict_file(&name));
+    }
+
+    // ========================================
+    // DEVICES
+    // ========================================
+
+    #[test]
+    fn test_conflict_device_name() {
+        let devices = vec![Device {
+            id: "ABCDEF1-XXXXXXX-XXXXXXX".to_string(),
+            name: "laptop".to_string(),
+        }];
+        assert_eq!(conflict_device_name(Some("ABCDEF1"), &devices), "laptop");
+        assert_eq!(conflict_device_name(Some("ZZZZZZZ"), &devices), "ZZZZZZZ");
+        assert_eq!(conflict_device_name(None, &devices), "unknown device");
+    }
+
+    // ========================================
+    // SYNC STATE
+    // ========================================
+
+    #[test]
+    fn test_apply_conflict_state() {
+        let conflict = "a.sync-conflict-20250115-103005-ABCDEF1.txt";
+        assert_eq!(
+            apply_conflict_state(conflict, SyncState::Synced),
+            SyncState::Conflict
+        );
+        assert_eq!(
+            apply_conflict_state(conflict, SyncState::RemoteOnly),
+            SyncState::RemoteOnly
+        );
+        assert_eq!(
+            apply_conflict_state("a.txt", SyncState::Synced),
+            SyncState::Synced
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Device management logic
+//!
+//! Pure functions for validating and formatting remote device settings.
+
+use crate::api::Folder;
+
+/// Compression modes supported by Syncthing, in cycling order
+pub const COMPRESSION_MODES: [&str; 3] = ["metadata", "always", "never"];
+
+/// Normalize and validate a device ID entered by the user
+///
+/// Accepts IDs with or without dashes/spaces and in any case, and returns the
+/// canonical form (uppercase, 8 groups of 7 characters separated by dashes).
+/// Only the format is checked here; Syncthing verifies the check digits.
+///
+/// # Returns
+/// * `Ok(String)` - Canonical device ID
+/// * `Err(String)` - Human-readable validation error
+///
+/// # Example
+/// ```
+/// use stui::logic::devices::normalize_device_id;
+///
+/// let id = normalize_device_id(
+///     "aaaaaaa bbbbbbb ccccccc ddddddd eeeeeee fffffff ggggggg hhhhhhh",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     id,
+///     "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH"
+/// );
+/// ```
+pub fn normalize_device_id(input: &str) -> Result<String, String> {
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    if compact.is_empty() {
+        return Err("Device ID is required".to_string());
+    }
+
+    if let Some(bad) = compact
+        .chars()
+        .find(|c| !matches!(c, 'A'..='Z' | '2'..='7'))
+    {
+        return Err(format!("Invalid character '{}' in device ID", bad));
+    }
+
+    if compact.len() != 56 {
+        return Err(format!(
+            "Device ID must be 56 characters (got {})",
+            compact.len()
+        ));
+    }
+
+    let groups: Vec<&str> = (0..8).map(|i| &compact[i * 7..(i + 1) * 7]).collect();
+    Ok(groups.join("-"))
+}
+
+/// Short form of a device ID (first group), as shown by the Syncthing web UI
+pub fn short_device_id(device_id: &str) -> &str {
+    device_id.split('-').next().unwrap_or(device_id)
+}
+
+/// Parse a comma/space separated address list
+///
+/// An empty list means "dynamic" (discovery), which is Syncthing's default.
+pub fn parse_addresses(input: &str) -> Vec<String> {
+    let addresses: Vec<String> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect();
+
+    if addresses.is_empty() {
+        vec!["dynamic".to_string()]
+    } else {
+        addresses
+    }
+}
+
+/// Format an address list for editing/display
+pub fn format_addresses(addresses: &[String]) -> String {
+    if addresses.is_empty() {
+        "dynamic".to_string()
+    } else {
+        addresses.join(", ")
+    }
+}
+
+/// Get the next compression mode (metadata → always → never → metadata)
+pub fn next_compression(current: &str) -> &'static str {
+    let idx = COMPRESSION_MODES
+        .iter()
+        .position(|m| *m == current)
+        .unwrap_or(0);
+    COMPRESSION_MODES[(idx + 1) % COMPRESSION_MODES.len()]
+}
+
+/// Get the display label for a compression mode (matches web UI wording)
+pub fn compression_label(mode: &str) -> &str {
+    match mode {
+        "metadata" => "Metadata Only",
+        "always" => "All Data",
+        "never" => "Off",
+        other => other,
+    }
+}
+
+/// Calculate a transfer rate in bytes/sec from two byte counters
+///
+/// Counter resets (current < previous) are treated as zero transfer.
+pub fn calculate_rate(previous_bytes: u64, current_bytes: u64, elapsed_secs: f64) -> f64 {
+    if elapsed_secs <= 0.0 {
+        return 0.0;
+    }
+    current_bytes.saturating_sub(previous_bytes) as f64 / elapsed_secs
+}
+
+/// Count how many folders are shared with a device
+pub fn count_shared_folders(device_id: &str, folders: &[Folder]) -> usize {
+    folders
+        .iter()
+        .filter(|folder| folder.devices.iter().any(|d| d.device_id == device_id))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::FolderDevice;
+
+    const VALID_ID: &str = "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH";
+
+    // ========================================
+    // DEVICE ID VALIDATION
+    // ========================================
+
+    #[test]
+    fn test_normalize_device_id_canonical() {
+        assert_eq!(normalize_device_id(VALID_ID).unwrap(), VALID_ID);
+    }
+
+    #[test]
+    fn test_normalize_device_id_compact_lowercase() {
+        let compact = VALID_ID.replace('-', "").to_lowercase();
+        assert_eq!(normalize_device_id(&compact).unwrap(), VALID_ID);
+    }
+
+    #[test]
+    fn test_normalize_device_id_trims_whitespace() {
+        let padded = format!("  {}\n", VALID_ID);
+        assert_eq!(normalize_device_id(&padded).unwrap(), VALID_ID);
+    }
+
+    #[test]
+    fn test_normalize_device_id_rejects_empty() {
+        assert!(normalize_device_id("   ").is_err());
+    }
+
+    #[test]
+    fn test_normalize_device_id_rejects_bad_characters() {
+        // '1', '0', '8' and '9' are not in the base32 alphabet
+        let bad = VALID_ID.replacen('A', "1", 1);
+        let err = normalize_device_id(&bad).unwrap_err();
+        assert!(err.contains("'1'"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_normalize_device_id_rejects_wrong_length() {
+        let err = normalize_device_id("AAAAAAA-BBBBBBB").unwrap_err();
+        assert!(err.contains("56"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_short_device_id() {
+        assert_eq!(short_device_id(VALID_ID), "AAAAAAA");
+        assert_eq!(short_device_id("NODASHES"), "NODASHES");
+    }
+
+    // ========================================
+    // ADDRESSES
+    // ========================================
+
+    #[test]
+    fn test_parse_addresses_mixed_separators() {
+        let parsed = parse_addresses("tcp://10.0.0.1:22000, quic://10.0.0.1:22000 dynamic");
+        assert_eq!(
+            parsed,
+            vec![
+                "tcp://10.0.0.1:22000".to_string(),
+                "quic://10.0.0.1:22000".to_string(),
+                "dynamic".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_addresses_empty_defaults_to_dynamic() {
+        assert_eq!(parse_addresses("  , "), vec!["dynamic".to_string()]);
+    }
+
+    #[test]
+    fn test_format_addresses_round_trip() {
+        let addresses = vec!["dynamic".to_string(), "tcp://host:22000".to_string()];
+        assert_eq!(parse_addresses(&format_addresses(&addresses)), addresses);
+        assert_eq!(format_addresses(&[]), "dynamic");
+    }
+
+    // ========================================
+    // COMPRESSION
+    // ========================================
+
+    #[test]
+    fn test_next_compression_cycles() {
+        assert_eq!(next_compression("metadata"), "always");
+        assert_eq!(next_compression("always"), "never");
+        assert_eq!(next_compression("never"), "metadata");
+        // Unknown values restart the cycle
+        assert_eq!(next_compression(""), "always");
+    }
+
+    #[test]
+    fn test_compression_label() {
+        assert_eq!(compression_label("metadata"), "Metadata Only");
+        assert_eq!(compression_label("always"), "All Data");
+        assert_eq!(compression_label("never"), "Off");
+        assert_eq!(compression_label("custom"), "custom");
+    }
+
+    // ========================================
+    // RATES & FOLDERS
+    // ========================================
+
+    #[test]
+    fn test_calculate_rate() {
+        assert_eq!(calculate_rate(1000, 3000, 2.0), 1000.0);
+        assert_eq!(calculate_rate(3000, 1000, 2.0), 0.0); // Counter reset
+        assert_eq!(calculate_rate(0, 1000, 0.0), 0.0); // No elapsed time
+    }
+
+    #[test]
+    fn test_count_shared_folders() {
+        let folder = |id: &str, devices: &[&str]| Folder {
+            id: id.to_string(),
+            label: None,
+            path: format!("/data/{}", id),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: devices
+                .iter()
+                .map(|d| FolderDevice {
+                    device_id: d.to_string(),
+                })
+                .collect(),
+        };
+
+        let folders = vec![
+            folder("a", &["DEV1", "DEV2"]),
+            folder("b", &["DEV2"]),
+            folder("c", &[]),
+        ];
+
+        assert_eq!(count_shared_folders("DEV1", &folders), 1);
+        assert_eq!(count_shared_folders("DEV2", &folders), 2);
+        assert_eq!(count_shared_folders("DEV3", &folders), 0);
+    }
+}
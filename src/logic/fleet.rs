@@ -0,0 +1,121 @@
+//! Fleet overview summaries
+//!
+//! Reduces the status of one Syncthing instance (system status, folder
+//! statuses and connections) to the row shown in the fleet overview.
+
+use crate::api::{ConnectionsResponse, FolderStatus};
+use crate::logic::health::{FolderHealth, folder_health};
+use crate::model::types::InstanceSummary;
+
+/// Summarize one instance
+///
+/// `folders` holds each folder's paused flag and `/rest/db/status` (None if
+/// the request failed). Need bytes of paused folders are not counted.
+pub fn summarize_instance(
+    my_id: &str,
+    uptime: u64,
+    folders: &[(bool, Option<FolderStatus>)],
+    connections: &ConnectionsResponse,
+) -> InstanceSummary {
+    let mut summary = InstanceSummary {
+        uptime,
+        folders: folders.len(),
+        ..Default::default()
+    };
+
+    for (paused, status) in folders {
+        match folder_health(*paused, status.as_ref()) {
+            FolderHealth::Syncing | FolderHealth::OutOfSync => summary.folders_out_of_sync += 1,
+            FolderHealth::Error => summary.folders_with_errors += 1,
+            FolderHealth::UpToDate | FolderHealth::Paused => {}
+        }
+        if !paused && let Some(status) = status {
+            summary.need_bytes += status.need_bytes;
+        }
+    }
+
+    for (device_id, connection) in &connections.connections {
+        if device_id == my_id {
+            continue;
+        }
+        summary.total_devices += 1;
+        if connection.connected {
+            summary.connected_devices += 1;
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ConnectionInfo;
+    use std::collections::HashMap;
+
+    fn status(state: &str, need_items: u64, need_bytes: u64, errors: u64) -> FolderStatus {
+        FolderStatus {
+            state: state.to_string(),
+            need_bytes,
+            need_files: need_items,
+            need_total_items: need_items,
+            errors,
+            ..Default::default()
+        }
+    }
+
+    fn connections(devices: &[(&str, bool)]) -> ConnectionsResponse {
+        ConnectionsResponse {
+            connections: devices
+                .iter()
+                .map(|(id, connected)| {
+                    (
+                        id.to_string(),
+                        ConnectionInfo {
+                            connected: *connected,
+                            address: String::new(),
+                            in_bytes_total: 0,
+                            out_bytes_total: 0,
+                            paused: false,
+                        },
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    // ========================================
+    // INSTANCE SUMMARY
+    // ========================================
+
+    #[test]
+    fn test_summary_counts_folder_health() {
+        let folders = vec![
+            (false, Some(status("idle", 0, 0, 0))),
+            (false, Some(status("idle", 3, 1000, 0))),
+            (false, Some(status("syncing", 1, 500, 0))),
+            (false, Some(status("idle", 0, 0, 2))),
+            (false, None),
+            (true, Some(status("idle", 9, 9000, 0))),
+        ];
+
+        let summary = summarize_instance("ME", 3600, &folders, &connections(&[]));
+        assert_eq!(summary.uptime, 3600);
+        assert_eq!(summary.folders, 6);
+        assert_eq!(summary.folders_out_of_sync, 2);
+        assert_eq!(
+            summary.folders_with_errors, 2,
+            "failed items and failed status"
+        );
+        assert_eq!(summary.need_bytes, 1500, "paused folders are not counted");
+    }
+
+    #[test]
+    fn test_summary_excludes_own_device() {
+        let conns = connections(&[("ME", true), ("NAS", true), ("PHONE", false)]);
+
+        let summary = summarize_instance("ME", 0, &[], &conns);
+        assert_eq!(summary.connected_devices, 1);
+        assert_eq!(summary.total_devices, 2);
+    }
+}
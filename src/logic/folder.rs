@@ -2,7 +2,7 @@
 //!
 //! Pure functions for folder-related validations and calculations.
 
-use crate::api::FolderStatus;
+use crate::api::{Folder, FolderStatus};
 use std::collections::HashMap;
 
 /// Check if a folder has local changes that can be reverted
@@ -21,6 +21,22 @@ pub fn has_local_changes(status: Option<&FolderStatus>) -> bool {
         .unwrap_or(false)
 }
 
+/// Check if a send-only folder has remote changes that can be overridden
+///
+/// Send-only folders never pull, so items changed on other devices stay
+/// needed until the local state is pushed over them (`/rest/db/override`).
+///
+/// # Examples
+/// ```
+/// use stui::logic::folder::has_remote_changes;
+///
+/// assert!(!has_remote_changes("sendonly", None));
+/// assert!(!has_remote_changes("sendreceive", None));
+/// ```
+pub fn has_remote_changes(folder_type: &str, status: Option<&FolderStatus>) -> bool {
+    folder_type == "sendonly" && status.is_some_and(|s| s.need_total_items > 0)
+}
+
 /// Check if file deletion is allowed given current navigation state
 ///
 /// File deletion only works when viewing breadcrumb contents (not the folder list).
@@ -151,6 +167,24 @@ pub fn calculate_local_state_summary(
     (total_files, total_dirs, total_bytes)
 }
 
+/// Find a folder by ID or label (as typed on the command line)
+///
+/// An exact ID wins over a label; labels are then matched exactly and
+/// finally case-insensitively.
+pub fn find_folder<'a>(folders: &'a [Folder], query: &str) -> Option<&'a Folder> {
+    folders
+        .iter()
+        .find(|f| f.id == query)
+        .or_else(|| folders.iter().find(|f| f.label.as_deref() == Some(query)))
+        .or_else(|| {
+            folders.iter().find(|f| {
+                f.label
+                    .as_deref()
+                    .is_some_and(|label| label.eq_ignore_ascii_case(query))
+            })
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +240,18 @@ mod tests {
         assert!(!has_local_changes(None));
     }
 
+    #[test]
+    fn test_has_remote_changes_only_for_send_only() {
+        let mut status = create_test_status(0);
+        assert!(!has_remote_changes("sendonly", Some(&status)));
+
+        status.need_total_items = 3;
+        assert!(has_remote_changes("sendonly", Some(&status)));
+        // Other folder types pull what they need
+        assert!(!has_remote_changes("sendreceive", Some(&status)));
+        assert!(!has_remote_changes("receiveonly", Some(&status)));
+    }
+
     #[test]
     fn test_can_delete_file_in_breadcrumb_view() {
         // Can delete when in breadcrumb view (focus_level > 0) with valid trail
@@ -360,4 +406,36 @@ mod tests {
             "Should handle folders with zero stats correctly"
         );
     }
+
+    // Tests for find_folder
+
+    fn folder(id: &str, label: Option<&str>) -> Folder {
+        Folder {
+            id: id.to_string(),
+            label: label.map(|l| l.to_string()),
+            path: format!("/data/{}", id),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_folder_by_id_or_label() {
+        let folders = vec![
+            folder("abcd-1234", Some("Photos")),
+            folder("docs", Some("Documents")),
+        ];
+
+        assert_eq!(find_folder(&folders, "abcd-1234").unwrap().id, "abcd-1234");
+        assert_eq!(find_folder(&folders, "Documents").unwrap().id, "docs");
+        assert_eq!(find_folder(&folders, "photos").unwrap().id, "abcd-1234");
+        assert!(find_folder(&folders, "music").is_none());
+    }
+
+    #[test]
+    fn test_find_folder_prefers_id_over_label() {
+        let folders = vec![folder("one", Some("two")), folder("two", Some("Other"))];
+        assert_eq!(find_folder(&folders, "two").unwrap().id, "two");
+    }
 }
@@ -3,6 +3,8 @@
 //! Pure functions for calculating folder card states and formatting card data
 
 use crate::api::{Folder, FolderStatus};
+use crate::logic::formatting::Symbols;
+use std::collections::HashMap;
 
 /// Card state enum for visual rendering
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +21,10 @@ pub enum FolderCardState {
         remote_needed: u64,
         local_changes: u64,
     },
+    /// Folder is being scanned for local changes
+    Scanning,
+    /// Send-only folder with remote changes it won't pull (can be overridden)
+    Overridable { remote_changed: u64 },
     /// Folder is paused
     Paused,
     /// Folder has errors
@@ -44,6 +50,10 @@ pub fn calculate_folder_card_state(
         return FolderCardState::Error;
     }
 
+    if status.state == "scanning" {
+        return FolderCardState::Scanning;
+    }
+
     let remote_needed = status.need_total_items;
     let local_changes = status.receive_only_total_items;
 
@@ -54,6 +64,12 @@ pub fn calculate_folder_card_state(
         };
     }
 
+    if folder.folder_type == "sendonly" && remote_needed > 0 {
+        return FolderCardState::Overridable {
+            remote_changed: remote_needed,
+        };
+    }
+
     if remote_needed > 0 || local_changes > 0 {
         FolderCardState::OutOfSync {
             remote_needed,
@@ -123,6 +139,8 @@ pub fn format_status_message(state: &FolderCardState) -> String {
             // Don't show count here - it's shown in detail on line 3
             "Syncing...".to_string()
         }
+        FolderCardState::Scanning => "Scanning...".to_string(),
+        FolderCardState::Overridable { .. } => "Remote changes".to_string(),
         FolderCardState::Paused => "Paused".to_string(),
         FolderCardState::Error => "Error".to_string(),
         FolderCardState::Loading => "Loading...".to_string(),
@@ -136,12 +154,16 @@ pub fn format_out_of_sync_details(
     local_changes: u64,
     need_bytes: u64,
     folder_type: &str,
+    symbols: &Symbols,
 ) -> Option<String> {
     let mut parts = Vec::new();
 
     if remote_needed > 0 {
         let size_str = format_size(need_bytes);
-        parts.push(format!("↓ {} files ({})", remote_needed, size_str));
+        parts.push(format!(
+            "{} {} files ({})",
+            symbols.download, remote_needed, size_str
+        ));
     }
 
     if local_changes > 0 {
@@ -153,10 +175,10 @@ pub fn format_out_of_sync_details(
             } else {
                 format!("{} files modified", local_changes)
             };
-            parts.push(format!("✎ {} locally", count_str));
+            parts.push(format!("{} {} locally", symbols.modified, count_str));
         } else {
             // For sendreceive and sendonly, show as upload
-            parts.push(format!("↑ {} files", local_changes));
+            parts.push(format!("{} {} files", symbols.upload, local_changes));
         }
     }
 
@@ -167,6 +189,57 @@ pub fn format_out_of_sync_details(
     }
 }
 
+/// Format the remote changes of a send-only folder
+pub fn format_remote_changes(remote_changed: u64, need_bytes: u64, symbols: &Symbols) -> String {
+    let count_str = if remote_changed == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{} files", remote_changed)
+    };
+    format!(
+        "{} {} changed remotely ({}), override to keep local",
+        symbols.remote_changed,
+        count_str,
+        format_size(need_bytes)
+    )
+}
+
+/// Format the scan status with the percentage from FolderScanProgress events
+pub fn format_scan_message(percent: Option<u8>) -> String {
+    match percent {
+        Some(percent) => format!("Scanning {}%", percent),
+        None => "Scanning...".to_string(),
+    }
+}
+
+/// Summarize remote devices that haven't caught up with a folder yet
+///
+/// `completions` maps device IDs to completion percentages (FolderCompletion
+/// events). Returns None when every device is at 100%.
+pub fn format_remote_completion(
+    completions: &HashMap<String, f64>,
+    symbols: &Symbols,
+) -> Option<String> {
+    let behind: Vec<f64> = completions
+        .values()
+        .copied()
+        .filter(|completion| *completion < 100.0)
+        .collect();
+    let lowest = behind.iter().copied().reduce(f64::min)?;
+
+    let devices = if behind.len() == 1 {
+        "1 device".to_string()
+    } else {
+        format!("{} devices", behind.len())
+    };
+    Some(format!(
+        "{} {} still syncing ({:.0}% complete)",
+        symbols.upload,
+        devices,
+        lowest.floor()
+    ))
+}
+
 /// Calculate card height in lines
 #[allow(dead_code)]
 pub fn calculate_card_height(state: &FolderCardState) -> u16 {
@@ -178,13 +251,10 @@ pub fn calculate_card_height(state: &FolderCardState) -> u16 {
         | FolderCardState::Syncing {
             remote_needed,
             local_changes,
-        } => {
-            if *remote_needed > 0 || *local_changes > 0 {
-                4 // Title + info + details + spacing
-            } else {
-                3
-            }
+        } if *remote_needed > 0 || *local_changes > 0 => {
+            4 // Title + info + details + spacing
         }
+        FolderCardState::Overridable { .. } => 4,
         _ => 3, // Title + info + spacing
     }
 }
@@ -470,6 +540,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_calculate_folder_card_state_scanning() {
+        let folder = Folder {
+            id: "test".to_string(),
+            label: None,
+            path: "/test".to_string(),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: vec![],
+        };
+
+        let mut status = FolderStatus {
+            state: "scanning".to_string(),
+            sequence: 0,
+            global_bytes: 0,
+            global_deleted: 0,
+            global_directories: 0,
+            global_files: 10,
+            global_symlinks: 0,
+            global_total_items: 10,
+            in_sync_bytes: 0,
+            in_sync_files: 0,
+            local_bytes: 0,
+            local_deleted: 0,
+            local_directories: 0,
+            local_files: 10,
+            local_symlinks: 0,
+            local_total_items: 10,
+            need_bytes: 0,
+            need_deletes: 0,
+            need_directories: 0,
+            need_files: 0,
+            need_symlinks: 0,
+            need_total_items: 3,
+            receive_only_changed_bytes: 0,
+            receive_only_changed_deletes: 0,
+            receive_only_changed_directories: 0,
+            receive_only_changed_files: 0,
+            receive_only_changed_symlinks: 0,
+            receive_only_total_items: 0,
+            errors: 0,
+        };
+
+        // Scanning wins over out-of-sync counts
+        let state = calculate_folder_card_state(&folder, Some(&status));
+        assert_eq!(state, FolderCardState::Scanning);
+
+        // Errors still take precedence
+        status.errors = 1;
+        let state = calculate_folder_card_state(&folder, Some(&status));
+        assert_eq!(state, FolderCardState::Error);
+    }
+
+    #[test]
+    fn test_calculate_folder_card_state_send_only_remote_changes() {
+        let mut folder = Folder {
+            id: "test".to_string(),
+            label: None,
+            path: "/test".to_string(),
+            paused: false,
+            folder_type: "sendonly".to_string(),
+            devices: vec![],
+        };
+        let status = FolderStatus {
+            state: "idle".to_string(),
+            sequence: 0,
+            global_bytes: 0,
+            global_deleted: 0,
+            global_directories: 0,
+            global_files: 10,
+            global_symlinks: 0,
+            global_total_items: 10,
+            in_sync_bytes: 0,
+            in_sync_files: 0,
+            local_bytes: 0,
+            local_deleted: 0,
+            local_directories: 0,
+            local_files: 10,
+            local_symlinks: 0,
+            local_total_items: 10,
+            need_bytes: 2048,
+            need_deletes: 0,
+            need_directories: 0,
+            need_files: 2,
+            need_symlinks: 0,
+            need_total_items: 2,
+            receive_only_changed_bytes: 0,
+            receive_only_changed_deletes: 0,
+            receive_only_changed_directories: 0,
+            receive_only_changed_files: 0,
+            receive_only_changed_symlinks: 0,
+            receive_only_total_items: 0,
+            errors: 0,
+        };
+
+        let state = calculate_folder_card_state(&folder, Some(&status));
+        assert_eq!(state, FolderCardState::Overridable { remote_changed: 2 });
+        assert_eq!(format_status_message(&state), "Remote changes");
+        assert_eq!(calculate_card_height(&state), 4);
+
+        // The same counts in a send & receive folder are downloads
+        folder.folder_type = "sendreceive".to_string();
+        assert_eq!(
+            calculate_folder_card_state(&folder, Some(&status)),
+            FolderCardState::OutOfSync {
+                remote_needed: 2,
+                local_changes: 0
+            }
+        );
+    }
+
     // ========================================
     // FORMATTING FUNCTIONS
     // ========================================
@@ -561,45 +742,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_remote_changes() {
+        assert_eq!(
+            format_remote_changes(1, 512, &Symbols::UNICODE),
+            "⇅ 1 file changed remotely (512 B), override to keep local"
+        );
+        assert_eq!(
+            format_remote_changes(3, 2048, &Symbols::UNICODE),
+            "⇅ 3 files changed remotely (2.0 KB), override to keep local"
+        );
+        assert_eq!(
+            format_remote_changes(3, 2048, &Symbols::ASCII),
+            "! 3 files changed remotely (2.0 KB), override to keep local"
+        );
+    }
+
+    #[test]
+    fn test_format_scan_message() {
+        assert_eq!(format_scan_message(Some(45)), "Scanning 45%");
+        assert_eq!(format_scan_message(None), "Scanning...");
+        assert_eq!(
+            format_status_message(&FolderCardState::Scanning),
+            "Scanning..."
+        );
+    }
+
+    #[test]
+    fn test_format_remote_completion() {
+        let mut completions = HashMap::new();
+        assert_eq!(
+            format_remote_completion(&completions, &Symbols::UNICODE),
+            None
+        );
+
+        completions.insert("NAS".to_string(), 100.0);
+        assert_eq!(
+            format_remote_completion(&completions, &Symbols::UNICODE),
+            None
+        );
+
+        completions.insert("PHONE".to_string(), 87.9);
+        assert_eq!(
+            format_remote_completion(&completions, &Symbols::UNICODE),
+            Some("↑ 1 device still syncing (87% complete)".to_string())
+        );
+
+        completions.insert("LAPTOP".to_string(), 40.0);
+        assert_eq!(
+            format_remote_completion(&completions, &Symbols::UNICODE),
+            Some("↑ 2 devices still syncing (40% complete)".to_string())
+        );
+    }
+
     #[test]
     fn test_format_out_of_sync_details() {
-        assert_eq!(format_out_of_sync_details(0, 0, 0, "sendreceive"), None);
+        assert_eq!(
+            format_out_of_sync_details(0, 0, 0, "sendreceive", &Symbols::UNICODE),
+            None
+        );
 
         // Send & Receive: remote needed
         assert_eq!(
-            format_out_of_sync_details(5, 0, 1024, "sendreceive"),
+            format_out_of_sync_details(5, 0, 1024, "sendreceive", &Symbols::UNICODE),
             Some("↓ 5 files (1.0 KB)".to_string())
         );
 
         // Send & Receive: local changes (uploading)
         assert_eq!(
-            format_out_of_sync_details(0, 3, 0, "sendreceive"),
+            format_out_of_sync_details(0, 3, 0, "sendreceive", &Symbols::UNICODE),
             Some("↑ 3 files".to_string())
         );
 
         // Send & Receive: both
         assert_eq!(
-            format_out_of_sync_details(5, 3, 2048, "sendreceive"),
+            format_out_of_sync_details(5, 3, 2048, "sendreceive", &Symbols::UNICODE),
             Some("↓ 5 files (2.0 KB), ↑ 3 files".to_string())
         );
 
         // Receive Only: local changes (modified locally, need revert)
         assert_eq!(
-            format_out_of_sync_details(0, 3, 0, "receiveonly"),
+            format_out_of_sync_details(0, 3, 0, "receiveonly", &Symbols::UNICODE),
             Some("✎ 3 files modified locally".to_string())
         );
 
         // Receive Only: both remote needed and local changes
         assert_eq!(
-            format_out_of_sync_details(5, 3, 2048, "receiveonly"),
+            format_out_of_sync_details(5, 3, 2048, "receiveonly", &Symbols::UNICODE),
             Some("↓ 5 files (2.0 KB), ✎ 3 files modified locally".to_string())
         );
 
         // Send Only: should not have local changes (only remote needed)
         assert_eq!(
-            format_out_of_sync_details(5, 0, 1024, "sendonly"),
+            format_out_of_sync_details(5, 0, 1024, "sendonly", &Symbols::UNICODE),
             Some("↓ 5 files (1.0 KB)".to_string())
         );
+
+        // ASCII icon mode spells the arrows out
+        assert_eq!(
+            format_out_of_sync_details(5, 3, 2048, "sendreceive", &Symbols::ASCII),
+            Some("dn: 5 files (2.0 KB), up: 3 files".to_string())
+        );
+        assert_eq!(
+            format_out_of_sync_details(0, 3, 0, "receiveonly", &Symbols::ASCII),
+            Some("* 3 files modified locally".to_string())
+        );
     }
 
     #[test]
@@ -0,0 +1,106 @@
+//! Failed item logic
+//!
+//! Pure functions for paging through `/rest/folder/errors` and for
+//! summarising Syncthing's pull error messages.
+
+/// Failed items fetched per page
+pub const FAILED_ITEMS_PAGE_SIZE: usize = 100;
+
+/// Load the next page when the selection is this close to the end
+const LOAD_MORE_THRESHOLD: usize = 10;
+
+/// Whether another page may follow (Syncthing doesn't report a total)
+pub fn has_more_pages(page_len: usize) -> bool {
+    page_len == FAILED_ITEMS_PAGE_SIZE
+}
+
+/// Whether the next page should be loaded for the current selection
+pub fn should_load_more(selected_index: usize, loaded: usize, has_more: bool) -> bool {
+    has_more && selected_index + LOAD_MORE_THRESHOLD >= loaded
+}
+
+/// Short description of a pull error for the failed items list
+///
+/// # Examples
+/// ```
+/// use stui::logic::folder_errors::describe_pull_error;
+///
+/// assert_eq!(
+///     describe_pull_error("opening temp file: open /data/.syncthing.a.tmp: permission denied"),
+///     "Permission denied"
+/// );
+/// assert_eq!(describe_pull_error("something unexpected"), "Sync error");
+/// ```
+pub fn describe_pull_error(error: &str) -> &'static str {
+    let error = error.to_lowercase();
+    let contains_any = |needles: &[&str]| needles.iter().any(|n| error.contains(n));
+
+    if contains_any(&[
+        "permission denied",
+        "access is denied",
+        "operation not permitted",
+    ]) {
+        "Permission denied"
+    } else if contains_any(&["no space left", "insufficient space", "not enough space"]) {
+        "No space left"
+    } else if contains_any(&["being used by another process", "file is locked"]) {
+        "File in use"
+    } else if contains_any(&["no such file", "cannot find the", "not found"]) {
+        "Not found"
+    } else if contains_any(&["file name too long", "path too long"]) {
+        "Name too long"
+    } else if contains_any(&[
+        "hash mismatch",
+        "changed during",
+        "modified but not rescanned",
+    ]) {
+        "Changed during sync"
+    } else if contains_any(&["no connected device has the required version"]) {
+        "Unavailable"
+    } else if contains_any(&["directory not empty", "directory contains unexpected files"]) {
+        "Directory not empty"
+    } else if contains_any(&["invalid", "reserved"]) {
+        "Invalid name"
+    } else {
+        "Sync error"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_more_pages() {
+        assert!(has_more_pages(FAILED_ITEMS_PAGE_SIZE));
+        assert!(!has_more_pages(FAILED_ITEMS_PAGE_SIZE - 1));
+        assert!(!has_more_pages(0));
+    }
+
+    #[test]
+    fn test_should_load_more() {
+        assert!(should_load_more(95, 100, true));
+        assert!(!should_load_more(50, 100, true));
+        assert!(!should_load_more(99, 100, false));
+    }
+
+    #[test]
+    fn test_describe_pull_error() {
+        let cases = [
+            ("writing: no space left on device", "No space left"),
+            (
+                "The process cannot access the file because it is being used by another process.",
+                "File in use",
+            ),
+            (
+                "no connected device has the required version of this file",
+                "Unavailable",
+            ),
+            ("delete dir: directory not empty", "Directory not empty"),
+            ("open foo: Permission Denied", "Permission denied"),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(describe_pull_error(error), expected, "{}", error);
+        }
+    }
+}
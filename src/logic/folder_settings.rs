@@ -0,0 +1,453 @@
+//! Folder settings logic
+//!
+//! Pure functions for the folder settings form: pre-filling it from a folder's
+//! config object (`/rest/config/folders/{id}`), validating the edited values
+//! back into that object, and listing the settings that changed.
+
+use crate::model::types::{FolderSettingsField, FolderSettingsForm, SettingChange};
+use serde_json::{Map, Value, json};
+
+/// Pull orders supported by Syncthing, in cycling order
+pub const PULL_ORDERS: [&str; 6] = [
+    "random",
+    "alphabetic",
+    "smallestFirst",
+    "largestFirst",
+    "oldestFirst",
+    "newestFirst",
+];
+
+/// Versioning types in cycling order (empty means no versioning)
+pub const VERSIONING_TYPES: [&str; 5] = ["", "trashcan", "simple", "staggered", "external"];
+
+/// Units of the minimum free disk space
+pub const DISK_FREE_UNITS: [&str; 5] = ["%", "kB", "MB", "GB", "TB"];
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Get the next (or previous) option after `current`, wrapping around
+///
+/// Unknown values start over at the first option.
+pub fn cycle_option(options: &[&'static str], current: &str, forward: bool) -> &'static str {
+    match options.iter().position(|o| *o == current) {
+        Some(idx) if forward => options[(idx + 1) % options.len()],
+        Some(idx) => options[(idx + options.len() - 1) % options.len()],
+        None => options[0],
+    }
+}
+
+/// Get the display label for a pull order (matches web UI wording)
+pub fn pull_order_label(order: &str) -> &str {
+    match order {
+        "random" => "Random",
+        "alphabetic" => "Alphabetic",
+        "smallestFirst" => "Smallest First",
+        "largestFirst" => "Largest First",
+        "oldestFirst" => "Oldest First",
+        "newestFirst" => "Newest First",
+        other => other,
+    }
+}
+
+/// Get the display label for a versioning type (matches web UI wording)
+pub fn versioning_label(versioning_type: &str) -> &str {
+    match versioning_type {
+        "" => "No File Versioning",
+        "trashcan" => "Trash Can",
+        "simple" => "Simple",
+        "staggered" => "Staggered",
+        "external" => "External",
+        other => other,
+    }
+}
+
+/// Format a JSON number for editing (whole numbers without a fraction)
+fn number_text(value: &Value) -> String {
+    match value.as_f64() {
+        Some(n) if n.fract() == 0.0 => format!("{}", n as i64),
+        Some(n) => n.to_string(),
+        None => String::new(),
+    }
+}
+
+/// JSON number for an edited value, whole numbers as integers
+///
+/// Older Syncthing versions store some of these as integers and reject floats.
+fn number_value(n: f64) -> Value {
+    if n.fract() == 0.0 {
+        json!(n as u64)
+    } else {
+        json!(n)
+    }
+}
+
+/// Pre-fill the settings form from a folder config object
+///
+/// Missing versioning parameters get Syncthing's defaults for their type.
+pub fn settings_form(folder_id: &str, config: Value) -> FolderSettingsForm {
+    let text = |key: &str| config[key].as_str().unwrap_or_default().to_string();
+    let flag = |key: &str| config[key].as_bool().unwrap_or(false);
+    let param = |key: &str| {
+        config["versioning"]["params"][key]
+            .as_str()
+            .map(str::to_string)
+    };
+
+    let max_age_days = param("maxAge")
+        .and_then(|secs| secs.parse::<u64>().ok())
+        .map(|secs| (secs / SECONDS_PER_DAY).to_string());
+
+    let unit = config["minDiskFree"]["unit"].as_str().unwrap_or("%");
+    let order = config["order"].as_str().unwrap_or("random");
+
+    FolderSettingsForm {
+        folder_id: folder_id.to_string(),
+        label: text("label"),
+        rescan_interval: number_text(&config["rescanIntervalS"]),
+        fs_watcher_enabled: flag("fsWatcherEnabled"),
+        fs_watcher_delay: number_text(&config["fsWatcherDelayS"]),
+        order: order.to_string(),
+        min_disk_free: number_text(&config["minDiskFree"]["value"]),
+        min_disk_free_unit: unit.to_string(),
+        ignore_perms: flag("ignorePerms"),
+        ignore_delete: flag("ignoreDelete"),
+        versioning_type: config["versioning"]["type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        versioning_keep: param("keep").unwrap_or_else(|| "5".to_string()),
+        versioning_cleanout_days: param("cleanoutDays").unwrap_or_else(|| "0".to_string()),
+        versioning_max_age_days: max_age_days.unwrap_or_else(|| "365".to_string()),
+        versioning_command: param("command").unwrap_or_default(),
+        focused_field: FolderSettingsField::Label,
+        config,
+    }
+}
+
+/// Validate the form and write its values into a copy of its config object
+///
+/// # Returns
+/// * `Ok(Value)` - Config object to PUT back to Syncthing
+/// * `Err(String)` - Human-readable validation error
+pub fn apply_settings(form: &FolderSettingsForm) -> Result<Value, String> {
+    let rescan_interval: u64 = form
+        .rescan_interval
+        .trim()
+        .parse()
+        .map_err(|_| "Rescan interval must be a whole number of seconds".to_string())?;
+
+    let fs_watcher_delay = form
+        .fs_watcher_delay
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|delay| delay.is_finite() && *delay > 0.0)
+        .ok_or_else(|| "Watcher delay must be a positive number of seconds".to_string())?;
+
+    let min_disk_free = form
+        .min_disk_free
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .ok_or_else(|| "Minimum free disk space must be a number of 0 or more".to_string())?;
+    if form.min_disk_free_unit == "%" && min_disk_free > 100.0 {
+        return Err("Minimum free disk space can't exceed 100%".to_string());
+    }
+
+    let mut config = form.config.clone();
+    config["label"] = json!(form.label.trim());
+    config["rescanIntervalS"] = json!(rescan_interval);
+    config["fsWatcherEnabled"] = json!(form.fs_watcher_enabled);
+    config["fsWatcherDelayS"] = number_value(fs_watcher_delay);
+    config["order"] = json!(form.order);
+    config["minDiskFree"]["value"] = number_value(min_disk_free);
+    config["minDiskFree"]["unit"] = json!(form.min_disk_free_unit);
+    config["ignorePerms"] = json!(form.ignore_perms);
+    config["ignoreDelete"] = json!(form.ignore_delete);
+
+    // Parameters of another versioning type don't carry over
+    let same_type = config["versioning"]["type"].as_str() == Some(form.versioning_type.as_str());
+    let mut params = match &config["versioning"]["params"] {
+        Value::Object(params) if same_type => params.clone(),
+        _ => Map::new(),
+    };
+    let cleanout_days = || {
+        form.versioning_cleanout_days
+            .trim()
+            .parse::<u64>()
+            .map(|days| json!(days.to_string()))
+            .map_err(|_| "Clean out after must be a whole number of days (0 = never)".to_string())
+    };
+    match form.versioning_type.as_str() {
+        "simple" => {
+            let keep = form
+                .versioning_keep
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|keep| *keep >= 1)
+                .ok_or_else(|| "Versions to keep must be at least 1".to_string())?;
+            params.insert("keep".to_string(), json!(keep.to_string()));
+            params.insert("cleanoutDays".to_string(), cleanout_days()?);
+        }
+        "trashcan" => {
+            params.insert("cleanoutDays".to_string(), cleanout_days()?);
+        }
+        "staggered" => {
+            let days: u64 = form.versioning_max_age_days.trim().parse().map_err(|_| {
+                "Maximum age must be a whole number of days (0 = forever)".to_string()
+            })?;
+            params.insert(
+                "maxAge".to_string(),
+                json!((days * SECONDS_PER_DAY).to_string()),
+            );
+        }
+        "external" => {
+            let command = form.versioning_command.trim();
+            if command.is_empty() {
+                return Err("External versioning needs a command".to_string());
+            }
+            params.insert("command".to_string(), json!(command));
+        }
+        _ => params.clear(),
+    }
+    config["versioning"]["type"] = json!(form.versioning_type);
+    config["versioning"]["params"] = Value::Object(params);
+
+    Ok(config)
+}
+
+/// Settings of a config object as (name, value) pairs for display
+fn display_settings(config: &Value) -> Vec<(&'static str, String)> {
+    let form = settings_form("", config.clone());
+    let yes_no = |flag: bool| if flag { "Yes" } else { "No" }.to_string();
+
+    let mut settings = vec![
+        ("Label", form.label),
+        ("Rescan interval", format!("{} s", form.rescan_interval)),
+        ("Watch for changes", yes_no(form.fs_watcher_enabled)),
+        ("Watcher delay", format!("{} s", form.fs_watcher_delay)),
+        ("Pull order", pull_order_label(&form.order).to_string()),
+        (
+            "Min. free disk",
+            format!("{} {}", form.min_disk_free, form.min_disk_free_unit),
+        ),
+        ("Ignore permissions", yes_no(form.ignore_perms)),
+        ("Ignore delete", yes_no(form.ignore_delete)),
+        (
+            "File versioning",
+            versioning_label(&form.versioning_type).to_string(),
+        ),
+    ];
+
+    let cleanout = match form.versioning_cleanout_days.as_str() {
+        "0" => "never".to_string(),
+        days => format!("{} days", days),
+    };
+    match form.versioning_type.as_str() {
+        "simple" => {
+            settings.push(("Keep versions", form.versioning_keep));
+            settings.push(("Clean out after", cleanout));
+        }
+        "trashcan" => settings.push(("Clean out after", cleanout)),
+        "staggered" => settings.push((
+            "Maximum age",
+            match form.versioning_max_age_days.as_str() {
+                "0" => "forever".to_string(),
+                days => format!("{} days", days),
+            },
+        )),
+        "external" => settings.push(("Command", form.versioning_command)),
+        _ => {}
+    }
+    settings
+}
+
+/// Settings that differ between two folder config objects
+///
+/// Settings only one side has (versioning parameters of another type) show
+/// as "-" on the other side.
+pub fn diff_settings(before: &Value, after: &Value) -> Vec<SettingChange> {
+    let before = display_settings(before);
+    let after = display_settings(after);
+    let value = |settings: &[(&'static str, String)], name: &str| {
+        settings
+            .iter()
+            .find(|(setting, _)| *setting == name)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    let mut names: Vec<&'static str> = before.iter().map(|(name, _)| *name).collect();
+    names.extend(
+        after
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| !before.iter().any(|(setting, _)| setting == name)),
+    );
+
+    names
+        .into_iter()
+        .filter_map(|setting| {
+            let (old, new) = (value(&before, setting), value(&after, setting));
+            (old != new).then_some(SettingChange {
+                setting,
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Value {
+        json!({
+            "id": "docs",
+            "label": "Documents",
+            "rescanIntervalS": 3600,
+            "fsWatcherEnabled": true,
+            "fsWatcherDelayS": 10,
+            "order": "random",
+            "minDiskFree": { "value": 1, "unit": "%" },
+            "ignorePerms": false,
+            "ignoreDelete": false,
+            "versioning": {
+                "type": "simple",
+                "params": { "keep": "10", "cleanoutDays": "0" },
+                "cleanupIntervalS": 3600,
+            },
+            "copiers": 0,
+            "markerName": ".stfolder",
+        })
+    }
+
+    // ========================================
+    // FORM ROUND TRIP
+    // ========================================
+
+    #[test]
+    fn test_settings_form_reads_config() {
+        let form = settings_form("docs", config());
+        assert_eq!(form.label, "Documents");
+        assert_eq!(form.rescan_interval, "3600");
+        assert!(form.fs_watcher_enabled);
+        assert_eq!(form.fs_watcher_delay, "10");
+        assert_eq!(form.min_disk_free, "1");
+        assert_eq!(form.versioning_type, "simple");
+        assert_eq!(form.versioning_keep, "10");
+        // Defaults for parameters of other types
+        assert_eq!(form.versioning_max_age_days, "365");
+    }
+
+    #[test]
+    fn test_apply_settings_preserves_unknown_fields() {
+        let mut form = settings_form("docs", config());
+        form.label = "  Docs  ".to_string();
+        form.fs_watcher_delay = "2.5".to_string();
+        form.order = "newestFirst".to_string();
+
+        let updated = apply_settings(&form).unwrap();
+        assert_eq!(updated["label"], "Docs");
+        assert_eq!(updated["fsWatcherDelayS"], 2.5);
+        assert_eq!(updated["rescanIntervalS"], 3600);
+        assert_eq!(updated["order"], "newestFirst");
+        assert_eq!(updated["copiers"], 0);
+        assert_eq!(updated["markerName"], ".stfolder");
+        assert_eq!(updated["versioning"]["cleanupIntervalS"], 3600);
+
+        // Unchanged form writes back the same object
+        assert_eq!(
+            apply_settings(&settings_form("docs", config())).unwrap(),
+            config()
+        );
+    }
+
+    #[test]
+    fn test_apply_settings_replaces_params_of_other_versioning_type() {
+        let mut form = settings_form("docs", config());
+        form.versioning_type = "staggered".to_string();
+        form.versioning_max_age_days = "30".to_string();
+
+        let updated = apply_settings(&form).unwrap();
+        assert_eq!(
+            updated["versioning"]["params"],
+            json!({ "maxAge": "2592000" })
+        );
+
+        form.versioning_type = String::new();
+        let updated = apply_settings(&form).unwrap();
+        assert_eq!(updated["versioning"]["params"], json!({}));
+    }
+
+    // ========================================
+    // VALIDATION
+    // ========================================
+
+    #[test]
+    fn test_apply_settings_rejects_invalid_values() {
+        let invalid = |edit: fn(&mut FolderSettingsForm)| {
+            let mut form = settings_form("docs", config());
+            edit(&mut form);
+            apply_settings(&form).unwrap_err()
+        };
+
+        assert!(invalid(|f| f.rescan_interval = "-1".to_string()).contains("Rescan interval"));
+        assert!(invalid(|f| f.fs_watcher_delay = "0".to_string()).contains("Watcher delay"));
+        assert!(invalid(|f| f.min_disk_free = "101".to_string()).contains("100%"));
+        assert!(invalid(|f| f.versioning_keep = "0".to_string()).contains("at least 1"));
+        assert!(
+            invalid(|f| {
+                f.versioning_type = "external".to_string();
+                f.versioning_command = " ".to_string();
+            })
+            .contains("command")
+        );
+
+        // 101 is fine when the unit isn't a percentage
+        let mut form = settings_form("docs", config());
+        form.min_disk_free = "101".to_string();
+        form.min_disk_free_unit = "GB".to_string();
+        assert!(apply_settings(&form).is_ok());
+    }
+
+    // ========================================
+    // DIFF AND CYCLING
+    // ========================================
+
+    #[test]
+    fn test_diff_settings_lists_changed_fields() {
+        let mut form = settings_form("docs", config());
+        form.fs_watcher_enabled = false;
+        form.versioning_type = "trashcan".to_string();
+        form.versioning_cleanout_days = "7".to_string();
+        let updated = apply_settings(&form).unwrap();
+
+        let change = |setting, before: &str, after: &str| SettingChange {
+            setting,
+            before: before.to_string(),
+            after: after.to_string(),
+        };
+        assert_eq!(
+            diff_settings(&config(), &updated),
+            vec![
+                change("Watch for changes", "Yes", "No"),
+                change("File versioning", "Simple", "Trash Can"),
+                change("Keep versions", "10", "-"),
+                change("Clean out after", "never", "7 days"),
+            ]
+        );
+        assert!(diff_settings(&config(), &config()).is_empty());
+    }
+
+    #[test]
+    fn test_cycle_option_wraps() {
+        assert_eq!(cycle_option(&PULL_ORDERS, "newestFirst", true), "random");
+        assert_eq!(cycle_option(&PULL_ORDERS, "random", false), "newestFirst");
+        assert_eq!(cycle_option(&VERSIONING_TYPES, "", true), "trashcan");
+        assert_eq!(cycle_option(&DISK_FREE_UNITS, "PB", true), "%");
+    }
+}
@@ -0,0 +1,363 @@
+//! Folder setup logic
+//!
+//! Pure functions for creating, sharing and removing folders: generating
+//! folder IDs, building device checklists, validating the new folder form
+//! and updating a folder config's device list.
+
+use crate::api::{Device, Folder, FolderDevice};
+use crate::logic::path::reverse_translate_path;
+use crate::model::types::{DeviceChoice, NewFolderForm, RemoveFolderPrompt};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+const ID_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Generate a folder ID like the web UI does ("xxxxx-xxxxx")
+///
+/// # Example
+/// ```
+/// use stui::logic::folder_setup::generate_folder_id;
+///
+/// let id = generate_folder_id(42);
+/// assert_eq!(id.len(), 11);
+/// assert_eq!(id.as_bytes()[5], b'-');
+/// assert_eq!(id, generate_folder_id(42));
+/// ```
+pub fn generate_folder_id(seed: u64) -> String {
+    // xorshift64*; zero is the one seed it can't leave
+    let mut state = seed.max(1);
+    let mut next_char = || {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        let n = state.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 32;
+        ID_ALPHABET[(n % ID_ALPHABET.len() as u64) as usize] as char
+    };
+
+    let first: String = (0..5).map(|_| next_char()).collect();
+    let second: String = (0..5).map(|_| next_char()).collect();
+    format!("{}-{}", first, second)
+}
+
+/// Build a sharing checklist of all remote devices
+///
+/// The local device is left out; devices in `shared_with` start checked.
+/// Devices are sorted by name like the devices modal.
+pub fn device_choices(
+    devices: &[Device],
+    my_id: Option<&str>,
+    shared_with: &[FolderDevice],
+) -> Vec<DeviceChoice> {
+    let mut choices: Vec<DeviceChoice> = devices
+        .iter()
+        .filter(|device| Some(device.id.as_str()) != my_id)
+        .map(|device| DeviceChoice {
+            device_id: device.id.clone(),
+            name: device.name.clone(),
+            selected: shared_with.iter().any(|d| d.device_id == device.id),
+        })
+        .collect();
+    choices.sort_by_key(|choice| choice.name.to_lowercase());
+    choices
+}
+
+/// Validate the new folder form and build the folder to add
+///
+/// # Returns
+/// * `Ok(Folder)` - Folder with the container path and checked devices
+/// * `Err(String)` - Human-readable validation error
+pub fn validate_new_folder(
+    form: &NewFolderForm,
+    existing: &[Folder],
+    path_map: &HashMap<String, String>,
+) -> Result<Folder, String> {
+    let folder_id = form.folder_id.trim();
+    if folder_id.is_empty() {
+        return Err("Folder ID is required".to_string());
+    }
+    if existing.iter().any(|f| f.id == folder_id) {
+        return Err(format!("Folder ID {} is already in use", folder_id));
+    }
+
+    let host_path = form.path.trim();
+    if host_path.is_empty() {
+        return Err("Folder path is required".to_string());
+    }
+
+    // Syncthing may run in a container, so map the host path back
+    let path = reverse_translate_path(host_path, path_map);
+    if let Some(other) = existing
+        .iter()
+        .find(|f| f.path.trim_end_matches('/') == path.trim_end_matches('/'))
+    {
+        return Err(format!(
+            "Path is already used by folder {}",
+            other
+                .label
+                .as_deref()
+                .filter(|l| !l.is_empty())
+                .unwrap_or(&other.id)
+        ));
+    }
+
+    Ok(Folder {
+        id: folder_id.to_string(),
+        label: Some(form.label.trim().to_string()),
+        path,
+        paused: false,
+        folder_type: form.folder_type.clone(),
+        devices: form
+            .devices
+            .iter()
+            .filter(|choice| choice.selected)
+            .map(|choice| FolderDevice {
+                device_id: choice.device_id.clone(),
+            })
+            .collect(),
+    })
+}
+
+/// Write the checklist into a copy of a folder config object
+///
+/// Devices that stay shared keep their entry as is (encryption passwords,
+/// introducer info). Entries for devices not in the checklist, such as the
+/// local device, are kept too.
+pub fn apply_sharing(config: &Value, choices: &[DeviceChoice]) -> Value {
+    let unchecked = |id: &str| {
+        choices
+            .iter()
+            .any(|choice| choice.device_id == id && !choice.selected)
+    };
+
+    let mut devices: Vec<Value> = config["devices"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| !unchecked(entry["deviceID"].as_str().unwrap_or_default()))
+        .collect();
+
+    for choice in choices.iter().filter(|choice| choice.selected) {
+        let listed = devices
+            .iter()
+            .any(|entry| entry["deviceID"].as_str() == Some(choice.device_id.as_str()));
+        if !listed {
+            devices.push(json!({ "deviceID": choice.device_id }));
+        }
+    }
+
+    let mut config = config.clone();
+    config["devices"] = Value::Array(devices);
+    config
+}
+
+/// Removal prompt for a folder (asks for its label, or its ID when unlabeled)
+pub fn remove_folder_prompt(folder: &Folder) -> RemoveFolderPrompt {
+    let name = match folder.label.as_deref() {
+        Some(label) if !label.is_empty() => label.to_string(),
+        _ => folder.id.clone(),
+    };
+    RemoveFolderPrompt {
+        folder_id: folder.id.clone(),
+        name,
+        input: String::new(),
+    }
+}
+
+/// Whether the typed name matches the folder's name exactly
+pub fn removal_confirmed(prompt: &RemoveFolderPrompt) -> bool {
+    prompt.input == prompt.name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::types::NewFolderField;
+
+    fn device(id: &str, name: &str) -> Device {
+        Device {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn folder(id: &str, path: &str) -> Folder {
+        Folder {
+            id: id.to_string(),
+            label: Some(id.to_uppercase()),
+            path: path.to_string(),
+            paused: false,
+            folder_type: "sendreceive".to_string(),
+            devices: vec![],
+        }
+    }
+
+    fn form(folder_id: &str, path: &str) -> NewFolderForm {
+        NewFolderForm {
+            label: "Music".to_string(),
+            folder_id: folder_id.to_string(),
+            path: path.to_string(),
+            path_edited: true,
+            folder_type: "sendonly".to_string(),
+            devices: vec![
+                DeviceChoice {
+                    device_id: "NAS".to_string(),
+                    name: "nas".to_string(),
+                    selected: true,
+                },
+                DeviceChoice {
+                    device_id: "PHONE".to_string(),
+                    name: "phone".to_string(),
+                    selected: false,
+                },
+            ],
+            device_index: 0,
+            focused_field: NewFolderField::Label,
+        }
+    }
+
+    // ========================================
+    // FOLDER IDS
+    // ========================================
+
+    #[test]
+    fn test_generate_folder_id_format() {
+        for seed in [0, 1, 7, u64::MAX] {
+            let id = generate_folder_id(seed);
+            let (first, second) = id.split_once('-').unwrap();
+            assert_eq!((first.len(), second.len()), (5, 5));
+            assert!(
+                id.chars()
+                    .all(|c| c == '-' || c.is_ascii_lowercase() || c.is_ascii_digit())
+            );
+        }
+        assert_ne!(generate_folder_id(1), generate_folder_id(2));
+    }
+
+    // ========================================
+    // DEVICE CHOICES
+    // ========================================
+
+    #[test]
+    fn test_device_choices_skip_local_device_and_check_shared() {
+        let devices = vec![
+            device("ME", "laptop"),
+            device("PHONE", "phone"),
+            device("NAS", "Nas"),
+        ];
+        let shared = vec![FolderDevice {
+            device_id: "PHONE".to_string(),
+        }];
+
+        let choices = device_choices(&devices, Some("ME"), &shared);
+        let ids: Vec<(&str, bool)> = choices
+            .iter()
+            .map(|c| (c.device_id.as_str(), c.selected))
+            .collect();
+        assert_eq!(ids, vec![("NAS", false), ("PHONE", true)]);
+    }
+
+    // ========================================
+    // NEW FOLDER VALIDATION
+    // ========================================
+
+    #[test]
+    fn test_validate_new_folder_maps_path_and_shares_checked_devices() {
+        let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+        let folder =
+            validate_new_folder(&form("music", "/mnt/storage/music"), &[], &path_map).unwrap();
+
+        assert_eq!(folder.id, "music");
+        assert_eq!(folder.label.as_deref(), Some("Music"));
+        assert_eq!(folder.path, "/data/music");
+        assert_eq!(folder.folder_type, "sendonly");
+        let devices: Vec<&str> = folder
+            .devices
+            .iter()
+            .map(|d| d.device_id.as_str())
+            .collect();
+        assert_eq!(devices, vec!["NAS"]);
+    }
+
+    #[test]
+    fn test_validate_new_folder_errors() {
+        let existing = vec![folder("photos", "/data/photos/")];
+        let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+        let check = |f: NewFolderForm| validate_new_folder(&f, &existing, &path_map).unwrap_err();
+
+        assert_eq!(check(form(" ", "/tmp/x")), "Folder ID is required");
+        assert_eq!(
+            check(form("photos", "/tmp/x")),
+            "Folder ID photos is already in use"
+        );
+        assert_eq!(check(form("music", "")), "Folder path is required");
+        assert_eq!(
+            check(form("music", "/mnt/storage/photos")),
+            "Path is already used by folder PHOTOS"
+        );
+    }
+
+    // ========================================
+    // SHARING
+    // ========================================
+
+    #[test]
+    fn test_apply_sharing_keeps_existing_entries() {
+        let config = json!({
+            "id": "docs",
+            "devices": [
+                { "deviceID": "ME" },
+                { "deviceID": "NAS", "encryptionPassword": "secret" },
+                { "deviceID": "PHONE" },
+            ],
+        });
+        let choices = vec![
+            DeviceChoice {
+                device_id: "NAS".to_string(),
+                name: "nas".to_string(),
+                selected: true,
+            },
+            DeviceChoice {
+                device_id: "PHONE".to_string(),
+                name: "phone".to_string(),
+                selected: false,
+            },
+            DeviceChoice {
+                device_id: "TABLET".to_string(),
+                name: "tablet".to_string(),
+                selected: true,
+            },
+        ];
+
+        let updated = apply_sharing(&config, &choices);
+        assert_eq!(
+            updated["devices"],
+            json!([
+                { "deviceID": "ME" },
+                { "deviceID": "NAS", "encryptionPassword": "secret" },
+                { "deviceID": "TABLET" },
+            ])
+        );
+        assert_eq!(updated["id"], "docs");
+    }
+
+    // ========================================
+    // REMOVAL
+    // ========================================
+
+    #[test]
+    fn test_removal_needs_exact_name() {
+        let mut prompt = remove_folder_prompt(&folder("docs", "/data/docs"));
+        assert_eq!(prompt.name, "DOCS");
+        assert!(!removal_confirmed(&prompt));
+
+        prompt.input = "docs".to_string();
+        assert!(!removal_confirmed(&prompt));
+        prompt.input = "DOCS".to_string();
+        assert!(removal_confirmed(&prompt));
+
+        let mut unlabeled = folder("abcde-12345", "/data/x");
+        unlabeled.label = Some(String::new());
+        assert_eq!(remove_folder_prompt(&unlabeled).name, "abcde-12345");
+    }
+}
@@ -2,6 +2,43 @@
 //!
 //! Pure functions for formatting data for human-readable display.
 
+/// Symbols used in formatted text, so the ASCII icon mode can swap them out
+///
+/// The UI picks the set matching its icon mode (`IconRenderer::symbols`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbols {
+    /// Marks downloads (data coming in)
+    pub download: &'static str,
+    /// Marks uploads (data going out)
+    pub upload: &'static str,
+    /// Marks files modified locally in a receive-only folder
+    pub modified: &'static str,
+    /// Marks files changed remotely in a send-only folder
+    pub remote_changed: &'static str,
+    /// Stands for a rate limit of zero
+    pub unlimited: &'static str,
+}
+
+impl Symbols {
+    /// Arrows and symbols for Unicode terminals (emoji and Nerd Font modes)
+    pub const UNICODE: Symbols = Symbols {
+        download: "↓",
+        upload: "↑",
+        modified: "✎",
+        remote_changed: "⇅",
+        unlimited: "∞",
+    };
+
+    /// Plain text for terminals without Unicode
+    pub const ASCII: Symbols = Symbols {
+        download: "dn:",
+        upload: "up:",
+        modified: "*",
+        remote_changed: "!",
+        unlimited: "unlimited",
+    };
+}
+
 /// Format uptime duration in human-readable format
 ///
 /// Converts seconds into a compact representation showing the two most
@@ -109,6 +146,32 @@ pub fn format_human_size(size: u64) -> String {
     }
 }
 
+/// Format transfer rate (bytes/sec) into human-readable string
+///
+/// # Examples
+/// ```
+/// use stui::logic::formatting::format_transfer_rate;
+///
+/// assert_eq!(format_transfer_rate(512.0), "512B/s");
+/// assert_eq!(format_transfer_rate(1536.0), "1.5K/s");
+/// assert_eq!(format_transfer_rate(2.5 * 1024.0 * 1024.0), "2.5M/s");
+/// ```
+pub fn format_transfer_rate(bytes_per_sec: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    if bytes_per_sec < KB {
+        format!("{:.0}B/s", bytes_per_sec)
+    } else if bytes_per_sec < MB {
+        format!("{:.1}K/s", bytes_per_sec / KB)
+    } else if bytes_per_sec < GB {
+        format!("{:.1}M/s", bytes_per_sec / MB)
+    } else {
+        format!("{:.2}G/s", bytes_per_sec / GB)
+    }
+}
+
 /// Format RFC 3339 datetime string to human-readable format (YYYY-MM-DD HH:MM:SS)
 ///
 /// Converts ISO 8601/RFC 3339 timestamps (e.g., "2024-01-15T14:30:45Z")
@@ -0,0 +1,156 @@
+//! Folder health for headless commands
+//!
+//! Reduces a folder's status to a coarse health value, and a set of folders to
+//! the process exit code of `stui status`, `stui folders` and `stui need`, so
+//! cron health checks can alert on anything other than 0.
+
+use crate::api::FolderStatus;
+
+/// Everything is in sync (or only paused folders are behind)
+pub const EXIT_OK: i32 = 0;
+/// The command itself failed (connection, unknown folder, bad arguments)
+pub const EXIT_FAILURE: i32 = 1;
+/// At least one folder is syncing, scanning or needs items
+pub const EXIT_OUT_OF_SYNC: i32 = 2;
+/// At least one folder is in an error state or has failed items
+pub const EXIT_SYNC_ERRORS: i32 = 3;
+
+/// Coarse folder health, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FolderHealth {
+    UpToDate,
+    Paused,
+    Syncing,
+    OutOfSync,
+    Error,
+}
+
+impl FolderHealth {
+    /// Stable name used in tables and JSON output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FolderHealth::UpToDate => "up-to-date",
+            FolderHealth::Paused => "paused",
+            FolderHealth::Syncing => "syncing",
+            FolderHealth::OutOfSync => "out-of-sync",
+            FolderHealth::Error => "error",
+        }
+    }
+}
+
+/// Classify a folder from its paused flag and `/rest/db/status`
+///
+/// A missing status (the request failed) counts as an error.
+///
+/// # Examples
+/// ```
+/// use stui::logic::health::{FolderHealth, folder_health};
+///
+/// assert_eq!(folder_health(true, None), FolderHealth::Paused);
+/// assert_eq!(folder_health(false, None), FolderHealth::Error);
+/// ```
+pub fn folder_health(paused: bool, status: Option<&FolderStatus>) -> FolderHealth {
+    if paused {
+        return FolderHealth::Paused;
+    }
+    let Some(status) = status else {
+        return FolderHealth::Error;
+    };
+
+    if status.state == "error" || status.errors > 0 {
+        FolderHealth::Error
+    } else if status.state != "idle" {
+        // syncing, scanning, sync-preparing, *-waiting, cleaning
+        FolderHealth::Syncing
+    } else if status.need_total_items > 0 || status.receive_only_total_items > 0 {
+        FolderHealth::OutOfSync
+    } else {
+        FolderHealth::UpToDate
+    }
+}
+
+/// Exit code for a set of folders (the worst folder decides)
+pub fn exit_code(healths: &[FolderHealth]) -> i32 {
+    match healths.iter().max() {
+        Some(FolderHealth::Error) => EXIT_SYNC_ERRORS,
+        Some(FolderHealth::Syncing | FolderHealth::OutOfSync) => EXIT_OUT_OF_SYNC,
+        _ => EXIT_OK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(state: &str, need: u64, local_changes: u64, errors: u64) -> FolderStatus {
+        FolderStatus {
+            state: state.to_string(),
+            need_total_items: need,
+            receive_only_total_items: local_changes,
+            errors,
+            ..Default::default()
+        }
+    }
+
+    // ========================================
+    // FOLDER HEALTH
+    // ========================================
+
+    #[test]
+    fn test_idle_folder_without_needs_is_up_to_date() {
+        let s = status("idle", 0, 0, 0);
+        assert_eq!(folder_health(false, Some(&s)), FolderHealth::UpToDate);
+    }
+
+    #[test]
+    fn test_paused_wins_over_status() {
+        let s = status("error", 5, 0, 3);
+        assert_eq!(folder_health(true, Some(&s)), FolderHealth::Paused);
+    }
+
+    #[test]
+    fn test_errors_win_over_activity() {
+        assert_eq!(
+            folder_health(false, Some(&status("syncing", 5, 0, 2))),
+            FolderHealth::Error
+        );
+        assert_eq!(
+            folder_health(false, Some(&status("error", 0, 0, 0))),
+            FolderHealth::Error
+        );
+    }
+
+    #[test]
+    fn test_busy_states_are_syncing() {
+        for state in ["syncing", "scanning", "sync-preparing", "scan-waiting"] {
+            let s = status(state, 0, 0, 0);
+            assert_eq!(folder_health(false, Some(&s)), FolderHealth::Syncing);
+        }
+    }
+
+    #[test]
+    fn test_needed_items_and_local_changes_are_out_of_sync() {
+        assert_eq!(
+            folder_health(false, Some(&status("idle", 4, 0, 0))),
+            FolderHealth::OutOfSync
+        );
+        assert_eq!(
+            folder_health(false, Some(&status("idle", 0, 2, 0))),
+            FolderHealth::OutOfSync
+        );
+    }
+
+    // ========================================
+    // EXIT CODES
+    // ========================================
+
+    #[test]
+    fn test_exit_code_uses_worst_folder() {
+        use FolderHealth::*;
+        assert_eq!(exit_code(&[]), EXIT_OK);
+        assert_eq!(exit_code(&[UpToDate, Paused]), EXIT_OK);
+        assert_eq!(exit_code(&[UpToDate, Syncing]), EXIT_OUT_OF_SYNC);
+        assert_eq!(exit_code(&[OutOfSync, UpToDate]), EXIT_OUT_OF_SYNC);
+        assert_eq!(exit_code(&[Syncing, Error, UpToDate]), EXIT_SYNC_ERRORS);
+    }
+}
@@ -1,79 +1,521 @@
 //! Ignore Pattern Matching and Validation Logic
 //!
-//! This module contains pure functions for matching files against Syncthing .stignore patterns
-//! and validating pattern syntax.
+//! This module contains pure functions for evaluating Syncthing .stignore patterns,
+//! validating pattern syntax and diffing edited ignore files.
 //!
-//! Patterns follow similar rules to .gitignore:
-//! - Patterns starting with `/` are relative to folder root
-//! - Patterns without `/` match anywhere in the path
-//! - Supports glob patterns (e.g., `*.tmp`, `temp*`, `**/cache`)
+//! Matching follows Syncthing's rules:
+//! - Patterns are checked in order and the first matching pattern decides
+//! - `!` negates a pattern (matching files are not ignored)
+//! - `(?i)` matches case-insensitively, `(?d)` marks files as deletable
+//! - Patterns starting with `/` match from the folder root, others at any depth
+//! - A pattern matching a directory also matches everything below it
+//! - `*` and `?` stay within one path component, `**` also crosses `/`
+//! - `[a-z]` ranges, `{a,b}` alternatives and `\` escapes are supported
+//! - `//` comments and blank lines are skipped
+//! - `#include` lines match the patterns of the included file at their
+//!   position; the REST API only returns those patterns in the `expanded`
+//!   list, so `included_patterns` recovers them from there
 
-/// Check if a file path matches a Syncthing ignore pattern
-///
-/// # Pattern Rules
-/// - Patterns starting with `/` match from the folder root
-/// - Patterns without `/` match anywhere in the path (including just the filename)
-/// - Supports glob patterns using the `glob` crate
+use crate::model::types::IgnoreDiffLine;
+use std::collections::HashMap;
+
+/// The pattern that decided whether a path is ignored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgnoreMatch {
+    /// True if the path is ignored, false if a `!` pattern keeps it
+    pub ignored: bool,
+    /// The deciding line as written in `.stignore`
+    pub pattern: String,
+    /// 1-based line number of the deciding line
+    pub line: usize,
+    /// Whether the pattern has the `(?d)` prefix
+    pub deletable: bool,
+    /// The `#include` line the pattern was read through, if it is not a line
+    /// of `.stignore` itself (`line` is then the include's line number)
+    pub include: Option<String>,
+}
+
+/// Ordered `.stignore` matcher
 ///
 /// # Examples
 /// ```
-/// use stui::logic::ignore::pattern_matches;
+/// use stui::logic::ignore::IgnoreMatcher;
+///
+/// let lines = vec![
+///     "// keep the important log".to_string(),
+///     "!important.log".to_string(),
+///     "*.log".to_string(),
+/// ];
+/// let matcher = IgnoreMatcher::new(&lines);
+///
+/// let verdict = matcher.match_path("logs/debug.log").unwrap();
+/// assert!(verdict.ignored);
+/// assert_eq!((verdict.line, verdict.pattern.as_str()), (3, "*.log"));
 ///
-/// assert!(pattern_matches("/foo/bar.txt", "/foo/bar.txt"));
-/// assert!(pattern_matches("*.tmp", "/some/path/file.tmp"));
-/// assert!(pattern_matches("cache", "/foo/cache/data"));
+/// assert!(!matcher.is_ignored("logs/important.log"));
+/// assert!(matcher.match_path("notes.txt").is_none());
 /// ```
-pub fn pattern_matches(pattern: &str, file_path: &str) -> bool {
-    // Syncthing ignore patterns are similar to .gitignore
-    // Patterns starting with / are relative to folder root
-    // Patterns without / match anywhere in the path
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreMatcher {
+    lines: Vec<CompiledLine>,
+}
+
+/// A compiled `.stignore` line
+#[derive(Debug, Clone)]
+struct CompiledLine {
+    source: String,
+    line: usize,
+    negated: bool,
+    case_insensitive: bool,
+    deletable: bool,
+    include: Option<String>,
+    /// Expanded globs (root or any depth, the path itself or its contents)
+    globs: Vec<Vec<Token>>,
+}
+
+impl IgnoreMatcher {
+    /// Compile `.stignore` lines in order
+    ///
+    /// Lines that fail to compile are skipped (Syncthing rejects the whole
+    /// file instead; use `validate_ignore_line` before saving). `#include`
+    /// lines match nothing; use `with_includes` to evaluate them.
+    pub fn new(lines: &[String]) -> Self {
+        Self::with_includes(lines, &HashMap::new())
+    }
+
+    /// Compile `.stignore` lines, reading `#include` lines from `included`
+    ///
+    /// `included` maps each `#include` line to its file's patterns, as
+    /// returned by `included_patterns`.
+    pub fn with_includes(lines: &[String], included: &HashMap<String, Vec<String>>) -> Self {
+        let lines = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, source)| match included.get(source.trim()) {
+                Some(patterns) if is_include(source) => patterns
+                    .iter()
+                    .filter_map(|pattern| compile_included(pattern, idx + 1, source.trim()))
+                    .collect(),
+                _ => compile_line(source, idx + 1)
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+            })
+            .collect();
 
-    let pattern = pattern.trim();
+        Self { lines }
+    }
 
-    // Exact match
-    if pattern == file_path {
-        return true;
+    /// Find the pattern deciding a path's ignore state (first match wins)
+    ///
+    /// Paths are relative to the folder root, with or without leading or
+    /// trailing `/`. Returns None when no pattern matches (not ignored).
+    pub fn match_path(&self, path: &str) -> Option<IgnoreMatch> {
+        let candidate = MatchCandidate::new(path);
+        self.lines
+            .iter()
+            .find(|line| candidate.matches(line))
+            .map(CompiledLine::to_match)
     }
 
-    // Pattern starts with / - match from root
-    if let Some(pattern_without_slash) = pattern.strip_prefix('/') {
-        // Exact match without leading slash
-        if pattern_without_slash == file_path.trim_start_matches('/') {
-            return true;
+    /// Whether a path is ignored
+    pub fn is_ignored(&self, path: &str) -> bool {
+        self.match_path(path).is_some_and(|m| m.ignored)
+    }
+
+    /// Every line whose pattern matches the path, in file order
+    ///
+    /// Unlike `match_path` this does not stop at the first match, which is
+    /// what the un-ignore flow needs to find all patterns hiding a file.
+    pub fn matching_lines(&self, path: &str) -> Vec<IgnoreMatch> {
+        let candidate = MatchCandidate::new(path);
+        self.lines
+            .iter()
+            .filter(|line| candidate.matches(line))
+            .map(CompiledLine::to_match)
+            .collect()
+    }
+}
+
+impl CompiledLine {
+    fn to_match(&self) -> IgnoreMatch {
+        IgnoreMatch {
+            ignored: !self.negated,
+            pattern: self.source.clone(),
+            line: self.line,
+            deletable: self.deletable,
+            include: self.include.clone(),
         }
+    }
+}
 
-        // Try glob matching
-        if let Ok(pattern_obj) = glob::Pattern::new(pattern_without_slash)
-            && pattern_obj.matches(file_path.trim_start_matches('/'))
-        {
-            return true;
+/// Compile one of the file's own lines
+fn compile_line(source: &str, line: usize) -> Option<CompiledLine> {
+    let parsed = parse_ignore_line(source)?;
+    let pattern = if parsed.case_insensitive {
+        parsed.pattern.to_lowercase()
+    } else {
+        parsed.pattern.to_string()
+    };
+    let globs = expand_pattern(&pattern)
+        .iter()
+        .map(|glob| compile_glob(glob))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+
+    Some(CompiledLine {
+        source: source.to_string(),
+        line,
+        negated: parsed.negated,
+        case_insensitive: parsed.case_insensitive,
+        deletable: parsed.deletable,
+        include: None,
+        globs,
+    })
+}
+
+/// Compile an expanded pattern read through the `#include` at `line`
+///
+/// Expanded patterns are already rooted globs (`**/` marks any depth), so
+/// `expand_pattern` treats them like patterns starting with `/`.
+fn compile_included(pattern: &str, line: usize, include: &str) -> Option<CompiledLine> {
+    let parsed = parse_ignore_line(pattern)?;
+    let mut glob = format!("/{}", parsed.pattern.trim_start_matches('/'));
+    if parsed.case_insensitive {
+        glob = glob.to_lowercase();
+    }
+    let mut bases = expand_pattern(&glob);
+    // Like Syncthing's own `**/x` expansion, also match at the root
+    if let Some(rest) = glob.strip_prefix("/**/") {
+        bases.extend(expand_pattern(&format!("/{}", rest)));
+    }
+    let globs = bases
+        .iter()
+        .map(|glob| compile_glob(glob))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+
+    Some(CompiledLine {
+        source: pattern.to_string(),
+        line,
+        negated: parsed.negated,
+        case_insensitive: parsed.case_insensitive,
+        deletable: parsed.deletable,
+        include: Some(include.to_string()),
+        globs,
+    })
+}
+
+/// Whether a line is an `#include` directive
+fn is_include(line: &str) -> bool {
+    line.trim_start().starts_with("#include")
+}
+
+/// Recover the patterns each `#include` line adds from Syncthing's `expanded` list
+///
+/// `expanded` holds every pattern in effect, in file order, with each line
+/// expanded Syncthing's way and included files read in. The entries between
+/// the ones of the file's own lines belong to the `#include` line between them.
+///
+/// # Examples
+/// ```
+/// use stui::logic::ignore::{IgnoreMatcher, included_patterns};
+///
+/// let lines = vec!["#include .stglobalignore".to_string(), "/build".to_string()];
+/// let expanded = vec!["**/*.log".to_string(), "build".to_string()];
+///
+/// let included = included_patterns(&lines, &expanded);
+/// assert_eq!(included["#include .stglobalignore"], vec!["**/*.log".to_string()]);
+///
+/// let verdict = IgnoreMatcher::with_includes(&lines, &included)
+///     .match_path("logs/debug.log")
+///     .unwrap();
+/// assert_eq!(verdict.include.as_deref(), Some("#include .stglobalignore"));
+/// assert_eq!(verdict.line, 1);
+/// ```
+pub fn included_patterns(lines: &[String], expanded: &[String]) -> HashMap<String, Vec<String>> {
+    let mut included: HashMap<String, Vec<String>> = HashMap::new();
+    let mut pos = 0;
+
+    for (idx, source) in lines.iter().enumerate() {
+        if is_include(source) {
+            // Everything up to the next own line's patterns came from the include
+            let next = lines[idx + 1..]
+                .iter()
+                .find_map(|line| parse_ignore_line(line));
+            let patterns = included.entry(source.trim().to_string()).or_default();
+            while let Some(entry) = expanded.get(pos) {
+                if next.is_some_and(|next| expands_to(&next, entry)) {
+                    break;
+                }
+                patterns.push(entry.clone());
+                pos += 1;
+            }
+        } else if let Some(parsed) = parse_ignore_line(source) {
+            while expanded
+                .get(pos)
+                .is_some_and(|entry| expands_to(&parsed, entry))
+            {
+                pos += 1;
+            }
+        }
+    }
+
+    included
+}
+
+/// Whether an entry of the `expanded` list is one of a line's expansions
+fn expands_to(line: &IgnoreLine, entry: &str) -> bool {
+    let Some(entry) = parse_ignore_line(entry) else {
+        return false;
+    };
+    if entry.negated != line.negated {
+        return false;
+    }
+
+    let pattern = if line.case_insensitive {
+        line.pattern.to_lowercase()
+    } else {
+        line.pattern.to_string()
+    };
+    let entry = if line.case_insensitive {
+        entry.pattern.to_lowercase()
+    } else {
+        entry.pattern.to_string()
+    };
+    let entry = entry.trim_start_matches('/');
+    entry == pattern.trim_start_matches('/') || expand_pattern(&pattern).iter().any(|e| e == entry)
+}
+
+/// A path prepared for matching (original and lowercased)
+struct MatchCandidate {
+    chars: Vec<char>,
+    lower: Vec<char>,
+}
+
+impl MatchCandidate {
+    fn new(path: &str) -> Self {
+        let path = path.trim_matches('/');
+        Self {
+            chars: path.chars().collect(),
+            lower: path.to_lowercase().chars().collect(),
         }
+    }
+
+    fn matches(&self, line: &CompiledLine) -> bool {
+        let text = if line.case_insensitive {
+            &self.lower
+        } else {
+            &self.chars
+        };
+        !text.is_empty() && line.globs.iter().any(|glob| glob_matches(glob, text))
+    }
+}
+
+/// Expand a pattern the way Syncthing does before compiling it
+///
+/// Each pattern also matches the contents of a matching directory (`/**`),
+/// and unrooted patterns match at any depth (`**/`).
+fn expand_pattern(pattern: &str) -> Vec<String> {
+    let bases = if pattern.ends_with("/**") {
+        vec![pattern.to_string()]
+    } else if pattern.ends_with('/') {
+        vec![format!("{}**", pattern)]
     } else {
-        // Pattern without / - match anywhere (path components or filename)
-        if let Ok(pattern_obj) = glob::Pattern::new(pattern) {
-            // Try matching the full path
-            if pattern_obj.matches(file_path.trim_start_matches('/')) {
-                return true;
+        vec![pattern.to_string(), format!("{}/**", pattern)]
+    };
+
+    bases
+        .into_iter()
+        .flat_map(|base| {
+            if let Some(rooted) = base.strip_prefix('/') {
+                vec![rooted.to_string()]
+            } else if let Some(rest) = base.strip_prefix("**/") {
+                let rest = rest.to_string();
+                vec![base, rest]
+            } else {
+                vec![format!("**/{}", base), base]
             }
+        })
+        .collect()
+}
 
-            // Try matching each path component (directories and filename)
-            for component in file_path.split('/') {
-                if !component.is_empty() && pattern_obj.matches(component) {
-                    return true;
+/// A compiled glob element
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Char(char),
+    /// `*`: any run of characters within one path component
+    Star,
+    /// `**`: any run of characters, including `/`
+    SuperStar,
+    /// `?`: one character other than `/`
+    Question,
+    /// `[...]`: one character in (or, negated, not in) the ranges
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    /// `{a,b}`: any of the alternatives
+    Alternatives(Vec<Vec<Token>>),
+}
+
+/// Compile a glob pattern into tokens
+fn compile_glob(pattern: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let (tokens, _) = parse_glob(&chars, 0, false)?;
+    Ok(tokens)
+}
+
+/// Parse tokens from `pos` until the end (or a `,`/`}` inside alternatives)
+fn parse_glob(
+    chars: &[char],
+    mut pos: usize,
+    in_alternatives: bool,
+) -> Result<(Vec<Token>, usize), String> {
+    let mut tokens = Vec::new();
+
+    while pos < chars.len() {
+        match chars[pos] {
+            ',' | '}' if in_alternatives => return Ok((tokens, pos)),
+            '*' if chars.get(pos + 1) == Some(&'*') => {
+                tokens.push(Token::SuperStar);
+                pos += 2;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                pos += 1;
+            }
+            '?' => {
+                tokens.push(Token::Question);
+                pos += 1;
+            }
+            '\\' => {
+                let escaped = chars
+                    .get(pos + 1)
+                    .ok_or_else(|| "Pattern ends with an escape character".to_string())?;
+                tokens.push(Token::Char(*escaped));
+                pos += 2;
+            }
+            '[' => {
+                let (token, next) = parse_class(chars, pos + 1)?;
+                tokens.push(token);
+                pos = next;
+            }
+            '{' => {
+                let mut alternatives = Vec::new();
+                pos += 1;
+                loop {
+                    let (alternative, next) = parse_glob(chars, pos, true)?;
+                    alternatives.push(alternative);
+                    match chars.get(next) {
+                        Some(',') => pos = next + 1,
+                        Some('}') => {
+                            pos = next + 1;
+                            break;
+                        }
+                        _ => return Err("Unclosed brace in pattern".to_string()),
+                    }
                 }
+                tokens.push(Token::Alternatives(alternatives));
+            }
+            c => {
+                tokens.push(Token::Char(c));
+                pos += 1;
             }
         }
     }
 
-    false
+    if in_alternatives {
+        return Err("Unclosed brace in pattern".to_string());
+    }
+    Ok((tokens, pos))
 }
 
-/// Find all patterns that match a given file path
+/// Parse a character class starting after its `[`
+fn parse_class(chars: &[char], mut pos: usize) -> Result<(Token, usize), String> {
+    let negated = matches!(chars.get(pos), Some('!') | Some('^'));
+    if negated {
+        pos += 1;
+    }
+
+    let mut ranges = Vec::new();
+    loop {
+        let c = match chars.get(pos) {
+            None => return Err("Unclosed bracket in pattern".to_string()),
+            Some(']') if !ranges.is_empty() => {
+                return Ok((Token::Class { negated, ranges }, pos + 1));
+            }
+            Some('\\') => {
+                pos += 1;
+                *chars
+                    .get(pos)
+                    .ok_or_else(|| "Unclosed bracket in pattern".to_string())?
+            }
+            Some(c) => *c,
+        };
+        pos += 1;
+
+        // Range like a-z (a trailing '-' is literal)
+        if chars.get(pos) == Some(&'-') && chars.get(pos + 1).is_some_and(|c| *c != ']') {
+            let end = chars[pos + 1];
+            if end < c {
+                return Err(format!("Invalid range {}-{} in pattern", c, end));
+            }
+            ranges.push((c, end));
+            pos += 2;
+        } else {
+            ranges.push((c, c));
+        }
+    }
+}
+
+/// Match tokens against the whole text
+fn glob_matches(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+
+    match token {
+        Token::Char(c) => text.first() == Some(c) && glob_matches(rest, &text[1..]),
+        Token::Question => {
+            text.first().is_some_and(|c| *c != '/') && glob_matches(rest, &text[1..])
+        }
+        Token::Class { negated, ranges } => {
+            text.first().is_some_and(|c| {
+                let in_class = ranges.iter().any(|(lo, hi)| (lo..=hi).contains(&c));
+                *c != '/' && in_class != *negated
+            }) && glob_matches(rest, &text[1..])
+        }
+        Token::Star => {
+            let mut i = 0;
+            loop {
+                if glob_matches(rest, &text[i..]) {
+                    return true;
+                }
+                if i == text.len() || text[i] == '/' {
+                    return false;
+                }
+                i += 1;
+            }
+        }
+        Token::SuperStar => (0..=text.len()).any(|i| glob_matches(rest, &text[i..])),
+        Token::Alternatives(alternatives) => alternatives.iter().any(|alternative| {
+            let mut combined = alternative.clone();
+            combined.extend_from_slice(rest);
+            glob_matches(&combined, text)
+        }),
+    }
+}
+
+/// Find all ignoring (non-negated) lines that match a given file path
 ///
-/// Returns a list of patterns from the input that match the file path.
+/// `#include` lines are evaluated against `included` (see `included_patterns`);
+/// their matches carry the include line in `IgnoreMatch::include`. Returns the
+/// matches in file order, so the first one is the line that decides the file
+/// is ignored (unless a `!` line matches before it).
 ///
 /// # Examples
 /// ```
+/// use std::collections::HashMap;
 /// use stui::logic::ignore::find_matching_patterns;
 ///
 /// let patterns = vec![
@@ -82,14 +524,19 @@ pub fn pattern_matches(pattern: &str, file_path: &str) -> bool {
 ///     "*.log".to_string(),
 /// ];
 ///
-/// let matches = find_matching_patterns(&patterns, "/foo/bar.tmp");
-/// assert_eq!(matches, vec!["*.tmp"]);
+/// let matches = find_matching_patterns(&patterns, &HashMap::new(), "/foo/bar.tmp");
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!((matches[0].line, matches[0].pattern.as_str()), (1, "*.tmp"));
 /// ```
-pub fn find_matching_patterns(patterns: &[String], file_path: &str) -> Vec<String> {
-    patterns
-        .iter()
-        .filter(|p| pattern_matches(p, file_path))
-        .cloned()
+pub fn find_matching_patterns(
+    patterns: &[String],
+    included: &HashMap<String, Vec<String>>,
+    file_path: &str,
+) -> Vec<IgnoreMatch> {
+    IgnoreMatcher::with_includes(patterns, included)
+        .matching_lines(file_path)
+        .into_iter()
+        .filter(|m| m.ignored)
         .collect()
 }
 
@@ -125,9 +572,6 @@ pub fn find_matching_patterns(patterns: &[String], file_path: &str) -> Vec<Strin
 /// assert!(validate_pattern("test[.txt").is_err());
 /// assert!(validate_pattern("line1\nline2").is_err());
 /// ```
-///
-/// Note: Currently unused but available for pattern input validation dialogs
-#[allow(dead_code)]
 pub fn validate_pattern(pattern: &str) -> Result<(), String> {
     // Check for empty pattern
     if pattern.trim().is_empty() {
@@ -153,7 +597,210 @@ pub fn validate_pattern(pattern: &str) -> Result<(), String> {
         return Err("Unclosed brace in pattern".to_string());
     }
 
-    Ok(())
+    // Anything else the matcher cannot compile (bad ranges, dangling escapes)
+    compile_glob(pattern).map(|_| ())
+}
+
+/// Whether a `.stignore` line is blank or a `//` comment
+pub fn is_comment_or_blank(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with("//")
+}
+
+/// A `.stignore` pattern line with its prefixes split off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IgnoreLine<'a> {
+    pub pattern: &'a str,
+    /// `!` prefix: matching files are not ignored
+    pub negated: bool,
+    /// `(?i)` prefix: case-insensitive match
+    pub case_insensitive: bool,
+    /// `(?d)` prefix: may be deleted when blocking directory removal
+    pub deletable: bool,
+}
+
+/// Split a `.stignore` line into its pattern and prefixes
+///
+/// Like Syncthing, the `!`, `(?i)` and `(?d)` prefixes may come in any
+/// order but each only once. Returns None for blank lines, comments and
+/// `#include` directives.
+///
+/// # Examples
+/// ```
+/// use stui::logic::ignore::parse_ignore_line;
+///
+/// let line = parse_ignore_line("!(?d)*.tmp").unwrap();
+/// assert_eq!(line.pattern, "*.tmp");
+/// assert!(line.negated && line.deletable && !line.case_insensitive);
+/// assert_eq!(parse_ignore_line("// comment"), None);
+/// assert_eq!(parse_ignore_line("#include .stglobalignore"), None);
+/// ```
+pub fn parse_ignore_line(line: &str) -> Option<IgnoreLine<'_>> {
+    if is_comment_or_blank(line) || line.trim_start().starts_with("#include") {
+        return None;
+    }
+
+    let mut parsed = IgnoreLine {
+        pattern: line.trim(),
+        negated: false,
+        case_insensitive: false,
+        deletable: false,
+    };
+    loop {
+        if let Some(rest) = parsed.pattern.strip_prefix('!').filter(|_| !parsed.negated) {
+            parsed.negated = true;
+            parsed.pattern = rest;
+        } else if let Some(rest) = parsed
+            .pattern
+            .strip_prefix("(?i)")
+            .filter(|_| !parsed.case_insensitive)
+        {
+            parsed.case_insensitive = true;
+            parsed.pattern = rest;
+        } else if let Some(rest) = parsed
+            .pattern
+            .strip_prefix("(?d)")
+            .filter(|_| !parsed.deletable)
+        {
+            parsed.deletable = true;
+            parsed.pattern = rest;
+        } else {
+            break;
+        }
+    }
+
+    Some(parsed)
+}
+
+/// Validate a single `.stignore` line as typed in the editor
+///
+/// Blank lines and comments are always valid; `#include` needs a file name.
+pub fn validate_ignore_line(line: &str) -> Result<(), String> {
+    if is_comment_or_blank(line) {
+        return Ok(());
+    }
+
+    if let Some(file) = line.trim_start().strip_prefix("#include") {
+        return if file.trim().is_empty() {
+            Err("#include needs a file name".to_string())
+        } else {
+            Ok(())
+        };
+    }
+
+    match parse_ignore_line(line) {
+        Some(parsed) => validate_pattern(parsed.pattern),
+        None => Ok(()),
+    }
+}
+
+/// Files whose ignore state depends on one line of an edited `.stignore`
+///
+/// Compares the patterns with and without the line at `index`. Returns
+/// `(newly_ignored, unignored)`: files the line ignores, and files a
+/// negated line keeps from being ignored.
+pub fn line_effect(lines: &[String], index: usize, paths: &[String]) -> (Vec<String>, Vec<String>) {
+    if index >= lines.len() || parse_ignore_line(&lines[index]).is_none() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let without: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, line)| line.clone())
+        .collect();
+    let with_line = IgnoreMatcher::new(lines);
+    let without_line = IgnoreMatcher::new(&without);
+
+    let mut newly_ignored = Vec::new();
+    let mut unignored = Vec::new();
+    for path in paths {
+        match (with_line.is_ignored(path), without_line.is_ignored(path)) {
+            (true, false) => newly_ignored.push(path.clone()),
+            (false, true) => unignored.push(path.clone()),
+            _ => {}
+        }
+    }
+
+    (newly_ignored, unignored)
+}
+
+/// Remove the line a match came from
+///
+/// Uses the match's line number when it still holds the same text, and
+/// otherwise removes every line with that text (the file changed meanwhile).
+pub fn remove_ignore_line(lines: &[String], m: &IgnoreMatch) -> Vec<String> {
+    let index = m.line.checked_sub(1);
+    if index.is_some_and(|i| lines.get(i) == Some(&m.pattern)) {
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != index)
+            .map(|(_, line)| line.clone())
+            .collect()
+    } else {
+        lines
+            .iter()
+            .filter(|line| **line != m.pattern)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Add an anchored `/path` line for each path, ahead of the existing lines
+///
+/// Paths that already have their line are skipped, so the whole batch can be
+/// written with a single `set_ignore_patterns` call. Returns the updated lines
+/// and how many were added.
+pub fn add_ignore_patterns(lines: &[String], relative_paths: &[String]) -> (Vec<String>, usize) {
+    let mut added: Vec<String> = Vec::new();
+    for path in relative_paths {
+        let pattern = format!("/{}", path);
+        if !lines.contains(&pattern) && !added.contains(&pattern) {
+            added.push(pattern);
+        }
+    }
+
+    let count = added.len();
+    added.extend(lines.iter().cloned());
+    (added, count)
+}
+
+/// Line-based diff between the saved and edited `.stignore` contents
+///
+/// Uses a longest-common-subsequence table, which is plenty for ignore files.
+pub fn diff_lines(old: &[String], new: &[String]) -> Vec<IgnoreDiffLine> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            diff.push(IgnoreDiffLine::Unchanged(old[i].clone()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(IgnoreDiffLine::Removed(old[i].clone()));
+            i += 1;
+        } else {
+            diff.push(IgnoreDiffLine::Added(new[j].clone()));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().cloned().map(IgnoreDiffLine::Removed));
+    diff.extend(new[j..].iter().cloned().map(IgnoreDiffLine::Added));
+    diff
 }
 
 #[cfg(test)]
@@ -164,6 +811,12 @@ mod tests {
     // PATTERN MATCHING
     // ========================================
 
+    fn pattern_matches(pattern: &str, file_path: &str) -> bool {
+        IgnoreMatcher::new(&[pattern.to_string()])
+            .match_path(file_path)
+            .is_some()
+    }
+
     #[test]
     fn test_exact_match() {
         assert!(pattern_matches("/foo/bar.txt", "/foo/bar.txt"));
@@ -204,6 +857,13 @@ mod tests {
     // FIND MATCHING PATTERNS
     // ========================================
 
+    fn pattern_texts(patterns: &[String], file_path: &str) -> Vec<String> {
+        find_matching_patterns(patterns, &HashMap::new(), file_path)
+            .into_iter()
+            .map(|m| m.pattern)
+            .collect()
+    }
+
     #[test]
     fn test_find_matching_patterns() {
         let patterns = vec![
@@ -213,13 +873,14 @@ mod tests {
             "specific.txt".to_string(),
         ];
 
-        let matches = find_matching_patterns(&patterns, "/foo/bar.tmp");
+        let matches = pattern_texts(&patterns, "/foo/bar.tmp");
         assert_eq!(matches, vec!["*.tmp"]);
 
-        let matches = find_matching_patterns(&patterns, "/cache/data");
-        assert!(matches.is_empty()); // "/cache/" pattern won't match "/cache/data" exactly
+        // A directory pattern also matches the directory's contents
+        let matches = pattern_texts(&patterns, "/cache/data");
+        assert_eq!(matches, vec!["/cache/"]);
 
-        let matches = find_matching_patterns(&patterns, "/foo/bar.log");
+        let matches = pattern_texts(&patterns, "/foo/bar.log");
         assert_eq!(matches, vec!["*.log"]);
     }
 
@@ -231,7 +892,7 @@ mod tests {
             "/foo/bar.tmp".to_string(),
         ];
 
-        let matches = find_matching_patterns(&patterns, "/foo/bar.tmp");
+        let matches = pattern_texts(&patterns, "/foo/bar.tmp");
         // Should match two patterns: *.tmp and /foo/bar.tmp
         // (temp* doesn't match because filename is bar.tmp, not temp*)
         assert_eq!(matches.len(), 2);
@@ -316,6 +977,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_pattern_uncompilable() {
+        assert_eq!(
+            validate_pattern("foo\\"),
+            Err("Pattern ends with an escape character".to_string())
+        );
+        assert_eq!(
+            validate_pattern("[z-a]"),
+            Err("Invalid range z-a in pattern".to_string())
+        );
+    }
+
     // ========================================
     // PATTERN VALIDATION - EDGE CASES
     // ========================================
@@ -332,4 +1005,209 @@ mod tests {
         assert!(validate_pattern("file_name.txt").is_ok());
         assert!(validate_pattern("file.name.txt").is_ok());
     }
+
+    // ========================================
+    // IGNORE LINES
+    // ========================================
+
+    #[test]
+    fn test_parse_ignore_line_prefixes() {
+        let line = parse_ignore_line("  *.tmp  ").unwrap();
+        assert_eq!((line.pattern, line.negated), ("*.tmp", false));
+
+        let line = parse_ignore_line("(?i)!Thumbs.db").unwrap();
+        assert_eq!(line.pattern, "Thumbs.db");
+        assert!(line.negated && line.case_insensitive);
+
+        // Each prefix only once; a second `!` is part of the pattern
+        assert_eq!(parse_ignore_line("!!a").unwrap().pattern, "!a");
+        assert_eq!(parse_ignore_line(""), None);
+    }
+
+    #[test]
+    fn test_validate_ignore_line() {
+        assert!(validate_ignore_line("").is_ok());
+        assert!(validate_ignore_line("// [unbalanced in comment").is_ok());
+        assert!(validate_ignore_line("#include extra.stignore").is_ok());
+        assert!(validate_ignore_line("#include").is_err());
+        assert!(validate_ignore_line("!*.{jpg,png").is_err());
+    }
+
+    // ========================================
+    // IGNORE EVALUATION
+    // ========================================
+
+    #[test]
+    fn test_is_ignored_first_match_wins() {
+        let lines = vec!["*.log".to_string(), "!important.log".to_string()];
+        // *.log comes first, so the negation never applies
+        assert!(IgnoreMatcher::new(&lines).is_ignored("important.log"));
+
+        let lines = vec!["!important.log".to_string(), "*.log".to_string()];
+        assert!(!IgnoreMatcher::new(&lines).is_ignored("important.log"));
+        assert!(IgnoreMatcher::new(&lines).is_ignored("debug.log"));
+    }
+
+    #[test]
+    fn test_is_ignored_covers_directory_contents() {
+        let lines = vec!["// build output".to_string(), "/target".to_string()];
+        assert!(IgnoreMatcher::new(&lines).is_ignored("target/debug/app"));
+        assert!(!IgnoreMatcher::new(&lines).is_ignored("src/target.rs"));
+    }
+
+    #[test]
+    fn test_line_effect() {
+        let lines = vec![
+            "!keep.tmp".to_string(),
+            "*.tmp".to_string(),
+            "// comment".to_string(),
+        ];
+        let paths = vec![
+            "a.tmp".to_string(),
+            "keep.tmp".to_string(),
+            "b.txt".to_string(),
+        ];
+
+        assert_eq!(
+            line_effect(&lines, 1, &paths),
+            (vec!["a.tmp".to_string()], vec![])
+        );
+        assert_eq!(
+            line_effect(&lines, 0, &paths),
+            (vec![], vec!["keep.tmp".to_string()])
+        );
+        assert_eq!(line_effect(&lines, 2, &paths), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_remove_ignore_line() {
+        let lines = vec!["*.tmp".to_string(), "a".to_string(), "*.tmp".to_string()];
+        let matched = IgnoreMatcher::new(&lines).matching_lines("x.tmp");
+
+        // Removes only the matched line while the file is unchanged
+        assert_eq!(remove_ignore_line(&lines, &matched[1]), vec!["*.tmp", "a"]);
+
+        // Falls back to removing by text when the line moved
+        let moved = vec!["a".to_string(), "*.tmp".to_string()];
+        assert_eq!(remove_ignore_line(&moved, &matched[0]), vec!["a"]);
+    }
+
+    // ========================================
+    // BATCH IGNORE
+    // ========================================
+
+    #[test]
+    fn test_add_ignore_patterns_prepends_in_order() {
+        let lines = vec!["*.tmp".to_string()];
+        let paths = vec!["docs/a.txt".to_string(), "docs/b".to_string()];
+
+        let (updated, added) = add_ignore_patterns(&lines, &paths);
+
+        assert_eq!(added, 2);
+        assert_eq!(updated, vec!["/docs/a.txt", "/docs/b", "*.tmp"]);
+    }
+
+    #[test]
+    fn test_add_ignore_patterns_skips_existing_and_duplicates() {
+        let lines = vec!["/docs/a.txt".to_string()];
+        let paths = vec![
+            "docs/a.txt".to_string(),
+            "docs/b".to_string(),
+            "docs/b".to_string(),
+        ];
+
+        let (updated, added) = add_ignore_patterns(&lines, &paths);
+
+        assert_eq!(added, 1);
+        assert_eq!(updated, vec!["/docs/b", "/docs/a.txt"]);
+    }
+
+    // ========================================
+    // DIFF
+    // ========================================
+
+    #[test]
+    fn test_diff_lines() {
+        let old = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let new = vec!["a".to_string(), "c".to_string(), "d".to_string()];
+
+        assert_eq!(
+            diff_lines(&old, &new),
+            vec![
+                IgnoreDiffLine::Unchanged("a".to_string()),
+                IgnoreDiffLine::Removed("b".to_string()),
+                IgnoreDiffLine::Unchanged("c".to_string()),
+                IgnoreDiffLine::Added("d".to_string()),
+            ]
+        );
+        assert!(
+            diff_lines(&old, &old)
+                .iter()
+                .all(|line| matches!(line, IgnoreDiffLine::Unchanged(_)))
+        );
+    }
+
+    // ========================================
+    // INCLUDED PATTERNS
+    // ========================================
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_included_patterns_between_own_lines() {
+        // Expanded the way Syncthing does: unrooted lines twice, includes read in
+        let lines = strings(&["/build", "#include .stglobalignore", "!keep.log", "*.tmp"]);
+        let expanded = strings(&[
+            "build",
+            "**/*.log",
+            "*.log",
+            "(?d)**/.DS_Store",
+            "(?d).DS_Store",
+            "!**/keep.log",
+            "!keep.log",
+            "**/*.tmp",
+            "*.tmp",
+        ]);
+
+        let included = included_patterns(&lines, &expanded);
+        assert_eq!(
+            included["#include .stglobalignore"],
+            strings(&["**/*.log", "*.log", "(?d)**/.DS_Store", "(?d).DS_Store"])
+        );
+
+        // The included patterns decide at the include's position
+        let matcher = IgnoreMatcher::with_includes(&lines, &included);
+        let verdict = matcher.match_path("logs/keep.log").unwrap();
+        assert!(verdict.ignored);
+        assert_eq!(verdict.line, 2);
+        assert_eq!(verdict.include.as_deref(), Some("#include .stglobalignore"));
+        assert!(matcher.match_path("a/.DS_Store").unwrap().deletable);
+
+        let verdict = matcher.match_path("build/out.o").unwrap();
+        assert_eq!((verdict.line, verdict.include), (1, None));
+    }
+
+    #[test]
+    fn test_included_patterns_without_expansion() {
+        // Expanded lists that repeat the lines as written work too
+        let lines = strings(&["#include common", "(?i)/Cache"]);
+        let expanded = strings(&["secret.txt", "(?i)/Cache"]);
+
+        let included = included_patterns(&lines, &expanded);
+        assert_eq!(included["#include common"], strings(&["secret.txt"]));
+
+        let matcher = IgnoreMatcher::with_includes(&lines, &included);
+        assert_eq!(matcher.match_path("secret.txt").unwrap().line, 1);
+        assert!(!matcher.is_ignored("sub/secret.txt"));
+        assert_eq!(matcher.match_path("cache/x").unwrap().include, None);
+    }
+
+    #[test]
+    fn test_new_skips_include_lines() {
+        let matcher = IgnoreMatcher::new(&strings(&["#include common", "*.tmp"]));
+        assert!(matcher.match_path("secret.txt").is_none());
+        assert!(matcher.is_ignored("a.tmp"));
+    }
 }
@@ -0,0 +1,983 @@
+//! Keybindings
+//!
+//! Maps key chords to actions per context (folder list, breadcrumbs, file
+//! preview, modals). The defaults reproduce the built-in bindings, including
+//! vim mode; `keymap` in config.yaml rebinds individual actions. The legend is
+//! generated from the same table, so remaps show up there too.
+
+use crate::logic::ui::next_vim_command_state;
+use crate::model::VimCommandState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+/// Where a key is pressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    FolderList,
+    Breadcrumb,
+    /// File info / preview popup
+    Preview,
+    /// List modals (devices, logs, history, ...); text inputs are never remapped
+    Modal,
+}
+
+/// List modals with keys of their own, bound in the `modal` context
+///
+/// Keys only conflict within one modal, so `r` can restore a version and
+/// also refresh the fleet overview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListModal {
+    Devices,
+    Versions,
+    Conflicts,
+    Transfers,
+    Logs,
+    Pending,
+    Overview,
+    RateLimits,
+    SystemMenu,
+}
+
+impl KeyContext {
+    pub const ALL: [KeyContext; 4] = [
+        KeyContext::FolderList,
+        KeyContext::Breadcrumb,
+        KeyContext::Preview,
+        KeyContext::Modal,
+    ];
+
+    /// Name used in config.yaml
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyContext::FolderList => "folder_list",
+            KeyContext::Breadcrumb => "breadcrumb",
+            KeyContext::Preview => "preview",
+            KeyContext::Modal => "modal",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|ctx| ctx.name() == name)
+    }
+}
+
+macro_rules! actions {
+    ($($variant:ident => $name:literal),* $(,)?) => {
+        /// Something a key can be bound to
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Action {
+            $($variant),*
+        }
+
+        impl Action {
+            pub const ALL: &'static [Action] = &[$(Action::$variant),*];
+
+            /// Name used in config.yaml
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Action::$variant => $name),*
+                }
+            }
+        }
+    };
+}
+
+actions! {
+    Quit => "quit",
+    Up => "up",
+    Down => "down",
+    Back => "back",
+    Select => "select",
+    PageUp => "page_up",
+    PageDown => "page_down",
+    HalfPageUp => "half_page_up",
+    HalfPageDown => "half_page_down",
+    First => "first",
+    Last => "last",
+    Close => "close",
+    Search => "search",
+    Filter => "filter",
+    FileInfo => "file_info",
+    CycleSort => "cycle_sort",
+    ReverseSort => "reverse_sort",
+    CycleDisplay => "cycle_display",
+    Rescan => "rescan",
+    Restore => "restore",
+    Override => "override",
+    Delete => "delete",
+    ToggleIgnore => "toggle_ignore",
+    IgnoreDelete => "ignore_delete",
+    ToggleMark => "toggle_mark",
+    MarkRange => "mark_range",
+    MarkMatches => "mark_matches",
+    OpenItem => "open_item",
+    OpenWebUi => "open_web_ui",
+    CopyPath => "copy_path",
+    ChangeType => "change_type",
+    FolderSettings => "folder_settings",
+    NewFolder => "new_folder",
+    ShareFolder => "share_folder",
+    RemoveFolder => "remove_folder",
+    PauseResume => "pause_resume",
+    UpdateHistory => "update_history",
+    Devices => "devices",
+    Pending => "pending",
+    Overview => "overview",
+    Profiles => "profiles",
+    System => "system",
+    Conflicts => "conflicts",
+    EditIgnores => "edit_ignores",
+    FailedItems => "failed_items",
+    Transfers => "transfers",
+    RemoteCompletion => "remote_completion",
+    Logs => "logs",
+    RateLimits => "rate_limits",
+    Versions => "versions",
+    AddDevice => "add_device",
+    EditDevice => "edit_device",
+    PauseDevice => "pause_device",
+    RemoveDevice => "remove_device",
+    RestoreVersion => "restore_version",
+    MarkVersion => "mark_version",
+    KeepOriginal => "keep_original",
+    KeepConflict => "keep_conflict",
+    KeepBoth => "keep_both",
+    Prioritize => "prioritize",
+    ToggleFollow => "toggle_follow",
+    CycleLevel => "cycle_level",
+    ClearErrors => "clear_errors",
+    Accept => "accept",
+    Dismiss => "dismiss",
+    Refresh => "refresh",
+    CustomLimit => "custom_limit",
+    Restart => "restart",
+    Shutdown => "shutdown",
+}
+
+impl Action {
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.name() == name)
+    }
+
+    /// Key the modal handlers understand for a modal action
+    pub fn modal_key(&self) -> Option<KeyCode> {
+        match self {
+            Action::Close => Some(KeyCode::Esc),
+            Action::Up => Some(KeyCode::Up),
+            Action::Down => Some(KeyCode::Down),
+            Action::Select => Some(KeyCode::Enter),
+            _ => None,
+        }
+    }
+
+    /// The list modal an action belongs to, when bound in the `modal` context
+    pub fn list_modal(&self) -> Option<ListModal> {
+        use Action::*;
+        let modal = match self {
+            AddDevice | EditDevice | PauseDevice | RemoveDevice => ListModal::Devices,
+            RestoreVersion | MarkVersion => ListModal::Versions,
+            KeepOriginal | KeepConflict | KeepBoth => ListModal::Conflicts,
+            Prioritize => ListModal::Transfers,
+            ToggleFollow | CycleLevel | Search | ClearErrors => ListModal::Logs,
+            Accept | Dismiss => ListModal::Pending,
+            Refresh => ListModal::Overview,
+            CustomLimit => ListModal::RateLimits,
+            Restart | Shutdown => ListModal::SystemMenu,
+            _ => return None,
+        };
+        Some(modal)
+    }
+
+    /// Whether the action can be bound in the `modal` context
+    pub fn is_modal(&self) -> bool {
+        self.modal_key().is_some() || self.list_modal().is_some()
+    }
+}
+
+/// A key with modifiers
+///
+/// Shift is dropped for characters and BackTab (it is already part of the key).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+/// A chord, optionally pressed twice in a row (vim's `gg`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub chord: KeyChord,
+    pub double: bool,
+}
+
+impl KeyBinding {
+    /// Parse a key as written in config.yaml
+    ///
+    /// # Examples
+    /// ```
+    /// use stui::logic::keymap::KeyBinding;
+    ///
+    /// assert_eq!(KeyBinding::parse("Ctrl-d").unwrap().display(), "^d");
+    /// assert_eq!(KeyBinding::parse("gg").unwrap().display(), "gg");
+    /// assert_eq!(KeyBinding::parse("PageDown").unwrap().display(), "PgDn");
+    /// assert!(KeyBinding::parse("Hyper-x").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text;
+
+        // Modifier prefixes; a lone "-" is the minus key
+        while let Some((prefix, tail)) = rest.split_once('-') {
+            if tail.is_empty() {
+                break;
+            }
+            modifiers |= match prefix.to_lowercase().as_str() {
+                "ctrl" | "c" => KeyModifiers::CONTROL,
+                "alt" | "a" | "m" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier '{}' in '{}'", prefix, text)),
+            };
+            rest = tail;
+        }
+
+        let mut chars = rest.chars();
+        let (code, double) = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), None, _) => (KeyCode::Char(c), false),
+            (Some(a), Some(b), None) if a == b && modifiers.is_empty() => (KeyCode::Char(a), true),
+            _ => (
+                named_key(rest).ok_or_else(|| format!("unknown key '{}'", text))?,
+                false,
+            ),
+        };
+
+        // Shift-Tab arrives as BackTab
+        let (code, modifiers) = match code {
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+            }
+            _ => (code, modifiers),
+        };
+
+        Ok(Self {
+            chord: KeyChord::new(code, modifiers),
+            double,
+        })
+    }
+
+    /// Short form for the legend
+    pub fn display(&self) -> String {
+        let key = match self.chord.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) if self.double => format!("{}{}", c, c),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Backspace => "Bksp".to_string(),
+            KeyCode::BackTab => "S-Tab".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Insert => "Ins".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            code => format!("{:?}", code),
+        };
+
+        let mut prefix = String::new();
+        if self.chord.modifiers.contains(KeyModifiers::CONTROL) {
+            prefix.push('^');
+        }
+        if self.chord.modifiers.contains(KeyModifiers::ALT) {
+            prefix.push_str("M-");
+        }
+        if self.chord.modifiers.contains(KeyModifiers::SHIFT) {
+            prefix.push_str("S-");
+        }
+        format!("{}{}", prefix, key)
+    }
+}
+
+/// Named (non-character) keys, case-insensitive
+fn named_key(name: &str) -> Option<KeyCode> {
+    let code = match name.to_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "minus" => KeyCode::Char('-'),
+        other => {
+            let n = other.strip_prefix('f')?.parse::<u8>().ok()?;
+            if !(1..=12).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(code)
+}
+
+/// Bindings of every context, in definition order (the first key of an
+/// action is the one shown in the legend)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    /// Whether the defaults were the vim ones (the legend shows vim navigation)
+    pub vim_mode: bool,
+    bindings: HashMap<KeyContext, Vec<(KeyBinding, Action)>>,
+}
+
+impl Keymap {
+    /// Built-in bindings
+    pub fn defaults(vim_mode: bool) -> Self {
+        use Action::*;
+
+        let mut keymap = Keymap {
+            vim_mode,
+            bindings: HashMap::new(),
+        };
+
+        // Shared by the folder list and the breadcrumbs
+        let mut common: Vec<(Action, Vec<&str>)> = vec![
+            (Quit, vec!["q"]),
+            (Rescan, vec!["r"]),
+            (Restore, vec!["R"]),
+            (Override, vec!["X"]),
+            (Conflicts, vec!["C"]),
+            (EditIgnores, vec!["E"]),
+            (FailedItems, vec!["F"]),
+            (Transfers, vec!["T"]),
+            (RemoteCompletion, vec!["N"]),
+            (Logs, vec!["L"]),
+            (RateLimits, vec!["B"]),
+            (CycleSort, vec!["s"]),
+            (ReverseSort, vec!["S"]),
+            (CycleDisplay, vec!["t"]),
+        ];
+        if vim_mode {
+            common.extend([
+                (Up, vec!["k", "Up"]),
+                (Down, vec!["j", "Down"]),
+                (Back, vec!["h", "Left", "Backspace"]),
+                (Select, vec!["l", "Enter", "Right"]),
+                (PageUp, vec!["Ctrl-b", "PageUp"]),
+                (PageDown, vec!["Ctrl-f", "PageDown"]),
+                (HalfPageUp, vec!["Ctrl-u"]),
+                (HalfPageDown, vec!["Ctrl-d"]),
+                (First, vec!["gg", "Home"]),
+                (Last, vec!["G", "End"]),
+            ]);
+        } else {
+            common.extend([
+                (Up, vec!["Up"]),
+                (Down, vec!["Down"]),
+                (Back, vec!["Left", "Backspace"]),
+                (Select, vec!["Enter", "Right"]),
+                (PageUp, vec!["PageUp"]),
+                (PageDown, vec!["PageDown"]),
+                (First, vec!["Home"]),
+                (Last, vec!["End"]),
+            ]);
+        }
+
+        let folder_list = [
+            (ChangeType, vec!["c"]),
+            (FolderSettings, vec!["e"]),
+            (NewFolder, vec!["a"]),
+            (ShareFolder, vec!["m"]),
+            (RemoveFolder, vec!["x"]),
+            (PauseResume, vec!["p"]),
+            (UpdateHistory, vec!["u"]),
+            (Devices, vec!["D"]),
+            (Pending, vec!["P"]),
+            (Overview, vec!["O"]),
+            (Profiles, vec!["W"]),
+            (System, vec!["Y"]),
+            (OpenWebUi, vec!["o"]),
+        ];
+        let search = if vim_mode { "/" } else { "Ctrl-f" };
+        let breadcrumb = [
+            (Search, vec![search]),
+            (Filter, vec!["f"]),
+            (FileInfo, vec!["?"]),
+            (Versions, vec!["V"]),
+            (OpenItem, vec!["o"]),
+            (CopyPath, vec!["c"]),
+            (ToggleIgnore, vec!["i"]),
+            (IgnoreDelete, vec!["I"]),
+            (Delete, vec!["d"]),
+            (ToggleMark, vec!["Space"]),
+            (MarkRange, vec!["v"]),
+            (MarkMatches, vec!["*"]),
+        ];
+        let preview = [
+            (Close, vec!["Esc", "?", "Left", "Backspace"]),
+            (Up, vec!["Up", "k"]),
+            (Down, vec!["Down", "j"]),
+            (HalfPageUp, vec!["PageUp", "Ctrl-u"]),
+            (HalfPageDown, vec!["PageDown", "Ctrl-d"]),
+            (PageUp, vec!["Ctrl-b"]),
+            (PageDown, vec!["Ctrl-f"]),
+            (First, vec!["gg"]),
+            (Last, vec!["G"]),
+        ];
+        let (modal_up, modal_down) = if vim_mode {
+            (vec!["Up", "k"], vec!["Down", "j"])
+        } else {
+            (vec!["Up"], vec!["Down"])
+        };
+        let modal = [
+            (Close, vec!["Esc"]),
+            (Up, modal_up),
+            (Down, modal_down),
+            (Select, vec!["Enter"]),
+            (AddDevice, vec!["a"]),
+            (EditDevice, vec!["e"]),
+            (PauseDevice, vec!["p"]),
+            (RemoveDevice, vec!["d"]),
+            (RestoreVersion, vec!["r"]),
+            (MarkVersion, vec!["Space"]),
+            (KeepOriginal, vec!["o"]),
+            (KeepConflict, vec!["c"]),
+            (KeepBoth, vec!["b"]),
+            (Prioritize, vec!["p"]),
+            (ToggleFollow, vec!["f"]),
+            (CycleLevel, vec!["l"]),
+            (Search, vec!["/"]),
+            (ClearErrors, vec!["x"]),
+            (Accept, vec!["a"]),
+            (Dismiss, vec!["x"]),
+            (Refresh, vec!["r"]),
+            (CustomLimit, vec!["c"]),
+            (Restart, vec!["r"]),
+            (Shutdown, vec!["s"]),
+        ];
+
+        let parse = |keys: &[&str]| -> Vec<KeyBinding> {
+            keys.iter()
+                .map(|k| KeyBinding::parse(k).expect("default key binding"))
+                .collect()
+        };
+        for (action, keys) in common.iter().chain(folder_list.iter()) {
+            keymap.bind(KeyContext::FolderList, *action, parse(keys));
+        }
+        for (action, keys) in common.iter().chain(breadcrumb.iter()) {
+            keymap.bind(KeyContext::Breadcrumb, *action, parse(keys));
+        }
+        for (action, keys) in &preview {
+            keymap.bind(KeyContext::Preview, *action, parse(keys));
+        }
+        for (action, keys) in &modal {
+            keymap.bind(KeyContext::Modal, *action, parse(keys));
+        }
+
+        keymap
+    }
+
+    /// Replace the keys of an action in a context
+    ///
+    /// Keys taken from other actions of the same context are unbound there,
+    /// so the new binding always wins. An empty list unbinds the action. In
+    /// the `modal` context, actions of different list modals share keys.
+    pub fn bind(&mut self, context: KeyContext, action: Action, keys: Vec<KeyBinding>) {
+        let overlaps = |other: Action| match (action.list_modal(), other.list_modal()) {
+            (Some(a), Some(b)) if context == KeyContext::Modal => a == b,
+            _ => true,
+        };
+        let entries = self.bindings.entry(context).or_default();
+        entries.retain(|(binding, bound)| {
+            *bound != action && !(keys.contains(binding) && overlaps(*bound))
+        });
+        entries.extend(keys.into_iter().map(|binding| (binding, action)));
+    }
+
+    /// Resolve a key press, tracking double-press bindings in `state`
+    ///
+    /// Returns the action to run (if any) and the next command state. Single
+    /// bindings win over double ones on the same key.
+    pub fn resolve(
+        &self,
+        context: KeyContext,
+        chord: &KeyChord,
+        state: VimCommandState,
+    ) -> (Option<Action>, VimCommandState) {
+        let entries = self
+            .bindings
+            .get(&context)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        let find = |double: bool| {
+            entries
+                .iter()
+                .find(|(binding, _)| binding.chord == *chord && binding.double == double)
+                .map(|(_, action)| *action)
+        };
+
+        if let Some(action) = find(false) {
+            return (Some(action), VimCommandState::None);
+        }
+        match find(true) {
+            Some(action) => {
+                let (next, complete) = next_vim_command_state(state, true);
+                (complete.then_some(action), next)
+            }
+            None => (None, VimCommandState::None),
+        }
+    }
+
+    /// Action of a single-press key (used to translate modal keys)
+    pub fn action(&self, context: KeyContext, chord: &KeyChord) -> Option<Action> {
+        self.resolve(context, chord, VimCommandState::None).0
+    }
+
+    /// Action of a key pressed in a list modal
+    ///
+    /// Only the shared modal actions (close, up, down, select) and the ones
+    /// of `modal` itself are considered.
+    pub fn modal_action(&self, modal: Option<ListModal>, chord: &KeyChord) -> Option<Action> {
+        self.bindings
+            .get(&KeyContext::Modal)?
+            .iter()
+            .find(|(binding, action)| {
+                binding.chord == *chord
+                    && !binding.double
+                    && action.list_modal().is_none_or(|own| Some(own) == modal)
+            })
+            .map(|(_, action)| *action)
+    }
+
+    /// Legend text of the first key bound to an action
+    pub fn key_label(&self, context: KeyContext, action: Action) -> Option<String> {
+        self.bindings
+            .get(&context)?
+            .iter()
+            .find(|(_, bound)| *bound == action)
+            .map(|(binding, _)| binding.display())
+    }
+
+    /// Legend text of every key bound to an action, joined with "/"
+    pub fn key_labels(&self, context: KeyContext, action: Action) -> Option<String> {
+        let labels: Vec<String> = self
+            .bindings
+            .get(&context)?
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(binding, _)| binding.display())
+            .collect();
+        (!labels.is_empty()).then(|| labels.join("/"))
+    }
+
+    /// Legend text of a list modal action, like "Esc" for `close`
+    ///
+    /// The built-in key (Esc, ↑, ↓, Enter) is shown when the action is
+    /// unbound, since it always works. Unbound modal-specific actions have no
+    /// label.
+    pub fn modal_label(&self, action: Action) -> String {
+        self.modal_labels(action).join("/")
+    }
+
+    /// Footer hints like "a:Add  Esc:Close", one per group of actions
+    ///
+    /// Keys of a group are joined with "/"; groups without keys are left out.
+    pub fn modal_hints(&self, hints: &[(&[Action], &str)]) -> String {
+        hints
+            .iter()
+            .filter_map(|(actions, text)| {
+                let keys: Vec<String> = actions
+                    .iter()
+                    .flat_map(|action| self.modal_labels(*action))
+                    .collect();
+                (!keys.is_empty()).then(|| format!("{}:{}", keys.join("/"), text))
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+
+    /// Legend text of the list modal navigation keys, like "↑↓/j/k"
+    ///
+    /// The first up and down keys are paired, the other ones follow.
+    pub fn modal_nav_label(&self) -> String {
+        let up = self.modal_labels(Action::Up);
+        let down = self.modal_labels(Action::Down);
+        let mut label = format!("{}{}", up[0], down[0]);
+        for key in down[1..].iter().chain(&up[1..]) {
+            label.push('/');
+            label.push_str(key);
+        }
+        label
+    }
+
+    fn modal_labels(&self, action: Action) -> Vec<String> {
+        let labels: Vec<String> = self
+            .bindings
+            .get(&KeyContext::Modal)
+            .into_iter()
+            .flatten()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(binding, _)| binding.display())
+            .collect();
+        if !labels.is_empty() {
+            return labels;
+        }
+
+        action
+            .modal_key()
+            .map(|code| {
+                KeyBinding {
+                    chord: KeyChord::new(code, KeyModifiers::NONE),
+                    double: false,
+                }
+                .display()
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        KeyBinding::parse(text).unwrap().chord
+    }
+
+    fn press(keymap: &Keymap, context: KeyContext, keys: &[&str]) -> Option<Action> {
+        let mut state = VimCommandState::None;
+        let mut action = None;
+        for key in keys {
+            (action, state) = keymap.resolve(context, &chord(key), state);
+        }
+        action
+    }
+
+    // ========================================
+    // PARSING
+    // ========================================
+
+    #[test]
+    fn test_parse_characters_and_modifiers() {
+        assert_eq!(
+            chord("q"),
+            KeyChord::new(KeyCode::Char('q'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("Ctrl-f"),
+            KeyChord::new(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("C-M-x"),
+            KeyChord::new(
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )
+        );
+        assert_eq!(
+            chord("-"),
+            KeyChord::new(KeyCode::Char('-'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("Ctrl--"),
+            KeyChord::new(KeyCode::Char('-'), KeyModifiers::CONTROL)
+        );
+    }
+
+    #[test]
+    fn test_parse_named_keys() {
+        assert_eq!(chord("enter").code, KeyCode::Enter);
+        assert_eq!(chord("Space").code, KeyCode::Char(' '));
+        assert_eq!(chord("PageDown").code, KeyCode::PageDown);
+        assert_eq!(chord("F5").code, KeyCode::F(5));
+        assert_eq!(
+            chord("Shift-Tab"),
+            KeyChord::new(KeyCode::BackTab, KeyModifiers::NONE)
+        );
+        assert!(KeyBinding::parse("F13").is_err());
+        assert!(KeyBinding::parse("Enterr").is_err());
+        assert!(KeyBinding::parse("").is_err());
+    }
+
+    #[test]
+    fn test_parse_double_press() {
+        let binding = KeyBinding::parse("gg").unwrap();
+        assert!(binding.double);
+        assert_eq!(binding.chord.code, KeyCode::Char('g'));
+        assert!(KeyBinding::parse("gh").is_err(), "only repeated keys");
+        assert!(KeyBinding::parse("Ctrl-gg").is_err());
+    }
+
+    #[test]
+    fn test_shift_is_ignored_for_characters() {
+        let event = KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT);
+        assert_eq!(KeyChord::from_event(&event), chord("R"));
+    }
+
+    // ========================================
+    // DEFAULTS
+    // ========================================
+
+    #[test]
+    fn test_defaults_per_context() {
+        let keymap = Keymap::defaults(false);
+        use KeyContext::*;
+        assert_eq!(press(&keymap, FolderList, &["c"]), Some(Action::ChangeType));
+        assert_eq!(press(&keymap, Breadcrumb, &["c"]), Some(Action::CopyPath));
+        assert_eq!(press(&keymap, FolderList, &["o"]), Some(Action::OpenWebUi));
+        assert_eq!(press(&keymap, Breadcrumb, &["o"]), Some(Action::OpenItem));
+        assert_eq!(
+            press(&keymap, FolderList, &["x"]),
+            Some(Action::RemoveFolder)
+        );
+        assert_eq!(press(&keymap, Breadcrumb, &["x"]), None);
+        assert_eq!(press(&keymap, FolderList, &["Y"]), Some(Action::System));
+        assert_eq!(press(&keymap, Breadcrumb, &["Y"]), None);
+        assert_eq!(
+            press(&keymap, Breadcrumb, &["Ctrl-f"]),
+            Some(Action::Search)
+        );
+        assert_eq!(press(&keymap, FolderList, &["d"]), None);
+        assert_eq!(press(&keymap, Breadcrumb, &["j"]), None, "no vim keys");
+    }
+
+    #[test]
+    fn test_vim_defaults() {
+        let keymap = Keymap::defaults(true);
+        use KeyContext::*;
+        assert_eq!(press(&keymap, Breadcrumb, &["j"]), Some(Action::Down));
+        assert_eq!(press(&keymap, Breadcrumb, &["Down"]), Some(Action::Down));
+        assert_eq!(press(&keymap, Breadcrumb, &["/"]), Some(Action::Search));
+        assert_eq!(
+            press(&keymap, Breadcrumb, &["Ctrl-f"]),
+            Some(Action::PageDown)
+        );
+        assert_eq!(press(&keymap, FolderList, &["g"]), None);
+        assert_eq!(press(&keymap, FolderList, &["g", "g"]), Some(Action::First));
+        assert_eq!(press(&keymap, FolderList, &["g", "j", "g"]), None);
+        assert_eq!(press(&keymap, FolderList, &["G"]), Some(Action::Last));
+    }
+
+    #[test]
+    fn test_preview_defaults_ignore_vim_mode() {
+        let keymap = Keymap::defaults(false);
+        assert_eq!(
+            press(&keymap, KeyContext::Preview, &["g", "g"]),
+            Some(Action::First)
+        );
+        assert_eq!(
+            press(&keymap, KeyContext::Preview, &["?"]),
+            Some(Action::Close)
+        );
+    }
+
+    #[test]
+    fn test_modal_vim_keys_only_in_vim_mode() {
+        let keymap = Keymap::defaults(false);
+        assert_eq!(press(&keymap, KeyContext::Modal, &["k"]), None);
+        assert_eq!(press(&keymap, KeyContext::Modal, &["j"]), None);
+        assert_eq!(press(&keymap, KeyContext::Modal, &["Up"]), Some(Action::Up));
+
+        let keymap = Keymap::defaults(true);
+        assert_eq!(press(&keymap, KeyContext::Modal, &["k"]), Some(Action::Up));
+        assert_eq!(
+            press(&keymap, KeyContext::Modal, &["j"]),
+            Some(Action::Down)
+        );
+    }
+
+    #[test]
+    fn test_modal_action_is_scoped_to_the_modal() {
+        let keymap = Keymap::defaults(false);
+        let r = chord("r");
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::Versions), &r),
+            Some(Action::RestoreVersion)
+        );
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::Overview), &r),
+            Some(Action::Refresh)
+        );
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::SystemMenu), &r),
+            Some(Action::Restart)
+        );
+        assert_eq!(keymap.modal_action(Some(ListModal::Devices), &r), None);
+        assert_eq!(keymap.modal_action(None, &r), None);
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::Devices), &chord("Esc")),
+            Some(Action::Close)
+        );
+    }
+
+    #[test]
+    fn test_bind_modal_action_keeps_other_modals() {
+        let mut keymap = Keymap::defaults(false);
+        keymap.bind(
+            KeyContext::Modal,
+            Action::AddDevice,
+            vec![KeyBinding::parse("d").unwrap()],
+        );
+
+        let devices = Some(ListModal::Devices);
+        assert_eq!(
+            keymap.modal_action(devices, &chord("d")),
+            Some(Action::AddDevice)
+        );
+        assert_eq!(keymap.modal_action(devices, &chord("a")), None);
+        assert_eq!(keymap.modal_label(Action::RemoveDevice), "");
+        // The pending inbox still accepts with `a`
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::Pending), &chord("a")),
+            Some(Action::Accept)
+        );
+
+        // Navigation keys displace modal actions everywhere
+        keymap.bind(
+            KeyContext::Modal,
+            Action::Down,
+            vec![KeyBinding::parse("x").unwrap()],
+        );
+        assert_eq!(
+            keymap.modal_action(Some(ListModal::Pending), &chord("x")),
+            Some(Action::Down)
+        );
+        assert_eq!(keymap.modal_label(Action::ClearErrors), "");
+    }
+
+    #[test]
+    fn test_modal_hints_skip_unbound_actions() {
+        let mut keymap = Keymap::defaults(false);
+        let hints: &[(&[Action], &str)] = &[
+            (&[Action::RestoreVersion, Action::Select], "Restore"),
+            (&[Action::Close], "Close"),
+        ];
+        assert_eq!(keymap.modal_hints(hints), "r/Enter:Restore  Esc:Close");
+
+        keymap.bind(KeyContext::Modal, Action::RestoreVersion, vec![]);
+        keymap.bind(KeyContext::Modal, Action::Select, vec![]);
+        assert_eq!(keymap.modal_hints(hints), "Enter:Restore  Esc:Close");
+
+        assert_eq!(
+            keymap.modal_hints(&[(&[Action::Prioritize], "Prioritize")]),
+            "p:Prioritize"
+        );
+        keymap.bind(KeyContext::Modal, Action::Prioritize, vec![]);
+        assert_eq!(
+            keymap.modal_hints(&[(&[Action::Prioritize], "Prioritize")]),
+            ""
+        );
+    }
+
+    // ========================================
+    // REBINDING
+    // ========================================
+
+    #[test]
+    fn test_bind_replaces_keys_and_steals_conflicts() {
+        let mut keymap = Keymap::defaults(false);
+        keymap.bind(
+            KeyContext::Breadcrumb,
+            Action::Delete,
+            vec![
+                KeyBinding::parse("x").unwrap(),
+                KeyBinding::parse("i").unwrap(),
+            ],
+        );
+
+        use KeyContext::*;
+        assert_eq!(press(&keymap, Breadcrumb, &["d"]), None);
+        assert_eq!(press(&keymap, Breadcrumb, &["x"]), Some(Action::Delete));
+        assert_eq!(press(&keymap, Breadcrumb, &["i"]), Some(Action::Delete));
+        assert_eq!(keymap.key_label(Breadcrumb, Action::ToggleIgnore), None);
+        assert_eq!(
+            keymap.key_labels(Breadcrumb, Action::Delete).unwrap(),
+            "x/i"
+        );
+        // Other contexts are untouched
+        assert_eq!(press(&keymap, FolderList, &["r"]), Some(Action::Rescan));
+    }
+
+    #[test]
+    fn test_bind_empty_unbinds() {
+        let mut keymap = Keymap::defaults(false);
+        keymap.bind(KeyContext::FolderList, Action::Quit, vec![]);
+        assert_eq!(press(&keymap, KeyContext::FolderList, &["q"]), None);
+        assert_eq!(keymap.key_label(KeyContext::FolderList, Action::Quit), None);
+    }
+
+    #[test]
+    fn test_key_label_uses_first_key() {
+        let keymap = Keymap::defaults(true);
+        assert_eq!(
+            keymap
+                .key_label(KeyContext::Breadcrumb, Action::Down)
+                .unwrap(),
+            "j"
+        );
+        assert_eq!(
+            keymap
+                .key_label(KeyContext::Breadcrumb, Action::First)
+                .unwrap(),
+            "gg"
+        );
+        assert_eq!(
+            keymap
+                .key_label(KeyContext::Breadcrumb, Action::HalfPageDown)
+                .unwrap(),
+            "^d"
+        );
+    }
+
+    #[test]
+    fn test_modal_labels_fall_back_to_builtin_keys() {
+        let mut keymap = Keymap::defaults(true);
+        assert_eq!(keymap.modal_nav_label(), "↑↓/j/k");
+        assert_eq!(keymap.modal_label(Action::Select), "Enter");
+
+        keymap.bind(
+            KeyContext::Modal,
+            Action::Close,
+            vec![KeyBinding::parse("q").unwrap()],
+        );
+        keymap.bind(KeyContext::Modal, Action::Down, vec![]);
+        assert_eq!(keymap.modal_label(Action::Close), "q");
+        assert_eq!(keymap.modal_nav_label(), "↑↓/k");
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for action in Action::ALL {
+            assert_eq!(Action::from_name(action.name()), Some(*action));
+        }
+        for context in KeyContext::ALL {
+            assert_eq!(KeyContext::from_name(context.name()), Some(context));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Syncthing log logic
+//!
+//! Pure functions for classifying, merging and filtering the entries of
+//! `/rest/system/log` and `/rest/system/error`.
+
+use crate::api::LogEntry;
+use crate::model::types::{LogLevel, LogLine};
+
+/// Maximum lines kept in the log viewer (oldest are dropped first)
+pub const MAX_LOG_LINES: usize = 2000;
+
+/// Determine the level of a log entry
+///
+/// Newer Syncthing versions report a numeric level (slog: -4 debug, 0 info,
+/// 4 warning, 8 error). Older ones don't, so the message prefix is checked
+/// and entries from `/rest/system/error` default to errors.
+///
+/// # Examples
+/// ```
+/// use stui::api::LogEntry;
+/// use stui::logic::logs::entry_level;
+/// use stui::model::types::LogLevel;
+///
+/// let entry = |level| LogEntry {
+///     when: "2025-01-15T10:30:05+01:00".to_string(),
+///     message: "Listening on 0.0.0.0:22000".to_string(),
+///     level,
+/// };
+/// assert_eq!(entry_level(&entry(Some(4)), false), LogLevel::Warning);
+/// assert_eq!(entry_level(&entry(None), false), LogLevel::Info);
+/// assert_eq!(entry_level(&entry(None), true), LogLevel::Error);
+/// ```
+pub fn entry_level(entry: &LogEntry, from_errors: bool) -> LogLevel {
+    if let Some(level) = entry.level {
+        return match level {
+            i32::MIN..=-1 => LogLevel::Debug,
+            0..=3 => LogLevel::Info,
+            4..=7 => LogLevel::Warning,
+            _ => LogLevel::Error,
+        };
+    }
+
+    let message = entry.message.trim_start();
+    if message.starts_with("DEBUG") {
+        LogLevel::Debug
+    } else if message.starts_with("WARNING") {
+        LogLevel::Warning
+    } else if message.starts_with("ERROR") || from_errors {
+        LogLevel::Error
+    } else {
+        LogLevel::Info
+    }
+}
+
+/// Convert API entries into log viewer lines
+pub fn to_log_lines(entries: &[LogEntry], from_errors: bool) -> Vec<LogLine> {
+    entries
+        .iter()
+        .map(|entry| LogLine {
+            when: entry.when.clone(),
+            level: entry_level(entry, from_errors),
+            message: entry.message.clone(),
+        })
+        .collect()
+}
+
+/// Merge new lines into the viewer's lines
+///
+/// Keeps lines ordered by time and drops duplicates (errors also show up in
+/// the regular log), keeping the more severe level. The result is capped at
+/// `MAX_LOG_LINES`, dropping the oldest lines.
+pub fn merge_log_lines(lines: &mut Vec<LogLine>, new_lines: Vec<LogLine>) {
+    for line in new_lines {
+        match lines
+            .iter_mut()
+            .find(|existing| existing.when == line.when && existing.message == line.message)
+        {
+            Some(existing) => existing.level = existing.level.max(line.level),
+            None => lines.push(line),
+        }
+    }
+
+    // Stable sort keeps arrival order for entries logged at the same time
+    lines.sort_by(|a, b| a.when.cmp(&b.when));
+
+    if lines.len() > MAX_LOG_LINES {
+        lines.drain(..lines.len() - MAX_LOG_LINES);
+    }
+}
+
+/// Lines passing the level filter and search query (case-insensitive)
+pub fn filter_log_lines<'a>(
+    lines: &'a [LogLine],
+    min_level: LogLevel,
+    query: &str,
+) -> Vec<&'a LogLine> {
+    let query = query.to_lowercase();
+    lines
+        .iter()
+        .filter(|line| line.level >= min_level)
+        .filter(|line| query.is_empty() || line.message.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// Get the next level filter (Debug → Info → Warning → Error → Debug)
+pub fn next_min_level(current: LogLevel) -> LogLevel {
+    match current {
+        LogLevel::Debug => LogLevel::Info,
+        LogLevel::Info => LogLevel::Warning,
+        LogLevel::Warning => LogLevel::Error,
+        LogLevel::Error => LogLevel::Debug,
+    }
+}
+
+/// Count errors newer than the last one the user has seen
+pub fn count_new_errors(errors: &[LogEntry], seen_until: Option<&str>) -> usize {
+    match seen_until {
+        Some(seen) => errors.iter().filter(|e| e.when.as_str() > seen).count(),
+        None => errors.len(),
+    }
+}
+
+/// Timestamp of the newest entry
+pub fn newest_when(entries: &[LogEntry]) -> Option<String> {
+    entries.iter().map(|e| e.when.clone()).max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(when: &str, message: &str) -> LogEntry {
+        LogEntry {
+            when: when.to_string(),
+            message: message.to_string(),
+            level: None,
+        }
+    }
+
+    fn line(when: &str, level: LogLevel, message: &str) -> LogLine {
+        LogLine {
+            when: when.to_string(),
+            level,
+            message: message.to_string(),
+        }
+    }
+
+    // ========================================
+    // LEVELS
+    // ========================================
+
+    #[test]
+    fn test_entry_level_numeric() {
+        let mut e = entry("t", "msg");
+        for (level, expected) in [
+            (-4, LogLevel::Debug),
+            (0, LogLevel::Info),
+            (4, LogLevel::Warning),
+            (8, LogLevel::Error),
+        ] {
+            e.level = Some(level);
+            assert_eq!(entry_level(&e, false), expected);
+        }
+    }
+
+    #[test]
+    fn test_entry_level_from_prefix() {
+        assert_eq!(
+            entry_level(&entry("t", "DEBUG: scanning"), false),
+            LogLevel::Debug
+        );
+        assert_eq!(
+            entry_level(&entry("t", "WARNING: Folder path missing"), true),
+            LogLevel::Warning
+        );
+    }
+
+    #[test]
+    fn test_next_min_level_cycles() {
+        let mut level = LogLevel::Debug;
+        for _ in 0..4 {
+            level = next_min_level(level);
+        }
+        assert_eq!(level, LogLevel::Debug);
+    }
+
+    // ========================================
+    // MERGING AND FILTERING
+    // ========================================
+
+    #[test]
+    fn test_merge_log_lines_dedupes_and_sorts() {
+        let mut lines = vec![
+            line("2025-01-15T10:00:02Z", LogLevel::Info, "b"),
+            line("2025-01-15T10:00:03Z", LogLevel::Info, "c"),
+        ];
+        merge_log_lines(
+            &mut lines,
+            vec![
+                line("2025-01-15T10:00:01Z", LogLevel::Info, "a"),
+                line("2025-01-15T10:00:03Z", LogLevel::Error, "c"),
+            ],
+        );
+
+        let messages: Vec<&str> = lines.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(messages, vec!["a", "b", "c"]);
+        assert_eq!(lines[2].level, LogLevel::Error);
+    }
+
+    #[test]
+    fn test_merge_log_lines_caps_length() {
+        let mut lines = Vec::new();
+        let new_lines = (0..MAX_LOG_LINES + 5)
+            .map(|i| line(&format!("2025-01-15T10:{:06}Z", i), LogLevel::Info, "x"))
+            .collect();
+        merge_log_lines(&mut lines, new_lines);
+
+        assert_eq!(lines.len(), MAX_LOG_LINES);
+        assert_eq!(lines[0].when, "2025-01-15T10:000005Z");
+    }
+
+    #[test]
+    fn test_filter_log_lines() {
+        let lines = vec![
+            line("1", LogLevel::Debug, "Scanning folder"),
+            line("2", LogLevel::Info, "Connected to laptop"),
+            line("3", LogLevel::Warning, "Folder PATH missing"),
+        ];
+
+        assert_eq!(filter_log_lines(&lines, LogLevel::Info, "").len(), 2);
+
+        let found = filter_log_lines(&lines, LogLevel::Debug, "folder");
+        let whens: Vec<&str> = found.iter().map(|l| l.when.as_str()).collect();
+        assert_eq!(whens, vec!["1", "3"]);
+    }
+
+    // ========================================
+    // ERROR BADGE
+    // ========================================
+
+    #[test]
+    fn test_count_new_errors() {
+        let errors = vec![
+            entry("2025-01-15T10:00:01Z", "a"),
+            entry("2025-01-15T10:00:05Z", "b"),
+        ];
+        assert_eq!(count_new_errors(&errors, None), 2);
+        assert_eq!(count_new_errors(&errors, Some("2025-01-15T10:00:01Z")), 1);
+        assert_eq!(
+            count_new_errors(&errors, newest_when(&errors).as_deref()),
+            0
+        );
+    }
+}
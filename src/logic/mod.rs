@@ -1,36 +1,64 @@
 //! Business Logic
 //!
 //! This module contains pure business logic functions that can be unit tested:
+//! - completion: Per-device folder completion and remote-need paging
+//! - conflicts: Sync-conflict file detection and resolution naming
+//! - devices: Remote device validation and formatting
 //! - errors: Error classification and formatting
 //! - file: File type detection and utilities
 //! - file_navigation: File navigation logic for jumping to files
+//! - fleet: Per-instance summaries for the fleet overview
 //! - folder: Folder validation and business logic
+//! - folder_errors: Failed item (pull error) paging and descriptions
+//! - folder_settings: Folder settings form pre-fill, validation and diff
+//! - folder_setup: New folder IDs and validation, sharing checklists and removal
 //! - formatting: Data formatting for human-readable display
+//! - health: Folder health and exit codes for headless commands
 //! - ignore: Pattern matching for .stignore rules
+//! - keymap: Key bindings per context and their config syntax
 //! - layout: UI layout calculations and constraints
+//! - logs: Syncthing log classification, merging and filtering
 //! - navigation: Navigation selection calculations
 //! - path: Path mapping and translation utilities
+//! - pending: Pending device/folder invitation helpers
 //! - performance: Batching and performance optimizations
 //! - platform: Cross-platform path helpers
+//! - rate_limits: Bandwidth limit presets, popup rows and the system bar indicator
 //! - search: Search query matching and filtering
 //! - sorting: Comparison functions for sorting browse items
 //! - sync_states: Sync state priority and transitions
+//! - transfers: Transfer queue order, download rates and progress bars
 //! - ui: UI state transitions and cycling
+//! - versions: Archived file version paths
 
+pub mod completion;
+pub mod conflicts;
+pub mod devices;
 pub mod errors;
 pub mod file;
 pub mod file_navigation;
+pub mod fleet;
 pub mod folder;
 pub mod folder_card;
+pub mod folder_errors;
 pub mod folder_history;
+pub mod folder_settings;
+pub mod folder_setup;
 pub mod formatting;
+pub mod health;
 pub mod ignore;
+pub mod keymap;
 pub mod layout;
+pub mod logs;
 pub mod navigation;
 pub mod path;
+pub mod pending;
 pub mod performance;
 pub mod platform;
+pub mod rate_limits;
 pub mod search;
 pub mod sorting;
 pub mod sync_states;
+pub mod transfers;
 pub mod ui;
+pub mod versions;
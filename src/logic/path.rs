@@ -46,6 +46,49 @@ pub fn translate_path(
     container_path
 }
 
+/// Translate a host path back to a container path (inverse of `translate_path`)
+///
+/// Used when the user types a local path that Syncthing (running in Docker)
+/// needs to see. The longest matching host prefix wins, and prefixes only
+/// match on whole path components (`/mnt/data` does not match `/mnt/database`).
+///
+/// # Returns
+/// The container path, or the input unchanged if no mapping matches
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use stui::logic::path::reverse_translate_path;
+///
+/// let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+/// assert_eq!(
+///     reverse_translate_path("/mnt/storage/photos", &path_map),
+///     "/data/photos"
+/// );
+/// assert_eq!(reverse_translate_path("/home/me/photos", &path_map), "/home/me/photos");
+/// ```
+pub fn reverse_translate_path(host_path: &str, path_map: &HashMap<String, String>) -> String {
+    let best_match = path_map
+        .iter()
+        .filter_map(|(container_prefix, host_prefix)| {
+            let normalized_host = host_prefix.trim_end_matches('/');
+            let remainder = host_path.strip_prefix(normalized_host)?;
+            if remainder.is_empty() || remainder.starts_with('/') {
+                Some((normalized_host.len(), container_prefix, remainder))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(len, _, _)| *len);
+
+    match best_match {
+        Some((_, container_prefix, remainder)) => {
+            format!("{}{}", container_prefix.trim_end_matches('/'), remainder)
+        }
+        None => host_path.to_string(),
+    }
+}
+
 /// Check if a path or any of its parent directories are in the given set
 ///
 /// This is used to validate whether a file operation is blocked by a pending operation
@@ -147,6 +190,51 @@ mod tests {
         assert_eq!(result, "/mnt/storage/media/");
     }
 
+    #[test]
+    fn test_reverse_translate_path_with_mapping() {
+        let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+
+        let result = reverse_translate_path("/mnt/storage/media/photos", &path_map);
+        assert_eq!(result, "/data/media/photos");
+
+        // Exact prefix maps to the container root
+        assert_eq!(reverse_translate_path("/mnt/storage", &path_map), "/data");
+    }
+
+    #[test]
+    fn test_reverse_translate_path_no_partial_component_match() {
+        let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+
+        let result = reverse_translate_path("/mnt/storage2/photos", &path_map);
+        assert_eq!(result, "/mnt/storage2/photos");
+    }
+
+    #[test]
+    fn test_reverse_translate_path_longest_prefix_wins() {
+        let path_map = HashMap::from([
+            ("/data".to_string(), "/mnt".to_string()),
+            ("/media".to_string(), "/mnt/media/".to_string()),
+        ]);
+
+        assert_eq!(
+            reverse_translate_path("/mnt/media/movies", &path_map),
+            "/media/movies"
+        );
+        assert_eq!(
+            reverse_translate_path("/mnt/other", &path_map),
+            "/data/other"
+        );
+    }
+
+    #[test]
+    fn test_reverse_translate_path_round_trip() {
+        let path_map = HashMap::from([("/data".to_string(), "/mnt/storage".to_string())]);
+
+        let host = translate_path("/data/media", "", &path_map);
+        let container = reverse_translate_path(host.trim_end_matches('/'), &path_map);
+        assert_eq!(container, "/data/media");
+    }
+
     // Tests for is_path_or_parent_in_set (TDD - written before implementation)
 
     #[test]
@@ -235,7 +323,7 @@ mod tests {
         // Should match either /foo or /foo/bar (both are parents)
         let matched = result.unwrap();
         assert!(
-            matched == PathBuf::from("/foo") || matched == PathBuf::from("/foo/bar"),
+            matched == std::path::Path::new("/foo") || matched == std::path::Path::new("/foo/bar"),
             "Should match one of the parent paths"
         );
     }
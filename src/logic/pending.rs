@@ -0,0 +1,231 @@
+//! Pending invitation logic
+//!
+//! Pure functions for turning Syncthing's pending device/folder maps into an
+//! inbox list and for pre-filling the accept form.
+
+use crate::api::{Device, PendingDevice, PendingFolder};
+use crate::logic::devices::short_device_id;
+use crate::model::types::PendingInvitation;
+use std::collections::HashMap;
+
+/// Folder types offered when accepting a folder, in cycling order
+pub const FOLDER_TYPES: [&str; 3] = ["sendreceive", "sendonly", "receiveonly"];
+
+/// Get the next folder type (sendreceive → sendonly → receiveonly → sendreceive)
+pub fn next_folder_type(current: &str) -> &'static str {
+    let idx = FOLDER_TYPES.iter().position(|t| *t == current).unwrap_or(0);
+    FOLDER_TYPES[(idx + 1) % FOLDER_TYPES.len()]
+}
+
+/// Get the previous folder type (wraps around)
+pub fn prev_folder_type(current: &str) -> &'static str {
+    let idx = FOLDER_TYPES.iter().position(|t| *t == current).unwrap_or(0);
+    FOLDER_TYPES[(idx + FOLDER_TYPES.len() - 1) % FOLDER_TYPES.len()]
+}
+
+/// Build the inbox list from the pending device and folder maps
+///
+/// Folder offers are expanded to one entry per offering device, with the
+/// device name looked up in the configured devices. Newest offers come first.
+pub fn build_invitations(
+    pending_devices: &HashMap<String, PendingDevice>,
+    pending_folders: &HashMap<String, PendingFolder>,
+    known_devices: &[Device],
+) -> Vec<PendingInvitation> {
+    let device_name = |device_id: &str| {
+        known_devices
+            .iter()
+            .find(|d| d.id == device_id && !d.name.is_empty())
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| short_device_id(device_id).to_string())
+    };
+
+    let devices = pending_devices
+        .iter()
+        .map(|(device_id, pending)| PendingInvitation::Device {
+            device_id: device_id.clone(),
+            name: pending.name.clone(),
+            address: pending.address.clone(),
+            time: pending.time.clone(),
+        });
+
+    let folders = pending_folders.iter().flat_map(|(folder_id, pending)| {
+        pending
+            .offered_by
+            .iter()
+            .map(|(device_id, offer)| PendingInvitation::Folder {
+                folder_id: folder_id.clone(),
+                label: offer.label.clone(),
+                device_id: device_id.clone(),
+                device_name: device_name(device_id),
+                time: offer.time.clone(),
+            })
+    });
+
+    let mut invitations: Vec<PendingInvitation> = devices.chain(folders).collect();
+    invitations.sort_by(|a, b| {
+        b.time()
+            .cmp(a.time())
+            .then_with(|| sort_key(a).cmp(&sort_key(b)))
+    });
+    invitations
+}
+
+/// Stable tie-breaker for invitations offered at the same time
+fn sort_key(invitation: &PendingInvitation) -> (&str, &str) {
+    match invitation {
+        PendingInvitation::Device { device_id, .. } => ("", device_id),
+        PendingInvitation::Folder {
+            folder_id,
+            device_id,
+            ..
+        } => (folder_id, device_id),
+    }
+}
+
+/// Suggest a local path for an offered folder
+///
+/// Places the folder next to the first existing folder (host paths), named
+/// after its label (or ID when unlabeled). Falls back to Syncthing's own
+/// default of `~/<label>` when no folders exist yet.
+///
+/// # Example
+/// ```
+/// use stui::logic::pending::suggest_folder_path;
+///
+/// let existing = vec!["/mnt/storage/music".to_string()];
+/// assert_eq!(suggest_folder_path(&existing, "Photos", "abcd-1234"), "/mnt/storage/Photos");
+/// assert_eq!(suggest_folder_path(&[], "", "abcd-1234"), "~/abcd-1234");
+/// ```
+pub fn suggest_folder_path(existing_host_paths: &[String], label: &str, folder_id: &str) -> String {
+    let name: String = if label.trim().is_empty() {
+        folder_id
+    } else {
+        label.trim()
+    }
+    .chars()
+    .map(|c| if c == '/' || c == '\\' { '_' } else { c })
+    .collect();
+
+    let parent = existing_host_paths.first().and_then(|path| {
+        let trimmed = path.trim_end_matches('/');
+        trimmed
+            .rfind('/')
+            .map(|idx| &trimmed[..idx])
+            .filter(|p| !p.is_empty())
+    });
+
+    match parent {
+        Some(parent) => format!("{}/{}", parent, name),
+        None => format!("~/{}", name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::PendingFolderOffer;
+
+    // ========================================
+    // FOLDER TYPES
+    // ========================================
+
+    #[test]
+    fn test_folder_type_cycles() {
+        assert_eq!(next_folder_type("sendreceive"), "sendonly");
+        assert_eq!(next_folder_type("receiveonly"), "sendreceive");
+        assert_eq!(prev_folder_type("sendreceive"), "receiveonly");
+        assert_eq!(prev_folder_type("sendonly"), "sendreceive");
+    }
+
+    // ========================================
+    // BUILD INVITATIONS
+    // ========================================
+
+    #[test]
+    fn test_build_invitations_expands_and_sorts() {
+        let pending_devices = HashMap::from([(
+            "PHONE01-XXXXXXX".to_string(),
+            PendingDevice {
+                time: "2025-01-15T10:00:00Z".to_string(),
+                name: "phone".to_string(),
+                address: "192.168.1.20:22000".to_string(),
+            },
+        )]);
+
+        let offer = |time: &str| PendingFolderOffer {
+            time: time.to_string(),
+            label: "Photos".to_string(),
+        };
+        let pending_folders = HashMap::from([(
+            "photos".to_string(),
+            PendingFolder {
+                offered_by: HashMap::from([
+                    ("LAPTOP1-XXXXXXX".to_string(), offer("2025-01-15T11:00:00Z")),
+                    ("UNKNOWN-XXXXXXX".to_string(), offer("2025-01-15T09:00:00Z")),
+                ]),
+            },
+        )]);
+
+        let known = vec![Device {
+            id: "LAPTOP1-XXXXXXX".to_string(),
+            name: "laptop".to_string(),
+        }];
+
+        let invitations = build_invitations(&pending_devices, &pending_folders, &known);
+        assert_eq!(invitations.len(), 3);
+
+        // Newest first
+        let times: Vec<&str> = invitations.iter().map(|i| i.time()).collect();
+        assert_eq!(
+            times,
+            vec![
+                "2025-01-15T11:00:00Z",
+                "2025-01-15T10:00:00Z",
+                "2025-01-15T09:00:00Z"
+            ]
+        );
+
+        // Known device name is used, unknown falls back to short ID
+        match &invitations[0] {
+            PendingInvitation::Folder { device_name, .. } => assert_eq!(device_name, "laptop"),
+            other => panic!("Expected folder invitation, got {:?}", other),
+        }
+        match &invitations[2] {
+            PendingInvitation::Folder { device_name, .. } => assert_eq!(device_name, "UNKNOWN"),
+            other => panic!("Expected folder invitation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_build_invitations_empty() {
+        assert!(build_invitations(&HashMap::new(), &HashMap::new(), &[]).is_empty());
+    }
+
+    // ========================================
+    // SUGGESTED PATH
+    // ========================================
+
+    #[test]
+    fn test_suggest_folder_path_uses_sibling_directory() {
+        let existing = vec!["/mnt/storage/music/".to_string()];
+        assert_eq!(
+            suggest_folder_path(&existing, "Photos", "id"),
+            "/mnt/storage/Photos"
+        );
+    }
+
+    #[test]
+    fn test_suggest_folder_path_sanitizes_label() {
+        let existing = vec!["/data/music".to_string()];
+        assert_eq!(suggest_folder_path(&existing, "a/b", "id"), "/data/a_b");
+    }
+
+    #[test]
+    fn test_suggest_folder_path_falls_back_to_home() {
+        assert_eq!(suggest_folder_path(&[], "Photos", "id"), "~/Photos");
+        // Folder directly under root has no usable parent
+        let existing = vec!["/music".to_string()];
+        assert_eq!(suggest_folder_path(&existing, "", "abc"), "~/abc");
+    }
+}
@@ -0,0 +1,253 @@
+//! Rate limit logic
+//!
+//! Pure functions for the bandwidth limit popup: presets, building the rows
+//! from the global options and device configs, applying a limit to one or
+//! both directions, and the system bar indicator.
+
+use crate::api::{DeviceConfig, RateLimits};
+use crate::logic::devices::short_device_id;
+use crate::logic::formatting::{Symbols, format_transfer_rate};
+use crate::model::types::{RateLimitDirection, RateLimitRow};
+
+/// Preset limits offered in the popup (label, KiB/s), selected with keys 0-2
+pub const RATE_PRESETS: [(&str, u64); 3] =
+    [("Unlimited", 0), ("1 MB/s", 1024), ("10 MB/s", 10 * 1024)];
+
+/// Format a limit in KiB/s like the transfer rates in the system bar
+///
+/// # Example
+/// ```
+/// use stui::logic::formatting::Symbols;
+/// use stui::logic::rate_limits::format_limit;
+///
+/// assert_eq!(format_limit(0, &Symbols::UNICODE), "∞");
+/// assert_eq!(format_limit(0, &Symbols::ASCII), "unlimited");
+/// assert_eq!(format_limit(1024, &Symbols::UNICODE), "1.0M/s");
+/// assert_eq!(format_limit(500, &Symbols::UNICODE), "500.0K/s");
+/// ```
+pub fn format_limit(kbps: u64, symbols: &Symbols) -> String {
+    if kbps == 0 {
+        symbols.unlimited.to_string()
+    } else {
+        format_transfer_rate(kbps as f64 * 1024.0)
+    }
+}
+
+/// Build the popup rows: global limits, then remote devices by name
+pub fn build_rows(
+    global: RateLimits,
+    devices: &[DeviceConfig],
+    my_id: Option<&str>,
+) -> Vec<RateLimitRow> {
+    let mut device_rows: Vec<RateLimitRow> = devices
+        .iter()
+        .filter(|device| Some(device.device_id.as_str()) != my_id)
+        .map(|device| RateLimitRow {
+            device_id: Some(device.device_id.clone()),
+            name: if device.name.is_empty() {
+                short_device_id(&device.device_id).to_string()
+            } else {
+                device.name.clone()
+            },
+            limits: device.rate_limits(),
+        })
+        .collect();
+    device_rows.sort_by_key(|row| row.name.to_lowercase());
+
+    let mut rows = vec![RateLimitRow {
+        device_id: None,
+        name: "All devices".to_string(),
+        limits: global,
+    }];
+    rows.extend(device_rows);
+    rows
+}
+
+/// Number of remote devices with a limit of their own
+pub fn count_limited_devices(devices: &[DeviceConfig], my_id: Option<&str>) -> usize {
+    devices
+        .iter()
+        .filter(|device| Some(device.device_id.as_str()) != my_id)
+        .filter(|device| device.rate_limits().is_limited())
+        .count()
+}
+
+/// Set the limit of one or both directions
+pub fn apply_limit(limits: RateLimits, direction: RateLimitDirection, kbps: u64) -> RateLimits {
+    match direction {
+        RateLimitDirection::Both => RateLimits {
+            max_send_kbps: kbps,
+            max_recv_kbps: kbps,
+        },
+        RateLimitDirection::Send => RateLimits {
+            max_send_kbps: kbps,
+            ..limits
+        },
+        RateLimitDirection::Receive => RateLimits {
+            max_recv_kbps: kbps,
+            ..limits
+        },
+    }
+}
+
+/// Get the next direction (Both → Send → Receive → Both)
+pub fn next_direction(direction: RateLimitDirection) -> RateLimitDirection {
+    match direction {
+        RateLimitDirection::Both => RateLimitDirection::Send,
+        RateLimitDirection::Send => RateLimitDirection::Receive,
+        RateLimitDirection::Receive => RateLimitDirection::Both,
+    }
+}
+
+/// Parse a custom limit typed in KiB/s (0 = unlimited)
+pub fn parse_custom_limit(input: &str) -> Result<u64, String> {
+    input
+        .trim()
+        .parse()
+        .map_err(|_| "Limit must be a whole number of KiB/s (0 = unlimited)".to_string())
+}
+
+/// System bar indicator while any limit is active
+///
+/// # Example
+/// ```
+/// use stui::api::RateLimits;
+/// use stui::logic::formatting::Symbols;
+/// use stui::logic::rate_limits::rate_limit_indicator;
+///
+/// let global = RateLimits { max_send_kbps: 1024, max_recv_kbps: 0 };
+/// assert_eq!(
+///     rate_limit_indicator(global, 0, &Symbols::UNICODE).as_deref(),
+///     Some("Limit ↓∞ ↑1.0M/s")
+/// );
+/// assert_eq!(
+///     rate_limit_indicator(global, 0, &Symbols::ASCII).as_deref(),
+///     Some("Limit dn:unlimited up:1.0M/s")
+/// );
+/// assert_eq!(rate_limit_indicator(RateLimits::default(), 0, &Symbols::UNICODE), None);
+/// ```
+pub fn rate_limit_indicator(
+    global: RateLimits,
+    limited_devices: usize,
+    symbols: &Symbols,
+) -> Option<String> {
+    let devices = match limited_devices {
+        0 => None,
+        1 => Some("1 device".to_string()),
+        n => Some(format!("{} devices", n)),
+    };
+
+    match (global.is_limited(), devices) {
+        (false, None) => None,
+        (false, Some(devices)) => Some(format!("Limit: {}", devices)),
+        (true, devices) => {
+            let mut text = format!(
+                "Limit {}{} {}{}",
+                symbols.download,
+                format_limit(global.max_recv_kbps, symbols),
+                symbols.upload,
+                format_limit(global.max_send_kbps, symbols)
+            );
+            if let Some(devices) = devices {
+                text.push_str(&format!(" +{}", devices));
+            }
+            Some(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(id: &str, name: &str, send: u64, recv: u64) -> DeviceConfig {
+        DeviceConfig {
+            device_id: id.to_string(),
+            name: name.to_string(),
+            addresses: vec!["dynamic".to_string()],
+            compression: "metadata".to_string(),
+            introducer: false,
+            paused: false,
+            max_send_kbps: send,
+            max_recv_kbps: recv,
+        }
+    }
+
+    fn limits(send: u64, recv: u64) -> RateLimits {
+        RateLimits {
+            max_send_kbps: send,
+            max_recv_kbps: recv,
+        }
+    }
+
+    // ========================================
+    // ROWS
+    // ========================================
+
+    #[test]
+    fn test_build_rows_global_first_then_remote_devices() {
+        let devices = vec![
+            device("ME", "laptop", 0, 0),
+            device("PHONE", "phone", 0, 512),
+            device("NAS", "Nas", 0, 0),
+        ];
+
+        let rows = build_rows(limits(1024, 0), &devices, Some("ME"));
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["All devices", "Nas", "phone"]);
+        assert_eq!(rows[0].device_id, None);
+        assert_eq!(rows[0].limits, limits(1024, 0));
+        assert_eq!(rows[2].limits, limits(0, 512));
+
+        assert_eq!(count_limited_devices(&devices, Some("ME")), 1);
+    }
+
+    // ========================================
+    // APPLYING LIMITS
+    // ========================================
+
+    #[test]
+    fn test_apply_limit_per_direction() {
+        let current = limits(100, 200);
+        assert_eq!(
+            apply_limit(current, RateLimitDirection::Both, 1024),
+            limits(1024, 1024)
+        );
+        assert_eq!(
+            apply_limit(current, RateLimitDirection::Send, 0),
+            limits(0, 200)
+        );
+        assert_eq!(
+            apply_limit(current, RateLimitDirection::Receive, 0),
+            limits(100, 0)
+        );
+        assert_eq!(
+            next_direction(RateLimitDirection::Receive),
+            RateLimitDirection::Both
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_limit() {
+        assert_eq!(parse_custom_limit(" 2048 "), Ok(2048));
+        assert_eq!(parse_custom_limit("0"), Ok(0));
+        assert!(parse_custom_limit("1.5").is_err());
+        assert!(parse_custom_limit("-1").is_err());
+    }
+
+    // ========================================
+    // INDICATOR
+    // ========================================
+
+    #[test]
+    fn test_rate_limit_indicator() {
+        assert_eq!(
+            rate_limit_indicator(limits(1024, 10240), 2, &Symbols::UNICODE).as_deref(),
+            Some("Limit ↓10.0M/s ↑1.0M/s +2 devices")
+        );
+        assert_eq!(
+            rate_limit_indicator(RateLimits::default(), 1, &Symbols::UNICODE).as_deref(),
+            Some("Limit: 1 device")
+        );
+    }
+}
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 /// Lower number = higher priority (displayed first)
 ///
 /// Priority order:
-/// 1. OutOfSync (⚠️) - Most important
+/// 1. OutOfSync (⚠️) / Conflict (💥) - Most important
 /// 2. Syncing (🔄) - Active operation
 /// 3. RemoteOnly (☁️)
 /// 4. LocalOnly (💻)
@@ -20,6 +20,7 @@ use std::collections::HashMap;
 pub fn sync_state_priority(state: SyncState) -> u8 {
     match state {
         SyncState::OutOfSync => 0,  // ⚠️ Most important
+        SyncState::Conflict => 0,   // 💥 Needs resolving, sorts with out-of-sync
         SyncState::Syncing => 1,    // 🔄 Active operation
         SyncState::RemoteOnly => 2, // ☁️
         SyncState::LocalOnly => 3,  // 💻
@@ -82,7 +83,7 @@ pub fn check_ignored_existence(
 /// # Priority Rules
 /// - If directory itself is RemoteOnly or Ignored → use that (takes precedence)
 /// - Otherwise, use highest priority child state:
-///   - Syncing > RemoteOnly > OutOfSync > LocalOnly > Synced
+///   - Syncing > Conflict > RemoteOnly > OutOfSync > LocalOnly > Synced
 /// - If all children synced → use directory's direct state
 ///
 /// # Arguments
@@ -104,6 +105,7 @@ pub fn aggregate_directory_state(
 
     // Collect child state flags
     let mut has_syncing = false;
+    let mut has_conflict = false;
     let mut has_remote_only = false;
     let mut has_out_of_sync = false;
     let mut has_local_only = false;
@@ -111,6 +113,7 @@ pub fn aggregate_directory_state(
     for state in child_states {
         match state {
             SyncState::Syncing => has_syncing = true,
+            SyncState::Conflict => has_conflict = true,
             SyncState::RemoteOnly => has_remote_only = true,
             SyncState::OutOfSync => has_out_of_sync = true,
             SyncState::LocalOnly => has_local_only = true,
@@ -118,9 +121,11 @@ pub fn aggregate_directory_state(
         }
     }
 
-    // Priority order: Syncing > RemoteOnly > OutOfSync > LocalOnly > Synced
+    // Priority order: Syncing > Conflict > RemoteOnly > OutOfSync > LocalOnly > Synced
     if has_syncing {
         SyncState::Syncing
+    } else if has_conflict {
+        SyncState::Conflict
     } else if has_remote_only {
         SyncState::RemoteOnly
     } else if has_out_of_sync {
@@ -289,6 +294,20 @@ mod aggregate_tests {
         );
     }
 
+    #[test]
+    fn test_aggregate_directory_state_conflict_child() {
+        // A conflict copy anywhere below surfaces on the directory
+        let direct_state = Some(SyncState::Synced);
+        let child_states = vec![
+            SyncState::RemoteOnly,
+            SyncState::Conflict,
+            SyncState::OutOfSync,
+        ];
+
+        let result = aggregate_directory_state(direct_state, &child_states);
+        assert_eq!(result, SyncState::Conflict);
+    }
+
     #[test]
     fn test_aggregate_directory_state_remote_only_direct() {
         // Directory itself is RemoteOnly - takes precedence
@@ -0,0 +1,307 @@
+//! Transfer queue logic
+//!
+//! Pure functions for the transfer queue: ordering `/rest/db/need` into
+//! in-progress, queued and remaining files, and turning successive
+//! DownloadProgress samples into rates, ETAs and progress bar segments.
+
+use crate::api::{DownloadProgress, NeedResponse};
+use crate::logic::devices::calculate_rate;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+/// Needed files fetched for the transfer queue
+pub const TRANSFER_QUEUE_PAGE_SIZE: u32 = 100;
+
+/// Part of the need list a file is in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferSection {
+    /// Being pulled right now
+    InProgress,
+    /// Next in line in the puller queue
+    Queued,
+    /// Needed but not queued yet
+    Rest,
+}
+
+impl TransferSection {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransferSection::InProgress => "Downloading",
+            TransferSection::Queued => "Queued",
+            TransferSection::Rest => "Waiting",
+        }
+    }
+}
+
+/// A needed file in queue order
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferEntry {
+    pub path: String,
+    pub size: u64,
+    pub deleted: bool,
+    pub section: TransferSection,
+}
+
+/// Latest progress of a file being pulled
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTransfer {
+    pub progress: DownloadProgress,
+    /// Time of the event the progress came from
+    pub updated: SystemTime,
+    /// Bytes/sec since the previous sample (None for the first one)
+    pub rate: Option<f64>,
+}
+
+/// Bytes of a file by where its blocks came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferBreakdown {
+    pub pulled: u64,
+    pub copied: u64,
+    pub reused: u64,
+}
+
+/// Flatten a need response into queue order (in progress, queued, rest)
+pub fn queue_entries(need: &NeedResponse) -> Vec<TransferEntry> {
+    let sections = [
+        (&need.progress, TransferSection::InProgress),
+        (&need.queued, TransferSection::Queued),
+        (&need.rest, TransferSection::Rest),
+    ];
+    sections
+        .into_iter()
+        .flat_map(|(files, section)| {
+            files.iter().map(move |file| TransferEntry {
+                path: file.name.clone(),
+                size: file.size,
+                deleted: file.deleted,
+                section,
+            })
+        })
+        .collect()
+}
+
+/// Build the transfers of a folder from a new DownloadProgress sample
+///
+/// Rates are measured against the previous sample of the same file; files
+/// missing from the sample have finished and are dropped.
+pub fn update_transfers(
+    previous: Option<&HashMap<String, FileTransfer>>,
+    progress: HashMap<String, DownloadProgress>,
+    at: SystemTime,
+) -> HashMap<String, FileTransfer> {
+    progress
+        .into_iter()
+        .map(|(path, progress)| {
+            let before = previous.and_then(|transfers| transfers.get(&path));
+            let rate = match before {
+                Some(before) => match at.duration_since(before.updated) {
+                    Ok(elapsed) if !elapsed.is_zero() => Some(calculate_rate(
+                        before.progress.bytes_done,
+                        progress.bytes_done,
+                        elapsed.as_secs_f64(),
+                    )),
+                    // Same timestamp (replayed event) - keep the last rate
+                    _ => before.rate,
+                },
+                None => None,
+            };
+            let transfer = FileTransfer {
+                progress,
+                updated: at,
+                rate,
+            };
+            (path, transfer)
+        })
+        .collect()
+}
+
+/// Completed fraction of a file in percent
+pub fn percent_done(progress: &DownloadProgress) -> u8 {
+    match (progress.bytes_done.min(progress.bytes_total) * 100).checked_div(progress.bytes_total) {
+        Some(percent) => percent as u8,
+        None => 0,
+    }
+}
+
+/// Seconds left at the current rate (None while the rate is unknown or zero)
+pub fn eta_secs(transfer: &FileTransfer) -> Option<u64> {
+    let rate = transfer.rate.filter(|rate| *rate > 0.0)?;
+    let remaining = transfer
+        .progress
+        .bytes_total
+        .saturating_sub(transfer.progress.bytes_done);
+    Some((remaining as f64 / rate).ceil() as u64)
+}
+
+/// Short ETA for the queue ("45s", "3m 20s", "2h 5m")
+pub fn format_eta(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// Bytes pulled, copied and reused so far
+///
+/// Syncthing reports blocks; all blocks but the last are the same size, so
+/// bytes are estimated from the average block size.
+pub fn breakdown(progress: &DownloadProgress) -> TransferBreakdown {
+    let bytes = |blocks: u64| match progress.total {
+        0 => 0,
+        total => (blocks as f64 * progress.bytes_total as f64 / total as f64) as u64,
+    };
+    TransferBreakdown {
+        pulled: bytes(progress.pulled),
+        copied: bytes(progress.copied_from_origin + progress.copied_from_elsewhere),
+        reused: bytes(progress.reused),
+    }
+}
+
+/// Widths of the reused, copied and pulled parts of a `width`-cell progress bar
+pub fn bar_segments(progress: &DownloadProgress, width: usize) -> (usize, usize, usize) {
+    if progress.total == 0 {
+        return (0, 0, 0);
+    }
+    let cells =
+        |blocks: u64| (blocks.min(progress.total) as usize * width) / progress.total as usize;
+
+    let reused = cells(progress.reused);
+    let copied = cells(progress.copied_from_origin + progress.copied_from_elsewhere);
+    let pulled = cells(progress.pulled);
+
+    // Rounding never makes the bar overflow
+    let copied = copied.min(width - reused);
+    let pulled = pulled.min(width - reused - copied);
+    (reused, copied, pulled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::FileInfo;
+    use std::time::Duration;
+
+    fn progress(bytes_done: u64, bytes_total: u64) -> DownloadProgress {
+        DownloadProgress {
+            total: 10,
+            pulled: 4,
+            reused: 3,
+            copied_from_origin: 1,
+            bytes_total,
+            bytes_done,
+            ..Default::default()
+        }
+    }
+
+    // ========================================
+    // QUEUE ORDER
+    // ========================================
+
+    #[test]
+    fn test_queue_entries_keep_need_order() {
+        let file = |name: &str, size: u64| FileInfo {
+            name: name.to_string(),
+            size,
+            ..serde_json::from_value(serde_json::json!({})).unwrap()
+        };
+        let need = NeedResponse {
+            progress: vec![file("video.mkv", 4096)],
+            queued: vec![file("b.txt", 2)],
+            rest: vec![file("c.txt", 3), file("a.txt", 1)],
+            page: 1,
+            perpage: 100,
+        };
+
+        let entries = queue_entries(&need);
+        let order: Vec<(&str, TransferSection)> = entries
+            .iter()
+            .map(|e| (e.path.as_str(), e.section))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("video.mkv", TransferSection::InProgress),
+                ("b.txt", TransferSection::Queued),
+                ("c.txt", TransferSection::Rest),
+                ("a.txt", TransferSection::Rest),
+            ]
+        );
+        assert_eq!(entries[0].size, 4096);
+    }
+
+    // ========================================
+    // RATES AND ETA
+    // ========================================
+
+    #[test]
+    fn test_update_transfers_measures_rate_between_samples() {
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let first = update_transfers(
+            None,
+            HashMap::from([("video.mkv".to_string(), progress(1_000, 11_000))]),
+            start,
+        );
+        assert_eq!(first["video.mkv"].rate, None);
+        assert_eq!(eta_secs(&first["video.mkv"]), None);
+
+        let second = update_transfers(
+            Some(&first),
+            HashMap::from([("video.mkv".to_string(), progress(6_000, 11_000))]),
+            start + Duration::from_secs(5),
+        );
+        assert_eq!(second["video.mkv"].rate, Some(1_000.0));
+        assert_eq!(eta_secs(&second["video.mkv"]), Some(5));
+
+        // Finished files disappear from the next sample
+        let third = update_transfers(
+            Some(&second),
+            HashMap::new(),
+            start + Duration::from_secs(10),
+        );
+        assert!(third.is_empty());
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(45), "45s");
+        assert_eq!(format_eta(200), "3m 20s");
+        assert_eq!(format_eta(7_500), "2h 5m");
+    }
+
+    // ========================================
+    // BREAKDOWN AND BAR
+    // ========================================
+
+    #[test]
+    fn test_breakdown_and_percent() {
+        let p = progress(8_000, 10_000);
+        assert_eq!(percent_done(&p), 80);
+        assert_eq!(
+            breakdown(&p),
+            TransferBreakdown {
+                pulled: 4_000,
+                copied: 1_000,
+                reused: 3_000,
+            }
+        );
+        assert_eq!(percent_done(&DownloadProgress::default()), 0);
+    }
+
+    #[test]
+    fn test_bar_segments_fit_width() {
+        assert_eq!(bar_segments(&progress(0, 0), 20), (6, 2, 8));
+        assert_eq!(bar_segments(&DownloadProgress::default(), 20), (0, 0, 0));
+
+        // Inconsistent counts are clamped to the bar
+        let overfull = DownloadProgress {
+            total: 2,
+            reused: 2,
+            pulled: 2,
+            ..Default::default()
+        };
+        assert_eq!(bar_segments(&overfull, 10), (10, 0, 0));
+    }
+}
@@ -0,0 +1,171 @@
+//! File version logic
+//!
+//! Pure functions for locating archived file versions on disk, mirroring the
+//! naming scheme Syncthing's versioners use inside the versions directory.
+
+/// Versions directory used when the folder's versioning `fsPath` is empty
+pub const DEFAULT_VERSIONS_DIR: &str = ".stversions";
+
+/// Whether the versioning type keeps browsable versions
+///
+/// External versioning hands files to a user script, so Syncthing cannot list them.
+pub fn supports_version_browsing(versioning_type: &str) -> bool {
+    matches!(versioning_type, "simple" | "staggered" | "trashcan")
+}
+
+/// Get the root directory of archived versions for a folder (container path)
+///
+/// Relative `fsPath` values are resolved against the folder root, like Syncthing does.
+///
+/// # Example
+/// ```
+/// use stui::logic::versions::versions_root;
+///
+/// assert_eq!(versions_root("/data/docs", ""), "/data/docs/.stversions");
+/// assert_eq!(versions_root("/data/docs/", "archive"), "/data/docs/archive");
+/// assert_eq!(versions_root("/data/docs", "/backup/docs"), "/backup/docs");
+/// ```
+pub fn versions_root(folder_path: &str, fs_path: &str) -> String {
+    let folder_path = folder_path.trim_end_matches('/');
+    let fs_path = fs_path.trim_end_matches('/');
+
+    if fs_path.is_empty() {
+        format!("{}/{}", folder_path, DEFAULT_VERSIONS_DIR)
+    } else if fs_path.starts_with('/') {
+        fs_path.to_string()
+    } else {
+        format!("{}/{}", folder_path, fs_path)
+    }
+}
+
+/// Convert a version timestamp to Syncthing's version tag (`YYYYMMDD-HHMMSS`)
+///
+/// The tag uses the wall-clock time of the archiving device, which is the
+/// offset the timestamp is reported in.
+pub fn version_tag(version_time: &str) -> Option<String> {
+    chrono::DateTime::parse_from_rfc3339(version_time)
+        .ok()
+        .map(|dt| dt.format("%Y%m%d-%H%M%S").to_string())
+}
+
+/// Insert a version tag before the file extension (`report.txt` → `report~TAG.txt`)
+///
+/// Follows Go's `filepath.Ext`, so only the last extension is kept after the tag.
+pub fn tag_filename(name: &str, tag: &str) -> String {
+    match name.rfind('.') {
+        Some(idx) => format!("{}~{}{}", &name[..idx], tag, &name[idx..]),
+        None => format!("{}~{}", name, tag),
+    }
+}
+
+/// Get the path of an archived version relative to the versions root
+///
+/// Simple and staggered versioning tag file names with the archive time;
+/// trash can versioning keeps the original name. Returns None for versioning
+/// types without browsable versions or unparseable timestamps.
+///
+/// # Example
+/// ```
+/// use stui::logic::versions::archived_version_path;
+///
+/// assert_eq!(
+///     archived_version_path("docs/report.txt", "2025-01-15T10:30:00+01:00", "simple"),
+///     Some("docs/report~20250115-103000.txt".to_string())
+/// );
+/// assert_eq!(
+///     archived_version_path("docs/report.txt", "2025-01-15T10:30:00+01:00", "trashcan"),
+///     Some("docs/report.txt".to_string())
+/// );
+/// ```
+pub fn archived_version_path(
+    file_path: &str,
+    version_time: &str,
+    versioning_type: &str,
+) -> Option<String> {
+    match versioning_type {
+        "trashcan" => Some(file_path.to_string()),
+        "simple" | "staggered" => {
+            let tag = version_tag(version_time)?;
+            let (dir, name) = match file_path.rfind('/') {
+                Some(idx) => (&file_path[..idx + 1], &file_path[idx + 1..]),
+                None => ("", file_path),
+            };
+            Some(format!("{}{}", dir, tag_filename(name, &tag)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================
+    // VERSIONS ROOT
+    // ========================================
+
+    #[test]
+    fn test_versions_root_default_and_custom() {
+        assert_eq!(versions_root("/data/docs", ""), "/data/docs/.stversions");
+        assert_eq!(versions_root("/data/docs", "old/"), "/data/docs/old");
+        assert_eq!(
+            versions_root("/data/docs", "/mnt/versions"),
+            "/mnt/versions"
+        );
+    }
+
+    #[test]
+    fn test_supports_version_browsing() {
+        assert!(supports_version_browsing("simple"));
+        assert!(supports_version_browsing("staggered"));
+        assert!(supports_version_browsing("trashcan"));
+        assert!(!supports_version_browsing("external"));
+        assert!(!supports_version_browsing(""));
+    }
+
+    // ========================================
+    // TAGS
+    // ========================================
+
+    #[test]
+    fn test_version_tag_keeps_reported_offset() {
+        assert_eq!(
+            version_tag("2025-01-15T10:30:05+01:00").as_deref(),
+            Some("20250115-103005")
+        );
+        assert_eq!(
+            version_tag("2025-01-15T23:59:59.123456789Z").as_deref(),
+            Some("20250115-235959")
+        );
+        assert_eq!(version_tag("not a time"), None);
+    }
+
+    #[test]
+    fn test_tag_filename() {
+        assert_eq!(tag_filename("report.txt", "T"), "report~T.txt");
+        assert_eq!(tag_filename("archive.tar.gz", "T"), "archive.tar~T.gz");
+        assert_eq!(tag_filename("Makefile", "T"), "Makefile~T");
+        assert_eq!(tag_filename(".bashrc", "T"), "~T.bashrc");
+    }
+
+    // ========================================
+    // ARCHIVED PATHS
+    // ========================================
+
+    #[test]
+    fn test_archived_version_path_root_file() {
+        assert_eq!(
+            archived_version_path("notes.md", "2025-01-15T10:30:00Z", "staggered"),
+            Some("notes~20250115-103000.md".to_string())
+        );
+    }
+
+    #[test]
+    fn test_archived_version_path_unsupported() {
+        assert_eq!(
+            archived_version_path("notes.md", "2025-01-15T10:30:00Z", "external"),
+            None
+        );
+        assert_eq!(archived_version_path("notes.md", "garbage", "simple"), None);
+    }
+}
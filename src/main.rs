@@ -29,6 +29,37 @@ struct Args {
     /// Path to config file (default: platform-specific, see docs)
     #[arg(short, long)]
     config: Option<String>,
+
+    /// Syncthing instance to connect to (a name from `profiles` in the config)
+    #[arg(short, long, global = true)]
+    profile: Option<String>,
+
+    /// Print machine-readable JSON instead of tables (subcommands only)
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Record every Syncthing event and API response to a JSONL file
+    #[arg(long, value_name = "FILE", conflicts_with = "replay_events")]
+    record_events: Option<PathBuf>,
+
+    /// Replay a recording instead of connecting to Syncthing
+    #[arg(long, value_name = "FILE")]
+    replay_events: Option<PathBuf>,
+
+    /// Replay speed multiplier (e.g. 10 replays ten times faster)
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0, requires = "replay_events", value_parser = parse_replay_speed)]
+    replay_speed: f64,
+
+    /// Run a single command without starting the TUI
+    #[command(subcommand)]
+    command: Option<cli::Command>,
+}
+
+fn parse_replay_speed(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(speed) if speed > 0.0 && speed.is_finite() => Ok(speed),
+        _ => Err(format!("'{}' is not a positive number", value)),
+    }
 }
 
 // Global flag for debug mode
@@ -37,6 +68,7 @@ static DEBUG_MODE: AtomicBool = AtomicBool::new(false);
 mod api;
 mod app;
 mod cache;
+mod cli;
 mod config;
 mod handlers;
 mod logic;
@@ -49,7 +81,8 @@ use api::{BrowseItem, Folder, SyncState, SyncthingClient};
 use cache::CacheDb;
 use config::Config;
 use stui::{DisplayMode, SortMode};
-use ui::icons::{IconMode, IconRenderer, IconTheme};
+use ui::icons::{IconMode, IconRenderer};
+use ui::theme::Theme;
 
 // Build version string with git hash and build date
 const VERSION: &str = concat!(
@@ -122,18 +155,30 @@ pub struct App {
     invalidation_rx: tokio::sync::mpsc::UnboundedReceiver<services::events::CacheInvalidation>,
     event_id_rx: tokio::sync::mpsc::UnboundedReceiver<u64>,
     icon_renderer: IconRenderer,
+    theme: Theme,
     image_picker: Option<ratatui_image::picker::Picker>,
     image_update_tx: tokio::sync::mpsc::UnboundedSender<(String, ImagePreviewState)>,
     image_update_rx: tokio::sync::mpsc::UnboundedReceiver<(String, ImagePreviewState)>,
+    fleet_update_tx: tokio::sync::mpsc::UnboundedSender<(String, model::FleetStatus)>,
+    fleet_update_rx: tokio::sync::mpsc::UnboundedReceiver<(String, model::FleetStatus)>,
 
     path_map: HashMap<String, String>,
     open_command: Option<String>,
     clipboard_command: Option<String>,
     base_url: String,
 
+    /// Configured Syncthing instances and the connected one
+    profiles: Vec<config::Profile>,
+    active_profile: String,
+    event_listener: Option<tokio::task::JoinHandle<()>>,
+    /// Recording or replay started with --record-events / --replay-events
+    event_session: Option<app::recording::EventSession>,
+
     last_status_update: Instant,
     last_system_status_update: Instant,
     last_connection_stats_fetch: Instant,
+    last_error_poll: Instant,
+    last_log_poll: Instant,
     last_directory_update: Instant,
     last_db_flush: Instant,
     last_reconnect_attempt: Instant,
@@ -296,9 +341,14 @@ impl App {
 
     /// Refresh device count by querying connections API
     async fn refresh_device_count(&mut self) {
-        match self.client.get_system_connections().await {
+        let connections = self.client.get_system_connections().await;
+        self.apply_device_count(connections);
+    }
+
+    /// Count connected devices (excluding self) from a connections response
+    pub(crate) fn apply_device_count(&mut self, connections: Result<api::ConnectionsResponse>) {
+        match connections {
             Ok(connections) => {
-                // Count connected devices (exclude self, filter by connected status)
                 let my_device_id = self
                     .model
                     .syncthing
@@ -370,79 +420,33 @@ impl App {
         )
     }
 
-    async fn new(config: Config, config_path: String) -> Result<Self> {
-        let client = SyncthingClient::new(config.base_url.clone(), config.api_key.clone());
-        let cache = CacheDb::new()?;
-
-        // Try to fetch folders from API, fall back to cache on error
-        let (folders, initial_connection_state) = match client.get_folders().await {
-            Ok(folders) => {
-                let _ = cache.save_folders(&folders);
-                (folders, model::syncthing::ConnectionState::Connected)
-            }
-            Err(e) => {
-                log_debug(&format!("Failed to fetch folders from API: {}", e));
-                let cached_folders = cache.get_all_folders().unwrap_or_else(|cache_err| {
-                    log_debug(&format!("Failed to load folders from cache: {}", cache_err));
-                    vec![]
-                });
-
-                if cached_folders.is_empty() {
-                    (
-                        vec![],
-                        model::syncthing::ConnectionState::Disconnected {
-                            error_type: logic::errors::classify_error(&e),
-                            message: logic::errors::format_error_message(&e),
-                        },
-                    )
-                } else {
-                    log_debug(&format!(
-                        "Using {} cached folders, will auto-retry",
-                        cached_folders.len()
-                    ));
-                    (
-                        cached_folders,
-                        model::syncthing::ConnectionState::Connecting {
-                            attempt: 1,
-                            last_error: Some(e.to_string()),
-                            next_retry_secs: 5,
-                        },
-                    )
-                }
-            }
+    async fn new(
+        config: Config,
+        config_path: String,
+        profile_name: Option<&str>,
+        session_mode: app::recording::SessionMode,
+    ) -> Result<Self> {
+        let mut profile = config.resolve_profile(profile_name)?;
+        let profiles = config.profiles();
+        let keymap = config.keymap(config.vim_mode)?;
+        let event_session = app::recording::EventSession::start(session_mode, &mut profile).await?;
+        let instance = if event_session.as_ref().is_some_and(|s| s.is_replay()) {
+            app::profiles::InstanceServices::start_replay(&profile)?
+        } else {
+            app::profiles::InstanceServices::start(&profile).await?
         };
 
-        let devices = client.get_devices().await.unwrap_or_default();
-
-        // Spawn API service worker
-        let (api_tx, api_rx) = services::api::spawn_api_service(client.clone());
-
-        // Get last event ID from cache
-        let last_event_id = cache.get_last_event_id().unwrap_or(0);
-
-        // Create channels for event listener
-        let (invalidation_tx, invalidation_rx) = tokio::sync::mpsc::unbounded_channel();
-        let (event_id_tx, event_id_rx) = tokio::sync::mpsc::unbounded_channel();
-
-        // Create channel for image updates
+        // Create channels for image updates and fleet overview polls
         let (image_update_tx, image_update_rx) = tokio::sync::mpsc::unbounded_channel();
-
-        // Spawn event listener
-        services::events::spawn_event_listener(
-            config.base_url.clone(),
-            config.api_key.clone(),
-            last_event_id,
-            invalidation_tx,
-            event_id_tx,
-        );
+        let (fleet_update_tx, fleet_update_rx) = tokio::sync::mpsc::unbounded_channel();
 
         // Parse icon mode from config
-        let icon_mode = match config.icon_mode.to_lowercase().as_str() {
-            "emoji" => IconMode::Emoji,
-            "nerdfont" | "nerd" | "nf" => IconMode::NerdFont,
-            _ => IconMode::NerdFont, // Default to nerd font
+        let icon_mode = IconMode::from_config(&config.icon_mode);
+        let theme = match &config.theme {
+            Some(theme) => Theme::load(theme, std::path::Path::new(&config_path).parent())?,
+            None => Theme::default(),
         };
-        let icon_renderer = IconRenderer::new(icon_mode, IconTheme::default());
+        let icon_renderer = IconRenderer::new(icon_mode, theme.icon_theme());
 
         // Initialize image preview protocol picker
         let (image_picker, image_font_size) = if config.image_preview_enabled {
@@ -500,41 +504,41 @@ impl App {
         let mut model = model::Model::new(config.vim_mode);
         model.ui.display_mode = DisplayMode::TimestampAndSize; // Start with most info
         model.ui.sort_mode = SortMode::Alphabetical;
-        model.syncthing.folders = folders.clone();
-        model.syncthing.devices = devices;
-        model.syncthing.connection_state = initial_connection_state.clone();
         model.ui.config_path = config_path;
         model.ui.image_font_size = image_font_size;
-
-        // Show setup help if no folders and disconnected
-        if folders.is_empty()
-            && matches!(
-                initial_connection_state,
-                model::syncthing::ConnectionState::Disconnected { .. }
-            )
-        {
-            model.ui.show_setup_help = true;
+        model.ui.keymap = keymap;
+        if profiles.len() > 1 {
+            model.ui.active_profile = Some(profile.name.clone());
         }
 
         let mut app = App {
             model,
-            client,
-            cache,
-            api_tx,
-            api_rx,
-            invalidation_rx,
-            event_id_rx,
+            client: instance.client,
+            cache: instance.cache,
+            api_tx: instance.api_tx,
+            api_rx: instance.api_rx,
+            invalidation_rx: instance.invalidation_rx,
+            event_id_rx: instance.event_id_rx,
             icon_renderer,
+            theme,
             image_picker,
             image_update_tx,
             image_update_rx,
-            path_map: config.path_map,
-            open_command: config.open_command,
-            clipboard_command: config.clipboard_command,
-            base_url: config.base_url,
+            fleet_update_tx,
+            fleet_update_rx,
+            path_map: profile.path_map,
+            open_command: profile.open_command,
+            clipboard_command: profile.clipboard_command,
+            base_url: profile.base_url,
+            profiles,
+            active_profile: profile.name,
+            event_listener: Some(instance.event_listener),
+            event_session,
             last_status_update: Instant::now(),
             last_system_status_update: Instant::now(),
             last_connection_stats_fetch: Instant::now(),
+            last_error_poll: Instant::now(),
+            last_log_poll: Instant::now(),
             last_directory_update: Instant::now(),
             last_db_flush: Instant::now(),
             last_reconnect_attempt: Instant::now(),
@@ -543,33 +547,104 @@ impl App {
             image_state_map: HashMap::new(),
         };
 
+        app.load_instance().await;
+
+        if let Some(session) = &app.event_session {
+            app.model.ui.show_toast(session.description());
+        }
+
+        Ok(app)
+    }
+
+    /// Load folders, devices and status of the connected instance
+    ///
+    /// Falls back to cached folders when the instance is unreachable.
+    async fn load_instance(&mut self) {
+        // Try to fetch folders from API, fall back to cache on error
+        let (folders, initial_connection_state) = match self.client.get_folders().await {
+            Ok(folders) => {
+                let _ = self.cache.save_folders(&folders);
+                (folders, model::syncthing::ConnectionState::Connected)
+            }
+            Err(e) => {
+                log_debug(&format!("Failed to fetch folders from API: {}", e));
+                let cached_folders = self.cache.get_all_folders().unwrap_or_else(|cache_err| {
+                    log_debug(&format!("Failed to load folders from cache: {}", cache_err));
+                    vec![]
+                });
+
+                if cached_folders.is_empty() {
+                    (
+                        vec![],
+                        model::syncthing::ConnectionState::Disconnected {
+                            error_type: logic::errors::classify_error(&e),
+                            message: logic::errors::format_error_message(&e),
+                        },
+                    )
+                } else {
+                    log_debug(&format!(
+                        "Using {} cached folders, will auto-retry",
+                        cached_folders.len()
+                    ));
+                    (
+                        cached_folders,
+                        model::syncthing::ConnectionState::Connecting {
+                            attempt: 1,
+                            last_error: Some(e.to_string()),
+                            next_retry_secs: 5,
+                            restart_requested: None,
+                        },
+                    )
+                }
+            }
+        };
+
+        // Show setup help if no folders and disconnected
+        if folders.is_empty()
+            && matches!(
+                initial_connection_state,
+                model::syncthing::ConnectionState::Disconnected { .. }
+            )
+        {
+            self.model.ui.show_setup_help = true;
+        }
+
+        self.model.syncthing.folders = folders;
+        self.model.syncthing.connection_state = initial_connection_state;
+        self.model.syncthing.devices = self.client.get_devices().await.unwrap_or_default();
+
         // Load folder statuses first (needed for cache validation)
-        app.load_folder_statuses().await;
+        self.load_folder_statuses().await;
 
         // Load cached device name (if available) to avoid "Unknown" flash
-        if let Ok(Some(cached_name)) = app.cache.get_device_name() {
-            app.model.syncthing.device_name = Some(cached_name);
+        if let Ok(Some(cached_name)) = self.cache.get_device_name() {
+            self.model.syncthing.device_name = Some(cached_name);
         }
 
         // Initialize system status and connection stats
-        if let Ok(device_name) = app.client.get_device_name().await {
-            app.model.syncthing.device_name = Some(device_name.clone());
+        if let Ok(device_name) = self.client.get_device_name().await {
+            self.model.syncthing.device_name = Some(device_name.clone());
             // Cache device name for next startup
-            let _ = app.cache.save_device_name(&device_name);
+            let _ = self.cache.save_device_name(&device_name);
+        }
+
+        if let Ok(sys_status) = self.client.get_system_status().await {
+            self.model.syncthing.system_status = Some(sys_status);
         }
 
-        if let Ok(sys_status) = app.client.get_system_status().await {
-            app.model.syncthing.system_status = Some(sys_status);
+        if let Ok(conn_stats) = self.client.get_connection_stats().await {
+            self.model.syncthing.last_connection_stats = Some((conn_stats.clone(), Instant::now()));
+            self.model.syncthing.connection_stats = Some(conn_stats);
         }
 
-        if let Ok(conn_stats) = app.client.get_connection_stats().await {
-            app.model.syncthing.last_connection_stats = Some((conn_stats.clone(), Instant::now()));
-            app.model.syncthing.connection_stats = Some(conn_stats);
+        // Errors reported before startup raise the badge until the log viewer is opened
+        if let Ok(errors) = self.client.get_system_errors().await {
+            self.model.syncthing.system_errors = errors;
         }
 
         // Pre-populate last folder updates from /rest/stats/folder for instant display
         // This matches what Syncthing web GUI shows and is more reliable than event parsing
-        match app.client.get_folder_stats().await {
+        match self.client.get_folder_stats().await {
             Ok(folder_updates) => {
                 log_debug(&format!(
                     "Pre-populated {} folder updates from stats API",
@@ -581,20 +656,18 @@ impl App {
                         folder_id, filename, timestamp
                     ));
                 }
-                app.model.syncthing.last_folder_updates = folder_updates;
+                self.model.syncthing.last_folder_updates = folder_updates;
             }
             Err(e) => {
                 log_debug(&format!("Failed to fetch folder stats: {}", e));
             }
         }
 
-        if !app.model.syncthing.folders.is_empty() {
-            app.model.navigation.folders_state_selection = Some(0);
+        if !self.model.syncthing.folders.is_empty() {
+            self.model.navigation.folders_state_selection = Some(0);
             // Try to load root level, but don't fail initialization if it errors (e.g., Syncthing down)
-            let _ = app.load_root_level(true).await; // Preview mode - focus stays on folders
+            let _ = self.load_root_level(true).await; // Preview mode - focus stays on folders
         }
-
-        Ok(app)
     }
 
     async fn load_folder_statuses(&mut self) {
@@ -927,16 +1000,45 @@ async fn main() -> Result<()> {
     let config_str = fs::read_to_string(&config_path)?;
     let mut config: Config = serde_yaml::from_str(&config_str)?;
 
+    // Headless subcommand: print and exit with a sync-state exit code
+    if let Some(command) = args.command {
+        let code = match run_headless(&config, args.profile.as_deref(), command, args.json).await {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("Error: {:#}", e);
+                logic::health::EXIT_FAILURE
+            }
+        };
+        std::process::exit(code);
+    }
+
     // Override config with CLI flags
     if args.vim {
         config.vim_mode = true;
     }
 
+    let session_mode = match (args.record_events, args.replay_events) {
+        (Some(path), _) => app::recording::SessionMode::Record(path),
+        (_, Some(path)) => app::recording::SessionMode::Replay {
+            path,
+            speed: args.replay_speed,
+        },
+        _ => app::recording::SessionMode::Live,
+    };
+
     // Initialize app
-    let mut app = App::new(config, config_path_str).await?;
+    let mut app = App::new(
+        config,
+        config_path_str,
+        args.profile.as_deref(),
+        session_mode,
+    )
+    .await?;
 
     // Load initial device count
     app.refresh_device_count().await;
+    app.refresh_rate_limits().await;
+    app.refresh_restart_required().await;
 
     // Setup terminal
     enable_raw_mode()?;
@@ -957,6 +1059,18 @@ async fn main() -> Result<()> {
     result
 }
 
+/// Run a headless subcommand against the selected profile
+async fn run_headless(
+    config: &Config,
+    profile_name: Option<&str>,
+    command: cli::Command,
+    json: bool,
+) -> Result<i32> {
+    let profile = config.resolve_profile(profile_name)?;
+    let client = SyncthingClient::new(profile.base_url, profile.api_key);
+    cli::run(command, &client, json).await
+}
+
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
@@ -1019,6 +1133,7 @@ async fn run_app<B: ratatui::backend::Backend>(
             // Persist event ID to cache periodically
             let _ = app.cache.save_last_event_id(event_id);
         }
+        app.check_replay_finished();
 
         // Process image updates from background loading tasks (non-blocking)
         while let Ok((file_path, image_state)) = app.image_update_rx.try_recv() {
@@ -1047,6 +1162,12 @@ async fn run_app<B: ratatui::backend::Backend>(
             }
         }
 
+        // Process fleet overview poll results (non-blocking)
+        while let Ok((profile, status)) = app.fleet_update_rx.try_recv() {
+            app.apply_fleet_update(&profile, status);
+        }
+        app.poll_fleet_if_due();
+
         // NOTE: Removed periodic status polling - we now rely on events for cache invalidation
         // Status updates now only happen:
         // 1. On app startup (initial load)
@@ -1077,10 +1198,12 @@ async fn run_app<B: ratatui::backend::Backend>(
                             attempt: 1,
                             last_error: Some(message.clone()),
                             next_retry_secs: next_delay.as_secs(),
+                            restart_requested: None,
                         };
                 } else if let model::syncthing::ConnectionState::Connecting {
                     attempt,
                     last_error,
+                    restart_requested,
                     ..
                 } = &app.model.syncthing.connection_state
                 {
@@ -1090,6 +1213,7 @@ async fn run_app<B: ratatui::backend::Backend>(
                             attempt: new_attempt,
                             last_error: last_error.clone(),
                             next_retry_secs: next_delay.as_secs(),
+                            restart_requested: *restart_requested,
                         };
                 }
 
@@ -1132,11 +1256,41 @@ async fn run_app<B: ratatui::backend::Backend>(
             }
         }
 
+        // Reload folders and devices after a ConfigSaved event
+        if app.model.ui.needs_config_reload {
+            app.model.ui.needs_config_reload = false;
+            app.request_config_reload();
+        }
+
+        // Reload failed items after a FolderErrors event
+        if app.model.ui.needs_failed_items_refresh {
+            app.model.ui.needs_failed_items_refresh = false;
+            app.request_failed_items_refresh();
+        }
+
+        // Reload the transfer queue after files started or finished syncing
+        if app.model.ui.needs_transfer_queue_refresh {
+            app.model.ui.needs_transfer_queue_refresh = false;
+            app.request_transfer_queue_refresh();
+        }
+
+        // Reload the remote completion modal (after FolderCompletion events)
+        if app.model.ui.needs_remote_completion_refresh {
+            app.model.ui.needs_remote_completion_refresh = false;
+            app.request_remote_completion_refresh();
+        }
+
+        // Reload pending invitations inbox after a pending-change event
+        if app.model.ui.needs_pending_refresh {
+            app.model.ui.needs_pending_refresh = false;
+            app.request_pending_refresh();
+        }
+
         // Refresh device/system status periodically (less frequently than folder stats)
         // System status every 30 seconds, connection stats every 2-3 seconds
         if app.last_system_status_update.elapsed() >= std::time::Duration::from_secs(30) {
             let _ = app.api_tx.send(services::api::ApiRequest::GetSystemStatus);
-            app.refresh_device_count().await;
+            let _ = app.api_tx.send(services::api::ApiRequest::GetDeviceCount);
             app.last_system_status_update = Instant::now();
         }
 
@@ -1145,6 +1299,34 @@ async fn run_app<B: ratatui::backend::Backend>(
                 .api_tx
                 .send(services::api::ApiRequest::GetConnectionStats);
             app.last_connection_stats_fetch = Instant::now();
+
+            // Keep device states and per-device rates live while the devices modal is open
+            app.request_devices_refresh();
+        }
+
+        // Poll Syncthing errors for the system bar badge
+        if app.last_error_poll.elapsed() >= std::time::Duration::from_secs(10)
+            && matches!(
+                app.model.syncthing.connection_state,
+                model::syncthing::ConnectionState::Connected
+            )
+        {
+            let _ = app.api_tx.send(services::api::ApiRequest::GetSystemErrors);
+            app.last_error_poll = Instant::now();
+        }
+
+        // Follow the Syncthing log while the log viewer is open
+        if app.last_log_poll.elapsed() >= std::time::Duration::from_millis(2000) {
+            if app
+                .model
+                .ui
+                .log_viewer
+                .as_ref()
+                .is_some_and(|viewer| viewer.follow)
+            {
+                app.request_log_refresh();
+            }
+            app.last_log_poll = Instant::now();
         }
 
         // Poll folders in transient states (scanning, syncing, cleaning)
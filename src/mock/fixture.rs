@@ -0,0 +1,328 @@
+//! Mock Fixture Format
+//!
+//! Declarative description of what the mock server serves: the local device,
+//! remote devices, folders with their files and ignore patterns, and events
+//! to emit while running.
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+fn default_api_key() -> String {
+    "mock-api-key".to_string()
+}
+
+fn default_my_id() -> String {
+    "MOCKDEV-AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG".to_string()
+}
+
+fn default_device_name() -> String {
+    "mock".to_string()
+}
+
+fn default_uptime() -> u64 {
+    3600
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_folder_type() -> String {
+    "sendreceive".to_string()
+}
+
+fn default_folder_state() -> String {
+    "idle".to_string()
+}
+
+fn default_modified() -> String {
+    "2025-01-01T12:00:00Z".to_string()
+}
+
+/// Everything the mock server serves
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Fixture {
+    /// Key clients must send in `X-API-Key`
+    #[serde(default = "default_api_key")]
+    pub api_key: String,
+    /// ID of the local device (reported by /rest/system/status)
+    #[serde(default = "default_my_id")]
+    pub my_id: String,
+    /// Name of the local device in the config
+    #[serde(default = "default_device_name")]
+    pub device_name: String,
+    /// Seconds reported as uptime
+    #[serde(default = "default_uptime")]
+    pub uptime: u64,
+    /// Remote devices
+    #[serde(default)]
+    pub devices: Vec<DeviceFixture>,
+    #[serde(default)]
+    pub folders: Vec<FolderFixture>,
+    /// Events emitted after the server starts
+    #[serde(default)]
+    pub events: Vec<ScriptedEvent>,
+}
+
+/// A remote device and its connection
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DeviceFixture {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub connected: bool,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub address: String,
+    #[serde(default)]
+    pub in_bytes: u64,
+    #[serde(default)]
+    pub out_bytes: u64,
+}
+
+/// A Syncthing folder and its contents
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FolderFixture {
+    pub id: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Path on disk (default: /mock/<id>)
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(rename = "type", default = "default_folder_type")]
+    pub folder_type: String,
+    #[serde(default)]
+    pub paused: bool,
+    /// State reported by /rest/db/status ("idle", "scanning", "syncing", ...)
+    #[serde(default = "default_folder_state")]
+    pub state: String,
+    /// IDs of the devices the folder is shared with
+    #[serde(default)]
+    pub devices: Vec<String>,
+    /// .stignore patterns
+    #[serde(default)]
+    pub ignores: Vec<String>,
+    /// Files; parent directories are created implicitly, a trailing `/`
+    /// declares an (empty) directory
+    #[serde(default)]
+    pub files: Vec<FileFixture>,
+}
+
+impl FolderFixture {
+    pub fn path(&self) -> String {
+        self.path
+            .clone()
+            .unwrap_or_else(|| format!("/mock/{}", self.id))
+    }
+}
+
+/// A file or directory inside a folder
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FileFixture {
+    /// Path relative to the folder root, `/`-separated
+    pub path: String,
+    #[serde(default)]
+    pub size: u64,
+    /// RFC 3339 modification time
+    #[serde(default = "default_modified")]
+    pub modified: String,
+    #[serde(default)]
+    pub state: FileState,
+}
+
+impl FileFixture {
+    pub fn new(path: &str, state: FileState) -> Self {
+        Self {
+            path: path.to_string(),
+            size: 0,
+            modified: default_modified(),
+            state,
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.path.ends_with('/')
+    }
+
+    /// Path without the trailing `/` of directories
+    pub fn name(&self) -> &str {
+        self.path.trim_end_matches('/')
+    }
+}
+
+/// How a file relates to the global state
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileState {
+    /// Local matches global
+    #[default]
+    Synced,
+    /// Local differs from global (listed by /rest/db/need)
+    OutOfSync,
+    /// Out of sync and currently downloading (in the need "progress" list)
+    Syncing,
+    /// Only in the global index
+    RemoteOnly,
+    /// Only in the local index (listed by /rest/db/localchanged)
+    LocalOnly,
+    /// Matched by an ignore pattern
+    Ignored,
+}
+
+/// An event the server emits on its own
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptedEvent {
+    /// Delay after server start
+    #[serde(default)]
+    pub after_ms: u64,
+    /// Syncthing event type (`LocalIndexUpdated`, `ItemStarted`, ...)
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// Event payload, passed through as-is
+    #[serde(default)]
+    pub data: serde_json::Value,
+    /// Fixture changes applied right before the event is emitted
+    #[serde(default)]
+    pub update: Option<FileUpdate>,
+}
+
+/// Files to add, replace or remove in one folder
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FileUpdate {
+    pub folder: String,
+    /// Added, or replacing the file with the same path
+    #[serde(default)]
+    pub files: Vec<FileFixture>,
+    /// Paths to remove
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+impl Fixture {
+    /// Parse and validate a fixture
+    pub fn from_yaml(text: &str) -> Result<Self> {
+        let fixture: Fixture = serde_yaml::from_str(text)?;
+        fixture.validate()?;
+        Ok(fixture)
+    }
+
+    /// Read a fixture file
+    pub fn load(path: &std::path::Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read fixture {}", path.display()))?;
+        Self::from_yaml(&text).with_context(|| format!("Invalid fixture {}", path.display()))
+    }
+
+    fn validate(&self) -> Result<()> {
+        let device_ids: HashSet<&str> = self.devices.iter().map(|d| d.id.as_str()).collect();
+        let mut folder_ids = HashSet::new();
+
+        for folder in &self.folders {
+            if !folder_ids.insert(folder.id.as_str()) {
+                bail!("folders.{}: duplicate folder id", folder.id);
+            }
+            for device in &folder.devices {
+                if !device_ids.contains(device.as_str()) {
+                    bail!("folders.{}: unknown device '{}'", folder.id, device);
+                }
+            }
+            validate_files(&folder.id, &folder.files)?;
+        }
+
+        for (i, event) in self.events.iter().enumerate() {
+            if let Some(update) = &event.update {
+                if !folder_ids.contains(update.folder.as_str()) {
+                    bail!("events[{}].update: unknown folder '{}'", i, update.folder);
+                }
+                validate_files(&update.folder, &update.files)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_files(folder_id: &str, files: &[FileFixture]) -> Result<()> {
+    for file in files {
+        if file.name().is_empty() || file.path.starts_with('/') {
+            bail!(
+                "folders.{}: invalid path '{}' (must be relative and non-empty)",
+                folder_id,
+                file.path
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_fixture_uses_defaults() {
+        let fixture = Fixture::from_yaml("folders:\n  - id: docs\n").unwrap();
+        assert_eq!(fixture.api_key, "mock-api-key");
+        assert_eq!(fixture.folders[0].folder_type, "sendreceive");
+        assert_eq!(fixture.folders[0].state, "idle");
+        assert_eq!(fixture.folders[0].path(), "/mock/docs");
+    }
+
+    #[test]
+    fn test_files_and_events_parse() {
+        let fixture = Fixture::from_yaml(
+            r#"
+devices:
+  - id: NAS
+    name: nas
+folders:
+  - id: docs
+    devices: [NAS]
+    files:
+      - { path: a.txt, size: 10 }
+      - { path: sub/b.txt, state: out_of_sync }
+      - { path: empty/ }
+events:
+  - type: LocalIndexUpdated
+    after_ms: 50
+    data: { folder: docs, filenames: [a.txt] }
+    update:
+      folder: docs
+      files: [{ path: a.txt, state: local_only }]
+      remove: [sub/b.txt]
+"#,
+        )
+        .unwrap();
+
+        let files = &fixture.folders[0].files;
+        assert_eq!(files[0].state, FileState::Synced);
+        assert_eq!(files[1].state, FileState::OutOfSync);
+        assert!(files[2].is_dir());
+        assert_eq!(files[2].name(), "empty");
+
+        let event = &fixture.events[0];
+        assert_eq!(event.event_type, "LocalIndexUpdated");
+        assert_eq!(event.data["filenames"][0], "a.txt");
+        assert_eq!(event.update.as_ref().unwrap().remove, vec!["sub/b.txt"]);
+    }
+
+    #[test]
+    fn test_invalid_fixtures_are_rejected() {
+        let err = |yaml: &str| Fixture::from_yaml(yaml).unwrap_err().to_string();
+
+        assert!(err("folders: [{ id: a }, { id: a }]").contains("duplicate folder id"));
+        assert!(err("folders: [{ id: a, devices: [X] }]").contains("unknown device 'X'"));
+        assert!(err("folders: [{ id: a, files: [{ path: /abs }] }]").contains("invalid path"));
+        assert!(err("events: [{ type: X, update: { folder: nope } }]").contains("unknown folder"));
+        assert!(err("folders: [{ id: a, files: [{ path: x, state: bogus }] }]").contains("bogus"));
+    }
+}
@@ -0,0 +1,158 @@
+//! Minimal HTTP/1.1 Plumbing
+//!
+//! Just enough HTTP for the servers in this module: one request per
+//! connection, `Content-Length` bodies, `Connection: close` responses.
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+pub(crate) struct Request {
+    pub method: String,
+    /// Path and query as sent
+    pub target: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    /// Header names are lowercase
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+pub(crate) struct Response {
+    pub status: u16,
+    pub content_type: String,
+    pub body: String,
+}
+
+impl Response {
+    pub fn json(value: serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/json".to_string(),
+            body: value.to_string(),
+        }
+    }
+
+    pub fn text(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8".to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            500 => "Internal Server Error",
+            502 => "Bad Gateway",
+            _ => "",
+        };
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len(),
+            self.body
+        )
+        .into_bytes()
+    }
+}
+
+/// Accept connections until the returned task is aborted, answering each
+/// request with `handler`
+pub(crate) fn serve<H, F>(listener: TcpListener, handler: H) -> JoinHandle<()>
+where
+    H: Fn(Request) -> F + Send + Sync + 'static,
+    F: Future<Output = Response> + Send + 'static,
+{
+    let handler = Arc::new(handler);
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let _ = handle_connection(stream, handler.as_ref()).await;
+            });
+        }
+    })
+}
+
+async fn handle_connection<H, F>(stream: TcpStream, handler: &H) -> Result<()>
+where
+    H: Fn(Request) -> F,
+    F: Future<Output = Response>,
+{
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target.clone(), HashMap::new()),
+    };
+    let request = Request {
+        method,
+        target,
+        path,
+        query,
+        headers,
+        body,
+    };
+
+    let response = handler(request).await;
+    writer.write_all(&response.to_bytes()).await?;
+    writer.shutdown().await?;
+    Ok(())
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    let decode = |s: &str| {
+        urlencoding::decode(s)
+            .map(|s| s.into_owned())
+            .unwrap_or_else(|_| s.to_string())
+    };
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (decode(key), decode(value)),
+            None => (decode(pair), String::new()),
+        })
+        .collect()
+}
@@ -0,0 +1,30 @@
+//! Mock Syncthing
+//!
+//! Stand-ins for a live Syncthing connection, for local development, bug
+//! reports and integration tests:
+//! - fixture: YAML description of devices, folders, files and scripted events
+//! - server: HTTP server answering the endpoints `SyncthingClient` uses
+//! - recording: JSONL format for recorded events and API responses
+//! - proxy: forwards to a real instance and records what it sends
+//! - replay: serves a recording in place of the instance
+//!
+//! The fixture server is only built for tests and with the `mock` feature;
+//! recording and replay back `--record-events` / `--replay-events`.
+
+mod http;
+
+#[cfg(any(test, feature = "mock"))]
+pub mod fixture;
+pub mod proxy;
+pub mod recording;
+pub mod replay;
+#[cfg(any(test, feature = "mock"))]
+pub mod server;
+
+#[cfg(any(test, feature = "mock"))]
+pub use fixture::{FileFixture, FileState, FileUpdate, Fixture};
+pub use proxy::RecordingProxy;
+pub use recording::{RecordedEntry, Recorder, Recording};
+pub use replay::ReplayServer;
+#[cfg(any(test, feature = "mock"))]
+pub use server::MockServer;
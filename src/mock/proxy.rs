@@ -0,0 +1,123 @@
+//! Recording Proxy
+//!
+//! Sits between stui and a real Syncthing instance, forwarding every request
+//! and writing what came back to a `Recorder`. Events from the listener's
+//! long-poll are recorded one by one; everything else as API responses.
+
+use super::http::{self, Request, Response};
+use super::recording::{RecordedEntry, Recorder};
+use anyhow::{Context, Result};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A running recording proxy; dropping it stops forwarding
+pub struct RecordingProxy {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+struct Upstream {
+    base_url: String,
+    client: reqwest::Client,
+    recorder: Recorder,
+}
+
+impl RecordingProxy {
+    /// Forward to `upstream` (a Syncthing base URL) from a free port on 127.0.0.1
+    pub async fn start(upstream: &str, recorder: Recorder) -> Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .context("Failed to bind recording proxy")?;
+        let addr = listener.local_addr()?;
+
+        let upstream = Arc::new(Upstream {
+            base_url: upstream.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            recorder,
+        });
+        let task = http::serve(listener, move |request| {
+            let upstream = upstream.clone();
+            async move { upstream.forward(request).await }
+        });
+
+        Ok(Self { addr, task })
+    }
+
+    /// Base URL to connect to instead of the instance
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for RecordingProxy {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Upstream {
+    async fn forward(&self, request: Request) -> Response {
+        let response = match self.send(&request).await {
+            Ok(response) => response,
+            Err(e) => Response::text(502, &format!("upstream: {:#}", e)),
+        };
+        let t_ms = self.recorder.elapsed_ms();
+
+        // The event listener's long-poll (history queries pass a limit and are
+        // answered from the recorded stream on replay)
+        let is_event_stream = request.method == "GET"
+            && request.path == "/rest/events"
+            && !request.query.contains_key("limit");
+
+        let entries = match serde_json::from_str::<Vec<serde_json::Value>>(&response.body) {
+            Ok(events) if is_event_stream && response.status == 200 => events
+                .into_iter()
+                .map(|event| RecordedEntry::Event { t_ms, event })
+                .collect(),
+            _ => vec![RecordedEntry::Api {
+                t_ms,
+                method: request.method.clone(),
+                path: request.target.clone(),
+                status: response.status,
+                body: response.body.clone(),
+            }],
+        };
+        // A failing write (e.g. full disk) shouldn't break the session being recorded
+        for entry in &entries {
+            let _ = self.recorder.record(entry);
+        }
+
+        response
+    }
+
+    async fn send(&self, request: &Request) -> Result<Response> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())?;
+        let mut builder = self
+            .client
+            .request(method, format!("{}{}", self.base_url, request.target))
+            .body(request.body.clone());
+        for header in ["x-api-key", "content-type"] {
+            if let Some(value) = request.headers.get(header) {
+                builder = builder.header(header, value);
+            }
+        }
+
+        let upstream = builder.send().await?;
+        let status = upstream.status().as_u16();
+        let content_type = upstream
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("text/plain")
+            .to_string();
+        let body = upstream.text().await?;
+
+        Ok(Response {
+            status,
+            content_type,
+            body,
+        })
+    }
+}
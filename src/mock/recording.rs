@@ -0,0 +1,143 @@
+//! Event Recordings
+//!
+//! JSONL files of everything a Syncthing instance sent during a session: each
+//! raw event from /rest/events and every other API response, stamped with
+//! the milliseconds since recording started.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// One line of a recording
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RecordedEntry {
+    /// A raw `SyncthingEvent` as delivered by /rest/events
+    Event { t_ms: u64, event: serde_json::Value },
+    /// The response to any other request
+    Api {
+        t_ms: u64,
+        method: String,
+        /// Path and query, e.g. `/rest/db/browse?folder=docs`
+        path: String,
+        status: u16,
+        body: String,
+    },
+}
+
+impl RecordedEntry {
+    pub fn t_ms(&self) -> u64 {
+        match self {
+            RecordedEntry::Event { t_ms, .. } | RecordedEntry::Api { t_ms, .. } => *t_ms,
+        }
+    }
+}
+
+/// A loaded recording
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    pub entries: Vec<RecordedEntry>,
+}
+
+impl Recording {
+    /// Parse JSONL text; blank lines are skipped
+    pub fn from_jsonl(text: &str) -> Result<Self> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| serde_json::from_str(line).with_context(|| format!("line {}", i + 1)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { entries })
+    }
+
+    /// Read a recording file
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        Self::from_jsonl(&text).with_context(|| format!("Invalid recording {}", path.display()))
+    }
+
+    /// Raw events in recorded order
+    pub fn events(&self) -> impl Iterator<Item = &serde_json::Value> {
+        self.entries.iter().filter_map(|entry| match entry {
+            RecordedEntry::Event { event, .. } => Some(event),
+            RecordedEntry::Api { .. } => None,
+        })
+    }
+}
+
+/// Appends entries to a recording file as they happen
+///
+/// Every entry is flushed right away so a crash still leaves a usable file.
+pub struct Recorder {
+    started: Instant,
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl Recorder {
+    /// Create (or truncate) the recording file
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create recording {}", path.display()))?;
+        Ok(Self {
+            started: Instant::now(),
+            writer: Mutex::new(BufWriter::new(file)),
+        })
+    }
+
+    /// Milliseconds since the recording started
+    pub fn elapsed_ms(&self) -> u64 {
+        self.started.elapsed().as_millis() as u64
+    }
+
+    pub fn record(&self, entry: &RecordedEntry) -> Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        serde_json::to_writer(&mut *writer, entry)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_round_trip_through_jsonl() {
+        let entries = vec![
+            RecordedEntry::Api {
+                t_ms: 0,
+                method: "GET".to_string(),
+                path: "/rest/db/status?folder=docs".to_string(),
+                status: 200,
+                body: "{\"state\":\"idle\"}".to_string(),
+            },
+            RecordedEntry::Event {
+                t_ms: 15,
+                event: serde_json::json!({ "id": 7, "type": "ItemStarted" }),
+            },
+        ];
+        let text: String = entries
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+
+        let recording = Recording::from_jsonl(&text).unwrap();
+        assert_eq!(recording.entries, entries);
+        assert_eq!(recording.events().count(), 1);
+        assert!(text.contains("\"kind\":\"event\""));
+    }
+
+    #[test]
+    fn test_invalid_line_is_reported() {
+        let err = Recording::from_jsonl("\n{\"kind\":\"event\",\"t_ms\":1,\"event\":{}}\nnope\n")
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("line 3"));
+    }
+}
@@ -0,0 +1,196 @@
+//! Replay Server
+//!
+//! Serves a `Recording` in place of a live Syncthing instance. Events are
+//! released at their recorded offsets (scaled by the replay speed) and API
+//! requests get the latest response recorded for the same method and path
+//! at that point of the session.
+
+use super::http::{self, Request, Response};
+use super::recording::{RecordedEntry, Recording};
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// A running replay; dropping it stops serving
+pub struct ReplayServer {
+    addr: SocketAddr,
+    replay: Arc<Replay>,
+    task: JoinHandle<()>,
+}
+
+struct Replay {
+    started: Instant,
+    speed: f64,
+    /// Recorded responses per (method, path with query), in recorded order
+    responses: HashMap<(String, String), Vec<RecordedResponse>>,
+    /// (offset, event ID, raw event) in recorded order
+    events: Vec<(u64, u64, Value)>,
+}
+
+struct RecordedResponse {
+    t_ms: u64,
+    status: u16,
+    body: String,
+}
+
+impl ReplayServer {
+    /// Start replaying on a free port on 127.0.0.1
+    ///
+    /// `speed` scales time: 1.0 replays in real time, 10.0 ten times faster.
+    pub async fn start(recording: Recording, speed: f64) -> Result<Self> {
+        if !(speed > 0.0 && speed.is_finite()) {
+            bail!("Replay speed must be a positive number, got {}", speed);
+        }
+
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .context("Failed to bind replay server")?;
+        let addr = listener.local_addr()?;
+
+        let mut responses: HashMap<(String, String), Vec<RecordedResponse>> = HashMap::new();
+        let mut events = Vec::new();
+        for entry in recording.entries {
+            match entry {
+                RecordedEntry::Event { t_ms, event } => {
+                    let id = event.get("id").and_then(Value::as_u64).unwrap_or(0);
+                    events.push((t_ms, id, event));
+                }
+                RecordedEntry::Api {
+                    t_ms,
+                    method,
+                    path,
+                    status,
+                    body,
+                } => responses
+                    .entry((method, path))
+                    .or_default()
+                    .push(RecordedResponse { t_ms, status, body }),
+            }
+        }
+
+        let replay = Arc::new(Replay {
+            started: Instant::now(),
+            speed,
+            responses,
+            events,
+        });
+        let handler_replay = replay.clone();
+        let task = http::serve(listener, move |request| {
+            let replay = handler_replay.clone();
+            async move { replay.respond(request).await }
+        });
+
+        Ok(Self { addr, replay, task })
+    }
+
+    /// Base URL to connect to instead of the instance
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Whether every recorded event has been released
+    pub fn is_finished(&self) -> bool {
+        let last = self.replay.events.last().map_or(0, |(t_ms, _, _)| *t_ms);
+        self.replay.elapsed_ms() >= last
+    }
+}
+
+impl Drop for ReplayServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Replay {
+    /// Recorded milliseconds that have played so far
+    fn elapsed_ms(&self) -> u64 {
+        (self.started.elapsed().as_secs_f64() * 1000.0 * self.speed) as u64
+    }
+
+    /// When the event recorded at `t_ms` is released
+    fn release_at(&self, t_ms: u64) -> Instant {
+        self.started + Duration::from_secs_f64(t_ms as f64 / 1000.0 / self.speed)
+    }
+
+    async fn respond(&self, request: Request) -> Response {
+        if request.method == "GET" && request.path == "/rest/events" {
+            return self.events(&request).await;
+        }
+
+        let key = (request.method.clone(), request.target.clone());
+        let Some(recorded) = self.responses.get(&key) else {
+            // Unrecorded writes succeed so the UI keeps going; reads weren't seen
+            return if request.method == "GET" {
+                Response::text(404, "not in recording")
+            } else {
+                Response::text(200, "")
+            };
+        };
+
+        // Latest response recorded by now, or the first one if it's still ahead
+        let now = self.elapsed_ms();
+        let response = recorded
+            .iter()
+            .rev()
+            .find(|r| r.t_ms <= now)
+            .unwrap_or(&recorded[0]);
+
+        let content_type = if serde_json::from_str::<Value>(&response.body).is_ok() {
+            "application/json"
+        } else {
+            "text/plain; charset=utf-8"
+        };
+        Response {
+            status: response.status,
+            content_type: content_type.to_string(),
+            body: response.body.clone(),
+        }
+    }
+
+    /// GET /rest/events: released events after `since`, waiting for the next
+    /// one until the timeout expires
+    async fn events(&self, request: &Request) -> Response {
+        let query = &request.query;
+        let since: u64 = query.get("since").and_then(|v| v.parse().ok()).unwrap_or(0);
+        let limit: Option<usize> = query.get("limit").and_then(|v| v.parse().ok());
+        let timeout: u64 = query
+            .get("timeout")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60);
+        let deadline = Instant::now() + Duration::from_secs(timeout);
+
+        loop {
+            let now = self.elapsed_ms();
+            let pending: Vec<Value> = self
+                .events
+                .iter()
+                .filter(|(t_ms, id, _)| *t_ms <= now && *id > since)
+                .map(|(_, _, event)| event.clone())
+                .collect();
+            if !pending.is_empty() {
+                let start = limit.map_or(0, |limit| pending.len().saturating_sub(limit));
+                return Response::json(Value::Array(pending[start..].to_vec()));
+            }
+
+            let next = self
+                .events
+                .iter()
+                .find(|(t_ms, id, _)| *t_ms > now && *id > since);
+            match next {
+                Some((t_ms, _, _)) if self.release_at(*t_ms) < deadline => {
+                    tokio::time::sleep_until(self.release_at(*t_ms)).await;
+                }
+                _ => {
+                    tokio::time::sleep_until(deadline).await;
+                    return Response::json(Value::Array(vec![]));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1037 @@
+//! Mock Server
+//!
+//! Answers the REST endpoints `SyncthingClient` and the event listener use,
+//! from the state described by a `Fixture`.
+
+use super::fixture::{FileFixture, FileState, FileUpdate, Fixture, FolderFixture};
+use super::http::{self, Request, Response};
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+/// A running mock Syncthing instance
+///
+/// Dropping it stops accepting connections and cancels scripted events.
+pub struct MockServer {
+    addr: SocketAddr,
+    api_key: String,
+    shared: Arc<Shared>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    /// Wakes long-polling /rest/events requests
+    new_event: Notify,
+}
+
+struct State {
+    fixture: Fixture,
+    /// Sequence number of each file, keyed by (folder ID, path)
+    file_sequences: HashMap<(String, String), u64>,
+    /// Highest sequence number per folder
+    folder_sequences: HashMap<String, u64>,
+    events: Vec<Value>,
+    requests: Vec<String>,
+    /// Files moved to the front of the download queue with /rest/db/prio, per folder
+    prioritized: HashMap<String, Vec<String>>,
+    /// Folder config objects written with PUT /rest/config/folders/{id}
+    folder_configs: HashMap<String, Value>,
+    /// Fields changed with PATCH /rest/config/devices/{id}, per device
+    device_patches: HashMap<String, serde_json::Map<String, Value>>,
+    /// The `options` section of the config
+    options: Value,
+    /// Saved options that only apply after a restart
+    restart_required: bool,
+}
+
+/// A file or directory as the index sees it (directories may be implied by file paths)
+#[derive(Debug, Clone)]
+struct Entry {
+    is_dir: bool,
+    size: u64,
+    modified: String,
+    state: FileState,
+}
+
+impl MockServer {
+    /// Start serving on a free port on 127.0.0.1
+    pub async fn start(fixture: Fixture) -> Result<Self> {
+        Self::bind(fixture, SocketAddr::from(([127, 0, 0, 1], 0))).await
+    }
+
+    /// Start serving on the given address
+    pub async fn bind(fixture: Fixture, addr: SocketAddr) -> Result<Self> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .with_context(|| format!("Failed to bind mock server to {}", addr))?;
+        let addr = listener.local_addr()?;
+        let api_key = fixture.api_key.clone();
+        let scripted = fixture.events.clone();
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::new(fixture)),
+            new_event: Notify::new(),
+        });
+
+        let handler_shared = shared.clone();
+        let accept_task = http::serve(listener, move |request| {
+            let shared = handler_shared.clone();
+            async move { respond(&shared, request).await }
+        });
+
+        let script_shared = shared.clone();
+        let script_task = tokio::spawn(async move {
+            let start = tokio::time::Instant::now();
+            let mut scripted = scripted;
+            scripted.sort_by_key(|event| event.after_ms);
+            for event in scripted {
+                tokio::time::sleep_until(start + Duration::from_millis(event.after_ms)).await;
+                if let Some(update) = &event.update {
+                    script_shared.state.lock().unwrap().update_files(update);
+                }
+                script_shared.emit(&event.event_type, event.data);
+            }
+        });
+
+        Ok(Self {
+            addr,
+            api_key,
+            shared,
+            tasks: vec![accept_task, script_task],
+        })
+    }
+
+    /// Base URL to pass to `SyncthingClient`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Emit an event to /rest/events listeners; returns its ID
+    pub fn emit(&self, event_type: &str, data: Value) -> u64 {
+        self.shared.emit(event_type, data)
+    }
+
+    /// Add, replace or remove files (bumps the folder sequence like a real index update)
+    pub fn update_files(&self, update: &FileUpdate) {
+        self.shared.state.lock().unwrap().update_files(update);
+    }
+
+    /// Requests served so far, as "METHOD /path?query"
+    pub fn requests(&self) -> Vec<String> {
+        self.shared.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+impl Shared {
+    fn emit(&self, event_type: &str, data: Value) -> u64 {
+        let id = {
+            let mut state = self.state.lock().unwrap();
+            let id = state.events.len() as u64 + 1;
+            state.events.push(json!({
+                "id": id,
+                "globalID": id,
+                "time": chrono::Utc::now().to_rfc3339(),
+                "type": event_type,
+                "data": data,
+            }));
+            id
+        };
+        self.new_event.notify_waiters();
+        id
+    }
+
+    /// GET /rest/events: wait until there are events after `since` or the timeout expires
+    async fn events(&self, query: &HashMap<String, String>) -> Response {
+        let since: u64 = query.get("since").and_then(|v| v.parse().ok()).unwrap_or(0);
+        let limit: Option<usize> = query.get("limit").and_then(|v| v.parse().ok());
+        let timeout: u64 = query
+            .get("timeout")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(60);
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout);
+
+        loop {
+            // Register interest before checking so an event in between isn't missed
+            let notified = self.new_event.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            let pending: Vec<Value> = {
+                let state = self.state.lock().unwrap();
+                let skip = (since as usize).min(state.events.len());
+                state.events[skip..].to_vec()
+            };
+            if !pending.is_empty() {
+                let start = limit.map_or(0, |limit| pending.len().saturating_sub(limit));
+                return Response::json(Value::Array(pending[start..].to_vec()));
+            }
+
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return Response::json(json!([]));
+            }
+        }
+    }
+}
+
+async fn respond(shared: &Shared, request: Request) -> Response {
+    let api_key = {
+        let mut state = shared.state.lock().unwrap();
+        state
+            .requests
+            .push(format!("{} {}", request.method, request.target));
+        state.fixture.api_key.clone()
+    };
+
+    if request.headers.get("x-api-key") != Some(&api_key) {
+        return Response::text(403, "CSRF Error");
+    }
+
+    if request.method == "GET" && request.path == "/rest/events" {
+        return shared.events(&request.query).await;
+    }
+
+    shared.state.lock().unwrap().route(&request)
+}
+
+impl State {
+    fn new(fixture: Fixture) -> Self {
+        let mut state = Self {
+            fixture,
+            file_sequences: HashMap::new(),
+            folder_sequences: HashMap::new(),
+            events: Vec::new(),
+            requests: Vec::new(),
+            prioritized: HashMap::new(),
+            folder_configs: HashMap::new(),
+            device_patches: HashMap::new(),
+            options: json!({ "maxSendKbps": 0, "maxRecvKbps": 0 }),
+            restart_required: false,
+        };
+
+        for folder in &state.fixture.folders {
+            for (i, file) in folder.files.iter().enumerate() {
+                state
+                    .file_sequences
+                    .insert((folder.id.clone(), file.name().to_string()), i as u64 + 1);
+            }
+            state
+                .folder_sequences
+                .insert(folder.id.clone(), folder.files.len() as u64);
+        }
+
+        state
+    }
+
+    fn update_files(&mut self, update: &FileUpdate) {
+        let Some(folder) = self
+            .fixture
+            .folders
+            .iter_mut()
+            .find(|f| f.id == update.folder)
+        else {
+            return;
+        };
+        let sequence = self.folder_sequences.entry(folder.id.clone()).or_default();
+
+        for path in &update.remove {
+            let path = path.trim_end_matches('/');
+            folder.files.retain(|file| file.name() != path);
+            *sequence += 1;
+        }
+
+        for file in &update.files {
+            *sequence += 1;
+            self.file_sequences
+                .insert((folder.id.clone(), file.name().to_string()), *sequence);
+            match folder.files.iter_mut().find(|f| f.name() == file.name()) {
+                Some(existing) => *existing = file.clone(),
+                None => folder.files.push(file.clone()),
+            }
+        }
+    }
+
+    fn route(&mut self, request: &Request) -> Response {
+        let query = &request.query;
+        let folder = || {
+            query
+                .get("folder")
+                .and_then(|id| self.fixture.folders.iter().find(|f| &f.id == id))
+        };
+
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/rest/system/config") => Response::json(json!({
+                "version": 37,
+                "folders": self
+                    .fixture
+                    .folders
+                    .iter()
+                    .map(|folder| self.folder_config(folder))
+                    .collect::<Vec<_>>(),
+                "devices": self.device_configs(),
+            })),
+            ("GET", "/rest/config/devices") => Response::json(Value::Array(self.device_configs())),
+            ("PATCH", path) if path.starts_with("/rest/config/devices/") => {
+                let id = urlencoding::decode(path.trim_start_matches("/rest/config/devices/"))
+                    .map(|id| id.into_owned())
+                    .unwrap_or_default();
+                let Ok(Value::Object(patch)) = serde_json::from_slice(&request.body) else {
+                    return Response::text(400, "invalid device patch");
+                };
+                if id != self.fixture.my_id && !self.fixture.devices.iter().any(|d| d.id == id) {
+                    return Response::text(404, "no such device");
+                }
+                self.device_patches.entry(id).or_default().extend(patch);
+                Response::text(200, "")
+            }
+            ("GET", "/rest/config/options") => Response::json(self.options.clone()),
+            ("PATCH", "/rest/config/options") => {
+                let Ok(Value::Object(patch)) = serde_json::from_slice(&request.body) else {
+                    return Response::text(400, "invalid options patch");
+                };
+                for (key, value) in patch {
+                    // Bandwidth limits apply immediately, other options on restart
+                    if key != "maxSendKbps" && key != "maxRecvKbps" {
+                        self.restart_required = true;
+                    }
+                    self.options[key] = value;
+                }
+                Response::text(200, "")
+            }
+            ("GET", "/rest/config/restart-required") => Response::json(json!({
+                "requiresRestart": self.restart_required,
+            })),
+            ("GET", "/rest/system/version") => Response::json(json!({
+                "arch": "amd64",
+                "codename": "Gold Grasshopper",
+                "longVersion": "syncthing v1.27.0 \"Gold Grasshopper\" (go1.21.5 linux-amd64) mock",
+                "os": "linux",
+                "version": "v1.27.0",
+            })),
+            ("POST", "/rest/system/restart") => {
+                // The server keeps answering; the uptime starts over
+                self.fixture.uptime = 0;
+                self.restart_required = false;
+                Response::json(json!({ "ok": "restarting" }))
+            }
+            ("POST", "/rest/system/shutdown") => Response::json(json!({ "ok": "shutting down" })),
+            ("GET", "/rest/system/status") => Response::json(json!({
+                "myID": self.fixture.my_id,
+                "uptime": self.fixture.uptime,
+                "startTime": "2025-01-01T00:00:00Z",
+            })),
+            ("GET", "/rest/system/connections") => Response::json(self.connections()),
+            ("GET", "/rest/system/log") => Response::json(json!({ "messages": [] })),
+            ("GET", "/rest/system/error") => Response::json(json!({ "errors": null })),
+            ("GET", "/rest/stats/folder") => Response::json(self.folder_stats()),
+            ("GET", "/rest/db/browse") => match folder() {
+                Some(folder) => Response::json(browse(folder, query.get("prefix"))),
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/file") => match folder() {
+                Some(folder) => {
+                    let file = query.get("file").map(String::as_str).unwrap_or_default();
+                    match self.file_details(folder, file) {
+                        Some(details) => Response::json(details),
+                        None => Response::text(404, "No such object in the index"),
+                    }
+                }
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/status") => match folder() {
+                Some(folder) => Response::json(self.folder_status(folder)),
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/need") => match folder() {
+                Some(folder) => {
+                    let page = query.get("page").and_then(|v| v.parse().ok()).unwrap_or(1);
+                    let perpage = query
+                        .get("perpage")
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(100);
+                    Response::json(self.need(folder, page, perpage))
+                }
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/completion") => match folder() {
+                Some(folder) => {
+                    let device = query.get("device").cloned().unwrap_or_default();
+                    Response::json(self.completion(folder, &device))
+                }
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/remoteneed") => match folder() {
+                Some(folder) => {
+                    let device = query.get("device").cloned().unwrap_or_default();
+                    let page = query.get("page").and_then(|v| v.parse().ok()).unwrap_or(1);
+                    let perpage = query
+                        .get("perpage")
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(100);
+                    Response::json(self.remote_need(folder, &device, page, perpage))
+                }
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/localchanged") => match folder() {
+                Some(folder) => Response::json(self.local_changed(folder)),
+                None => Response::text(404, "no such folder"),
+            },
+            ("GET", "/rest/db/ignores") => match folder() {
+                Some(folder) => Response::json(json!({
+                    "ignore": folder.ignores,
+                    "expanded": folder.ignores,
+                })),
+                None => Response::text(404, "no such folder"),
+            },
+            ("POST", "/rest/db/ignores") => {
+                #[derive(serde::Deserialize)]
+                struct IgnoresRequest {
+                    ignore: Vec<String>,
+                }
+                let Ok(body) = serde_json::from_slice::<IgnoresRequest>(&request.body) else {
+                    return Response::text(400, "invalid ignores body");
+                };
+                let id = query.get("folder").cloned().unwrap_or_default();
+                match self.fixture.folders.iter_mut().find(|f| f.id == id) {
+                    Some(folder) => {
+                        folder.ignores = body.ignore;
+                        Response::json(json!({
+                            "ignore": folder.ignores,
+                            "expanded": folder.ignores,
+                        }))
+                    }
+                    None => Response::text(404, "no such folder"),
+                }
+            }
+            ("POST", "/rest/db/prio") => {
+                let Some(id) = folder().map(|f| f.id.clone()) else {
+                    return Response::text(404, "no such folder");
+                };
+                let file = query.get("file").cloned().unwrap_or_default();
+                let queue = self.prioritized.entry(id.clone()).or_default();
+                queue.retain(|path| path != &file);
+                queue.insert(0, file);
+                let folder = self.fixture.folders.iter().find(|f| f.id == id).unwrap();
+                Response::json(self.need(folder, 1, 100))
+            }
+            ("POST", "/rest/db/override") => {
+                let id = query.get("folder").cloned().unwrap_or_default();
+                let Some(folder) = self.fixture.folders.iter_mut().find(|f| f.id == id) else {
+                    return Response::text(404, "no such folder");
+                };
+                // Only send-only folders override; the local version becomes global
+                if folder.folder_type == "sendonly" {
+                    folder
+                        .files
+                        .retain(|file| file.state != FileState::RemoteOnly);
+                    for file in &mut folder.files {
+                        if matches!(file.state, FileState::OutOfSync | FileState::Syncing) {
+                            file.state = FileState::Synced;
+                        }
+                    }
+                    *self.folder_sequences.entry(id).or_default() += 1;
+                }
+                Response::text(200, "")
+            }
+            ("POST", "/rest/db/scan") => match folder() {
+                Some(_) => Response::text(200, ""),
+                None => Response::text(404, "no such folder"),
+            },
+            ("POST", "/rest/config/folders") => {
+                let Ok(config) = serde_json::from_slice::<Value>(&request.body) else {
+                    return Response::text(400, "invalid folder config");
+                };
+                let Some(id) = config["id"].as_str().filter(|id| !id.is_empty()) else {
+                    return Response::text(400, "folder ID is required");
+                };
+                if self.fixture.folders.iter().any(|f| f.id == id) {
+                    return Response::text(400, "folder ID already exists");
+                }
+                self.fixture.folders.push(FolderFixture {
+                    id: id.to_string(),
+                    label: config["label"].as_str().map(str::to_string),
+                    path: config["path"].as_str().map(str::to_string),
+                    folder_type: config["type"].as_str().unwrap_or("sendreceive").to_string(),
+                    paused: config["paused"].as_bool().unwrap_or(false),
+                    state: "idle".to_string(),
+                    devices: config["devices"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|d| d["deviceID"].as_str().map(str::to_string))
+                        .collect(),
+                    ignores: Vec::new(),
+                    files: Vec::new(),
+                });
+                Response::text(200, "")
+            }
+            ("DELETE", path) if path.starts_with("/rest/config/folders/") => {
+                let id = folder_id_from_path(path);
+                let before = self.fixture.folders.len();
+                self.fixture.folders.retain(|f| f.id != id);
+                if self.fixture.folders.len() == before {
+                    return Response::text(404, "no such folder");
+                }
+                self.folder_configs.remove(&id);
+                self.prioritized.remove(&id);
+                Response::text(200, "")
+            }
+            ("GET", path) if path.starts_with("/rest/config/folders/") => {
+                let id = folder_id_from_path(path);
+                match self.fixture.folders.iter().find(|f| f.id == id) {
+                    Some(folder) => Response::json(self.folder_config(folder)),
+                    None => Response::text(404, "no such folder"),
+                }
+            }
+            ("PUT", path) if path.starts_with("/rest/config/folders/") => {
+                let id = folder_id_from_path(path);
+                let Ok(config) = serde_json::from_slice::<Value>(&request.body) else {
+                    return Response::text(400, "invalid folder config");
+                };
+                let Some(folder) = self.fixture.folders.iter_mut().find(|f| f.id == id) else {
+                    return Response::text(404, "no such folder");
+                };
+                if let Some(label) = config["label"].as_str() {
+                    folder.label = Some(label.to_string());
+                }
+                if let Some(folder_type) = config["type"].as_str() {
+                    folder.folder_type = folder_type.to_string();
+                }
+                if let Some(paused) = config["paused"].as_bool() {
+                    folder.paused = paused;
+                }
+                if let Some(devices) = config["devices"].as_array() {
+                    folder.devices = devices
+                        .iter()
+                        .filter_map(|d| d["deviceID"].as_str().map(str::to_string))
+                        .collect();
+                }
+                self.folder_configs.insert(id, config);
+                Response::text(200, "")
+            }
+            _ => Response::text(404, "404 page not found"),
+        }
+    }
+
+    /// Config object of a folder, as last written with PUT if it was
+    fn folder_config(&self, folder: &FolderFixture) -> Value {
+        self.folder_configs
+            .get(&folder.id)
+            .cloned()
+            .unwrap_or_else(|| folder_config(folder))
+    }
+
+    fn device_configs(&self) -> Vec<Value> {
+        let local = json!({
+            "deviceID": self.fixture.my_id,
+            "name": self.fixture.device_name,
+            "addresses": ["dynamic"],
+            "compression": "metadata",
+            "introducer": false,
+            "paused": false,
+            "maxSendKbps": 0,
+            "maxRecvKbps": 0,
+        });
+        std::iter::once(local)
+            .chain(self.fixture.devices.iter().map(|device| {
+                let addresses = if device.address.is_empty() {
+                    vec!["dynamic".to_string()]
+                } else {
+                    vec![format!("tcp://{}", device.address)]
+                };
+                json!({
+                    "deviceID": device.id,
+                    "name": device.name,
+                    "addresses": addresses,
+                    "compression": "metadata",
+                    "introducer": false,
+                    "paused": device.paused,
+                    "maxSendKbps": 0,
+                    "maxRecvKbps": 0,
+                })
+            }))
+            .map(|mut config| {
+                if let Some(patch) = config["deviceID"]
+                    .as_str()
+                    .and_then(|id| self.device_patches.get(id))
+                {
+                    for (key, value) in patch {
+                        config[key] = value.clone();
+                    }
+                }
+                config
+            })
+            .collect()
+    }
+
+    fn connections(&self) -> Value {
+        let devices = &self.fixture.devices;
+        let connections: serde_json::Map<String, Value> = devices
+            .iter()
+            .map(|device| {
+                (
+                    device.id.clone(),
+                    json!({
+                        "connected": device.connected,
+                        "paused": device.paused,
+                        "address": if device.connected { device.address.as_str() } else { "" },
+                        "inBytesTotal": device.in_bytes,
+                        "outBytesTotal": device.out_bytes,
+                    }),
+                )
+            })
+            .collect();
+
+        json!({
+            "total": {
+                "at": chrono::Utc::now().to_rfc3339(),
+                "inBytesTotal": devices.iter().map(|d| d.in_bytes).sum::<u64>(),
+                "outBytesTotal": devices.iter().map(|d| d.out_bytes).sum::<u64>(),
+            },
+            "connections": connections,
+        })
+    }
+
+    /// Last modified file per folder
+    fn folder_stats(&self) -> Value {
+        let stats: serde_json::Map<String, Value> = self
+            .fixture
+            .folders
+            .iter()
+            .map(|folder| {
+                let last = folder
+                    .files
+                    .iter()
+                    .filter(|file| !file.is_dir())
+                    .max_by(|a, b| a.modified.cmp(&b.modified));
+                (
+                    folder.id.clone(),
+                    json!({
+                        "lastFile": {
+                            "at": last.map_or("1970-01-01T00:00:00Z", |f| f.modified.as_str()),
+                            "filename": last.map_or("", |f| f.name()),
+                            "deleted": false,
+                        },
+                        "lastScan": "2025-01-01T12:00:00Z",
+                    }),
+                )
+            })
+            .collect();
+        Value::Object(stats)
+    }
+
+    fn sequence(&self, folder: &FolderFixture, path: &str) -> u64 {
+        self.file_sequences
+            .get(&(folder.id.clone(), path.to_string()))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    fn file_info(&self, folder: &FolderFixture, path: &str, entry: &Entry) -> Value {
+        file_info(path, entry, self.sequence(folder, path), "v1")
+    }
+
+    fn file_details(&self, folder: &FolderFixture, path: &str) -> Option<Value> {
+        let path = path.trim_end_matches('/');
+        let entries = entries(folder, true);
+        let entry = entries.get(path)?;
+        let sequence = self.sequence(folder, path);
+
+        let availability: Vec<Value> = self
+            .fixture
+            .devices
+            .iter()
+            .filter(|device| device.connected && folder.devices.contains(&device.id))
+            .map(|device| json!({ "id": device.id, "fromTemporary": false }))
+            .collect();
+
+        let local = file_info(path, entry, sequence, "v1");
+        let (local, global, availability) = match entry.state {
+            FileState::Synced => (local.clone(), local, availability),
+            FileState::OutOfSync | FileState::Syncing => (
+                local,
+                file_info(path, entry, sequence + 1, "v2"),
+                availability,
+            ),
+            FileState::RemoteOnly => {
+                let mut missing = local.clone();
+                missing["sequence"] = json!(0);
+                (missing, local, availability)
+            }
+            FileState::LocalOnly => {
+                let mut local = local;
+                local["invalid"] = json!(folder.folder_type == "receiveonly");
+                (local, Value::Null, vec![])
+            }
+            FileState::Ignored => {
+                let mut local = local;
+                local["ignored"] = json!(true);
+                (local, Value::Null, vec![])
+            }
+        };
+
+        Some(json!({
+            "local": local,
+            "global": global,
+            "availability": availability,
+        }))
+    }
+
+    fn folder_status(&self, folder: &FolderFixture) -> Value {
+        #[derive(Default)]
+        struct Counts {
+            files: u64,
+            directories: u64,
+            bytes: u64,
+        }
+        impl Counts {
+            fn add(&mut self, entry: &Entry) {
+                if entry.is_dir {
+                    self.directories += 1;
+                } else {
+                    self.files += 1;
+                    self.bytes += entry.size;
+                }
+            }
+            fn items(&self) -> u64 {
+                self.files + self.directories
+            }
+        }
+
+        let mut global = Counts::default();
+        let mut local = Counts::default();
+        let mut need = Counts::default();
+        let mut local_changed = Counts::default();
+
+        for entry in entries(folder, true).values() {
+            match entry.state {
+                FileState::Synced => {
+                    global.add(entry);
+                    local.add(entry);
+                }
+                FileState::OutOfSync | FileState::Syncing => {
+                    global.add(entry);
+                    local.add(entry);
+                    need.add(entry);
+                }
+                FileState::RemoteOnly => {
+                    global.add(entry);
+                    need.add(entry);
+                }
+                FileState::LocalOnly => {
+                    local.add(entry);
+                    local_changed.add(entry);
+                }
+                FileState::Ignored => {}
+            }
+        }
+
+        let receive_only = folder.folder_type == "receiveonly";
+        let changed = |value: u64| if receive_only { value } else { 0 };
+
+        json!({
+            "state": folder.state,
+            "sequence": self.folder_sequences.get(&folder.id).copied().unwrap_or(0),
+            "globalBytes": global.bytes,
+            "globalDeleted": 0,
+            "globalDirectories": global.directories,
+            "globalFiles": global.files,
+            "globalSymlinks": 0,
+            "globalTotalItems": global.items(),
+            "inSyncBytes": global.bytes - need.bytes,
+            "inSyncFiles": global.files - need.files,
+            "localBytes": local.bytes,
+            "localDeleted": 0,
+            "localDirectories": local.directories,
+            "localFiles": local.files,
+            "localSymlinks": 0,
+            "localTotalItems": local.items(),
+            "needBytes": need.bytes,
+            "needDeletes": 0,
+            "needDirectories": need.directories,
+            "needFiles": need.files,
+            "needSymlinks": 0,
+            "needTotalItems": need.items(),
+            "receiveOnlyChangedBytes": changed(local_changed.bytes),
+            "receiveOnlyChangedDeletes": 0,
+            "receiveOnlyChangedDirectories": changed(local_changed.directories),
+            "receiveOnlyChangedFiles": changed(local_changed.files),
+            "receiveOnlyChangedSymlinks": 0,
+            "receiveOnlyTotalItems": changed(local_changed.items()),
+            "errors": 0,
+        })
+    }
+
+    /// GET /rest/db/need: downloading files in `progress`, prioritized files in
+    /// `queued` and the rest in `rest`, paginated across all three like Syncthing does
+    fn need(&self, folder: &FolderFixture, page: usize, perpage: usize) -> Value {
+        let prioritized = self
+            .prioritized
+            .get(&folder.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        // (section, position in section, file info); sections are progress, queued, rest
+        let mut needed: Vec<(usize, usize, Value)> = entries(folder, true)
+            .iter()
+            .filter(|(_, entry)| {
+                matches!(
+                    entry.state,
+                    FileState::Syncing | FileState::OutOfSync | FileState::RemoteOnly
+                )
+            })
+            .enumerate()
+            .map(|(i, (path, entry))| {
+                let queue_position = prioritized.iter().position(|p| p == path);
+                let (section, position) = match (entry.state, queue_position) {
+                    (FileState::Syncing, _) => (0, i),
+                    (_, Some(position)) => (1, position),
+                    _ => (2, i),
+                };
+                (section, position, self.file_info(folder, path, entry))
+            })
+            .collect();
+        needed.sort_by_key(|(section, position, _)| (*section, *position));
+
+        let mut sections: [Vec<Value>; 3] = Default::default();
+        for (section, _, info) in needed
+            .into_iter()
+            .skip(page.saturating_sub(1) * perpage)
+            .take(perpage)
+        {
+            sections[section].push(info);
+        }
+        let [progress, queued, rest] = sections;
+
+        json!({
+            "progress": progress,
+            "queued": queued,
+            "rest": rest,
+            "page": page,
+            "perpage": perpage,
+        })
+    }
+
+    /// Files a remote device needs: every local-only file, unless the folder
+    /// is receive-only (its local changes are never sent)
+    fn remote_needed<'a>(
+        &self,
+        folder: &FolderFixture,
+        entries: &'a BTreeMap<String, Entry>,
+        device: &str,
+    ) -> Vec<(&'a String, &'a Entry)> {
+        if !folder.devices.iter().any(|id| id == device) || folder.folder_type == "receiveonly" {
+            return Vec::new();
+        }
+        entries
+            .iter()
+            .filter(|(_, entry)| entry.state == FileState::LocalOnly)
+            .collect()
+    }
+
+    /// GET /rest/db/completion: how far `device` is, from the files it needs
+    fn completion(&self, folder: &FolderFixture, device: &str) -> Value {
+        let entries = entries(folder, true);
+        let needed = self.remote_needed(folder, &entries, device);
+        let need_bytes: u64 = needed.iter().map(|(_, entry)| entry.size).sum();
+        let global_bytes: u64 = entries
+            .values()
+            .filter(|entry| entry.state != FileState::Ignored)
+            .map(|entry| entry.size)
+            .sum();
+        let completion = match global_bytes {
+            0 => 100.0,
+            total => 100.0 * (total - need_bytes) as f64 / total as f64,
+        };
+        let remote_state = if folder.devices.iter().any(|id| id == device) {
+            "valid"
+        } else {
+            "notSharing"
+        };
+
+        json!({
+            "completion": completion,
+            "globalBytes": global_bytes,
+            "globalItems": entries.len(),
+            "needBytes": need_bytes,
+            "needItems": needed.len(),
+            "needDeletes": 0,
+            "remoteState": remote_state,
+            "sequence": self.folder_sequences.get(&folder.id).copied().unwrap_or(0),
+        })
+    }
+
+    /// GET /rest/db/remoteneed
+    fn remote_need(
+        &self,
+        folder: &FolderFixture,
+        device: &str,
+        page: usize,
+        perpage: usize,
+    ) -> Value {
+        let entries = entries(folder, true);
+        let files: Vec<Value> = self
+            .remote_needed(folder, &entries, device)
+            .into_iter()
+            .skip(page.saturating_sub(1) * perpage)
+            .take(perpage)
+            .map(|(path, entry)| self.file_info(folder, path, entry))
+            .collect();
+        json!({ "files": files, "page": page, "perpage": perpage })
+    }
+
+    fn local_changed(&self, folder: &FolderFixture) -> Value {
+        let files: Vec<Value> = entries(folder, true)
+            .iter()
+            .filter(|(_, entry)| entry.state == FileState::LocalOnly)
+            .map(|(path, entry)| self.file_info(folder, path, entry))
+            .collect();
+        json!({ "files": files, "page": 1, "perpage": files.len().max(1) })
+    }
+}
+
+fn folder_config(folder: &FolderFixture) -> Value {
+    json!({
+        "id": folder.id,
+        "label": folder.label.clone().unwrap_or_default(),
+        "path": folder.path(),
+        "type": folder.folder_type,
+        "paused": folder.paused,
+        "devices": folder
+            .devices
+            .iter()
+            .map(|id| json!({ "deviceID": id }))
+            .collect::<Vec<_>>(),
+        "rescanIntervalS": 3600,
+        "fsWatcherEnabled": true,
+        "fsWatcherDelayS": 10,
+        "order": "random",
+        "minDiskFree": { "value": 1, "unit": "%" },
+        "ignorePerms": false,
+        "ignoreDelete": false,
+        "versioning": { "type": "", "params": {}, "fsPath": "" },
+    })
+}
+
+/// Folder ID from a /rest/config/folders/{id} path
+fn folder_id_from_path(path: &str) -> String {
+    let id = path.trim_start_matches("/rest/config/folders/");
+    urlencoding::decode(id)
+        .map(|id| id.into_owned())
+        .unwrap_or_else(|_| id.to_string())
+}
+
+/// All index entries of a folder by path, with parent directories filled in
+///
+/// Local-only files are left out when `include_local_only` is false, as they
+/// aren't part of the global tree that /rest/db/browse shows.
+fn entries(folder: &FolderFixture, include_local_only: bool) -> BTreeMap<String, Entry> {
+    let mut entries = BTreeMap::new();
+
+    for file in &folder.files {
+        if !include_local_only && file.state == FileState::LocalOnly {
+            continue;
+        }
+
+        let name = file.name();
+        let mut parent = name;
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            entries.entry(dir.to_string()).or_insert_with(|| Entry {
+                is_dir: true,
+                size: 0,
+                modified: file.modified.clone(),
+                state: FileState::Synced,
+            });
+            parent = dir;
+        }
+
+        entries.insert(name.to_string(), entry_for(file));
+    }
+
+    entries
+}
+
+fn entry_for(file: &FileFixture) -> Entry {
+    Entry {
+        is_dir: file.is_dir(),
+        size: file.size,
+        modified: file.modified.clone(),
+        state: file.state,
+    }
+}
+
+fn file_type(is_dir: bool) -> &'static str {
+    if is_dir {
+        "FILE_INFO_TYPE_DIRECTORY"
+    } else {
+        "FILE_INFO_TYPE_FILE"
+    }
+}
+
+fn file_info(path: &str, entry: &Entry, sequence: u64, version: &str) -> Value {
+    json!({
+        "name": path,
+        "type": file_type(entry.is_dir),
+        "size": entry.size,
+        "modified": entry.modified,
+        "modifiedBy": "MOCKDEV",
+        "sequence": sequence,
+        "version": [format!("MOCKDEV:{}", version)],
+        "blocksHash": format!("hash-{}-{}", path, version),
+        "deleted": false,
+        "ignored": false,
+        "invalid": false,
+        "noPermissions": false,
+        "permissions": "0644",
+        "numBlocks": 1,
+        "localFlags": 0,
+        "mustRescan": false,
+        "inodeChange": entry.modified,
+    })
+}
+
+/// GET /rest/db/browse: direct children of `prefix` in the global tree
+fn browse(folder: &FolderFixture, prefix: Option<&String>) -> Value {
+    let prefix = prefix.map(|p| p.trim_matches('/')).unwrap_or_default();
+    let items: Vec<Value> = entries(folder, false)
+        .iter()
+        .filter_map(|(path, entry)| {
+            let name = if prefix.is_empty() {
+                path.as_str()
+            } else {
+                path.strip_prefix(prefix)?.strip_prefix('/')?
+            };
+            (!name.contains('/')).then(|| {
+                json!({
+                    "name": name,
+                    "type": file_type(entry.is_dir),
+                    "modTime": entry.modified,
+                    "size": entry.size,
+                })
+            })
+        })
+        .collect();
+    Value::Array(items)
+}
@@ -60,6 +60,19 @@ impl Model {
             .and_then(|idx| self.syncthing.folders.get(idx))
     }
 
+    /// Get the folder actions apply to: the selected folder in folder view,
+    /// or the browsed folder in breadcrumb view
+    pub fn current_folder(&self) -> Option<&crate::api::Folder> {
+        if self.navigation.focus_level == 0 {
+            self.selected_folder()
+        } else {
+            self.navigation
+                .breadcrumb_trail
+                .get(self.navigation.focus_level - 1)
+                .and_then(|level| self.syncthing.get_folder(&level.folder_id))
+        }
+    }
+
     /// Get current breadcrumb level (if any)
     pub fn current_level(&self) -> Option<&BreadcrumbLevel> {
         self.navigation.current_level()
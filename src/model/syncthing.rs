@@ -6,8 +6,11 @@
 use std::collections::HashMap;
 use std::time::{Instant, SystemTime};
 
-use crate::api::{ConnectionStats, Device, Folder, FolderStatus, SystemStatus};
+use crate::api::{
+    ConnectionStats, Device, Folder, FolderStatus, LogEntry, RateLimits, SystemStatus,
+};
 use crate::logic::errors::ErrorType;
+use crate::logic::transfers::FileTransfer;
 
 /// Connection state for the Syncthing API
 #[derive(Clone, Debug, PartialEq)]
@@ -19,6 +22,8 @@ pub enum ConnectionState {
         attempt: u32,
         last_error: Option<String>,
         next_retry_secs: u64,
+        /// When we asked Syncthing to restart (set while waiting for it to come back)
+        restart_requested: Option<Instant>,
     },
     /// Failed to connect (with error type and user-friendly message)
     Disconnected {
@@ -69,11 +74,32 @@ pub struct SyncthingModel {
     /// Connected device count
     pub connected_device_count: Option<usize>,
 
+    /// Global bandwidth limits (options.maxSendKbps/maxRecvKbps)
+    pub global_rate_limits: RateLimits,
+
+    /// Number of remote devices with their own bandwidth limit
+    pub rate_limited_devices: usize,
+
+    /// Saved config changes wait for a restart (/rest/config/restart-required)
+    pub restart_required: bool,
+
+    /// Errors reported by /rest/system/error (oldest first)
+    pub system_errors: Vec<LogEntry>,
+
     // ============================================
     // FOLDER-SPECIFIC STATE
     // ============================================
     /// Last update timestamp for each folder (folder_id -> (timestamp, filename))
     pub last_folder_updates: HashMap<String, (SystemTime, String)>,
+
+    /// Scan progress percentage of folders being scanned (from FolderScanProgress events)
+    pub scan_progress: HashMap<String, u8>,
+
+    /// Completion percentage of each remote device (folder_id -> device_id -> percent)
+    pub remote_completion: HashMap<String, HashMap<String, f64>>,
+
+    /// Files being pulled, from DownloadProgress events (folder_id -> path -> transfer)
+    pub transfers: HashMap<String, HashMap<String, FileTransfer>>,
 }
 
 impl SyncthingModel {
@@ -88,6 +114,7 @@ impl SyncthingModel {
                 attempt: 0,
                 last_error: None,
                 next_retry_secs: 5,
+                restart_requested: None,
             },
             system_status: None,
             connection_stats: None,
@@ -95,7 +122,14 @@ impl SyncthingModel {
             device_name: None,
             last_transfer_rates: None,
             connected_device_count: None,
+            global_rate_limits: RateLimits::default(),
+            rate_limited_devices: 0,
+            restart_required: false,
+            system_errors: Vec::new(),
             last_folder_updates: HashMap::new(),
+            scan_progress: HashMap::new(),
+            remote_completion: HashMap::new(),
+            transfers: HashMap::new(),
         }
     }
 
@@ -121,6 +155,45 @@ impl SyncthingModel {
                 )
             })
     }
+
+    /// Wait for Syncthing to come back after asking it to restart
+    ///
+    /// The background reconnection retries every `retry_secs` and the
+    /// system bar shows "Restarting" instead of the connection errors.
+    pub fn begin_restart(&mut self, retry_secs: u64) {
+        self.connection_state = ConnectionState::Connecting {
+            attempt: 0,
+            last_error: None,
+            next_retry_secs: retry_secs,
+            restart_requested: Some(Instant::now()),
+        };
+    }
+
+    /// Mark the API as reachable; returns true when this ends a restart
+    ///
+    /// While restarting, only a system status `uptime` (seconds) from the new
+    /// process ends the restart: answers to requests sent before it keep the
+    /// "Restarting" state. An instance still up a minute later never restarted.
+    pub fn mark_connected(&mut self, uptime: Option<u64>) -> bool {
+        if let ConnectionState::Connecting {
+            restart_requested: Some(requested),
+            ..
+        } = &self.connection_state
+        {
+            let elapsed = requested.elapsed().as_secs();
+            match uptime {
+                Some(uptime) if uptime <= elapsed || elapsed >= 60 => {}
+                _ => return false,
+            }
+            self.connection_state = ConnectionState::Connected;
+            // A restart applies every pending config change
+            self.restart_required = false;
+            return true;
+        }
+
+        self.connection_state = ConnectionState::Connected;
+        false
+    }
 }
 
 impl Default for SyncthingModel {
@@ -172,15 +245,47 @@ mod tests {
             attempt: 1,
             last_error: None,
             next_retry_secs: 5,
+            restart_requested: None,
         };
         let state4 = ConnectionState::Connecting {
             attempt: 1,
             last_error: None,
             next_retry_secs: 5,
+            restart_requested: None,
         };
         assert_eq!(state3, state4);
     }
 
+    #[test]
+    fn test_restart_waits_for_new_process() {
+        let mut model = SyncthingModel::new();
+        model.connection_state = ConnectionState::Connected;
+        model.restart_required = true;
+
+        model.begin_restart(2);
+        assert!(matches!(
+            model.connection_state,
+            ConnectionState::Connecting {
+                restart_requested: Some(_),
+                last_error: None,
+                next_retry_secs: 2,
+                ..
+            }
+        ));
+
+        // Answers from the old process don't end the restart
+        assert!(!model.mark_connected(None));
+        assert!(!model.mark_connected(Some(3600)));
+        assert!(model.restart_required);
+
+        assert!(model.mark_connected(Some(0)));
+        assert_eq!(model.connection_state, ConnectionState::Connected);
+        assert!(!model.restart_required);
+
+        // Reconnecting after a lost connection is not a restart
+        assert!(!model.mark_connected(None));
+    }
+
     #[test]
     fn test_devices_storage() {
         let mut model = SyncthingModel::new();
@@ -7,7 +7,10 @@ use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::time::{Instant, SystemTime};
 
-use crate::api::{BrowseItem, FileDetails, SyncState};
+use crate::api::{BrowseItem, FileDetails, FileInfo, FolderError, SyncState};
+use crate::logic::completion::DeviceCompletion;
+use crate::logic::ignore::IgnoreMatch;
+use crate::logic::transfers::TransferEntry;
 
 /// Vim command state for tracking double-key commands like 'gg'
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -21,7 +24,10 @@ pub enum VimCommandState {
 pub struct PatternSelectionState {
     pub folder_id: String,
     pub item_name: String,
-    pub patterns: Vec<String>,
+    /// Path of the item relative to the folder root
+    pub relative_path: String,
+    /// Lines ignoring the item, deciding line first
+    pub patterns: Vec<IgnoreMatch>,
     pub selected_index: Option<usize>,
 }
 
@@ -47,6 +53,8 @@ pub struct BreadcrumbLevel {
     pub file_sync_states: HashMap<String, SyncState>,
     pub ignored_exists: HashMap<String, bool>,
     pub translated_base_path: String,
+    pub marked: HashSet<String>, // Names of items marked for batch operations
+    pub mark_anchor: Option<String>, // Last item toggled (start of a range mark)
 }
 
 impl BreadcrumbLevel {
@@ -66,13 +74,85 @@ impl BreadcrumbLevel {
         self.file_sync_states.get(name).copied()
     }
 
-    /// Get relative path for an item
+    /// Get relative path for an item (prefix already ends with '/')
     pub fn relative_path(&self, item_name: &str) -> String {
         match &self.prefix {
-            Some(prefix) => format!("{}/{}", prefix, item_name),
+            Some(prefix) => format!("{}{}", prefix, item_name),
             None => item_name.to_string(),
         }
     }
+
+    /// Whether an item is marked for batch operations
+    pub fn is_marked(&self, name: &str) -> bool {
+        self.marked.contains(name)
+    }
+
+    /// Toggle the mark on the selected item and make it the range anchor
+    pub fn toggle_mark(&mut self) {
+        let Some(name) = self.selected_item().map(|item| item.name.clone()) else {
+            return;
+        };
+        if !self.marked.remove(&name) {
+            self.marked.insert(name.clone());
+        }
+        self.mark_anchor = Some(name);
+    }
+
+    /// Mark every displayed item between the anchor and the selection (inclusive)
+    ///
+    /// Without a visible anchor only the selected item is marked.
+    pub fn mark_range(&mut self) {
+        let Some(selected) = self.selected_index else {
+            return;
+        };
+        let anchor = self
+            .mark_anchor
+            .as_ref()
+            .and_then(|name| self.display_items().iter().position(|i| &i.name == name))
+            .unwrap_or(selected);
+
+        let (start, end) = (anchor.min(selected), anchor.max(selected));
+        let names: Vec<String> = self
+            .display_items()
+            .iter()
+            .skip(start)
+            .take(end + 1 - start)
+            .map(|item| item.name.clone())
+            .collect();
+        self.marked.extend(names);
+        self.mark_anchor = self.selected_item().map(|item| item.name.clone());
+    }
+
+    /// Mark every displayed item (all items matching the active search/filter)
+    ///
+    /// Returns the number of newly marked items.
+    pub fn mark_all_displayed(&mut self) -> usize {
+        let names: Vec<String> = self
+            .display_items()
+            .iter()
+            .map(|item| item.name.clone())
+            .filter(|name| !self.marked.contains(name))
+            .collect();
+        let added = names.len();
+        self.marked.extend(names);
+        added
+    }
+
+    /// Clear all marks
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.mark_anchor = None;
+    }
+
+    /// Marked items that still exist in this level, in list order
+    ///
+    /// Uses the unfiltered items so marks survive changing the search.
+    pub fn marked_items(&self) -> Vec<&BrowseItem> {
+        self.items
+            .iter()
+            .filter(|item| self.marked.contains(&item.name))
+            .collect()
+    }
 }
 
 /// Information about a pending ignore+delete operation
@@ -83,6 +163,35 @@ pub struct PendingDeleteInfo {
     pub rescan_triggered: bool,
 }
 
+/// Operation applied to all marked items of a breadcrumb level
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchOperation {
+    Ignore,
+    IgnoreDelete,
+    Delete,
+}
+
+impl BatchOperation {
+    /// Verb used in the confirmation prompt and result toast
+    pub fn label(&self) -> &'static str {
+        match self {
+            BatchOperation::Ignore => "Ignore",
+            BatchOperation::IgnoreDelete => "Ignore and delete",
+            BatchOperation::Delete => "Delete",
+        }
+    }
+}
+
+/// A marked item captured when a batch operation is requested
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchItem {
+    pub name: String,
+    pub relative_path: String, // Path from the folder root
+    pub host_path: String,     // Mapped path on this machine
+    pub is_dir: bool,
+    pub exists: bool, // Present on disk when the batch was requested
+}
+
 /// File information popup state
 /// Note: image_state removed - stays in Runtime (not cloneable)
 #[derive(Clone, Debug)]
@@ -109,6 +218,14 @@ pub enum ConfirmAction {
         folder_id: String,
         changed_files: Vec<String>,
     },
+    Override {
+        folder_id: String,
+        folder_label: String,
+        /// First page of the items changed remotely
+        changed_files: Vec<String>,
+        /// All items changed remotely (the list may be cut short)
+        total: u64,
+    },
     Delete {
         path: String,
         name: String,
@@ -128,6 +245,45 @@ pub enum ConfirmAction {
         folder_id: String,
         folder_label: String,
     },
+    PauseResumeDevice {
+        device_id: String,
+        name: String,
+        is_paused: bool,
+    },
+    RemoveDevice {
+        device_id: String,
+        name: String,
+    },
+    RestoreVersion {
+        folder_id: String,
+        /// (file path, version time) pairs, at most one per file
+        versions: Vec<(String, String)>,
+    },
+    ResolveConflict {
+        folder_id: String,
+        conflict_path: String,
+        original_path: String,
+        resolution: ConflictResolution,
+    },
+    SaveIgnorePatterns {
+        folder_id: String,
+        diff: Vec<IgnoreDiffLine>,
+    },
+    SaveFolderSettings {
+        folder_id: String,
+        changes: Vec<SettingChange>,
+    },
+    Batch {
+        folder_id: String,
+        operation: BatchOperation,
+        items: Vec<BatchItem>,
+    },
+    RestartSyncthing {
+        device_name: String,
+    },
+    ShutdownSyncthing {
+        device_name: String,
+    },
 }
 
 /// Folder sync breakdown - category counts for out-of-sync items
@@ -138,6 +294,8 @@ pub struct FolderSyncBreakdown {
     pub remote_only: usize,
     pub modified: usize,
     pub local_only: usize,
+    /// Remote changes a send-only folder won't pull
+    pub remote_changed: usize,
 }
 
 /// Out-of-sync filter state for breadcrumb view
@@ -185,6 +343,17 @@ pub struct FolderHistoryEntry {
 pub struct DeviceDetailsModal {
     pub devices: Vec<DeviceInfo>,
     pub selected_index: usize,
+    /// When the device list was last refreshed (baseline for per-device rates)
+    pub refreshed_at: Instant,
+    /// Add/edit form (shown on top of the device list)
+    pub editor: Option<DeviceEditorState>,
+}
+
+impl DeviceDetailsModal {
+    /// Get currently selected device
+    pub fn selected_device(&self) -> Option<&DeviceInfo> {
+        self.devices.get(self.selected_index)
+    }
 }
 
 /// Device information for details modal
@@ -198,6 +367,949 @@ pub struct DeviceInfo {
     pub upload_rate: Option<f64>,   // bytes/sec
     pub shared_folder_count: usize,
     pub total_folder_count: usize,
+    // Configuration (from /rest/config/devices)
+    pub addresses: Vec<String>,
+    pub compression: String,
+    pub introducer: bool,
+    pub paused: bool,
+    // Raw counters used to calculate rates between refreshes
+    pub in_bytes_total: u64,
+    pub out_bytes_total: u64,
+}
+
+/// Field in the device add/edit form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceEditorField {
+    DeviceId,
+    Name,
+    Addresses,
+    Compression,
+    Introducer,
+}
+
+/// Device add/edit form state
+#[derive(Debug, Clone)]
+pub struct DeviceEditorState {
+    /// None when adding a new device, Some(id) when editing
+    pub original_device_id: Option<String>,
+    pub device_id: String,
+    pub name: String,
+    pub addresses: String, // Comma-separated, parsed on save
+    pub compression: String,
+    pub introducer: bool,
+    pub paused: bool,
+    pub focused_field: DeviceEditorField,
+}
+
+impl DeviceEditorState {
+    /// Empty form for adding a new device
+    pub fn new_device() -> Self {
+        Self {
+            original_device_id: None,
+            device_id: String::new(),
+            name: String::new(),
+            addresses: "dynamic".to_string(),
+            compression: "metadata".to_string(),
+            introducer: false,
+            paused: false,
+            focused_field: DeviceEditorField::DeviceId,
+        }
+    }
+
+    /// Form pre-filled from an existing device
+    pub fn edit_device(device: &DeviceInfo) -> Self {
+        Self {
+            original_device_id: Some(device.device_id.clone()),
+            device_id: device.device_id.clone(),
+            name: device.device_name.clone(),
+            addresses: crate::logic::devices::format_addresses(&device.addresses),
+            compression: device.compression.clone(),
+            introducer: device.introducer,
+            paused: device.paused,
+            focused_field: DeviceEditorField::Name,
+        }
+    }
+
+    /// Whether this form adds a new device (device ID is editable)
+    pub fn is_new(&self) -> bool {
+        self.original_device_id.is_none()
+    }
+
+    /// Fields shown in the form (device ID is read-only when editing)
+    pub fn fields(&self) -> &'static [DeviceEditorField] {
+        if self.is_new() {
+            &[
+                DeviceEditorField::DeviceId,
+                DeviceEditorField::Name,
+                DeviceEditorField::Addresses,
+                DeviceEditorField::Compression,
+                DeviceEditorField::Introducer,
+            ]
+        } else {
+            &[
+                DeviceEditorField::Name,
+                DeviceEditorField::Addresses,
+                DeviceEditorField::Compression,
+                DeviceEditorField::Introducer,
+            ]
+        }
+    }
+
+    /// Move focus to the next field (wraps around)
+    pub fn focus_next(&mut self) {
+        let fields = self.fields();
+        let idx = fields
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = fields[(idx + 1) % fields.len()];
+    }
+
+    /// Move focus to the previous field (wraps around)
+    pub fn focus_prev(&mut self) {
+        let fields = self.fields();
+        let idx = fields
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = fields[(idx + fields.len() - 1) % fields.len()];
+    }
+
+    /// Text buffer for the focused field (None for toggle/choice fields)
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused_field {
+            DeviceEditorField::DeviceId => Some(&mut self.device_id),
+            DeviceEditorField::Name => Some(&mut self.name),
+            DeviceEditorField::Addresses => Some(&mut self.addresses),
+            DeviceEditorField::Compression | DeviceEditorField::Introducer => None,
+        }
+    }
+}
+
+/// A device or folder offer that has not been accepted yet
+///
+/// Built from /rest/cluster/pending/devices and /rest/cluster/pending/folders.
+/// A folder offered by several devices yields one invitation per device.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingInvitation {
+    Device {
+        device_id: String,
+        name: String,
+        address: String,
+        time: String, // RFC 3339
+    },
+    Folder {
+        folder_id: String,
+        label: String,
+        device_id: String,
+        device_name: String,
+        time: String, // RFC 3339
+    },
+}
+
+impl PendingInvitation {
+    /// When the offer was (last) made
+    pub fn time(&self) -> &str {
+        match self {
+            PendingInvitation::Device { time, .. } | PendingInvitation::Folder { time, .. } => time,
+        }
+    }
+}
+
+/// Pending invitations inbox modal state
+#[derive(Debug, Clone)]
+pub struct PendingInboxModal {
+    pub invitations: Vec<PendingInvitation>,
+    pub selected_index: usize,
+    /// Accept form for a folder offer (shown on top of the list)
+    pub accept_form: Option<AcceptFolderForm>,
+}
+
+impl PendingInboxModal {
+    /// Get currently selected invitation
+    pub fn selected_invitation(&self) -> Option<&PendingInvitation> {
+        self.invitations.get(self.selected_index)
+    }
+}
+
+/// Form for accepting a folder offer
+#[derive(Debug, Clone)]
+pub struct AcceptFolderForm {
+    pub folder_id: String,
+    pub label: String,
+    pub device_id: String,
+    /// Local (host) path, reverse-translated through path_map on submit
+    pub path: String,
+    pub folder_type: String, // "sendreceive", "sendonly", "receiveonly"
+}
+
+/// Field in the folder settings form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FolderSettingsField {
+    Label,
+    RescanInterval,
+    FsWatcher,
+    FsWatcherDelay,
+    PullOrder,
+    MinDiskFree,
+    MinDiskFreeUnit,
+    IgnorePerms,
+    IgnoreDelete,
+    Versioning,
+    VersioningKeep,
+    VersioningCleanoutDays,
+    VersioningMaxAge,
+    VersioningCommand,
+}
+
+/// Folder settings form state
+///
+/// Numbers are edited as text and validated on submit.
+#[derive(Debug, Clone)]
+pub struct FolderSettingsForm {
+    pub folder_id: String,
+    /// Full folder config as loaded; the edited fields are written into a copy
+    /// of it so fields the form doesn't show are preserved
+    pub config: serde_json::Value,
+    pub label: String,
+    pub rescan_interval: String, // seconds
+    pub fs_watcher_enabled: bool,
+    pub fs_watcher_delay: String, // seconds
+    pub order: String,            // "random", "alphabetic", "smallestFirst", ...
+    pub min_disk_free: String,
+    pub min_disk_free_unit: String, // "%", "kB", "MB", "GB", "TB"
+    pub ignore_perms: bool,
+    pub ignore_delete: bool,
+    pub versioning_type: String, // "", "simple", "staggered", "trashcan", "external"
+    pub versioning_keep: String,
+    pub versioning_cleanout_days: String,
+    pub versioning_max_age_days: String,
+    pub versioning_command: String,
+    pub focused_field: FolderSettingsField,
+}
+
+impl FolderSettingsForm {
+    /// Fields shown in the form (versioning parameters depend on the type)
+    pub fn fields(&self) -> Vec<FolderSettingsField> {
+        use FolderSettingsField::*;
+
+        let mut fields = vec![
+            Label,
+            RescanInterval,
+            FsWatcher,
+            FsWatcherDelay,
+            PullOrder,
+            MinDiskFree,
+            MinDiskFreeUnit,
+            IgnorePerms,
+            IgnoreDelete,
+            Versioning,
+        ];
+        match self.versioning_type.as_str() {
+            "simple" => fields.extend([VersioningKeep, VersioningCleanoutDays]),
+            "trashcan" => fields.push(VersioningCleanoutDays),
+            "staggered" => fields.push(VersioningMaxAge),
+            "external" => fields.push(VersioningCommand),
+            _ => {}
+        }
+        fields
+    }
+
+    /// Move focus to the next field (wraps around)
+    pub fn focus_next(&mut self) {
+        let fields = self.fields();
+        let idx = fields
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = fields[(idx + 1) % fields.len()];
+    }
+
+    /// Move focus to the previous field (wraps around)
+    pub fn focus_prev(&mut self) {
+        let fields = self.fields();
+        let idx = fields
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = fields[(idx + fields.len() - 1) % fields.len()];
+    }
+
+    /// Text buffer for the focused field (None for toggle/choice fields)
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused_field {
+            FolderSettingsField::Label => Some(&mut self.label),
+            FolderSettingsField::RescanInterval => Some(&mut self.rescan_interval),
+            FolderSettingsField::FsWatcherDelay => Some(&mut self.fs_watcher_delay),
+            FolderSettingsField::MinDiskFree => Some(&mut self.min_disk_free),
+            FolderSettingsField::VersioningKeep => Some(&mut self.versioning_keep),
+            FolderSettingsField::VersioningCleanoutDays => Some(&mut self.versioning_cleanout_days),
+            FolderSettingsField::VersioningMaxAge => Some(&mut self.versioning_max_age_days),
+            FolderSettingsField::VersioningCommand => Some(&mut self.versioning_command),
+            FolderSettingsField::FsWatcher
+            | FolderSettingsField::PullOrder
+            | FolderSettingsField::MinDiskFreeUnit
+            | FolderSettingsField::IgnorePerms
+            | FolderSettingsField::IgnoreDelete
+            | FolderSettingsField::Versioning => None,
+        }
+    }
+}
+
+/// A changed setting shown when confirming the folder settings form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingChange {
+    pub setting: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// A device in a folder's sharing checklist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceChoice {
+    pub device_id: String,
+    pub name: String,
+    pub selected: bool,
+}
+
+/// Field in the new folder form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewFolderField {
+    Label,
+    FolderId,
+    Path,
+    FolderType,
+    Devices,
+}
+
+/// New folder form state
+#[derive(Debug, Clone)]
+pub struct NewFolderForm {
+    pub label: String,
+    /// Pre-filled with a generated ID, may be edited
+    pub folder_id: String,
+    /// Local (host) path, reverse-translated through path_map on submit
+    pub path: String,
+    /// Whether the path was typed by the user (stops following the label)
+    pub path_edited: bool,
+    pub folder_type: String, // "sendreceive", "sendonly", "receiveonly"
+    /// Remote devices to share the folder with
+    pub devices: Vec<DeviceChoice>,
+    pub device_index: usize,
+    pub focused_field: NewFolderField,
+}
+
+impl NewFolderForm {
+    const FIELDS: [NewFolderField; 5] = [
+        NewFolderField::Label,
+        NewFolderField::FolderId,
+        NewFolderField::Path,
+        NewFolderField::FolderType,
+        NewFolderField::Devices,
+    ];
+
+    /// Move focus to the next field (wraps around)
+    pub fn focus_next(&mut self) {
+        let idx = Self::FIELDS
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = Self::FIELDS[(idx + 1) % Self::FIELDS.len()];
+    }
+
+    /// Move focus to the previous field (wraps around)
+    pub fn focus_prev(&mut self) {
+        let idx = Self::FIELDS
+            .iter()
+            .position(|f| *f == self.focused_field)
+            .unwrap_or(0);
+        self.focused_field = Self::FIELDS[(idx + Self::FIELDS.len() - 1) % Self::FIELDS.len()];
+    }
+
+    /// Text buffer for the focused field (None for choice/checklist fields)
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        match self.focused_field {
+            NewFolderField::Label => Some(&mut self.label),
+            NewFolderField::FolderId => Some(&mut self.folder_id),
+            NewFolderField::Path => {
+                self.path_edited = true;
+                Some(&mut self.path)
+            }
+            NewFolderField::FolderType | NewFolderField::Devices => None,
+        }
+    }
+}
+
+/// Sharing checklist for an existing folder
+#[derive(Debug, Clone)]
+pub struct FolderSharingModal {
+    pub folder_id: String,
+    pub label: String,
+    pub devices: Vec<DeviceChoice>,
+    pub selected_index: usize,
+}
+
+/// Typed-name confirmation for removing a folder
+#[derive(Debug, Clone)]
+pub struct RemoveFolderPrompt {
+    pub folder_id: String,
+    /// Name the user has to type (label, or ID for unlabeled folders)
+    pub name: String,
+    pub input: String,
+}
+
+/// Which direction a rate limit preset applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDirection {
+    Both,
+    Send,
+    Receive,
+}
+
+/// A row of the rate limit popup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitRow {
+    /// None for the global limits
+    pub device_id: Option<String>,
+    pub name: String,
+    pub limits: crate::api::RateLimits,
+}
+
+/// Rate limit popup state (global limits first, then one row per remote device)
+#[derive(Debug, Clone)]
+pub struct RateLimitModal {
+    pub rows: Vec<RateLimitRow>,
+    pub selected_index: usize,
+    pub direction: RateLimitDirection,
+    /// Custom limit being typed (KiB/s), shown instead of the key hints
+    pub custom_input: Option<String>,
+}
+
+impl RateLimitModal {
+    /// Get currently selected row
+    pub fn selected_row(&self) -> Option<&RateLimitRow> {
+        self.rows.get(self.selected_index)
+    }
+}
+
+/// Entries of the system menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemMenuItem {
+    Restart,
+    Shutdown,
+}
+
+impl SystemMenuItem {
+    pub const ALL: [SystemMenuItem; 2] = [SystemMenuItem::Restart, SystemMenuItem::Shutdown];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SystemMenuItem::Restart => "Restart Syncthing",
+            SystemMenuItem::Shutdown => "Shut down Syncthing",
+        }
+    }
+}
+
+/// System menu state (Syncthing version, restart and shutdown)
+#[derive(Debug, Clone)]
+pub struct SystemMenu {
+    /// None when /rest/system/version could not be loaded
+    pub version: Option<crate::api::SystemVersion>,
+    pub selected_index: usize,
+}
+
+impl SystemMenu {
+    /// Get currently selected entry
+    pub fn selected_item(&self) -> Option<SystemMenuItem> {
+        SystemMenuItem::ALL.get(self.selected_index).copied()
+    }
+}
+
+/// Archived version of a file shown in the versions modal
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileVersionEntry {
+    pub file_path: String,
+    pub version_time: String, // RFC 3339
+    pub mod_time: String,     // RFC 3339
+    pub size: u64,
+}
+
+/// File versions modal state (browse and restore `.stversions` history)
+#[derive(Debug, Clone)]
+pub struct FileVersionsModal {
+    pub folder_id: String,
+    /// Files whose versions are listed (the marked files, or the selected one)
+    pub file_paths: Vec<String>,
+    pub versioning_type: String,
+    /// Container path of the versions directory
+    pub versions_root: String,
+    /// Grouped by file, newest first
+    pub versions: Vec<FileVersionEntry>,
+    pub selected_index: usize,
+    /// Versions marked for restore (file path -> version time), at most one per file
+    pub marked: HashMap<String, String>,
+    /// Content of the selected version (same format as the file info preview)
+    pub preview: Result<String, String>,
+    pub preview_is_binary: bool,
+    pub scroll_offset: u16,
+}
+
+impl FileVersionsModal {
+    /// Get currently selected version
+    pub fn selected_version(&self) -> Option<&FileVersionEntry> {
+        self.versions.get(self.selected_index)
+    }
+
+    /// Whether a version is marked for restore
+    pub fn is_marked(&self, version: &FileVersionEntry) -> bool {
+        self.marked.get(&version.file_path) == Some(&version.version_time)
+    }
+
+    /// Toggle the mark on the selected version (replacing another marked
+    /// version of the same file)
+    pub fn toggle_mark(&mut self) {
+        let Some(version) = self.selected_version().cloned() else {
+            return;
+        };
+        if self.is_marked(&version) {
+            self.marked.remove(&version.file_path);
+        } else {
+            self.marked.insert(version.file_path, version.version_time);
+        }
+    }
+
+    /// Versions to restore: the marked ones (by path), or the selected one
+    pub fn versions_to_restore(&self) -> Vec<(String, String)> {
+        if self.marked.is_empty() {
+            return self
+                .selected_version()
+                .map(|v| vec![(v.file_path.clone(), v.version_time.clone())])
+                .unwrap_or_default();
+        }
+        let mut versions: Vec<_> = self
+            .marked
+            .iter()
+            .map(|(path, time)| (path.clone(), time.clone()))
+            .collect();
+        versions.sort();
+        versions
+    }
+}
+
+/// Which file(s) to keep when resolving a sync conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Keep the original, delete the conflict copy
+    Original,
+    /// Keep the conflict copy, moving it over the original
+    Conflict,
+    /// Keep both, renaming the conflict copy
+    Both,
+}
+
+/// Sync-conflict copy found in a folder
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConflictEntry {
+    /// Relative path of the conflict copy
+    pub conflict_path: String,
+    /// Relative path of the file it conflicts with
+    pub original_path: String,
+    /// Conflict time (`YYYYMMDD-HHMMSS`, from the file name)
+    pub timestamp: String,
+    /// Name of the device whose change lost the conflict (short ID if unknown)
+    pub device_name: String,
+    pub conflict_size: u64,
+    pub conflict_modified: String, // RFC 3339, empty if unavailable
+    /// None when the original no longer exists
+    pub original_size: Option<u64>,
+    pub original_modified: String, // RFC 3339, empty if unavailable
+}
+
+/// Conflicts modal state (list and resolve sync conflicts in a folder)
+#[derive(Debug, Clone)]
+pub struct ConflictsModal {
+    pub folder_id: String,
+    pub folder_label: String,
+    /// Newest first
+    pub conflicts: Vec<ConflictEntry>,
+    pub selected_index: usize,
+    /// Content of the original file (same format as the file info preview)
+    pub original_preview: Result<String, String>,
+    /// Content of the conflict copy
+    pub conflict_preview: Result<String, String>,
+    pub scroll_offset: u16,
+}
+
+impl ConflictsModal {
+    /// Get currently selected conflict
+    pub fn selected_conflict(&self) -> Option<&ConflictEntry> {
+        self.conflicts.get(self.selected_index)
+    }
+}
+
+/// One line of the `.stignore` diff shown before saving
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IgnoreDiffLine {
+    Unchanged(String),
+    Added(String),
+    Removed(String),
+}
+
+/// `.stignore` editor modal state
+#[derive(Debug, Clone)]
+pub struct IgnoreEditorModal {
+    pub folder_id: String,
+    pub folder_label: String,
+    /// Lines as last loaded from Syncthing
+    pub saved_lines: Vec<String>,
+    /// Lines being edited (never empty)
+    pub lines: Vec<String>,
+    pub cursor_line: usize,
+    /// Cursor position within the line (in characters)
+    pub cursor_col: usize,
+    /// Relative paths of all cached files in the folder (for the match preview)
+    pub cached_paths: Vec<String>,
+    /// Set after Esc with unsaved changes; a second Esc discards them
+    pub discard_armed: bool,
+    /// Cached paths the current line newly ignores
+    pub preview_ignored: Vec<String>,
+    /// Cached paths the current (negated) line un-ignores
+    pub preview_unignored: Vec<String>,
+    /// Cursor line and contents the preview was computed for
+    pub preview_for: Option<(usize, Vec<String>)>,
+}
+
+impl IgnoreEditorModal {
+    /// Create an editor for the given `.stignore` lines
+    pub fn new(
+        folder_id: String,
+        folder_label: String,
+        lines: Vec<String>,
+        cached_paths: Vec<String>,
+    ) -> Self {
+        let editable = if lines.is_empty() {
+            vec![String::new()]
+        } else {
+            lines.clone()
+        };
+        Self {
+            folder_id,
+            folder_label,
+            saved_lines: lines,
+            lines: editable,
+            cursor_line: 0,
+            cursor_col: 0,
+            cached_paths,
+            discard_armed: false,
+            preview_ignored: Vec::new(),
+            preview_unignored: Vec::new(),
+            preview_for: None,
+        }
+    }
+
+    /// Lines to save (a lone empty line means an empty `.stignore`)
+    pub fn lines_to_save(&self) -> Vec<String> {
+        if self.lines.len() == 1 && self.lines[0].is_empty() {
+            Vec::new()
+        } else {
+            self.lines.clone()
+        }
+    }
+
+    /// Whether the edited lines differ from the saved ones
+    pub fn is_modified(&self) -> bool {
+        self.lines_to_save() != self.saved_lines
+    }
+
+    /// Byte offset of the cursor within the current line
+    fn cursor_byte(&self) -> usize {
+        let line = &self.lines[self.cursor_line];
+        line.char_indices()
+            .nth(self.cursor_col)
+            .map(|(idx, _)| idx)
+            .unwrap_or(line.len())
+    }
+
+    fn current_line_chars(&self) -> usize {
+        self.lines[self.cursor_line].chars().count()
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let byte = self.cursor_byte();
+        self.lines[self.cursor_line].insert(byte, c);
+        self.cursor_col += 1;
+    }
+
+    /// Delete the character before the cursor, joining lines at column 0
+    pub fn backspace(&mut self) {
+        if self.cursor_col > 0 {
+            self.cursor_col -= 1;
+            let byte = self.cursor_byte();
+            self.lines[self.cursor_line].remove(byte);
+        } else if self.cursor_line > 0 {
+            let line = self.lines.remove(self.cursor_line);
+            self.cursor_line -= 1;
+            self.cursor_col = self.current_line_chars();
+            self.lines[self.cursor_line].push_str(&line);
+        }
+    }
+
+    /// Split the current line at the cursor (inserts a new line)
+    pub fn split_line(&mut self) {
+        let byte = self.cursor_byte();
+        let rest = self.lines[self.cursor_line].split_off(byte);
+        self.cursor_line += 1;
+        self.lines.insert(self.cursor_line, rest);
+        self.cursor_col = 0;
+    }
+
+    /// Delete the current line
+    pub fn delete_line(&mut self) {
+        self.lines.remove(self.cursor_line);
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+        self.cursor_line = self.cursor_line.min(self.lines.len() - 1);
+        self.cursor_col = self.cursor_col.min(self.current_line_chars());
+    }
+
+    /// Swap the current line with the one above (-1) or below (+1)
+    pub fn move_line(&mut self, up: bool) {
+        let target = if up {
+            match self.cursor_line.checked_sub(1) {
+                Some(target) => target,
+                None => return,
+            }
+        } else if self.cursor_line + 1 < self.lines.len() {
+            self.cursor_line + 1
+        } else {
+            return;
+        };
+        self.lines.swap(self.cursor_line, target);
+        self.cursor_line = target;
+    }
+
+    /// Comment out the current line with `// `, or uncomment it
+    pub fn toggle_comment(&mut self) {
+        let line = &mut self.lines[self.cursor_line];
+        if let Some(rest) = line.strip_prefix("// ").or_else(|| line.strip_prefix("//")) {
+            let removed = line.chars().count() - rest.chars().count();
+            *line = rest.to_string();
+            self.cursor_col = self.cursor_col.saturating_sub(removed);
+        } else {
+            line.insert_str(0, "// ");
+            self.cursor_col += 3;
+        }
+    }
+
+    pub fn move_cursor_line(&mut self, up: bool) {
+        if up {
+            self.cursor_line = self.cursor_line.saturating_sub(1);
+        } else {
+            self.cursor_line = (self.cursor_line + 1).min(self.lines.len() - 1);
+        }
+        self.cursor_col = self.cursor_col.min(self.current_line_chars());
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        self.cursor_col = self.cursor_col.saturating_sub(1);
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        self.cursor_col = (self.cursor_col + 1).min(self.current_line_chars());
+    }
+
+    pub fn move_cursor_home(&mut self) {
+        self.cursor_col = 0;
+    }
+
+    pub fn move_cursor_end(&mut self) {
+        self.cursor_col = self.current_line_chars();
+    }
+}
+
+/// Failed items modal state (paged `/rest/folder/errors`)
+#[derive(Debug, Clone)]
+pub struct FailedItemsModal {
+    pub folder_id: String,
+    pub folder_label: String,
+    pub items: Vec<FolderError>,
+    pub selected_index: usize,
+    /// Number of pages loaded so far (pages start at 1)
+    pub pages_loaded: usize,
+    /// Whether the last loaded page was full (more may follow)
+    pub has_more: bool,
+}
+
+impl FailedItemsModal {
+    /// Get currently selected failed item
+    pub fn selected_item(&self) -> Option<&FolderError> {
+        self.items.get(self.selected_index)
+    }
+}
+
+/// Transfer queue modal state (first page of `/rest/db/need`)
+#[derive(Debug, Clone)]
+pub struct TransferQueueModal {
+    pub folder_id: String,
+    pub folder_label: String,
+    pub entries: Vec<TransferEntry>,
+    pub selected_index: usize,
+    /// Total needed items from the folder status (the queue shows one page)
+    pub need_total: u64,
+}
+
+impl TransferQueueModal {
+    /// Get currently selected entry
+    pub fn selected_entry(&self) -> Option<&TransferEntry> {
+        self.entries.get(self.selected_index)
+    }
+}
+
+/// Remote device completion modal state (one folder, every device sharing it)
+#[derive(Debug, Clone)]
+pub struct RemoteCompletionModal {
+    pub folder_id: String,
+    pub folder_label: String,
+    pub devices: Vec<DeviceCompletion>,
+    pub selected_index: usize,
+    /// Files the selected device still needs, when drilled into
+    pub remote_need: Option<RemoteNeedList>,
+}
+
+impl RemoteCompletionModal {
+    /// Get currently selected device
+    pub fn selected_device(&self) -> Option<&DeviceCompletion> {
+        self.devices.get(self.selected_index)
+    }
+}
+
+/// Files a remote device needs (pages of `/rest/db/remoteneed`)
+#[derive(Debug, Clone)]
+pub struct RemoteNeedList {
+    pub device_id: String,
+    pub device_name: String,
+    pub files: Vec<FileInfo>,
+    pub selected_index: usize,
+    /// Number of pages loaded so far (pages start at 1)
+    pub pages_loaded: usize,
+    /// Whether the last loaded page was full (more may follow)
+    pub has_more: bool,
+}
+
+/// Severity of a Syncthing log line (ordered from least to most severe)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl LogLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warning => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// Line shown in the log viewer
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub when: String, // RFC 3339
+    pub level: LogLevel,
+    pub message: String,
+}
+
+/// Log viewer modal state (`/rest/system/log` and `/rest/system/error`)
+#[derive(Debug, Clone)]
+pub struct LogViewerModal {
+    /// Oldest first
+    pub lines: Vec<LogLine>,
+    /// First visible line (index into the filtered lines)
+    pub scroll_offset: usize,
+    /// Keep the newest line in view and poll for new entries
+    pub follow: bool,
+    /// Lines below this level are hidden
+    pub min_level: LogLevel,
+    pub search_query: String,
+    /// Whether the search input is receiving keystrokes
+    pub search_active: bool,
+}
+
+/// Syncthing instance listed in the profile switcher
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileEntry {
+    pub name: String,
+    pub base_url: String,
+}
+
+/// Profile switcher modal state
+#[derive(Debug, Clone)]
+pub struct ProfileSwitcherModal {
+    pub profiles: Vec<ProfileEntry>,
+    pub selected_index: usize,
+    /// Name of the profile the app is connected to
+    pub active: String,
+}
+
+impl ProfileSwitcherModal {
+    /// Get currently selected profile
+    pub fn selected_profile(&self) -> Option<&ProfileEntry> {
+        self.profiles.get(self.selected_index)
+    }
+}
+
+/// Health summary of one instance in the fleet overview
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstanceSummary {
+    pub uptime: u64,
+    pub folders: usize,
+    /// Folders syncing, scanning or needing items (paused folders excluded)
+    pub folders_out_of_sync: usize,
+    pub need_bytes: u64,
+    /// Folders in an error state or with failed items
+    pub folders_with_errors: usize,
+    pub connected_devices: usize,
+    /// Remote devices (the instance itself excluded)
+    pub total_devices: usize,
+}
+
+/// Poll result of one instance in the fleet overview
+#[derive(Debug, Clone, PartialEq)]
+pub enum FleetStatus {
+    Polling,
+    Reachable(InstanceSummary),
+    Unreachable(String),
+}
+
+/// Row of the fleet overview (one per configured profile)
+#[derive(Debug, Clone)]
+pub struct FleetRow {
+    pub profile: String,
+    pub status: FleetStatus,
+}
+
+/// Fleet overview modal state (read-only summary of every profile)
+#[derive(Debug, Clone)]
+pub struct FleetOverviewModal {
+    pub rows: Vec<FleetRow>,
+    pub selected_index: usize,
+    /// Name of the profile the app is connected to
+    pub active: String,
+    /// When the last poll was started
+    pub polled_at: Instant,
+}
+
+impl FleetOverviewModal {
+    /// Get currently selected row
+    pub fn selected_row(&self) -> Option<&FleetRow> {
+        self.rows.get(self.selected_index)
+    }
 }
 
 #[cfg(test)]
@@ -315,6 +1427,67 @@ mod tests {
         assert_ne!(action1, action3);
     }
 
+    // ========================================
+    // FILE VERSIONS MODAL
+    // ========================================
+
+    #[test]
+    fn test_file_versions_marks_one_version_per_file() {
+        let version = |file_path: &str, version_time: &str| FileVersionEntry {
+            file_path: file_path.to_string(),
+            version_time: version_time.to_string(),
+            mod_time: version_time.to_string(),
+            size: 1,
+        };
+        let mut modal = FileVersionsModal {
+            folder_id: "docs".to_string(),
+            file_paths: vec!["a.txt".to_string(), "b.txt".to_string()],
+            versioning_type: "simple".to_string(),
+            versions_root: "/data/docs/.stversions".to_string(),
+            versions: vec![
+                version("a.txt", "2024-01-02T00:00:00Z"),
+                version("a.txt", "2024-01-01T00:00:00Z"),
+                version("b.txt", "2024-01-03T00:00:00Z"),
+            ],
+            selected_index: 1,
+            marked: HashMap::new(),
+            preview: Ok(String::new()),
+            preview_is_binary: false,
+            scroll_offset: 0,
+        };
+        let pair = |path: &str, time: &str| (path.to_string(), time.to_string());
+
+        // Without marks the selected version is restored
+        assert_eq!(
+            modal.versions_to_restore(),
+            vec![pair("a.txt", "2024-01-01T00:00:00Z")]
+        );
+
+        modal.toggle_mark();
+        modal.selected_index = 2;
+        modal.toggle_mark();
+        assert_eq!(
+            modal.versions_to_restore(),
+            vec![
+                pair("a.txt", "2024-01-01T00:00:00Z"),
+                pair("b.txt", "2024-01-03T00:00:00Z"),
+            ]
+        );
+
+        // Marking another version of a file replaces its mark
+        modal.selected_index = 0;
+        modal.toggle_mark();
+        assert!(modal.is_marked(&modal.versions[0]));
+        assert!(!modal.is_marked(&modal.versions[1]));
+
+        // Toggling a marked version unmarks it
+        modal.toggle_mark();
+        assert_eq!(
+            modal.versions_to_restore(),
+            vec![pair("b.txt", "2024-01-03T00:00:00Z")]
+        );
+    }
+
     // ========================================
     // BREADCRUMB LEVEL
     // ========================================
@@ -344,6 +1517,8 @@ mod tests {
             file_sync_states: HashMap::new(),
             ignored_exists: HashMap::new(),
             translated_base_path: "/test".to_string(),
+            marked: HashSet::new(),
+            mark_anchor: None,
             filtered_items: None,
         };
 
@@ -392,6 +1567,8 @@ mod tests {
             file_sync_states: HashMap::new(),
             ignored_exists: HashMap::new(),
             translated_base_path: "/test".to_string(),
+            marked: HashSet::new(),
+            mark_anchor: None,
         };
 
         // BUG: selected_item() returns items[0] = dir1 instead of filtered_items[0] = dir2
@@ -431,6 +1608,8 @@ mod tests {
             file_sync_states: HashMap::new(),
             ignored_exists: HashMap::new(),
             translated_base_path: "/test".to_string(),
+            marked: HashSet::new(),
+            mark_anchor: None,
             filtered_items: Some(vec![]), // Zero matches - should show empty list
         };
 
@@ -469,6 +1648,8 @@ mod tests {
             file_sync_states: HashMap::new(),
             ignored_exists: HashMap::new(),
             translated_base_path: "/test".to_string(),
+            marked: HashSet::new(),
+            mark_anchor: None,
             filtered_items: None, // No filter active
         };
 
@@ -477,6 +1658,112 @@ mod tests {
         assert_eq!(displayed.len(), 2, "No filter (None) should show all items");
     }
 
+    // ========================================
+    // MULTI-SELECT MARKS
+    // ========================================
+
+    fn level_with_items(names: &[&str]) -> BreadcrumbLevel {
+        BreadcrumbLevel {
+            folder_id: "test".to_string(),
+            folder_label: "Test".to_string(),
+            folder_path: "/test".to_string(),
+            prefix: Some("docs/".to_string()),
+            items: names
+                .iter()
+                .map(|name| BrowseItem {
+                    name: name.to_string(),
+                    item_type: "FILE_INFO_TYPE_FILE".to_string(),
+                    mod_time: "2025-01-09T10:00:00Z".to_string(),
+                    size: 0,
+                })
+                .collect(),
+            filtered_items: None,
+            selected_index: Some(0),
+            file_sync_states: HashMap::new(),
+            ignored_exists: HashMap::new(),
+            translated_base_path: "/test/docs".to_string(),
+            marked: HashSet::new(),
+            mark_anchor: None,
+        }
+    }
+
+    fn marked_names(level: &BreadcrumbLevel) -> Vec<&str> {
+        level
+            .marked_items()
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_toggle_mark_marks_and_unmarks_selection() {
+        let mut level = level_with_items(&["a", "b", "c"]);
+        level.selected_index = Some(1);
+
+        level.toggle_mark();
+        assert_eq!(marked_names(&level), vec!["b"]);
+
+        level.toggle_mark();
+        assert!(marked_names(&level).is_empty());
+        assert_eq!(level.mark_anchor.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_mark_range_from_anchor_in_either_direction() {
+        let mut level = level_with_items(&["a", "b", "c", "d", "e"]);
+        level.selected_index = Some(3);
+        level.toggle_mark();
+
+        level.selected_index = Some(1);
+        level.mark_range();
+        assert_eq!(marked_names(&level), vec!["b", "c", "d"]);
+
+        // Without an anchor only the selection is marked
+        level.clear_marks();
+        level.selected_index = Some(4);
+        level.mark_range();
+        assert_eq!(marked_names(&level), vec!["e"]);
+    }
+
+    #[test]
+    fn test_mark_range_uses_filtered_positions() {
+        let mut level = level_with_items(&["a", "b", "c", "d"]);
+        level.filtered_items = Some(vec![level.items[0].clone(), level.items[3].clone()]);
+        level.toggle_mark(); // "a" at filtered index 0
+
+        level.selected_index = Some(1); // "d"
+        level.mark_range();
+        assert_eq!(marked_names(&level), vec!["a", "d"]);
+    }
+
+    #[test]
+    fn test_mark_all_displayed_marks_search_matches_only() {
+        let mut level = level_with_items(&["a.txt", "b.log", "c.txt"]);
+        level.filtered_items = Some(vec![level.items[0].clone(), level.items[2].clone()]);
+
+        assert_eq!(level.mark_all_displayed(), 2);
+        assert_eq!(level.mark_all_displayed(), 0);
+
+        // Marks survive clearing the search
+        level.filtered_items = None;
+        assert_eq!(marked_names(&level), vec!["a.txt", "c.txt"]);
+    }
+
+    #[test]
+    fn test_marked_items_skip_removed_entries() {
+        let mut level = level_with_items(&["a", "b"]);
+        level.marked.insert("b".to_string());
+        level.marked.insert("gone".to_string());
+
+        assert_eq!(marked_names(&level), vec!["b"]);
+    }
+
+    #[test]
+    fn test_relative_path_joins_prefix() {
+        let level = level_with_items(&["a"]);
+        assert_eq!(level.relative_path("a"), "docs/a");
+    }
+
     // ========================================
     // CONFIRM ACTION - RESCAN VARIANT
     // ========================================
@@ -660,4 +1947,173 @@ mod tests {
         assert!(!modal.has_more);
         assert_eq!(modal.total_files_scanned, 50);
     }
+
+    // ========================================
+    // DEVICE EDITOR
+    // ========================================
+
+    fn test_device_info() -> DeviceInfo {
+        DeviceInfo {
+            device_id: "AAAAAAA-BBBBBBB".to_string(),
+            device_name: "laptop".to_string(),
+            connected: true,
+            address: "192.168.1.10:22000".to_string(),
+            download_rate: None,
+            upload_rate: None,
+            shared_folder_count: 1,
+            total_folder_count: 3,
+            addresses: vec!["dynamic".to_string(), "tcp://laptop:22000".to_string()],
+            compression: "always".to_string(),
+            introducer: true,
+            paused: false,
+            in_bytes_total: 0,
+            out_bytes_total: 0,
+        }
+    }
+
+    #[test]
+    fn test_device_editor_new_device_cycles_all_fields() {
+        let mut editor = DeviceEditorState::new_device();
+        assert!(editor.is_new());
+        assert_eq!(editor.focused_field, DeviceEditorField::DeviceId);
+
+        for _ in 0..editor.fields().len() {
+            editor.focus_next();
+        }
+        assert_eq!(editor.focused_field, DeviceEditorField::DeviceId);
+
+        editor.focus_prev();
+        assert_eq!(editor.focused_field, DeviceEditorField::Introducer);
+    }
+
+    #[test]
+    fn test_device_editor_edit_skips_device_id() {
+        let mut editor = DeviceEditorState::edit_device(&test_device_info());
+        assert!(!editor.is_new());
+        assert_eq!(editor.focused_field, DeviceEditorField::Name);
+        assert_eq!(editor.addresses, "dynamic, tcp://laptop:22000");
+        assert_eq!(editor.compression, "always");
+        assert!(editor.introducer);
+
+        editor.focus_prev();
+        assert_eq!(editor.focused_field, DeviceEditorField::Introducer);
+        editor.focus_next();
+        assert_eq!(editor.focused_field, DeviceEditorField::Name);
+    }
+
+    #[test]
+    fn test_device_editor_focused_text() {
+        let mut editor = DeviceEditorState::new_device();
+        editor.focused_field = DeviceEditorField::Name;
+        editor.focused_text_mut().unwrap().push_str("nas");
+        assert_eq!(editor.name, "nas");
+
+        editor.focused_field = DeviceEditorField::Introducer;
+        assert!(editor.focused_text_mut().is_none());
+    }
+
+    #[test]
+    fn test_device_modal_selected_device() {
+        let modal = DeviceDetailsModal {
+            devices: vec![test_device_info()],
+            selected_index: 0,
+            refreshed_at: Instant::now(),
+            editor: None,
+        };
+        assert_eq!(modal.selected_device().unwrap().device_name, "laptop");
+
+        let empty = DeviceDetailsModal {
+            devices: vec![],
+            ..modal
+        };
+        assert!(empty.selected_device().is_none());
+    }
+
+    // ========================================
+    // PENDING INBOX
+    // ========================================
+
+    #[test]
+    fn test_pending_inbox_selected_invitation() {
+        let modal = PendingInboxModal {
+            invitations: vec![
+                PendingInvitation::Device {
+                    device_id: "AAAAAAA-BBBBBBB".to_string(),
+                    name: "phone".to_string(),
+                    address: "192.168.1.20:22000".to_string(),
+                    time: "2025-01-15T10:30:00Z".to_string(),
+                },
+                PendingInvitation::Folder {
+                    folder_id: "photos".to_string(),
+                    label: "Photos".to_string(),
+                    device_id: "CCCCCCC-DDDDDDD".to_string(),
+                    device_name: "laptop".to_string(),
+                    time: "2025-01-15T10:31:00Z".to_string(),
+                },
+            ],
+            selected_index: 1,
+            accept_form: None,
+        };
+
+        let selected = modal.selected_invitation().unwrap();
+        assert!(matches!(selected, PendingInvitation::Folder { .. }));
+        assert_eq!(selected.time(), "2025-01-15T10:31:00Z");
+    }
+
+    // ========================================
+    // IGNORE EDITOR
+    // ========================================
+
+    fn editor(lines: &[&str]) -> IgnoreEditorModal {
+        IgnoreEditorModal::new(
+            "folder".to_string(),
+            "Folder".to_string(),
+            lines.iter().map(|l| l.to_string()).collect(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_ignore_editor_split_and_join_lines() {
+        let mut editor = editor(&["*.tmpcache"]);
+        editor.cursor_col = 5;
+        editor.split_line();
+        assert_eq!(editor.lines, vec!["*.tmp", "cache"]);
+        assert_eq!((editor.cursor_line, editor.cursor_col), (1, 0));
+
+        editor.backspace();
+        assert_eq!(editor.lines, vec!["*.tmpcache"]);
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 5));
+        assert!(!editor.is_modified());
+    }
+
+    #[test]
+    fn test_ignore_editor_move_delete_and_comment() {
+        let mut editor = editor(&["a", "b"]);
+        editor.move_line(false);
+        assert_eq!(editor.lines, vec!["b", "a"]);
+        assert_eq!(editor.cursor_line, 1);
+
+        editor.toggle_comment();
+        assert_eq!(editor.lines[1], "// a");
+        editor.toggle_comment();
+        assert_eq!(editor.lines[1], "a");
+
+        editor.delete_line();
+        editor.delete_line();
+        assert_eq!(editor.lines, vec![""]);
+        assert!(editor.lines_to_save().is_empty());
+        assert!(editor.is_modified());
+    }
+
+    #[test]
+    fn test_ignore_editor_multibyte_cursor() {
+        let mut editor = editor(&["é"]);
+        editor.move_cursor_end();
+        editor.insert_char('ü');
+        assert_eq!(editor.lines[0], "éü");
+        editor.backspace();
+        editor.backspace();
+        assert_eq!(editor.lines[0], "");
+    }
 }
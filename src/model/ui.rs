@@ -9,6 +9,7 @@ use super::types::{
     ConfirmAction, FileInfoPopupState, FolderHistoryModal, FolderTypeSelectionState,
     OutOfSyncFilterState, OutOfSyncSummaryState, PatternSelectionState, VimCommandState,
 };
+use crate::logic::keymap::{Keymap, ListModal};
 use crate::{DisplayMode, SortMode};
 
 /// UI preferences and popups
@@ -32,6 +33,9 @@ pub struct UiModel {
     /// Vim command state (for 'gg' double-key)
     pub vim_command_state: VimCommandState,
 
+    /// Active key bindings (defaults plus `keymap` from config)
+    pub keymap: Keymap,
+
     // ============================================
     // DIALOGS & POPUPS
     // ============================================
@@ -56,6 +60,9 @@ pub struct UiModel {
     /// Flag to trigger folder refresh in main loop (after reconnection)
     pub needs_folder_refresh: bool,
 
+    /// Whether folders and devices should be reloaded (set by ConfigSaved events)
+    pub needs_config_reload: bool,
+
     /// Toast message (text, timestamp)
     pub toast_message: Option<(String, Instant)>,
 
@@ -89,6 +96,72 @@ pub struct UiModel {
     /// Device details modal state
     pub device_details_modal: Option<super::types::DeviceDetailsModal>,
 
+    /// File versions modal state
+    pub file_versions_modal: Option<super::types::FileVersionsModal>,
+
+    /// Sync conflicts modal state
+    pub conflicts_modal: Option<super::types::ConflictsModal>,
+
+    /// `.stignore` editor modal state
+    pub ignore_editor: Option<super::types::IgnoreEditorModal>,
+
+    /// Folder settings form state
+    pub folder_settings: Option<super::types::FolderSettingsForm>,
+
+    /// New folder form state
+    pub new_folder: Option<super::types::NewFolderForm>,
+
+    /// Folder sharing checklist state
+    pub folder_sharing: Option<super::types::FolderSharingModal>,
+
+    /// Typed-name confirmation for removing a folder
+    pub remove_folder: Option<super::types::RemoveFolderPrompt>,
+
+    /// Rate limit popup state
+    pub rate_limits: Option<super::types::RateLimitModal>,
+
+    /// System menu state (version, restart, shutdown)
+    pub system_menu: Option<super::types::SystemMenu>,
+
+    /// Pending device/folder invitations inbox state
+    pub pending_inbox: Option<super::types::PendingInboxModal>,
+
+    /// Whether the pending inbox should be reloaded (set by pending-change events)
+    pub needs_pending_refresh: bool,
+
+    /// Failed items modal state
+    pub failed_items_modal: Option<super::types::FailedItemsModal>,
+
+    /// Whether the failed items modal should be reloaded (set by FolderErrors events)
+    pub needs_failed_items_refresh: bool,
+
+    /// Transfer queue modal state
+    pub transfer_queue: Option<super::types::TransferQueueModal>,
+
+    /// Whether the transfer queue should be reloaded (set by ItemStarted/ItemFinished events)
+    pub needs_transfer_queue_refresh: bool,
+
+    /// Remote device completion modal state
+    pub remote_completion: Option<super::types::RemoteCompletionModal>,
+
+    /// Whether the remote device completion should be reloaded (set by FolderCompletion events)
+    pub needs_remote_completion_refresh: bool,
+
+    /// Syncthing log viewer state
+    pub log_viewer: Option<super::types::LogViewerModal>,
+
+    /// Time of the newest error shown in the log viewer (errors after it raise the badge)
+    pub errors_seen_until: Option<String>,
+
+    /// Profile switcher modal state
+    pub profile_switcher: Option<super::types::ProfileSwitcherModal>,
+
+    /// Name of the connected profile (None when only one instance is configured)
+    pub active_profile: Option<String>,
+
+    /// Fleet overview modal state (all profiles at a glance)
+    pub fleet_overview: Option<super::types::FleetOverviewModal>,
+
     // ============================================
     // VISUAL STATE
     // ============================================
@@ -111,6 +184,7 @@ impl UiModel {
             display_mode: DisplayMode::TimestampAndSize,
             vim_mode,
             vim_command_state: VimCommandState::None,
+            keymap: Keymap::defaults(vim_mode),
             confirm_action: None,
             pattern_selection: None,
             folder_type_selection: None,
@@ -118,6 +192,7 @@ impl UiModel {
             show_setup_help: false,
             config_path: String::new(),
             needs_folder_refresh: false,
+            needs_config_reload: false,
             toast_message: None,
             search_mode: false,
             search_query: String::new(),
@@ -127,6 +202,28 @@ impl UiModel {
             folder_history_modal: None,
             folder_activity: std::collections::HashMap::new(),
             device_details_modal: None,
+            file_versions_modal: None,
+            conflicts_modal: None,
+            ignore_editor: None,
+            folder_settings: None,
+            new_folder: None,
+            folder_sharing: None,
+            remove_folder: None,
+            rate_limits: None,
+            system_menu: None,
+            pending_inbox: None,
+            needs_pending_refresh: false,
+            failed_items_modal: None,
+            needs_failed_items_refresh: false,
+            transfer_queue: None,
+            needs_transfer_queue_refresh: false,
+            remote_completion: None,
+            needs_remote_completion_refresh: false,
+            log_viewer: None,
+            errors_seen_until: None,
+            profile_switcher: None,
+            active_profile: None,
+            fleet_overview: None,
             sixel_cleanup_frames: 0,
             image_font_size: None,
             should_quit: false,
@@ -143,6 +240,64 @@ impl UiModel {
             || self.search_mode
     }
 
+    /// Check if a list modal (one navigated with the `modal` key bindings)
+    /// has focus; text inputs inside modals are excluded
+    pub fn has_list_modal(&self) -> bool {
+        self.out_of_sync_summary.is_some()
+            || self.folder_history_modal.is_some()
+            || self
+                .device_details_modal
+                .as_ref()
+                .is_some_and(|modal| modal.editor.is_none())
+            || self.file_versions_modal.is_some()
+            || self.conflicts_modal.is_some()
+            || self.failed_items_modal.is_some()
+            || self.transfer_queue.is_some()
+            || self.remote_completion.is_some()
+            || self.folder_sharing.is_some()
+            || self
+                .rate_limits
+                .as_ref()
+                .is_some_and(|modal| modal.custom_input.is_none())
+            || self.system_menu.is_some()
+            || self
+                .log_viewer
+                .as_ref()
+                .is_some_and(|viewer| !viewer.search_active)
+            || self
+                .pending_inbox
+                .as_ref()
+                .is_some_and(|inbox| inbox.accept_form.is_none())
+            || self.fleet_overview.is_some()
+            || self.profile_switcher.is_some()
+    }
+
+    /// The focused list modal, when it has keys of its own
+    pub fn list_modal(&self) -> Option<ListModal> {
+        let modal = if self.device_details_modal.is_some() {
+            ListModal::Devices
+        } else if self.file_versions_modal.is_some() {
+            ListModal::Versions
+        } else if self.rate_limits.is_some() {
+            ListModal::RateLimits
+        } else if self.system_menu.is_some() {
+            ListModal::SystemMenu
+        } else if self.conflicts_modal.is_some() {
+            ListModal::Conflicts
+        } else if self.transfer_queue.is_some() {
+            ListModal::Transfers
+        } else if self.log_viewer.is_some() {
+            ListModal::Logs
+        } else if self.pending_inbox.is_some() {
+            ListModal::Pending
+        } else if self.fleet_overview.is_some() {
+            ListModal::Overview
+        } else {
+            return None;
+        };
+        Some(modal)
+    }
+
     /// Close all modal dialogs
     pub fn close_all_modals(&mut self) {
         self.confirm_action = None;
@@ -204,6 +359,41 @@ mod tests {
         assert!(model.has_modal());
     }
 
+    #[test]
+    fn test_has_list_modal_excludes_text_input() {
+        let mut model = UiModel::new(false);
+        assert!(!model.has_list_modal());
+
+        model.log_viewer = Some(super::super::types::LogViewerModal {
+            lines: vec![],
+            scroll_offset: 0,
+            follow: true,
+            min_level: super::super::types::LogLevel::Info,
+            search_query: String::new(),
+            search_active: false,
+        });
+        assert!(model.has_list_modal());
+
+        model.log_viewer.as_mut().unwrap().search_active = true;
+        assert!(!model.has_list_modal());
+    }
+
+    #[test]
+    fn test_list_modal() {
+        let mut model = UiModel::new(false);
+        assert_eq!(model.list_modal(), None);
+
+        model.log_viewer = Some(super::super::types::LogViewerModal {
+            lines: vec![],
+            scroll_offset: 0,
+            follow: true,
+            min_level: super::super::types::LogLevel::Info,
+            search_query: String::new(),
+            search_active: false,
+        });
+        assert_eq!(model.list_modal(), Some(ListModal::Logs));
+    }
+
     #[test]
     fn test_close_all_modals() {
         let mut model = UiModel::new(false);
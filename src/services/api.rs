@@ -1,5 +1,5 @@
 use anyhow::Result;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::sync::atomic::Ordering;
@@ -7,9 +7,11 @@ use tokio::sync::mpsc;
 use tokio::time::{Duration, interval};
 
 use crate::api::{
-    BrowseItem, ConnectionStats, Device, FileDetails, FolderStatus, NeedResponse, SyncthingClient,
-    SystemStatus,
+    BrowseItem, ConnectionStats, ConnectionsResponse, Device, DeviceConfig, FileDetails, FileInfo,
+    Folder, FolderError, FolderStatus, LogEntry, NeedResponse, PendingDevice, PendingFolder,
+    RateLimits, SyncthingClient, SystemStatus,
 };
+use crate::logic::completion::DeviceCompletion;
 use crate::utils;
 
 fn log_debug(msg: &str) {
@@ -58,6 +60,27 @@ pub(crate) enum RequestKey {
     LocalChanged {
         folder_id: String,
     },
+    Config,
+    DeviceCount,
+    DeviceInfos,
+    RateLimits,
+    RestartRequired,
+    SystemErrors,
+    SystemLog,
+    PendingInvitations,
+    FailedItems {
+        folder_id: String,
+    },
+    TransferQueue {
+        folder_id: String,
+    },
+    RemoteCompletion {
+        folder_id: String,
+    },
+    RemoteNeed {
+        folder_id: String,
+        device_id: String,
+    },
 }
 
 /// API request types
@@ -83,6 +106,12 @@ pub enum ApiRequest {
     /// Trigger folder rescan (always high priority)
     RescanFolder { folder_id: String },
 
+    /// Rescan only some paths of a folder (marked items)
+    RescanPaths {
+        folder_id: String,
+        paths: Vec<String>,
+    },
+
     /// Get system status (device info, uptime)
     GetSystemStatus,
 
@@ -101,6 +130,50 @@ pub enum ApiRequest {
 
     /// Get local changed files (receive-only folders)
     GetLocalChanged { folder_id: String },
+
+    /// Reload folders and devices (after a ConfigSaved event)
+    ReloadConfig,
+
+    /// Count connected remote devices
+    GetDeviceCount,
+
+    /// Get device config and live connections for the devices modal
+    GetDeviceInfos,
+
+    /// Get global limits and device configs for the rate limit indicator
+    GetRateLimits,
+
+    /// Check whether saved config changes wait for a restart
+    GetRestartRequired,
+
+    /// Get Syncthing's error list
+    GetSystemErrors,
+
+    /// Get log lines newer than `since`
+    GetSystemLog { since: Option<String> },
+
+    /// Get pending device and folder invitations
+    GetPendingInvitations,
+
+    /// Get the first `count` failed items of a folder
+    GetFailedItems { folder_id: String, count: usize },
+
+    /// Get the first page of a folder's download queue
+    GetTransferQueue { folder_id: String },
+
+    /// Get the completion of every remote device sharing a folder
+    GetRemoteCompletion {
+        folder_id: String,
+        /// (device ID, display name) pairs
+        devices: Vec<(String, String)>,
+    },
+
+    /// Get the first `count` files a remote device still needs
+    GetRemoteNeed {
+        folder_id: String,
+        device_id: String,
+        count: usize,
+    },
 }
 
 impl ApiRequest {
@@ -137,10 +210,12 @@ impl ApiRequest {
                 folder_id: folder_id.clone(),
             },
             // Write operations don't deduplicate
-            ApiRequest::RescanFolder { .. } => RequestKey::Browse {
-                folder_id: format!("write-{:?}", std::time::Instant::now()),
-                prefix: None,
-            },
+            ApiRequest::RescanFolder { .. } | ApiRequest::RescanPaths { .. } => {
+                RequestKey::Browse {
+                    folder_id: format!("write-{:?}", std::time::Instant::now()),
+                    prefix: None,
+                }
+            }
             ApiRequest::GetSystemStatus => RequestKey::SystemStatus,
             ApiRequest::GetConnectionStats => RequestKey::ConnectionStats,
             ApiRequest::GetDevices => RequestKey::Devices,
@@ -150,10 +225,41 @@ impl ApiRequest {
             ApiRequest::GetLocalChanged { folder_id } => RequestKey::LocalChanged {
                 folder_id: folder_id.clone(),
             },
+            ApiRequest::ReloadConfig => RequestKey::Config,
+            ApiRequest::GetDeviceCount => RequestKey::DeviceCount,
+            ApiRequest::GetDeviceInfos => RequestKey::DeviceInfos,
+            ApiRequest::GetRateLimits => RequestKey::RateLimits,
+            ApiRequest::GetRestartRequired => RequestKey::RestartRequired,
+            ApiRequest::GetSystemErrors => RequestKey::SystemErrors,
+            ApiRequest::GetSystemLog { .. } => RequestKey::SystemLog,
+            ApiRequest::GetPendingInvitations => RequestKey::PendingInvitations,
+            ApiRequest::GetFailedItems { folder_id, .. } => RequestKey::FailedItems {
+                folder_id: folder_id.clone(),
+            },
+            ApiRequest::GetTransferQueue { folder_id } => RequestKey::TransferQueue {
+                folder_id: folder_id.clone(),
+            },
+            ApiRequest::GetRemoteCompletion { folder_id, .. } => RequestKey::RemoteCompletion {
+                folder_id: folder_id.clone(),
+            },
+            ApiRequest::GetRemoteNeed {
+                folder_id,
+                device_id,
+                ..
+            } => RequestKey::RemoteNeed {
+                folder_id: folder_id.clone(),
+                device_id: device_id.clone(),
+            },
         }
     }
 }
 
+/// Pending devices and folders, keyed like the REST responses
+pub type PendingOffers = (
+    HashMap<String, PendingDevice>,
+    HashMap<String, PendingFolder>,
+);
+
 /// API response types
 #[derive(Debug)]
 pub enum ApiResponse {
@@ -201,6 +307,62 @@ pub enum ApiResponse {
         folder_id: String,
         file_paths: Vec<String>,
     },
+
+    ConfigResult {
+        devices: Result<Vec<Device>, anyhow::Error>,
+        folders: Result<Vec<Folder>, anyhow::Error>,
+    },
+
+    DeviceCountResult {
+        connections: Result<ConnectionsResponse, anyhow::Error>,
+    },
+
+    DeviceInfosResult {
+        infos: Result<(Vec<DeviceConfig>, ConnectionsResponse), anyhow::Error>,
+    },
+
+    RateLimitsResult {
+        limits: Result<(RateLimits, Vec<DeviceConfig>), anyhow::Error>,
+    },
+
+    RestartRequiredResult {
+        required: Result<bool, anyhow::Error>,
+    },
+
+    SystemErrorsResult {
+        errors: Result<Vec<LogEntry>, anyhow::Error>,
+    },
+
+    SystemLogResult {
+        entries: Result<Vec<LogEntry>, anyhow::Error>,
+    },
+
+    PendingInvitationsResult {
+        invitations: Result<PendingOffers, anyhow::Error>,
+    },
+
+    FailedItemsResult {
+        folder_id: String,
+        count: usize,
+        items: Result<Vec<FolderError>, anyhow::Error>,
+    },
+
+    TransferQueueResult {
+        folder_id: String,
+        need: Result<NeedResponse, anyhow::Error>,
+    },
+
+    RemoteCompletionResult {
+        folder_id: String,
+        devices: Result<Vec<DeviceCompletion>, anyhow::Error>,
+    },
+
+    RemoteNeedResult {
+        folder_id: String,
+        device_id: String,
+        count: usize,
+        files: Result<Vec<FileInfo>, anyhow::Error>,
+    },
 }
 
 /// Internal message for tracking completed requests
@@ -308,6 +470,42 @@ impl ApiService {
         client.get_local_changed_files(folder_id).await
     }
 
+    /// Fetch the completion of each (device ID, name) pair for a folder
+    ///
+    /// A device whose completion can't be fetched is listed without one.
+    pub(crate) async fn get_device_completions(
+        client: &SyncthingClient,
+        folder_id: &str,
+        devices: Vec<(String, String)>,
+    ) -> Result<Vec<DeviceCompletion>> {
+        let connections = client.get_system_connections().await?;
+
+        let mut completions = Vec::with_capacity(devices.len());
+        for (device_id, name) in devices {
+            let completion = match client.get_completion(folder_id, &device_id).await {
+                Ok(completion) => Some(completion),
+                Err(e) => {
+                    log_debug(&format!(
+                        "Failed to get completion of {} for {}: {}",
+                        folder_id, device_id, e
+                    ));
+                    None
+                }
+            };
+            completions.push(DeviceCompletion {
+                connected: connections
+                    .connections
+                    .get(&device_id)
+                    .is_some_and(|c| c.connected),
+                device_id,
+                name,
+                completion,
+            });
+        }
+
+        Ok(completions)
+    }
+
     /// Execute an API request and return the response
     async fn execute_request(client: &SyncthingClient, request: ApiRequest) -> ApiResponse {
         match request {
@@ -378,6 +576,21 @@ impl ApiService {
                 }
             }
 
+            ApiRequest::RescanPaths { folder_id, paths } => {
+                match client.rescan_paths(&folder_id, &paths).await {
+                    Ok(()) => ApiResponse::RescanResult {
+                        folder_id,
+                        success: true,
+                        error: None,
+                    },
+                    Err(e) => ApiResponse::RescanResult {
+                        folder_id,
+                        success: false,
+                        error: Some(e),
+                    },
+                }
+            }
+
             ApiRequest::GetSystemStatus => {
                 let status = client.get_system_status().await;
 
@@ -445,6 +658,115 @@ impl ApiService {
                     }
                 }
             }
+
+            ApiRequest::ReloadConfig => {
+                let devices = client.get_devices().await;
+                let folders = client.get_folders().await;
+
+                ApiResponse::ConfigResult { devices, folders }
+            }
+
+            ApiRequest::GetDeviceCount => {
+                let connections = client.get_system_connections().await;
+
+                ApiResponse::DeviceCountResult { connections }
+            }
+
+            ApiRequest::GetDeviceInfos => {
+                let infos = async {
+                    let configs = client.get_config_devices().await?;
+                    let connections = client.get_system_connections().await?;
+                    Ok((configs, connections))
+                }
+                .await;
+
+                ApiResponse::DeviceInfosResult { infos }
+            }
+
+            ApiRequest::GetRateLimits => {
+                let limits = async {
+                    let global = client.get_global_rate_limits().await?;
+                    let devices = client.get_config_devices().await?;
+                    Ok((global, devices))
+                }
+                .await;
+
+                ApiResponse::RateLimitsResult { limits }
+            }
+
+            ApiRequest::GetRestartRequired => {
+                let required = client.get_restart_required().await;
+
+                ApiResponse::RestartRequiredResult { required }
+            }
+
+            ApiRequest::GetSystemErrors => {
+                let errors = client.get_system_errors().await;
+
+                ApiResponse::SystemErrorsResult { errors }
+            }
+
+            ApiRequest::GetSystemLog { since } => {
+                let entries = client.get_system_log(since.as_deref()).await;
+
+                ApiResponse::SystemLogResult { entries }
+            }
+
+            ApiRequest::GetPendingInvitations => {
+                let invitations = async {
+                    let devices = client.get_pending_devices().await?;
+                    let folders = client.get_pending_folders().await?;
+                    Ok((devices, folders))
+                }
+                .await;
+
+                ApiResponse::PendingInvitationsResult { invitations }
+            }
+
+            ApiRequest::GetFailedItems { folder_id, count } => {
+                let items = client.get_folder_errors(&folder_id, 1, count).await;
+
+                ApiResponse::FailedItemsResult {
+                    folder_id,
+                    count,
+                    items,
+                }
+            }
+
+            ApiRequest::GetTransferQueue { folder_id } => {
+                let need = client
+                    .get_needed_files(
+                        &folder_id,
+                        Some(1),
+                        Some(crate::logic::transfers::TRANSFER_QUEUE_PAGE_SIZE),
+                    )
+                    .await;
+
+                ApiResponse::TransferQueueResult { folder_id, need }
+            }
+
+            ApiRequest::GetRemoteCompletion { folder_id, devices } => {
+                let devices = Self::get_device_completions(client, &folder_id, devices).await;
+
+                ApiResponse::RemoteCompletionResult { folder_id, devices }
+            }
+
+            ApiRequest::GetRemoteNeed {
+                folder_id,
+                device_id,
+                count,
+            } => {
+                let files = client
+                    .get_remote_need(&folder_id, &device_id, 1, count)
+                    .await;
+
+                ApiResponse::RemoteNeedResult {
+                    folder_id,
+                    device_id,
+                    count,
+                    files,
+                }
+            }
         }
     }
 }
@@ -468,9 +790,12 @@ pub fn spawn_api_service(
 
         loop {
             tokio::select! {
-                // Receive new requests
-                Some(request) = request_rx.recv() => {
-                    service.enqueue(request);
+                // Receive new requests; stop once the app drops its sender (profile switch)
+                request = request_rx.recv() => {
+                    match request {
+                        Some(request) => service.enqueue(request),
+                        None => break,
+                    }
                 }
 
                 // Handle completion notifications
@@ -1,8 +1,10 @@
+use crate::api::{DownloadProgress, FolderStatus};
 use crate::utils;
 use anyhow::Result;
 use chrono::DateTime;
 use reqwest::Client;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::sync::atomic::Ordering;
@@ -90,16 +92,153 @@ pub enum CacheInvalidation {
         event_message: String,
         timestamp: std::time::SystemTime,
     },
+    /// A folder's failed items changed
+    FolderErrors { folder_id: String },
+    /// Pending device/folder invitations changed
+    PendingChanged {
+        /// Toast text for new offers (None when offers were only removed)
+        message: Option<String>,
+    },
+    /// A remote device connected or disconnected
+    DeviceConnection { device_id: String, connected: bool },
+    /// Fresh folder status pushed by Syncthing (same data as /rest/db/status)
+    FolderSummary {
+        folder_id: String,
+        status: Box<FolderStatus>,
+    },
+    /// A folder moved to a new state (idle, scanning, syncing, ...)
+    FolderState { folder_id: String, state: String },
+    /// A remote device's completion percentage for a folder
+    FolderCompletion {
+        folder_id: String,
+        device_id: String,
+        completion: f64,
+    },
+    /// A folder was paused or resumed
+    FolderPaused { folder_id: String, paused: bool },
+    /// The configuration was saved (possibly from the web UI)
+    ConfigSaved,
+    /// Scan progress of a folder
+    ScanProgress {
+        folder_id: String,
+        current: u64,
+        total: u64,
+    },
+    /// Every file currently being pulled (folder ID -> file path -> progress)
+    ///
+    /// Files missing from a later event have finished.
+    DownloadProgress {
+        folders: HashMap<String, HashMap<String, DownloadProgress>>,
+        timestamp: std::time::SystemTime,
+    },
+}
+
+/// Build the invalidation for events that update folder/device state directly
+///
+/// Covers DeviceConnected/Disconnected, FolderSummary, StateChanged,
+/// FolderCompletion, FolderPaused/Resumed, ConfigSaved and FolderScanProgress.
+/// Returns None for other event types or when required fields are missing.
+fn state_change_invalidation(event: &SyncthingEvent) -> Option<CacheInvalidation> {
+    let data = &event.data;
+    let string = |key: &str| data.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    let number = |key: &str| data.get(key).and_then(|v| v.as_u64());
+
+    match event.event_type.as_str() {
+        "DeviceConnected" | "DeviceDisconnected" => Some(CacheInvalidation::DeviceConnection {
+            device_id: string("id")?,
+            connected: event.event_type == "DeviceConnected",
+        }),
+        "FolderSummary" => {
+            let summary = data.get("summary")?.clone();
+            match serde_json::from_value::<FolderStatus>(summary) {
+                Ok(status) => Some(CacheInvalidation::FolderSummary {
+                    folder_id: string("folder")?,
+                    status: Box::new(status),
+                }),
+                Err(e) => {
+                    log_debug(&format!(
+                        "DEBUG [EVENT]: Failed to parse FolderSummary: {}",
+                        e
+                    ));
+                    None
+                }
+            }
+        }
+        "StateChanged" => Some(CacheInvalidation::FolderState {
+            folder_id: string("folder")?,
+            state: string("to")?,
+        }),
+        "FolderCompletion" => Some(CacheInvalidation::FolderCompletion {
+            folder_id: string("folder")?,
+            device_id: string("device")?,
+            completion: data.get("completion").and_then(|v| v.as_f64())?,
+        }),
+        // FolderPaused/FolderResumed carry the folder ID as "id"
+        "FolderPaused" | "FolderResumed" => Some(CacheInvalidation::FolderPaused {
+            folder_id: string("id")?,
+            paused: event.event_type == "FolderPaused",
+        }),
+        "ConfigSaved" => Some(CacheInvalidation::ConfigSaved),
+        "FolderScanProgress" => Some(CacheInvalidation::ScanProgress {
+            folder_id: string("folder")?,
+            current: number("current")?,
+            total: number("total")?,
+        }),
+        _ => None,
+    }
+}
+
+/// Build a toast message for PendingDevicesChanged / PendingFoldersChanged
+///
+/// Returns None when nothing was added (e.g. an offer was accepted or dismissed).
+/// The key hint is added when the toast is shown, from the active keymap.
+fn pending_change_message(event_type: &str, data: &serde_json::Value) -> Option<String> {
+    let added = data.get("added").and_then(|v| v.as_array())?;
+    let field = |entry: &serde_json::Value, key: &str| {
+        entry
+            .get(key)
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    };
+
+    match (event_type, added.as_slice()) {
+        (_, []) => None,
+        ("PendingDevicesChanged", [entry]) => {
+            let name = field(entry, "name")
+                .or_else(|| {
+                    field(entry, "deviceID")
+                        .map(|id| crate::logic::devices::short_device_id(&id).to_string())
+                })
+                .unwrap_or_else(|| "unknown".to_string());
+            Some(format!("Device {} wants to connect", name))
+        }
+        ("PendingDevicesChanged", entries) => {
+            Some(format!("{} devices want to connect", entries.len()))
+        }
+        ("PendingFoldersChanged", [entry]) => {
+            let label = field(entry, "folderLabel")
+                .or_else(|| field(entry, "folderID"))
+                .unwrap_or_else(|| "unknown".to_string());
+            Some(format!("Folder '{}' was offered", label))
+        }
+        ("PendingFoldersChanged", entries) => {
+            Some(format!("{} folders were offered", entries.len()))
+        }
+        _ => None,
+    }
 }
 
 /// Spawn the event listener task
+///
+/// Abort the returned handle to stop listening (used when switching profiles).
 pub fn spawn_event_listener(
     base_url: String,
     api_key: String,
     last_event_id: u64,
     invalidation_tx: mpsc::UnboundedSender<CacheInvalidation>,
     event_id_tx: mpsc::UnboundedSender<u64>,
-) {
+) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         if let Err(e) = event_listener_loop(
             base_url,
@@ -112,79 +251,7 @@ pub fn spawn_event_listener(
         {
             eprintln!("Event listener fatal error: {}", e);
         }
-    });
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_event_time_valid_rfc3339() {
-        // Test parsing a valid RFC3339 timestamp from Syncthing
-        // Use a dynamically generated timestamp that's always 1 hour in the past
-        use chrono::Utc;
-        let one_hour_ago = Utc::now() - chrono::Duration::hours(1);
-        let timestamp_str = one_hour_ago.to_rfc3339();
-
-        let parsed = parse_event_time(&timestamp_str);
-
-        // Verify it's not the fallback (current time) by checking it's in the past
-        let now = SystemTime::now();
-        assert!(
-            parsed < now,
-            "Parsed timestamp should be in the past, not current time"
-        );
-
-        // Verify the time is reasonable (should be about 1 hour ago, within 2 hours tolerance)
-        let duration_since = now.duration_since(parsed).unwrap();
-        assert!(
-            duration_since.as_secs() > 3000, // At least 50 minutes
-            "Timestamp should be at least 50 minutes old"
-        );
-        assert!(
-            duration_since.as_secs() < 7200, // Less than 2 hours
-            "Timestamp should be less than 2 hours old"
-        );
-    }
-
-    #[test]
-    fn test_parse_event_time_invalid_falls_back() {
-        // Test that invalid timestamps fall back to current time
-        let invalid_str = "not-a-timestamp";
-        let parsed = parse_event_time(invalid_str);
-
-        // Should be very close to now (within 1 second)
-        let now = SystemTime::now();
-        let diff = now.duration_since(parsed).unwrap_or(Duration::from_secs(0));
-        assert!(
-            diff.as_secs() < 1,
-            "Invalid timestamp should fall back to current time"
-        );
-    }
-
-    #[test]
-    fn test_parse_event_time_preserves_timestamp_accuracy() {
-        // Test that we preserve the exact timestamp from the event
-        // Use a specific timestamp from 2025-01-01
-        let timestamp_str = "2025-01-01T12:00:00.123456789Z";
-        let parsed = parse_event_time(timestamp_str);
-
-        // Convert back to check it matches
-        use chrono::DateTime;
-        let dt: chrono::DateTime<chrono::FixedOffset> =
-            DateTime::parse_from_rfc3339(timestamp_str).unwrap();
-        let expected = SystemTime::from(dt);
-
-        // Should be identical (within nanosecond precision)
-        let diff = parsed
-            .duration_since(expected)
-            .unwrap_or_else(|e| e.duration());
-        assert!(
-            diff.as_nanos() == 0,
-            "Timestamp should be parsed with full precision"
-        );
-    }
+    })
 }
 
 async fn event_listener_loop(
@@ -439,6 +506,62 @@ async fn event_listener_loop(
                                         let _ = invalidation_tx.send(invalidation);
                                     }
                                 }
+                                "FolderErrors" => {
+                                    if let Some(folder_id) =
+                                        event.data.get("folder").and_then(|v| v.as_str())
+                                    {
+                                        let invalidation = CacheInvalidation::FolderErrors {
+                                            folder_id: folder_id.to_string(),
+                                        };
+                                        log_debug(&format!(
+                                            "DEBUG [EVENT]: FolderErrors: {:?}",
+                                            invalidation
+                                        ));
+                                        let _ = invalidation_tx.send(invalidation);
+                                    }
+                                }
+                                "PendingDevicesChanged" | "PendingFoldersChanged" => {
+                                    let invalidation = CacheInvalidation::PendingChanged {
+                                        message: pending_change_message(
+                                            &event.event_type,
+                                            &event.data,
+                                        ),
+                                    };
+                                    log_debug(&format!(
+                                        "DEBUG [EVENT]: {}: {:?}",
+                                        event.event_type, invalidation
+                                    ));
+                                    let _ = invalidation_tx.send(invalidation);
+                                }
+                                "DeviceConnected" | "DeviceDisconnected" | "FolderSummary"
+                                | "StateChanged" | "FolderCompletion" | "FolderPaused"
+                                | "FolderResumed" | "ConfigSaved" | "FolderScanProgress" => {
+                                    if let Some(invalidation) = state_change_invalidation(event) {
+                                        log_debug(&format!(
+                                            "DEBUG [EVENT]: {}: {:?}",
+                                            event.event_type, invalidation
+                                        ));
+                                        let _ = invalidation_tx.send(invalidation);
+                                    }
+                                }
+                                "DownloadProgress" => {
+                                    match serde_json::from_value(event.data.clone()) {
+                                        Ok(folders) => {
+                                            let invalidation =
+                                                CacheInvalidation::DownloadProgress {
+                                                    folders,
+                                                    timestamp: parse_event_time(&event.time),
+                                                };
+                                            let _ = invalidation_tx.send(invalidation);
+                                        }
+                                        Err(e) => {
+                                            log_debug(&format!(
+                                                "DEBUG [EVENT]: Failed to parse DownloadProgress: {}",
+                                                e
+                                            ));
+                                        }
+                                    }
+                                }
                                 _ => {
                                     // Ignore other event types (but log them for debugging)
                                     log_debug(&format!(
@@ -476,3 +599,258 @@ async fn event_listener_loop(
         // If timeout occurred (empty array), we keep same last_event_id and try again
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_event_time_valid_rfc3339() {
+        // Test parsing a valid RFC3339 timestamp from Syncthing
+        // Use a dynamically generated timestamp that's always 1 hour in the past
+        use chrono::Utc;
+        let one_hour_ago = Utc::now() - chrono::Duration::hours(1);
+        let timestamp_str = one_hour_ago.to_rfc3339();
+
+        let parsed = parse_event_time(&timestamp_str);
+
+        // Verify it's not the fallback (current time) by checking it's in the past
+        let now = SystemTime::now();
+        assert!(
+            parsed < now,
+            "Parsed timestamp should be in the past, not current time"
+        );
+
+        // Verify the time is reasonable (should be about 1 hour ago, within 2 hours tolerance)
+        let duration_since = now.duration_since(parsed).unwrap();
+        assert!(
+            duration_since.as_secs() > 3000, // At least 50 minutes
+            "Timestamp should be at least 50 minutes old"
+        );
+        assert!(
+            duration_since.as_secs() < 7200, // Less than 2 hours
+            "Timestamp should be less than 2 hours old"
+        );
+    }
+
+    #[test]
+    fn test_parse_event_time_invalid_falls_back() {
+        // Test that invalid timestamps fall back to current time
+        let invalid_str = "not-a-timestamp";
+        let parsed = parse_event_time(invalid_str);
+
+        // Should be very close to now (within 1 second)
+        let now = SystemTime::now();
+        let diff = now.duration_since(parsed).unwrap_or(Duration::from_secs(0));
+        assert!(
+            diff.as_secs() < 1,
+            "Invalid timestamp should fall back to current time"
+        );
+    }
+
+    #[test]
+    fn test_parse_event_time_preserves_timestamp_accuracy() {
+        // Test that we preserve the exact timestamp from the event
+        // Use a specific timestamp from 2025-01-01
+        let timestamp_str = "2025-01-01T12:00:00.123456789Z";
+        let parsed = parse_event_time(timestamp_str);
+
+        // Convert back to check it matches
+        use chrono::DateTime;
+        let dt: chrono::DateTime<chrono::FixedOffset> =
+            DateTime::parse_from_rfc3339(timestamp_str).unwrap();
+        let expected = SystemTime::from(dt);
+
+        // Should be identical (within nanosecond precision)
+        let diff = parsed
+            .duration_since(expected)
+            .unwrap_or_else(|e| e.duration());
+        assert!(
+            diff.as_nanos() == 0,
+            "Timestamp should be parsed with full precision"
+        );
+    }
+
+    #[test]
+    fn test_pending_change_message_single_device() {
+        let data = serde_json::json!({
+            "added": [{
+                "deviceID": "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH",
+                "name": "phone",
+                "address": "192.168.1.20:22000"
+            }]
+        });
+        assert_eq!(
+            pending_change_message("PendingDevicesChanged", &data).as_deref(),
+            Some("Device phone wants to connect")
+        );
+
+        // Unnamed device falls back to the short ID
+        let data = serde_json::json!({
+            "added": [{
+                "deviceID": "AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG-HHHHHHH"
+            }]
+        });
+        assert_eq!(
+            pending_change_message("PendingDevicesChanged", &data).as_deref(),
+            Some("Device AAAAAAA wants to connect")
+        );
+    }
+
+    #[test]
+    fn test_pending_change_message_folders() {
+        let data = serde_json::json!({
+            "added": [{"deviceID": "AAAAAAA", "folderID": "abcd-1234", "folderLabel": "Photos"}]
+        });
+        assert_eq!(
+            pending_change_message("PendingFoldersChanged", &data).as_deref(),
+            Some("Folder 'Photos' was offered")
+        );
+
+        let data = serde_json::json!({
+            "added": [
+                {"deviceID": "AAAAAAA", "folderID": "one"},
+                {"deviceID": "AAAAAAA", "folderID": "two"}
+            ]
+        });
+        assert_eq!(
+            pending_change_message("PendingFoldersChanged", &data).as_deref(),
+            Some("2 folders were offered")
+        );
+    }
+
+    #[test]
+    fn test_pending_change_message_removed_only() {
+        let data = serde_json::json!({
+            "removed": [{"deviceID": "AAAAAAA", "folderID": "abcd-1234"}]
+        });
+        assert_eq!(pending_change_message("PendingFoldersChanged", &data), None);
+
+        let data = serde_json::json!({"added": [], "removed": [{"deviceID": "AAAAAAA"}]});
+        assert_eq!(pending_change_message("PendingDevicesChanged", &data), None);
+    }
+
+    fn event(event_type: &str, data: serde_json::Value) -> SyncthingEvent {
+        SyncthingEvent {
+            id: 1,
+            global_id: 1,
+            time: "2025-01-01T12:00:00Z".to_string(),
+            event_type: event_type.to_string(),
+            data,
+        }
+    }
+
+    #[test]
+    fn test_state_change_invalidation_devices_and_pause() {
+        let connected = event(
+            "DeviceConnected",
+            serde_json::json!({"id": "NAS0000", "deviceName": "nas", "type": "tcp-client"}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&connected),
+            Some(CacheInvalidation::DeviceConnection { device_id, connected: true }) if device_id == "NAS0000"
+        ));
+
+        let disconnected = event(
+            "DeviceDisconnected",
+            serde_json::json!({"id": "NAS0000", "error": "reading: EOF"}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&disconnected),
+            Some(CacheInvalidation::DeviceConnection {
+                connected: false,
+                ..
+            })
+        ));
+
+        let resumed = event(
+            "FolderResumed",
+            serde_json::json!({"id": "docs", "label": "Docs"}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&resumed),
+            Some(CacheInvalidation::FolderPaused { folder_id, paused: false }) if folder_id == "docs"
+        ));
+    }
+
+    #[test]
+    fn test_state_change_invalidation_folder_events() {
+        let state = event(
+            "StateChanged",
+            serde_json::json!({"folder": "docs", "from": "idle", "to": "scanning"}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&state),
+            Some(CacheInvalidation::FolderState { folder_id, state }) if folder_id == "docs" && state == "scanning"
+        ));
+
+        let progress = event(
+            "FolderScanProgress",
+            serde_json::json!({"folder": "docs", "current": 100, "total": 400, "rate": 25.5}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&progress),
+            Some(CacheInvalidation::ScanProgress {
+                current: 100,
+                total: 400,
+                ..
+            })
+        ));
+
+        let completion = event(
+            "FolderCompletion",
+            serde_json::json!({"folder": "docs", "device": "NAS0000", "completion": 87.5, "needBytes": 1024}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&completion),
+            Some(CacheInvalidation::FolderCompletion { device_id, completion, .. })
+                if device_id == "NAS0000" && completion == 87.5
+        ));
+
+        let summary = event(
+            "FolderSummary",
+            serde_json::json!({"folder": "docs", "summary": {
+                "state": "idle", "sequence": 12, "globalBytes": 2048, "globalDeleted": 0,
+                "globalDirectories": 1, "globalFiles": 3, "globalSymlinks": 0,
+                "globalTotalItems": 4, "inSyncBytes": 2048, "inSyncFiles": 3,
+                "localBytes": 2048, "localDeleted": 0, "localDirectories": 1,
+                "localFiles": 3, "localSymlinks": 0, "localTotalItems": 4,
+                "needBytes": 0, "needDeletes": 0, "needDirectories": 0, "needFiles": 0,
+                "needSymlinks": 0, "needTotalItems": 0, "receiveOnlyChangedBytes": 0,
+                "receiveOnlyChangedDeletes": 0, "receiveOnlyChangedDirectories": 0,
+                "receiveOnlyChangedFiles": 0, "receiveOnlyChangedSymlinks": 0,
+                "receiveOnlyTotalItems": 0, "errors": 0
+            }}),
+        );
+        assert!(matches!(
+            state_change_invalidation(&summary),
+            Some(CacheInvalidation::FolderSummary { folder_id, status }) if folder_id == "docs" && status.sequence == 12
+        ));
+    }
+
+    #[test]
+    fn test_state_change_invalidation_missing_fields() {
+        // Malformed events are dropped rather than half-applied
+        assert!(
+            state_change_invalidation(&event(
+                "StateChanged",
+                serde_json::json!({"folder": "docs"})
+            ))
+            .is_none()
+        );
+        assert!(
+            state_change_invalidation(&event(
+                "FolderSummary",
+                serde_json::json!({"folder": "docs", "summary": {"state": "idle"}})
+            ))
+            .is_none()
+        );
+        assert!(
+            state_change_invalidation(&event("LocalIndexUpdated", serde_json::json!({}))).is_none()
+        );
+        assert!(matches!(
+            state_change_invalidation(&event("ConfigSaved", serde_json::json!({"version": 37}))),
+            Some(CacheInvalidation::ConfigSaved)
+        ));
+    }
+}
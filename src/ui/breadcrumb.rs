@@ -1,10 +1,11 @@
 use super::icons::IconRenderer;
+use super::theme::Theme;
 use crate::api::{BrowseItem, SyncState};
 use ::stui::DisplayMode;
 use ratatui::{
     Frame,
     layout::{Margin, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Scrollbar, ScrollbarOrientation, ScrollbarState},
 };
@@ -42,6 +43,7 @@ fn build_list_item<'a>(
     panel_width: u16,
     display_mode: DisplayMode,
     add_spacing: bool,
+    theme: &Theme,
 ) -> ListItem<'a> {
     let is_directory = item.item_type == "FILE_INFO_TYPE_DIRECTORY";
 
@@ -99,7 +101,7 @@ fn build_list_item<'a>(
         line_spans.push(Span::raw(" ".repeat(padding)));
         line_spans.push(Span::styled(
             info_string,
-            Style::default().fg(Color::Rgb(120, 120, 120)),
+            Style::default().fg(theme.text_muted),
         ));
         return ListItem::new(Line::from(line_spans));
     }
@@ -145,7 +147,7 @@ fn build_list_item<'a>(
             line_spans.push(Span::raw(" ".repeat(padding)));
             line_spans.push(Span::styled(
                 truncated_info,
-                Style::default().fg(Color::Rgb(120, 120, 120)),
+                Style::default().fg(theme.text_muted),
             ));
             return ListItem::new(Line::from(line_spans));
         }
@@ -167,12 +169,14 @@ pub fn render_breadcrumb_panel(
     filtered_items: Option<&Vec<BrowseItem>>, // Filtered view
     file_sync_states: &std::collections::HashMap<String, SyncState>,
     ignored_exists: &std::collections::HashMap<String, bool>,
+    marked: &std::collections::HashSet<String>, // Items marked for batch operations
     state: &mut ratatui::widgets::ListState,
     title: &str,
     is_focused: bool,
     is_parent_selected: bool,
     display_mode: DisplayMode,
     icon_renderer: &IconRenderer,
+    theme: &Theme,
     _translated_base_path: &str,
     _prefix: Option<&str>,
 ) {
@@ -200,23 +204,34 @@ pub fn render_breadcrumb_panel(
                 icon_renderer.item_with_sync_state(is_directory, sync_state)
             };
 
-            build_list_item(
+            let list_item = build_list_item(
                 item,
                 icon_spans,
                 panel_width,
                 display_mode,
                 !is_focused && !is_parent_selected, // Add spacing when neither focused nor parent selected
-            )
+                theme,
+            );
+
+            if marked.contains(&item.name) {
+                list_item.style(
+                    Style::default()
+                        .fg(theme.label)
+                        .add_modifier(Modifier::UNDERLINED),
+                )
+            } else {
+                list_item
+            }
         })
         .collect();
 
     // Build list widget with conditional styling
     let border_color = if is_focused {
-        Color::Cyan
+        theme.border
     } else if is_parent_selected {
-        Color::Blue // Distinct color for parent selection
+        theme.border_parent // Distinct color for parent selection
     } else {
-        Color::Gray
+        theme.border_inactive
     };
 
     let mut list = List::new(list_items).block(
@@ -229,20 +244,12 @@ pub fn render_breadcrumb_panel(
     // Add highlight when focused (with arrow) or parent selected (without arrow)
     if is_focused {
         list = list
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(theme.selection().add_modifier(Modifier::BOLD))
             .highlight_symbol("> ");
         f.render_stateful_widget(list, area, state);
     } else if is_parent_selected {
         list = list
-            .highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(theme.selection().add_modifier(Modifier::BOLD))
             .highlight_symbol("  "); // Two spaces to maintain alignment
         f.render_stateful_widget(list, area, state);
     } else {
@@ -0,0 +1,197 @@
+//! Sync conflicts modal rendering
+//!
+//! Lists the conflict copies in a folder above a side-by-side preview of the
+//! selected original and its conflicting copy.
+
+use crate::logic::conflicts::format_conflict_time;
+use crate::logic::formatting::format_datetime;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::ConflictsModal;
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use crate::utils;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the sync conflicts modal
+pub fn render_conflicts_modal(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &mut ConflictsModal,
+) {
+    // Calculate centered modal dimensions (90% width, 80% height)
+    let modal_width = (area.width as f32 * 0.9) as u16;
+    let modal_height = (area.height as f32 * 0.8) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    let list_height = (modal_state.conflicts.len() as u16 + 2).clamp(4, modal_area.height / 3);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(list_height), Constraint::Min(6)])
+        .split(modal_area);
+
+    // Conflict list
+    let dim = Style::default().fg(theme.text_muted);
+    let items: Vec<ListItem> = modal_state
+        .conflicts
+        .iter()
+        .map(|conflict| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    conflict.original_path.clone(),
+                    Style::default().fg(theme.text),
+                ),
+                Span::raw("  "),
+                Span::styled(format_conflict_time(&conflict.timestamp), dim),
+                Span::raw("  from "),
+                Span::styled(
+                    conflict.device_name.clone(),
+                    Style::default().fg(theme.label),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Sync Conflicts: {} ({}) ",
+                    modal_state.folder_label,
+                    modal_state.conflicts.len()
+                ))
+                .title_bottom(format!(
+                    " {} ",
+                    keymap.modal_hints(&[
+                        (&[Action::KeepOriginal], "Keep Original"),
+                        (&[Action::KeepConflict], "Keep Conflict"),
+                        (&[Action::KeepBoth], "Keep Both"),
+                        (&[Action::Close], "Close"),
+                    ])
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.conflicts.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+    f.render_stateful_widget(list, rows[0], &mut list_state);
+
+    let Some(conflict) = modal_state.selected_conflict().cloned() else {
+        return;
+    };
+
+    // Side-by-side previews (original left, conflict copy right)
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[1]);
+
+    let original_title = match conflict.original_size {
+        Some(size) => format!(
+            " Original: {} ({}, modified {}) ",
+            conflict.original_path,
+            utils::format_bytes(size),
+            format_datetime(&conflict.original_modified)
+        ),
+        None => format!(" Original: {} (missing) ", conflict.original_path),
+    };
+    let conflict_title = format!(
+        " Conflict from {} ({}, modified {}) ",
+        conflict.device_name,
+        utils::format_bytes(conflict.conflict_size),
+        format_datetime(&conflict.conflict_modified)
+    );
+
+    // Both panes share one scroll offset so matching lines stay aligned
+    let original_text = preview_text(&modal_state.original_preview);
+    let conflict_text = preview_text(&modal_state.conflict_preview);
+    let viewport_height = columns[0].height.saturating_sub(2) as usize;
+    let longest = original_text.lines.len().max(conflict_text.lines.len());
+    let max_scroll = longest.saturating_sub(viewport_height);
+    let clamped_scroll = (modal_state.scroll_offset as usize).min(max_scroll) as u16;
+    modal_state.scroll_offset = clamped_scroll;
+
+    render_preview_pane(
+        f,
+        theme,
+        keymap,
+        icon_renderer,
+        columns[0],
+        original_text,
+        original_title,
+        clamped_scroll,
+    );
+    render_preview_pane(
+        f,
+        theme,
+        keymap,
+        icon_renderer,
+        columns[1],
+        conflict_text,
+        conflict_title,
+        clamped_scroll,
+    );
+}
+
+/// Parse preview content (or its error) into styled text
+fn preview_text(preview: &Result<String, String>) -> ratatui::text::Text<'static> {
+    let content = match preview {
+        Ok(text) => text.clone(),
+        Err(msg) => format!("Error: {}", msg),
+    };
+    crate::logic::file::parse_ansi_to_text(&content)
+}
+
+/// Render one preview pane
+#[allow(clippy::too_many_arguments)]
+fn render_preview_pane(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    text: ratatui::text::Text<'static>,
+    title: String,
+    scroll: u16,
+) {
+    // NOTE: Don't set .style() on the Paragraph - it would override ANSI colors
+    let preview = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  PgUp/PgDn:Scroll ",
+                    keymap.modal_nav_label()
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    f.render_widget(preview, area);
+}
@@ -0,0 +1,308 @@
+//! Device management modal rendering
+//!
+//! Displays remote devices with connection state, address and live rates,
+//! plus the add/edit form shown on top of the list.
+
+use crate::logic::devices::{compression_label, format_addresses, short_device_id};
+use crate::logic::formatting::format_transfer_rate;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{DeviceDetailsModal, DeviceEditorField, DeviceEditorState, DeviceInfo};
+use crate::ui::icons::{ConnectionIcon, IconRenderer};
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the device management modal
+pub fn render_devices_modal(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &DeviceDetailsModal,
+) {
+    // Calculate centered modal dimensions (80% width, 70% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.7) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(7)])
+        .split(modal_area);
+
+    let items: Vec<ListItem> = if modal_state.devices.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No remote devices configured",
+            Style::default().fg(theme.text_secondary),
+        ))]
+    } else {
+        modal_state
+            .devices
+            .iter()
+            .map(|device| device_list_item(device, theme, icon_renderer))
+            .collect()
+    };
+
+    let connected = modal_state.devices.iter().filter(|d| d.connected).count();
+    let title = format!(
+        " Devices ({}/{} connected) ",
+        connected,
+        modal_state.devices.len()
+    );
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(format!(
+                    " {} ",
+                    keymap.modal_hints(&[
+                        (&[Action::AddDevice], "Add"),
+                        (&[Action::EditDevice], "Edit"),
+                        (&[Action::PauseDevice], "Pause/Resume"),
+                        (&[Action::RemoveDevice], "Remove"),
+                        (&[Action::Close], "Close"),
+                    ])
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.devices.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+
+    f.render_widget(Clear, modal_area);
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    // Details of the selected device
+    let details = match modal_state.selected_device() {
+        Some(device) => device_details_lines(device, theme),
+        None => vec![],
+    };
+    let details_widget = Paragraph::new(details)
+        .block(
+            Block::default()
+                .title(" Details ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(details_widget, chunks[1]);
+
+    if let Some(editor) = &modal_state.editor {
+        render_device_editor(f, theme, icon_renderer, area, editor);
+    }
+}
+
+/// Build a single row for the device list
+fn device_list_item<'a>(
+    device: &'a DeviceInfo,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+) -> ListItem<'a> {
+    let (icon, label, color) = if device.paused {
+        (ConnectionIcon::Paused, "Paused", theme.paused)
+    } else if device.connected {
+        (ConnectionIcon::Connected, "Connected", theme.success)
+    } else {
+        (ConnectionIcon::Disconnected, "Disconnected", theme.error)
+    };
+    let status = format!("{} {:<12}", icon_renderer.render_connection(icon), label);
+
+    let symbols = icon_renderer.symbols();
+    let rates = match (device.download_rate, device.upload_rate) {
+        (Some(down), Some(up)) if device.connected => format!(
+            "{}{} {}{}",
+            symbols.download,
+            format_transfer_rate(down),
+            symbols.upload,
+            format_transfer_rate(up)
+        ),
+        _ => String::new(),
+    };
+
+    let spans = vec![
+        Span::styled(status, Style::default().fg(color)),
+        Span::raw("  "),
+        Span::styled(device.device_name.clone(), Style::default().fg(theme.text)),
+        Span::styled(
+            format!(" ({})", short_device_id(&device.device_id)),
+            Style::default().fg(theme.text_muted),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!(
+                "{}/{} folders",
+                device.shared_folder_count, device.total_folder_count
+            ),
+            Style::default().fg(theme.text_muted),
+        ),
+        Span::raw("  "),
+        Span::styled(rates, Style::default().fg(theme.label)),
+    ];
+
+    ListItem::new(Line::from(spans))
+}
+
+/// Build the detail lines for the selected device
+fn device_details_lines(device: &DeviceInfo, theme: &Theme) -> Vec<Line<'static>> {
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(theme.label));
+
+    let address = if device.connected && !device.address.is_empty() {
+        device.address.clone()
+    } else {
+        "-".to_string()
+    };
+
+    vec![
+        Line::from(vec![label("ID: "), Span::raw(device.device_id.clone())]),
+        Line::from(vec![label("Address: "), Span::raw(address)]),
+        Line::from(vec![
+            label("Configured: "),
+            Span::raw(format_addresses(&device.addresses)),
+        ]),
+        Line::from(vec![
+            label("Compression: "),
+            Span::raw(compression_label(&device.compression).to_string()),
+            Span::raw("  "),
+            label("Introducer: "),
+            Span::raw(if device.introducer { "Yes" } else { "No" }),
+        ]),
+    ]
+}
+
+/// Render the add/edit device form
+fn render_device_editor(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    editor: &DeviceEditorState,
+) {
+    let form_width = 76.min(area.width);
+    let form_height = 11;
+    let form_area = Rect {
+        x: (area.width.saturating_sub(form_width)) / 2,
+        y: (area.height.saturating_sub(form_height)) / 2,
+        width: form_width,
+        height: form_height.min(area.height),
+    };
+
+    let field_line = |field: DeviceEditorField, label: &'static str, value: String| {
+        let focused = editor.focused_field == field;
+        let marker = if focused {
+            icon_renderer.render_highlight()
+        } else {
+            "  "
+        };
+        let value_style = if focused {
+            Style::default()
+                .fg(theme.text)
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let is_text = matches!(
+            field,
+            DeviceEditorField::DeviceId | DeviceEditorField::Name | DeviceEditorField::Addresses
+        );
+        let cursor = if focused && is_text {
+            icon_renderer.render_cursor()
+        } else {
+            ""
+        };
+        Line::from(vec![
+            Span::raw(marker),
+            Span::styled(format!("{:<13}", label), Style::default().fg(theme.label)),
+            Span::styled(format!("{}{}", value, cursor), value_style),
+        ])
+    };
+
+    let device_id_line = if editor.is_new() {
+        field_line(
+            DeviceEditorField::DeviceId,
+            "Device ID:",
+            editor.device_id.clone(),
+        )
+    } else {
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!("{:<13}", "Device ID:"),
+                Style::default().fg(theme.label),
+            ),
+            Span::styled(
+                editor.device_id.clone(),
+                Style::default().fg(theme.text_secondary),
+            ),
+        ])
+    };
+
+    let lines = vec![
+        device_id_line,
+        field_line(DeviceEditorField::Name, "Name:", editor.name.clone()),
+        field_line(
+            DeviceEditorField::Addresses,
+            "Addresses:",
+            editor.addresses.clone(),
+        ),
+        field_line(
+            DeviceEditorField::Compression,
+            "Compression:",
+            icon_renderer.render_choice(compression_label(&editor.compression)),
+        ),
+        field_line(
+            DeviceEditorField::Introducer,
+            "Introducer:",
+            if editor.introducer { "[x]" } else { "[ ]" }.to_string(),
+        ),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Addresses: comma-separated, e.g. dynamic, tcp://host:22000",
+            Style::default().fg(theme.text_secondary),
+        )),
+    ];
+
+    let title = if editor.is_new() {
+        " Add Device ".to_string()
+    } else {
+        format!(" Edit Device: {} ", editor.name)
+    };
+
+    let form = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_bottom(
+                    icon_renderer
+                        .render_text(" Tab/↑↓:Field  Space:Toggle  Enter:Save  Esc:Cancel "),
+                )
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog());
+
+    f.render_widget(Clear, form_area);
+    f.render_widget(form, form_area);
+}
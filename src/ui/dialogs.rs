@@ -1,7 +1,7 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Margin, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{
         Block, Borders, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
@@ -10,7 +10,9 @@ use ratatui::{
 };
 
 use super::icons::IconRenderer;
-use crate::model::FileInfoPopupState;
+use super::theme::Theme;
+use crate::logic::ignore::IgnoreMatch;
+use crate::model::{BatchItem, BatchOperation, FileInfoPopupState};
 use crate::utils;
 use crate::{ImagePreviewState, api::Device};
 
@@ -25,7 +27,7 @@ fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
 }
 
 /// Render the revert confirmation dialog (for restoring deleted files in receive-only folders)
-pub fn render_revert_confirmation(f: &mut Frame, changed_files: &[String]) {
+pub fn render_revert_confirmation(f: &mut Frame, theme: &Theme, changed_files: &[String]) {
     let file_list = changed_files
         .iter()
         .take(5)
@@ -61,9 +63,62 @@ pub fn render_revert_confirmation(f: &mut Frame, changed_files: &[String]) {
             Block::default()
                 .borders(Borders::ALL)
                 .title("Confirm Revert")
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(theme.dialog_border)),
         )
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render the override confirmation dialog (send-only folders)
+pub fn render_override_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    folder_label: &str,
+    changed_files: &[String],
+    total: u64,
+) {
+    let file_list = changed_files
+        .iter()
+        .take(5)
+        .map(|file| format!("  - {}", file))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let shown = changed_files.len().min(5) as u64;
+    let more_text = if total > shown {
+        format!("\n  ... and {} more", total - shown)
+    } else {
+        String::new()
+    };
+
+    let prompt_text = format!(
+        "Override remote changes in {}?\n\n\
+        WARNING: The local version replaces {} remote change(s):\n{}{}\n\n\
+        Continue? (y/n)",
+        folder_label,
+        total.max(changed_files.len() as u64),
+        file_list,
+        more_text
+    );
+
+    // Center the prompt - adjust height based on number of files shown
+    let area = f.area();
+    let prompt_width = 60;
+    let base_height = 11;
+    let prompt_height = base_height + shown as u16;
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm Override")
+                .border_style(Style::default().fg(theme.dialog_danger)),
+        )
+        .style(theme.dialog())
         .wrap(ratatui::widgets::Wrap { trim: false });
 
     f.render_widget(ratatui::widgets::Clear, prompt_area);
@@ -71,7 +126,7 @@ pub fn render_revert_confirmation(f: &mut Frame, changed_files: &[String]) {
 }
 
 /// Render the delete confirmation dialog
-pub fn render_delete_confirmation(f: &mut Frame, display_name: &str, is_dir: bool) {
+pub fn render_delete_confirmation(f: &mut Frame, theme: &Theme, display_name: &str, is_dir: bool) {
     let item_type = if is_dir { "directory" } else { "file" };
 
     let prompt_text = format!(
@@ -95,9 +150,84 @@ pub fn render_delete_confirmation(f: &mut Frame, display_name: &str, is_dir: boo
             Block::default()
                 .borders(Borders::ALL)
                 .title("Confirm Delete")
-                .border_style(Style::default().fg(Color::Red)),
+                .border_style(Style::default().fg(theme.dialog_danger)),
+        )
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render the confirmation dialog for a batch operation on marked items
+pub fn render_batch_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    operation: BatchOperation,
+    items: &[BatchItem],
+) {
+    let dirs = items.iter().filter(|item| item.is_dir).count();
+    let on_disk = items.iter().filter(|item| item.exists).count();
+
+    let item_list = items
+        .iter()
+        .take(5)
+        .map(|item| {
+            let suffix = if item.is_dir { "/" } else { "" };
+            format!("  - {}{}", item.relative_path, suffix)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let more_text = if items.len() > 5 {
+        format!("\n  ... and {} more", items.len() - 5)
+    } else {
+        String::new()
+    };
+
+    let warning = match operation {
+        BatchOperation::Ignore => "Adds one /path line per item to .stignore.".to_string(),
+        BatchOperation::IgnoreDelete | BatchOperation::Delete => format!(
+            "WARNING: Removes {} item(s) from disk. This cannot be undone!",
+            on_disk
+        ),
+    };
+
+    let prompt_text = format!(
+        "{} {} marked item(s)?\n\n\
+        {} file(s), {} directory(ies):\n{}{}\n\n\
+        {}\n\n\
+        Continue? (y/n)",
+        operation.label(),
+        items.len(),
+        items.len() - dirs,
+        dirs,
+        item_list,
+        more_text,
+        warning
+    );
+
+    // Center the prompt - adjust height based on number of items shown
+    let area = f.area();
+    let prompt_width = 60;
+    let base_height = 12;
+    let item_lines = items.len().min(5) + usize::from(items.len() > 5);
+    let prompt_height = base_height + item_lines as u16;
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let border_color = match operation {
+        BatchOperation::Ignore => theme.dialog_border,
+        BatchOperation::IgnoreDelete | BatchOperation::Delete => theme.dialog_danger,
+    };
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Confirm {}", operation.label()))
+                .border_style(Style::default().fg(border_color)),
         )
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+        .style(theme.dialog())
         .wrap(ratatui::widgets::Wrap { trim: false });
 
     f.render_widget(ratatui::widgets::Clear, prompt_area);
@@ -105,7 +235,12 @@ pub fn render_delete_confirmation(f: &mut Frame, display_name: &str, is_dir: boo
 }
 
 /// Render pause/resume folder confirmation dialog
-pub fn render_pause_resume_confirmation(f: &mut Frame, folder_label: &str, is_paused: bool) {
+pub fn render_pause_resume_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    folder_label: &str,
+    is_paused: bool,
+) {
     let action = if is_paused { "Resume" } else { "Pause" };
     let action_lower = if is_paused { "resume" } else { "pause" };
 
@@ -124,9 +259,9 @@ pub fn render_pause_resume_confirmation(f: &mut Frame, folder_label: &str, is_pa
     let prompt_area = centered_rect(area, prompt_width, prompt_height);
 
     let border_color = if is_paused {
-        Color::Green
+        theme.success
     } else {
-        Color::Yellow
+        theme.warning
     };
 
     let prompt = Paragraph::new(prompt_text)
@@ -136,7 +271,211 @@ pub fn render_pause_resume_confirmation(f: &mut Frame, folder_label: &str, is_pa
                 .title(format!("Confirm {}", action))
                 .border_style(Style::default().fg(border_color)),
         )
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render pause/resume device confirmation dialog
+pub fn render_device_pause_resume_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    device_name: &str,
+    is_paused: bool,
+) {
+    let action = if is_paused { "Resume" } else { "Pause" };
+    let action_lower = if is_paused { "resume" } else { "pause" };
+
+    let prompt_text = format!(
+        "{} device?\n\n\
+        Device: {}\n\n\
+        This will {} syncing with this device.\n\n\
+        Continue? (y/n)",
+        action, device_name, action_lower
+    );
+
+    // Center the prompt
+    let area = f.area();
+    let prompt_width = 50;
+    let prompt_height = 10;
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let border_color = if is_paused {
+        theme.success
+    } else {
+        theme.warning
+    };
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Confirm {}", action))
+                .border_style(Style::default().fg(border_color)),
+        )
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render remove device confirmation dialog
+pub fn render_remove_device_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    device_name: &str,
+    device_id: &str,
+) {
+    let prompt_text = format!(
+        "Remove device from configuration?\n\n\
+        Device: {}\n\
+        ID: {}\n\n\
+        The device will be unshared from all folders.\n\n\
+        Continue? (y/n)",
+        device_name,
+        crate::logic::devices::short_device_id(device_id)
+    );
+
+    // Center the prompt
+    let area = f.area();
+    let prompt_width = 52;
+    let prompt_height = 11;
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm Remove Device")
+                .border_style(Style::default().fg(theme.dialog_danger)),
+        )
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render restore file version confirmation dialog
+///
+/// `versions` holds (file path, version time) pairs, one per file.
+pub fn render_restore_version_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    versions: &[(String, String)],
+) {
+    let prompt_text = match versions {
+        [(file_path, version_time)] => format!(
+            "Restore archived version?\n\n\
+            File: {}\n\
+            Version: {}\n\n\
+            The current file will be archived and replaced.\n\n\
+            Continue? (y/n)",
+            file_path,
+            crate::logic::formatting::format_datetime(version_time)
+        ),
+        _ => {
+            let version_list = versions
+                .iter()
+                .take(5)
+                .map(|(file_path, version_time)| {
+                    format!(
+                        "  - {} ({})",
+                        file_path,
+                        crate::logic::formatting::format_datetime(version_time)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let more_text = if versions.len() > 5 {
+                format!("\n  ... and {} more", versions.len() - 5)
+            } else {
+                String::new()
+            };
+            format!(
+                "Restore {} archived versions?\n\n\
+                {}{}\n\n\
+                The current files will be archived and replaced.\n\n\
+                Continue? (y/n)",
+                versions.len(),
+                version_list,
+                more_text
+            )
+        }
+    };
+
+    // Center the prompt - adjust height based on number of files shown
+    let area = f.area();
+    let prompt_width = 60;
+    let prompt_height = match versions.len() {
+        1 => 11,
+        n => 9 + (n.min(5) + usize::from(n > 5)) as u16,
+    };
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm Restore Version")
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog())
+        .wrap(ratatui::widgets::Wrap { trim: false });
+
+    f.render_widget(ratatui::widgets::Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Render sync conflict resolution confirmation dialog
+pub fn render_resolve_conflict_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    conflict_path: &str,
+    original_path: &str,
+    resolution: crate::model::ConflictResolution,
+) {
+    let (title, outcome) = match resolution {
+        crate::model::ConflictResolution::Original => (
+            "Confirm Keep Original",
+            "The conflict copy will be deleted.",
+        ),
+        crate::model::ConflictResolution::Conflict => (
+            "Confirm Keep Conflict",
+            "The original will be replaced by the conflict copy.",
+        ),
+        crate::model::ConflictResolution::Both => (
+            "Confirm Keep Both",
+            "The conflict copy will be renamed so both are kept.",
+        ),
+    };
+
+    let prompt_text = format!(
+        "Resolve sync conflict?\n\n\
+        Original: {}\n\
+        Conflict: {}\n\n\
+        {}\n\n\
+        Continue? (y/n)",
+        original_path, conflict_path, outcome
+    );
+
+    // Center the prompt
+    let area = f.area();
+    let prompt_width = 70;
+    let prompt_height = 11;
+    let prompt_area = centered_rect(area, prompt_width, prompt_height);
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog())
         .wrap(ratatui::widgets::Wrap { trim: false });
 
     f.render_widget(ratatui::widgets::Clear, prompt_area);
@@ -144,11 +483,34 @@ pub fn render_pause_resume_confirmation(f: &mut Frame, folder_label: &str, is_pa
 }
 
 /// Render the pattern selection menu (for removing ignore patterns)
-pub fn render_pattern_selection(f: &mut Frame, patterns: &[String], state: &mut ListState) {
+///
+/// Each entry shows its `.stignore` line number; the first one is the line
+/// that currently decides the item is ignored.
+pub fn render_pattern_selection(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    patterns: &[IgnoreMatch],
+    state: &mut ListState,
+) {
     let menu_items: Vec<ListItem> = patterns
         .iter()
-        .map(|pattern| {
-            ListItem::new(Span::raw(pattern.clone())).style(Style::default().fg(Color::White))
+        .enumerate()
+        .map(|(i, m)| {
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:>4}  ", m.line),
+                    Style::default().fg(theme.text_muted),
+                ),
+                Span::styled(m.pattern.clone(), Style::default().fg(theme.text)),
+            ];
+            if i == 0 {
+                spans.push(Span::styled(
+                    "  (deciding)",
+                    Style::default().fg(theme.label),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -168,14 +530,14 @@ pub fn render_pattern_selection(f: &mut Frame, patterns: &[String], state: &mut
             Block::default()
                 .borders(Borders::ALL)
                 .title("Select Pattern to Remove (↑↓ to navigate, Enter to remove, Esc to cancel)")
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(theme.dialog_border)),
         )
         .highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme.selection_bg)
                 .add_modifier(Modifier::BOLD),
         )
-        .highlight_symbol("► ");
+        .highlight_symbol(icon_renderer.render_highlight());
 
     f.render_widget(ratatui::widgets::Clear, menu_area);
     f.render_stateful_widget(menu, menu_area, state);
@@ -184,6 +546,8 @@ pub fn render_pattern_selection(f: &mut Frame, patterns: &[String], state: &mut
 /// Render the folder type selection menu
 pub fn render_folder_type_selection(
     f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
     folder_label: &str,
     current_type: &str,
     state: &mut ListState,
@@ -197,10 +561,10 @@ pub fn render_folder_type_selection(
     let menu_items: Vec<ListItem> = types
         .iter()
         .map(|(display_name, api_name)| {
-            let mut style = Style::default().fg(Color::White);
+            let mut style = Style::default().fg(theme.text);
             // Highlight current type
             if *api_name == current_type {
-                style = style.add_modifier(Modifier::ITALIC).fg(Color::Cyan);
+                style = style.add_modifier(Modifier::ITALIC).fg(theme.info);
             }
             ListItem::new(Span::styled(*display_name, style))
         })
@@ -224,22 +588,24 @@ pub fn render_folder_type_selection(
                 .borders(Borders::ALL)
                 .title(title)
                 .title_bottom("↑↓ to navigate, Enter to select, Esc to cancel")
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(theme.dialog_border)),
         )
         .highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme.selection_bg)
                 .add_modifier(Modifier::BOLD),
         )
-        .highlight_symbol("► ");
+        .highlight_symbol(icon_renderer.render_highlight());
 
     f.render_widget(ratatui::widgets::Clear, menu_area);
     f.render_stateful_widget(menu, menu_area, state);
 }
 
 /// Render the file information popup with metadata and preview
+#[allow(clippy::too_many_arguments)]
 pub fn render_file_info(
     f: &mut Frame,
+    theme: &Theme,
     state: &mut FileInfoPopupState,
     devices: &[Device],
     my_device_id: Option<&str>,
@@ -277,6 +643,7 @@ pub fn render_file_info(
     // Render metadata column
     render_metadata_column(
         f,
+        theme,
         columns[0],
         state,
         devices,
@@ -286,11 +653,20 @@ pub fn render_file_info(
     );
 
     // Render preview column
-    render_preview_column(f, columns[1], state, image_font_size, image_state_map);
+    render_preview_column(
+        f,
+        theme,
+        columns[1],
+        state,
+        image_font_size,
+        image_state_map,
+    );
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_metadata_column(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     state: &mut FileInfoPopupState,
     devices: &[Device],
@@ -302,7 +678,7 @@ fn render_metadata_column(
 
     // Name
     lines.push(Line::from(vec![
-        Span::styled("Name: ", Style::default().fg(Color::Yellow)),
+        Span::styled("Name: ", Style::default().fg(theme.label)),
         Span::raw(&state.browse_item.name),
     ]));
 
@@ -313,19 +689,19 @@ fn render_metadata_column(
         "File"
     };
     lines.push(Line::from(vec![
-        Span::styled("Type: ", Style::default().fg(Color::Yellow)),
+        Span::styled("Type: ", Style::default().fg(theme.label)),
         Span::raw(item_type),
     ]));
 
     // Size
     lines.push(Line::from(vec![
-        Span::styled("Size: ", Style::default().fg(Color::Yellow)),
+        Span::styled("Size: ", Style::default().fg(theme.label)),
         Span::raw(utils::format_bytes(state.browse_item.size)),
     ]));
 
     // Modified time
     lines.push(Line::from(vec![
-        Span::styled("Modified: ", Style::default().fg(Color::Yellow)),
+        Span::styled("Modified: ", Style::default().fg(theme.label)),
         Span::raw(crate::logic::formatting::format_datetime(
             &state.browse_item.mod_time,
         )),
@@ -340,7 +716,7 @@ fn render_metadata_column(
         && let Some((width, height)) = metadata.dimensions
     {
         lines.push(Line::from(vec![
-            Span::styled("Resolution: ", Style::default().fg(Color::Yellow)),
+            Span::styled("Resolution: ", Style::default().fg(theme.label)),
             Span::raw(format!("{}x{}", width, height)),
         ]));
     }
@@ -352,18 +728,18 @@ fn render_metadata_column(
         // Local state
         if let Some(local) = &details.local {
             lines.push(Line::from(vec![
-                Span::styled("State (Local): ", Style::default().fg(Color::Yellow)),
+                Span::styled("State (Local): ", Style::default().fg(theme.label)),
                 Span::raw(if local.deleted { "Deleted" } else { "Present" }),
             ]));
 
             lines.push(Line::from(vec![
-                Span::styled("Ignored: ", Style::default().fg(Color::Yellow)),
+                Span::styled("Ignored: ", Style::default().fg(theme.label)),
                 Span::raw(if local.ignored { "Yes" } else { "No" }),
             ]));
 
             if !local.permissions.is_empty() {
                 lines.push(Line::from(vec![
-                    Span::styled("Permissions: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Permissions: ", Style::default().fg(theme.label)),
                     Span::raw(&local.permissions),
                 ]));
             }
@@ -379,7 +755,7 @@ fn render_metadata_column(
                     });
 
                 lines.push(Line::from(vec![
-                    Span::styled("Modified By: ", Style::default().fg(Color::Yellow)),
+                    Span::styled("Modified By: ", Style::default().fg(theme.label)),
                     Span::raw(device_name),
                 ]));
             }
@@ -404,7 +780,7 @@ fn render_metadata_column(
 
             let mut status_spans = vec![Span::styled(
                 "Sync Status: ",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.label),
             )];
             // Add just the status icon (second element, skip the file icon)
             if icon_spans.len() > 1 {
@@ -427,9 +803,7 @@ fn render_metadata_column(
         if !other_devices.is_empty() {
             lines.push(Line::from(Span::styled(
                 "Available on (connected):",
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
             )));
 
             for device_avail in other_devices {
@@ -447,13 +821,13 @@ fn render_metadata_column(
         } else {
             lines.push(Line::from(Span::styled(
                 "Available on: Only this device",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.text_muted),
             )));
         }
     } else {
         lines.push(Line::from(Span::styled(
             "API details not available",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.text_secondary),
         )));
     }
 
@@ -461,13 +835,13 @@ fn render_metadata_column(
 
     // Disk status
     lines.push(Line::from(vec![
-        Span::styled("Exists on Disk: ", Style::default().fg(Color::Yellow)),
+        Span::styled("Exists on Disk: ", Style::default().fg(theme.label)),
         Span::styled(
             if state.exists_on_disk { "Yes" } else { "No" },
             if state.exists_on_disk {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.success)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.error)
             },
         ),
     ]));
@@ -475,7 +849,7 @@ fn render_metadata_column(
     if state.is_binary {
         lines.push(Line::from(Span::styled(
             "⚠️  Binary file",
-            Style::default().fg(Color::Magenta),
+            Style::default().fg(theme.accent),
         )));
     }
 
@@ -483,10 +857,10 @@ fn render_metadata_column(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(theme.border))
                 .title("Metadata"),
         )
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .wrap(Wrap { trim: false });
 
     f.render_widget(paragraph, area);
@@ -494,6 +868,7 @@ fn render_metadata_column(
 
 fn render_preview_column(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     state: &mut FileInfoPopupState,
     image_font_size: Option<(u16, u16)>,
@@ -509,10 +884,10 @@ fn render_preview_column(
                         .block(
                             Block::default()
                                 .borders(Borders::ALL)
-                                .border_style(Style::default().fg(Color::Cyan))
+                                .border_style(Style::default().fg(theme.border))
                                 .title("Preview"),
                         )
-                        .style(Style::default().fg(Color::Yellow));
+                        .style(Style::default().fg(theme.label));
                     f.render_widget(paragraph, area);
                 }
                 ImagePreviewState::Ready { .. } => {
@@ -531,7 +906,7 @@ fn render_preview_column(
 
                         let block = Block::default()
                             .borders(Borders::ALL)
-                            .border_style(Style::default().fg(Color::Cyan))
+                            .border_style(Style::default().fg(theme.border))
                             .title(title);
 
                         // Render block first
@@ -617,7 +992,7 @@ fn render_preview_column(
                 }
                 ImagePreviewState::Failed { metadata } => {
                     // Show image &metadata as fallback
-                    render_image_metadata(f, area, metadata);
+                    render_image_metadata(f, theme, area, metadata);
                 }
             }
         } else {
@@ -626,10 +1001,10 @@ fn render_preview_column(
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Cyan))
+                        .border_style(Style::default().fg(theme.border))
                         .title("Preview"),
                 )
-                .style(Style::default().fg(Color::Yellow));
+                .style(Style::default().fg(theme.label));
             f.render_widget(paragraph, area);
         }
     } else {
@@ -701,7 +1076,7 @@ fn render_preview_column(
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Cyan))
+                    .border_style(Style::default().fg(theme.border))
                     .title("Preview (↑↓/j/k, ^d/^u, ^f/^b, gg/G, PgUp/PgDn)"),
             )
             .wrap(ratatui::widgets::Wrap { trim: false })
@@ -732,12 +1107,17 @@ fn render_preview_column(
     }
 }
 
-fn render_image_metadata(f: &mut Frame, area: Rect, metadata: &crate::ImageMetadata) {
+fn render_image_metadata(
+    f: &mut Frame,
+    theme: &Theme,
+    area: Rect,
+    metadata: &crate::ImageMetadata,
+) {
     let mut lines = vec![
         Line::from(Span::styled(
             "Image preview unavailable",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.label)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
@@ -746,7 +1126,7 @@ fn render_image_metadata(f: &mut Frame, area: Rect, metadata: &crate::ImageMetad
     // Format
     if let Some(format) = &metadata.format {
         lines.push(Line::from(vec![
-            Span::styled("Format: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Format: ", Style::default().fg(theme.info)),
             Span::raw(format),
         ]));
     }
@@ -754,7 +1134,7 @@ fn render_image_metadata(f: &mut Frame, area: Rect, metadata: &crate::ImageMetad
     // Dimensions
     if let Some((width, height)) = metadata.dimensions {
         lines.push(Line::from(vec![
-            Span::styled("Dimensions: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Dimensions: ", Style::default().fg(theme.info)),
             Span::raw(format!("{}x{}", width, height)),
         ]));
     }
@@ -762,7 +1142,7 @@ fn render_image_metadata(f: &mut Frame, area: Rect, metadata: &crate::ImageMetad
     // File size
     if metadata.file_size > 0 {
         lines.push(Line::from(vec![
-            Span::styled("File Size: ", Style::default().fg(Color::Cyan)),
+            Span::styled("File Size: ", Style::default().fg(theme.info)),
             Span::raw(utils::format_bytes(metadata.file_size)),
         ]));
     }
@@ -771,27 +1151,27 @@ fn render_image_metadata(f: &mut Frame, area: Rect, metadata: &crate::ImageMetad
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(theme.border))
                 .title("Preview (Metadata Only)"),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme.text));
 
     f.render_widget(paragraph, area);
 }
 
 /// Render the setup help dialog (shown when no cache and connection fails)
-pub fn render_setup_help(f: &mut Frame, error_message: &str, config_path: &str) {
+pub fn render_setup_help(f: &mut Frame, theme: &Theme, error_message: &str, config_path: &str) {
     let lines = vec![
         Line::from(Span::styled(
             "Cannot connect to Syncthing API",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.label)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             format!("Error: {}", error_message),
-            Style::default().fg(Color::Red),
+            Style::default().fg(theme.error),
         )),
         Line::from(""),
         Line::from("Please check:"),
@@ -800,16 +1180,16 @@ pub fn render_setup_help(f: &mut Frame, error_message: &str, config_path: &str)
         Line::from("  • Is the API key valid?"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Config: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Config: ", Style::default().fg(theme.info)),
             Span::raw(config_path),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("[r] ", Style::default().fg(Color::Green)),
+            Span::styled("[r] ", Style::default().fg(theme.success)),
             Span::raw("Retry    "),
-            Span::styled("[c] ", Style::default().fg(Color::Green)),
+            Span::styled("[c] ", Style::default().fg(theme.success)),
             Span::raw("Copy config path    "),
-            Span::styled("[q] ", Style::default().fg(Color::Green)),
+            Span::styled("[q] ", Style::default().fg(theme.success)),
             Span::raw("Quit"),
         ]),
     ];
@@ -830,9 +1210,9 @@ pub fn render_setup_help(f: &mut Frame, error_message: &str, config_path: &str)
             Block::default()
                 .borders(Borders::ALL)
                 .title("Connection Failed - Setup Help")
-                .border_style(Style::default().fg(Color::Red)),
+                .border_style(Style::default().fg(theme.dialog_danger)),
         )
-        .style(Style::default().fg(Color::White).bg(Color::Black))
+        .style(theme.dialog())
         .wrap(Wrap { trim: false });
 
     f.render_widget(ratatui::widgets::Clear, prompt_area);
@@ -840,7 +1220,7 @@ pub fn render_setup_help(f: &mut Frame, error_message: &str, config_path: &str)
 }
 
 /// Render the rescan confirmation dialog
-pub fn render_rescan_confirmation(f: &mut Frame, folder_label: &str) {
+pub fn render_rescan_confirmation(f: &mut Frame, theme: &Theme, folder_label: &str) {
     use ratatui::widgets::Clear;
 
     let text = vec![
@@ -853,7 +1233,7 @@ pub fn render_rescan_confirmation(f: &mut Frame, folder_label: &str) {
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.border))
         .title(" Rescan Folder ");
 
     let paragraph = Paragraph::new(text).block(block);
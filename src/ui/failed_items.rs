@@ -0,0 +1,123 @@
+//! Failed items modal rendering
+//!
+//! Lists the files a folder failed to sync with a short description of each
+//! pull error, above the full error text of the selected item.
+
+use crate::logic::folder_errors::describe_pull_error;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::FailedItemsModal;
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the failed items modal
+pub fn render_failed_items_modal(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &FailedItemsModal,
+) {
+    // Calculate centered modal dimensions (80% width, 70% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.7) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(4), Constraint::Length(5)])
+        .split(modal_area);
+
+    let dim = Style::default().fg(theme.text_muted);
+    let items: Vec<ListItem> = modal_state
+        .items
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(item.path.clone(), Style::default().fg(theme.text)),
+                Span::raw("  "),
+                Span::styled(
+                    describe_pull_error(&item.error),
+                    Style::default().fg(theme.error),
+                ),
+                Span::raw("  "),
+                Span::styled(item.error.clone(), dim),
+            ]))
+        })
+        .collect();
+
+    let count = if modal_state.has_more {
+        format!("{}+", modal_state.items.len())
+    } else {
+        modal_state.items.len().to_string()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Failed Items: {} ({}) ",
+                    modal_state.folder_label, count
+                ))
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  {}:Jump to file  {}:Close ",
+                    keymap.modal_nav_label(),
+                    keymap.modal_label(Action::Select),
+                    keymap.modal_label(Action::Close)
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.items.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+    f.render_stateful_widget(list, rows[0], &mut list_state);
+
+    // Full error of the selected item
+    let detail = match modal_state.selected_item() {
+        Some(item) => vec![
+            Line::from(Span::styled(
+                item.path.clone(),
+                Style::default().fg(theme.text),
+            )),
+            Line::from(Span::styled(
+                item.error.clone(),
+                Style::default().fg(theme.error),
+            )),
+        ],
+        None => vec![Line::from(Span::styled("No failed items", dim))],
+    };
+
+    let detail = Paragraph::new(detail)
+        .block(
+            Block::default()
+                .title(" Error ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(detail, rows[1]);
+}
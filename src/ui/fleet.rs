@@ -0,0 +1,197 @@
+//! Fleet overview rendering
+//!
+//! One row per configured instance with uptime, folders out of sync, need
+//! bytes, folder errors and connected devices. Unreachable instances show the
+//! connection error instead.
+
+use crate::logic::formatting::{format_human_size, format_uptime};
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{FleetOverviewModal, FleetRow, FleetStatus};
+use crate::ui::icons::{ConnectionIcon, IconRenderer};
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the fleet overview
+pub fn render_fleet_overview(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    overview: &FleetOverviewModal,
+) {
+    // Calculate centered modal dimensions (80% width, 60% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.6) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    let healthy = overview
+        .rows
+        .iter()
+        .filter(|row| {
+            matches!(&row.status, FleetStatus::Reachable(s)
+                if s.folders_out_of_sync == 0 && s.folders_with_errors == 0)
+        })
+        .count();
+
+    let block = Block::default()
+        .title(format!(
+            " Fleet Overview ({}/{} in sync) ",
+            healthy,
+            overview.rows.len()
+        ))
+        .title_bottom(format!(
+            " {} ",
+            keymap.modal_hints(&[
+                (&[Action::Select], "Open instance"),
+                (&[Action::Refresh], "Refresh"),
+                (&[Action::Close], "Close"),
+            ])
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+    let inner = block.inner(modal_area);
+
+    f.render_widget(Clear, modal_area);
+    f.render_widget(block, modal_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+
+    let name_width = overview
+        .rows
+        .iter()
+        .map(|row| row.profile.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Instance".len());
+
+    // The active instance is marked like a live connection
+    let active_marker = format!(
+        "{} ",
+        icon_renderer.render_connection(ConnectionIcon::Connected)
+    );
+    let blank_marker = " ".repeat(Span::raw(active_marker.as_str()).width());
+
+    // Header is offset by the highlight symbol and marker widths so columns line up
+    let offset = Span::raw(icon_renderer.render_highlight()).width() + blank_marker.len();
+    let header = format!(
+        "{}{:<name_width$}  {:<11}  {:>8}  {:>11}  {:>6}  {:>6}  {:>7}",
+        " ".repeat(offset),
+        "Instance",
+        "Status",
+        "Uptime",
+        "Out of sync",
+        "Need",
+        "Errors",
+        "Devices"
+    );
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            header,
+            Style::default()
+                .fg(theme.label)
+                .add_modifier(Modifier::BOLD),
+        )),
+        rows[0],
+    );
+
+    let items: Vec<ListItem> = overview
+        .rows
+        .iter()
+        .map(|row| {
+            let marker = if row.profile == overview.active {
+                &active_marker
+            } else {
+                &blank_marker
+            };
+            fleet_list_item(row, theme, marker, name_width)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !overview.rows.is_empty() {
+        list_state.select(Some(overview.selected_index));
+    }
+    f.render_stateful_widget(list, rows[1], &mut list_state);
+}
+
+/// Build a single row for the fleet list
+fn fleet_list_item<'a>(
+    row: &'a FleetRow,
+    theme: &Theme,
+    marker: &str,
+    name_width: usize,
+) -> ListItem<'a> {
+    let mut spans = vec![
+        Span::styled(marker.to_string(), Style::default().fg(theme.success)),
+        Span::styled(
+            format!("{:<name_width$}  ", row.profile),
+            Style::default().fg(theme.text),
+        ),
+    ];
+
+    match &row.status {
+        FleetStatus::Polling => {
+            spans.push(Span::styled(
+                "Polling...",
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+        FleetStatus::Unreachable(error) => {
+            spans.push(Span::styled(
+                format!("{:<11}  ", "Unreachable"),
+                Style::default().fg(theme.error),
+            ));
+            spans.push(Span::styled(
+                error.clone(),
+                Style::default().fg(theme.text_muted),
+            ));
+        }
+        FleetStatus::Reachable(summary) => {
+            let (label, color) = if summary.folders_with_errors > 0 {
+                ("Errors", theme.error)
+            } else if summary.folders_out_of_sync > 0 {
+                ("Out of sync", theme.warning)
+            } else {
+                ("Up to date", theme.success)
+            };
+            spans.push(Span::styled(
+                format!("{:<11}  ", label),
+                Style::default().fg(color),
+            ));
+            spans.push(Span::raw(format!(
+                "{:>8}  {:>11}  {:>6}  {:>6}  {:>7}",
+                format_uptime(summary.uptime),
+                format!("{}/{}", summary.folders_out_of_sync, summary.folders),
+                format_human_size(summary.need_bytes).trim(),
+                summary.folders_with_errors,
+                format!("{}/{}", summary.connected_devices, summary.total_devices),
+            )));
+        }
+    }
+
+    ListItem::new(Line::from(spans))
+}
@@ -5,10 +5,11 @@
 use crate::logic::formatting::format_human_size;
 use crate::model::types::FolderHistoryModal;
 use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{
         Block, Borders, Clear, List, ListItem, Scrollbar, ScrollbarOrientation, ScrollbarState,
@@ -20,6 +21,7 @@ use ratatui::{
 /// Shows a centered modal with scrollable list of file updates.
 pub fn render_folder_history_modal(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     modal_state: &FolderHistoryModal,
     _icon_renderer: &IconRenderer,
@@ -80,13 +82,13 @@ pub fn render_folder_history_modal(
 
             // Build line spans with number prefix and right-aligned info
             let spans = vec![
-                Span::styled(number_str, Style::default().fg(Color::Rgb(120, 120, 120))), // Match date/size color
+                Span::styled(number_str, Style::default().fg(theme.text_muted)), // Match date/size color
                 Span::raw("  "), // Double space after number
-                Span::styled(&entry.file_path, Style::default().fg(Color::White)),
+                Span::styled(&entry.file_path, Style::default().fg(theme.text)),
                 Span::raw(" ".repeat(padding)),
                 Span::styled(
                     info_str,
-                    Style::default().fg(Color::Rgb(120, 120, 120)), // Match breadcrumb gray
+                    Style::default().fg(theme.text_muted), // Match breadcrumb gray
                 ),
             ];
 
@@ -121,9 +123,9 @@ pub fn render_folder_history_modal(
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(theme.border)),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(Style::default().bg(theme.selection_bg));
 
     // Create stateful list state for scrolling
     let mut list_state = ratatui::widgets::ListState::default();
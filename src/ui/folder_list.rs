@@ -1,13 +1,15 @@
-use super::icons::IconRenderer;
+use super::icons::{FolderState, IconRenderer};
+use super::theme::Theme;
 use crate::api::{Folder, FolderStatus};
 use crate::logic::folder_card::{
     FolderCardState, calculate_folder_card_state, format_file_count, format_folder_type,
-    format_out_of_sync_details, format_size, format_status_message,
+    format_out_of_sync_details, format_remote_changes, format_remote_completion,
+    format_scan_message, format_size, format_status_message,
 };
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState},
 };
@@ -23,8 +25,11 @@ pub fn render_folder_list(
     _statuses_loaded: bool,
     folders_state: &mut ListState,
     is_focused: bool,
-    _icon_renderer: &IconRenderer,
+    icon_renderer: &IconRenderer,
+    theme: &Theme,
     _last_folder_updates: &HashMap<String, (std::time::SystemTime, String)>,
+    scan_progress: &HashMap<String, u8>,
+    remote_completion: &HashMap<String, HashMap<String, f64>>,
 ) {
     // Calculate title with folder counts
     let title = calculate_folder_list_title(folders, folder_statuses);
@@ -59,7 +64,17 @@ pub fn render_folder_list(
             let status = folder_statuses.get(&folder.id);
             let card_state = calculate_folder_card_state(folder, status);
 
-            render_folder_card(folder, status, &card_state, max_size_width, max_count_width)
+            render_folder_card(
+                folder,
+                status,
+                &card_state,
+                scan_progress.get(&folder.id).copied(),
+                remote_completion.get(&folder.id),
+                max_size_width,
+                max_count_width,
+                icon_renderer,
+                theme,
+            )
         })
         .collect();
 
@@ -69,14 +84,14 @@ pub fn render_folder_list(
                 .borders(Borders::ALL)
                 .title(title)
                 .border_style(if is_focused {
-                    Style::default().fg(Color::Cyan)
+                    Style::default().fg(theme.border)
                 } else {
                     Style::default()
                 }),
         )
         .highlight_style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.selection_fg)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -133,32 +148,36 @@ fn calculate_folder_list_title(
 }
 
 /// Render a single folder card as a multi-line ListItem
+#[allow(clippy::too_many_arguments)]
 fn render_folder_card(
     folder: &Folder,
     status: Option<&FolderStatus>,
     state: &FolderCardState,
+    scan_percent: Option<u8>,
+    remote_completion: Option<&HashMap<String, f64>>,
     max_size_width: usize,
     max_count_width: usize,
+    icon_renderer: &IconRenderer,
+    theme: &Theme,
 ) -> ListItem<'static> {
     let mut lines = Vec::new();
 
     // Line 1: Folder icon + sync status + name
     let display_name = folder.label.as_ref().unwrap_or(&folder.id);
-    let icon = match state {
-        FolderCardState::Synced => "✅",
-        FolderCardState::OutOfSync { .. } => "⚠️",
-        FolderCardState::Syncing { .. } => "🔄",
-        FolderCardState::Paused => "⏸",
-        FolderCardState::Error => "❌",
-        FolderCardState::Loading => "⏳",
+    let folder_state = match state {
+        FolderCardState::Synced => FolderState::Synced,
+        FolderCardState::OutOfSync { .. } => FolderState::OutOfSync,
+        FolderCardState::Syncing { .. } => FolderState::Syncing,
+        FolderCardState::Scanning => FolderState::Scanning,
+        FolderCardState::Overridable { .. } => FolderState::LocalOnly,
+        FolderCardState::Paused => FolderState::Paused,
+        FolderCardState::Error => FolderState::Error,
+        FolderCardState::Loading => FolderState::Loading,
     };
 
-    lines.push(Line::from(vec![
-        Span::raw("📁"),
-        Span::raw(icon),
-        Span::raw(" "),
-        Span::raw(display_name.to_string()),
-    ]));
+    let mut title_spans = icon_renderer.folder_with_status(folder_state);
+    title_spans.push(Span::raw(display_name.to_string()));
+    lines.push(Line::from(title_spans));
 
     // Line 2: Type | Size | File Count | Status
     // Use fixed width for Type (14) and dynamic widths for Size/Count based on actual data
@@ -169,17 +188,32 @@ fn render_folder_card(
     let file_count_str = status
         .map(|s| format_file_count(s.global_files))
         .unwrap_or_else(|| "...".to_string());
-    let status_msg = format_status_message(state);
-
-    lines.push(Line::from(format!(
-        "     {:<14} │ {:>width_size$} │ {:>width_count$} │ {}",
-        folder_type_str,
-        size_str,
-        file_count_str,
-        status_msg,
-        width_size = max_size_width,
-        width_count = max_count_width
-    )));
+    let status_msg = match state {
+        FolderCardState::Scanning => format_scan_message(scan_percent),
+        _ => format_status_message(state),
+    };
+    let status_color = match state {
+        FolderCardState::Synced => theme.synced,
+        FolderCardState::OutOfSync { .. } => theme.out_of_sync,
+        FolderCardState::Syncing { .. } => theme.syncing,
+        FolderCardState::Scanning => theme.scanning,
+        FolderCardState::Overridable { .. } => theme.local_only,
+        FolderCardState::Paused => theme.paused,
+        FolderCardState::Error => theme.error,
+        FolderCardState::Loading => theme.unknown,
+    };
+
+    lines.push(Line::from(vec![
+        Span::raw(format!(
+            "     {:<14} │ {:>width_size$} │ {:>width_count$} │ ",
+            folder_type_str,
+            size_str,
+            file_count_str,
+            width_size = max_size_width,
+            width_count = max_count_width
+        )),
+        Span::styled(status_msg, Style::default().fg(status_color)),
+    ]));
 
     // Line 3 (optional): Out-of-sync details (for both OutOfSync and Syncing states)
     match state {
@@ -197,11 +231,27 @@ fn render_folder_card(
                     *local_changes,
                     status.need_bytes,
                     &folder.folder_type,
+                    &icon_renderer.symbols(),
                 )
             {
                 lines.push(Line::from(format!("     {}", details)));
             }
         }
+        FolderCardState::Overridable { remote_changed } => {
+            let need_bytes = status.map(|s| s.need_bytes).unwrap_or(0);
+            lines.push(Line::from(format!(
+                "     {}",
+                format_remote_changes(*remote_changed, need_bytes, &icon_renderer.symbols())
+            )));
+        }
+        // Locally up to date, but remote devices may still be pulling from us
+        FolderCardState::Synced => {
+            if let Some(details) = remote_completion.and_then(|completions| {
+                format_remote_completion(completions, &icon_renderer.symbols())
+            }) {
+                lines.push(Line::from(format!("     {}", details)));
+            }
+        }
         _ => {}
     }
 
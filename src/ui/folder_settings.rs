@@ -0,0 +1,194 @@
+//! Folder settings form rendering
+//!
+//! Draws the folder settings form (label, scanning, pull order, disk space,
+//! permissions and versioning) and the confirmation listing changed settings.
+
+use crate::logic::folder_settings::{pull_order_label, versioning_label};
+use crate::model::types::{FolderSettingsField, FolderSettingsForm, SettingChange};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the folder settings form
+pub fn render_folder_settings(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    form: &FolderSettingsForm,
+) {
+    let fields = form.fields();
+    let form_width = 72.min(area.width);
+    let form_height = (fields.len() as u16 + 5).min(area.height);
+    let form_area = Rect {
+        x: (area.width.saturating_sub(form_width)) / 2,
+        y: (area.height.saturating_sub(form_height)) / 2,
+        width: form_width,
+        height: form_height,
+    };
+
+    let check = |flag: bool| if flag { "[x]" } else { "[ ]" }.to_string();
+    let choice = |label: &str| icon_renderer.render_choice(label);
+
+    let mut lines: Vec<Line> = fields
+        .iter()
+        .map(|&field| {
+            let (label, value) = match field {
+                FolderSettingsField::Label => ("Label:", form.label.clone()),
+                FolderSettingsField::RescanInterval => {
+                    ("Rescan (s):", form.rescan_interval.clone())
+                }
+                FolderSettingsField::FsWatcher => {
+                    ("Watch changes:", check(form.fs_watcher_enabled))
+                }
+                FolderSettingsField::FsWatcherDelay => {
+                    ("Watch delay (s):", form.fs_watcher_delay.clone())
+                }
+                FolderSettingsField::PullOrder => {
+                    ("Pull order:", choice(pull_order_label(&form.order)))
+                }
+                FolderSettingsField::MinDiskFree => ("Min. free disk:", form.min_disk_free.clone()),
+                FolderSettingsField::MinDiskFreeUnit => ("Unit:", choice(&form.min_disk_free_unit)),
+                FolderSettingsField::IgnorePerms => ("Ignore perms:", check(form.ignore_perms)),
+                FolderSettingsField::IgnoreDelete => ("Ignore delete:", check(form.ignore_delete)),
+                FolderSettingsField::Versioning => (
+                    "Versioning:",
+                    choice(versioning_label(&form.versioning_type)),
+                ),
+                FolderSettingsField::VersioningKeep => {
+                    ("Keep versions:", form.versioning_keep.clone())
+                }
+                FolderSettingsField::VersioningCleanoutDays => {
+                    ("Clean out (d):", form.versioning_cleanout_days.clone())
+                }
+                FolderSettingsField::VersioningMaxAge => {
+                    ("Max age (d):", form.versioning_max_age_days.clone())
+                }
+                FolderSettingsField::VersioningCommand => {
+                    ("Command:", form.versioning_command.clone())
+                }
+            };
+
+            let focused = form.focused_field == field;
+            let marker = if focused {
+                icon_renderer.render_highlight()
+            } else {
+                "  "
+            };
+            let value_style = if focused {
+                Style::default()
+                    .fg(theme.text)
+                    .bg(theme.selection_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let is_text = matches!(
+                field,
+                FolderSettingsField::Label
+                    | FolderSettingsField::RescanInterval
+                    | FolderSettingsField::FsWatcherDelay
+                    | FolderSettingsField::MinDiskFree
+                    | FolderSettingsField::VersioningKeep
+                    | FolderSettingsField::VersioningCleanoutDays
+                    | FolderSettingsField::VersioningMaxAge
+                    | FolderSettingsField::VersioningCommand
+            );
+            let cursor = if focused && is_text {
+                icon_renderer.render_cursor()
+            } else {
+                ""
+            };
+            Line::from(vec![
+                Span::raw(marker),
+                Span::styled(format!("{:<17}", label), Style::default().fg(theme.label)),
+                Span::styled(format!("{}{}", value, cursor), value_style),
+            ])
+        })
+        .collect();
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Rescan 0 disables periodic scans; clean out / max age 0 keeps forever",
+        Style::default().fg(theme.text_secondary),
+    )));
+
+    let form_widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Folder Settings: {} ", form.folder_id))
+                .title_bottom(
+                    icon_renderer
+                        .render_text(" Tab/↑↓:Field  Space/←→:Change  Enter:Save  Esc:Cancel "),
+                )
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog());
+
+    f.render_widget(Clear, form_area);
+    f.render_widget(form_widget, form_area);
+}
+
+/// Render confirmation dialog listing the changed folder settings
+pub fn render_save_folder_settings_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    changes: &[SettingChange],
+) {
+    let area = f.area();
+
+    let mut lines: Vec<Line> = changes
+        .iter()
+        .map(|change| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<20}", change.setting),
+                    Style::default().fg(theme.label),
+                ),
+                Span::styled(change.before.clone(), Style::default().fg(theme.error)),
+                Span::raw(icon_renderer.render_text(" → ")),
+                Span::styled(change.after.clone(), Style::default().fg(theme.success)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from("Save folder settings? (y/n)"));
+
+    let width = 70.min(area.width);
+    let height = (lines.len() as u16 + 2)
+        .min(area.height.saturating_sub(2))
+        .max(5);
+    let prompt_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    // Keep the question visible when the list is taller than the dialog
+    let scroll = (lines.len() as u16).saturating_sub(height.saturating_sub(2));
+
+    let prompt = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Confirm Folder Settings ({} changed)",
+                    changes.len()
+                ))
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(Style::default().bg(theme.dialog_bg))
+        .scroll((scroll, 0));
+
+    f.render_widget(Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
@@ -0,0 +1,259 @@
+//! Folder setup rendering
+//!
+//! Draws the new folder form, the sharing checklist of an existing folder and
+//! the typed-name prompt for removing a folder.
+
+use crate::logic::devices::short_device_id;
+use crate::logic::folder_card::format_folder_type;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{
+    DeviceChoice, FolderSharingModal, NewFolderField, NewFolderForm, RemoveFolderPrompt,
+};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Checklist row for a device ("[x] name (ABCDEFG)")
+fn device_choice_spans<'a>(choice: &'a DeviceChoice, theme: &Theme) -> Vec<Span<'a>> {
+    let name = if choice.name.is_empty() {
+        short_device_id(&choice.device_id)
+    } else {
+        choice.name.as_str()
+    };
+    vec![
+        Span::styled(
+            if choice.selected { "[x] " } else { "[ ] " },
+            Style::default().fg(theme.accent),
+        ),
+        Span::styled(name.to_string(), Style::default().fg(theme.text)),
+        Span::styled(
+            format!(" ({})", short_device_id(&choice.device_id)),
+            Style::default().fg(theme.text_muted),
+        ),
+    ]
+}
+
+/// Render the new folder form
+pub fn render_new_folder(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    form: &NewFolderForm,
+) {
+    let device_rows = form.devices.len().max(1) as u16;
+    let form_width = 76.min(area.width);
+    let form_height = (device_rows + 9).min(area.height);
+    let form_area = Rect {
+        x: (area.width.saturating_sub(form_width)) / 2,
+        y: (area.height.saturating_sub(form_height)) / 2,
+        width: form_width,
+        height: form_height,
+    };
+
+    let focused_style = Style::default()
+        .fg(theme.text)
+        .bg(theme.selection_bg)
+        .add_modifier(Modifier::BOLD);
+    let field_line = |field: NewFolderField, label: &'static str, value: String, is_text: bool| {
+        let focused = form.focused_field == field;
+        let marker = if focused {
+            icon_renderer.render_highlight()
+        } else {
+            "  "
+        };
+        let cursor = if focused && is_text {
+            icon_renderer.render_cursor()
+        } else {
+            ""
+        };
+        let value_style = if focused {
+            focused_style
+        } else {
+            Style::default().fg(theme.text)
+        };
+        Line::from(vec![
+            Span::raw(marker),
+            Span::styled(format!("{:<13}", label), Style::default().fg(theme.label)),
+            Span::styled(format!("{}{}", value, cursor), value_style),
+        ])
+    };
+
+    let mut lines = vec![
+        field_line(NewFolderField::Label, "Label:", form.label.clone(), true),
+        field_line(
+            NewFolderField::FolderId,
+            "Folder ID:",
+            form.folder_id.clone(),
+            true,
+        ),
+        field_line(NewFolderField::Path, "Local path:", form.path.clone(), true),
+        field_line(
+            NewFolderField::FolderType,
+            "Folder type:",
+            icon_renderer.render_choice(&format_folder_type(&form.folder_type)),
+            false,
+        ),
+        field_line(NewFolderField::Devices, "Share with:", String::new(), false),
+    ];
+
+    if form.devices.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "    No remote devices configured",
+            Style::default().fg(theme.text_secondary),
+        )));
+    }
+    let devices_focused = form.focused_field == NewFolderField::Devices;
+    for (i, choice) in form.devices.iter().enumerate() {
+        let mut spans = vec![Span::raw("    ")];
+        spans.extend(device_choice_spans(choice, theme));
+        let mut line = Line::from(spans);
+        if devices_focused && i == form.device_index {
+            line = line.style(focused_style);
+        }
+        lines.push(line);
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Path as seen on this machine (mapped through path_map)",
+        Style::default().fg(theme.text_secondary),
+    )));
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" New Folder ")
+                .title_bottom(
+                    icon_renderer
+                        .render_text(" Tab/↑↓:Field  Space/←→:Change  Enter:Add  Esc:Cancel "),
+                )
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog());
+
+    f.render_widget(Clear, form_area);
+    f.render_widget(widget, form_area);
+}
+
+/// Render the sharing checklist of a folder
+pub fn render_folder_sharing(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal: &FolderSharingModal,
+) {
+    let modal_width = 64.min(area.width);
+    let modal_height = (modal.devices.len() as u16 + 2).clamp(5, area.height.max(5));
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width,
+        height: modal_height.min(area.height),
+    };
+
+    let items: Vec<ListItem> = modal
+        .devices
+        .iter()
+        .map(|choice| ListItem::new(Line::from(device_choice_spans(choice, theme))))
+        .collect();
+
+    let name = if modal.label.is_empty() {
+        &modal.folder_id
+    } else {
+        &modal.label
+    };
+    let shared = modal.devices.iter().filter(|d| d.selected).count();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" Share {} ({} devices) ", name, shared))
+                .title_bottom(format!(
+                    " Space:Toggle  {}:Save  {}:Cancel ",
+                    keymap.modal_label(Action::Select),
+                    keymap.modal_label(Action::Close)
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(modal.selected_index));
+
+    f.render_widget(Clear, modal_area);
+    f.render_stateful_widget(list, modal_area, &mut list_state);
+}
+
+/// Render the typed-name prompt for removing a folder
+pub fn render_remove_folder(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    prompt: &RemoveFolderPrompt,
+) {
+    let prompt_width = 60.min(area.width);
+    let prompt_height = 11.min(area.height);
+    let prompt_area = Rect {
+        x: (area.width.saturating_sub(prompt_width)) / 2,
+        y: (area.height.saturating_sub(prompt_height)) / 2,
+        width: prompt_width,
+        height: prompt_height,
+    };
+
+    let lines = vec![
+        Line::from("Remove folder from configuration?"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Folder: ", Style::default().fg(theme.label)),
+            Span::raw(format!("{} ({})", prompt.name, prompt.folder_id)),
+        ]),
+        Line::from("Files on disk are kept; other devices stop syncing it with this one."),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Type "),
+            Span::styled(
+                prompt.name.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" to confirm:"),
+        ]),
+        Line::from(Span::styled(
+            format!("{}{}", prompt.input, icon_renderer.render_cursor()),
+            Style::default()
+                .fg(theme.text)
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Confirm Remove Folder")
+                .title_bottom(" Enter:Remove  Esc:Cancel ")
+                .border_style(Style::default().fg(theme.dialog_danger)),
+        )
+        .style(theme.dialog())
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, prompt_area);
+    f.render_widget(widget, prompt_area);
+}
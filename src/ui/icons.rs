@@ -4,18 +4,60 @@ use ratatui::{
 };
 
 use crate::api::SyncState;
+use crate::logic::formatting::Symbols;
 
 /// Icon display mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IconMode {
     Emoji,    // Standard emoji icons (📁, 📄, etc.)
     NerdFont, // Nerd Fonts icons (U+E5FF, etc.)
+    Ascii,    // Plain ASCII markers (=, !, ~, etc.) for consoles without Unicode
+}
+
+impl IconMode {
+    /// Parse the `icon_mode` config value ("auto" picks ASCII on terminals
+    /// that can't show Unicode, Nerd Fonts otherwise)
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "emoji" => IconMode::Emoji,
+            "nerdfont" | "nerd" | "nf" => IconMode::NerdFont,
+            "ascii" => IconMode::Ascii,
+            "auto" => {
+                let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+                    .iter()
+                    .filter_map(|var| std::env::var(var).ok())
+                    .find(|value| !value.is_empty());
+                let term = std::env::var("TERM").ok();
+                Self::detect(term.as_deref(), locale.as_deref())
+            }
+            _ => IconMode::NerdFont, // Default to nerd font
+        }
+    }
+
+    /// Pick a mode from `TERM` and the effective locale (`LC_ALL`, `LC_CTYPE`
+    /// or `LANG`, whichever is set first)
+    fn detect(term: Option<&str>, locale: Option<&str>) -> Self {
+        // The Linux virtual console only has a 512-glyph font
+        if term == Some("linux") {
+            return IconMode::Ascii;
+        }
+
+        // No locale means the POSIX locale, which is ASCII
+        let utf8 = locale.is_some_and(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        });
+        if utf8 {
+            IconMode::NerdFont
+        } else {
+            IconMode::Ascii
+        }
+    }
 }
 
 /// Folder states for rendering
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FolderState {
-    #[allow(dead_code)]
     Loading,
     Paused,
     Syncing,
@@ -27,6 +69,15 @@ pub enum FolderState {
     LocalOnly,
 }
 
+/// Connection states shown in the system bar and the device list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionIcon {
+    Connected,
+    Connecting,
+    Disconnected,
+    Paused,
+}
+
 /// Icon theme using terminal colors (respects user's terminal theme)
 #[derive(Debug, Clone)]
 pub struct IconTheme {
@@ -42,6 +93,7 @@ pub struct IconTheme {
     pub remote_only_color: Color,
     pub ignored_color: Color,
     pub syncing_color: Color,
+    pub conflict_color: Color,
     pub scanning_color: Color,
     pub unknown_color: Color,
     pub error_color: Color,
@@ -63,6 +115,7 @@ impl Default for IconTheme {
             remote_only_color: Color::White,  // Only on remote
             ignored_color: Color::Red,        // Ignored files
             syncing_color: Color::Yellow,     // Currently syncing
+            conflict_color: Color::LightRed,  // Sync-conflict copies
             scanning_color: Color::Magenta,   // Scanning for changes
             unknown_color: Color::Gray,       // Unknown state (transient)
             error_color: Color::Red,          // Error state
@@ -80,13 +133,14 @@ enum StatusType {
     RemoteOnly,
     Ignored,
     Syncing,
+    Conflict,
     Scanning,
     Unknown,
     Error,
     Paused,
 }
 
-/// Icon renderer that handles both emoji and Nerd Font modes
+/// Icon renderer that handles emoji, Nerd Font and ASCII modes
 pub struct IconRenderer {
     mode: IconMode,
     theme: IconTheme,
@@ -97,6 +151,10 @@ impl IconRenderer {
         Self { mode, theme }
     }
 
+    pub fn mode(&self) -> IconMode {
+        self.mode
+    }
+
     /// Render a folder with its status
     pub fn folder_with_status(&self, state: FolderState) -> Vec<Span<'static>> {
         let mut spans = vec![self.sync_folder_icon()];
@@ -136,6 +194,7 @@ impl IconRenderer {
             SyncState::RemoteOnly => StatusType::RemoteOnly,
             SyncState::Ignored => StatusType::Ignored,
             SyncState::Syncing => StatusType::Syncing,
+            SyncState::Conflict => StatusType::Conflict,
             SyncState::Unknown => StatusType::Unknown,
         };
 
@@ -168,6 +227,9 @@ impl IconRenderer {
                     "\u{F070} ", // eye-slash icon
                     Style::default().fg(self.theme.ignored_color),
                 ),
+                IconMode::Ascii => {
+                    Span::styled("i ", Style::default().fg(self.theme.ignored_color))
+                }
             };
             spans.push(muted_span);
         } else {
@@ -179,6 +241,9 @@ impl IconRenderer {
                 IconMode::NerdFont => {
                     Span::styled("\u{F05E} ", Style::default().fg(self.theme.ignored_color))
                 }
+                IconMode::Ascii => {
+                    Span::styled("x ", Style::default().fg(self.theme.ignored_color))
+                }
             };
             spans.push(block_span);
         }
@@ -196,6 +261,7 @@ impl IconRenderer {
                 "\u{F07C}",
                 Style::default().fg(self.theme.sync_folder_color),
             ),
+            IconMode::Ascii => Span::styled("#", Style::default().fg(self.theme.sync_folder_color)),
         }
     }
 
@@ -206,6 +272,7 @@ impl IconRenderer {
             IconMode::NerdFont => {
                 Span::styled("\u{E5FF}", Style::default().fg(self.theme.folder_color))
             }
+            IconMode::Ascii => Span::styled("+", Style::default().fg(self.theme.folder_color)),
         }
     }
 
@@ -216,27 +283,30 @@ impl IconRenderer {
             IconMode::NerdFont => {
                 Span::styled("\u{F15B}", Style::default().fg(self.theme.file_color))
             }
+            IconMode::Ascii => Span::styled("-", Style::default().fg(self.theme.file_color)),
         }
     }
 
     /// Get status icon span
     fn status_icon(&self, status: StatusType) -> Span<'static> {
-        let (emoji_icon, nerd_icon, color) = match status {
-            StatusType::Synced => ("✅ ", "\u{F00C} ", self.theme.synced_color),
-            StatusType::OutOfSync => ("⚠️ ", "\u{F071} ", self.theme.out_of_sync_color),
-            StatusType::LocalOnly => ("💻 ", "\u{F109} ", self.theme.local_only_color),
-            StatusType::RemoteOnly => ("☁️ ", "\u{F0C2} ", self.theme.remote_only_color),
-            StatusType::Ignored => ("🚫 ", "\u{F05E} ", self.theme.ignored_color),
-            StatusType::Syncing => ("🔄 ", "\u{F021} ", self.theme.syncing_color),
-            StatusType::Scanning => ("🔍 ", "\u{F002} ", self.theme.scanning_color),
-            StatusType::Unknown => ("❓ ", "\u{F128} ", self.theme.unknown_color),
-            StatusType::Error => ("❌ ", "\u{F00D} ", self.theme.error_color),
-            StatusType::Paused => ("⏸  ", "\u{F04C}  ", self.theme.paused_color),
+        let (emoji_icon, nerd_icon, ascii_icon, color) = match status {
+            StatusType::Synced => ("✅ ", "\u{F00C} ", "= ", self.theme.synced_color),
+            StatusType::OutOfSync => ("⚠️ ", "\u{F071} ", "! ", self.theme.out_of_sync_color),
+            StatusType::LocalOnly => ("💻 ", "\u{F109} ", "L ", self.theme.local_only_color),
+            StatusType::RemoteOnly => ("☁️ ", "\u{F0C2} ", "R ", self.theme.remote_only_color),
+            StatusType::Ignored => ("🚫 ", "\u{F05E} ", "x ", self.theme.ignored_color),
+            StatusType::Syncing => ("🔄 ", "\u{F021} ", "~ ", self.theme.syncing_color),
+            StatusType::Conflict => ("💥 ", "\u{F126} ", "C ", self.theme.conflict_color),
+            StatusType::Scanning => ("🔍 ", "\u{F002} ", "* ", self.theme.scanning_color),
+            StatusType::Unknown => ("❓ ", "\u{F128} ", "? ", self.theme.unknown_color),
+            StatusType::Error => ("❌ ", "\u{F00D} ", "E ", self.theme.error_color),
+            StatusType::Paused => ("⏸  ", "\u{F04C}  ", "P ", self.theme.paused_color),
         };
 
         let icon = match self.mode {
             IconMode::Emoji => emoji_icon,
             IconMode::NerdFont => nerd_icon,
+            IconMode::Ascii => ascii_icon,
         };
 
         Span::styled(icon, Style::default().fg(color))
@@ -247,6 +317,7 @@ impl IconRenderer {
         match self.mode {
             IconMode::Emoji => "🕐",
             IconMode::NerdFont => "\u{F017}", // clock icon
+            IconMode::Ascii => "@",
         }
     }
 
@@ -255,6 +326,297 @@ impl IconRenderer {
         match self.mode {
             IconMode::Emoji => "📡",
             IconMode::NerdFont => "\u{F6FF}", // network-wired icon
+            IconMode::Ascii => "<>",
+        }
+    }
+
+    /// Symbols for formatted text (arrows are spelled out in ASCII mode)
+    pub fn symbols(&self) -> Symbols {
+        match self.mode {
+            IconMode::Ascii => Symbols::ASCII,
+            IconMode::Emoji | IconMode::NerdFont => Symbols::UNICODE,
         }
     }
+
+    /// Render the connection state marker (system bar, device list, active
+    /// profile)
+    pub fn render_connection(&self, state: ConnectionIcon) -> &'static str {
+        match (self.mode, state) {
+            (IconMode::Ascii, ConnectionIcon::Connected) => "+",
+            (IconMode::Ascii, ConnectionIcon::Connecting) => "~",
+            (IconMode::Ascii, ConnectionIcon::Disconnected) => "x",
+            (IconMode::Ascii, ConnectionIcon::Paused) => "=",
+            (_, ConnectionIcon::Connected) => "🟢",
+            (_, ConnectionIcon::Connecting) => "🟡",
+            (_, ConnectionIcon::Disconnected) => "🔴",
+            (_, ConnectionIcon::Paused) => "⏸️",
+        }
+    }
+
+    /// Render the warning sign for banners
+    pub fn render_warning(&self) -> &'static str {
+        match self.mode {
+            IconMode::Ascii => "!",
+            IconMode::Emoji | IconMode::NerdFont => "⚠",
+        }
+    }
+
+    /// Render the restart marker of the "Restart required" badge
+    pub fn render_restart(&self) -> &'static str {
+        match self.mode {
+            IconMode::Ascii => "*",
+            IconMode::Emoji | IconMode::NerdFont => "⟳",
+        }
+    }
+
+    /// Render the marker of the selected list row or form field
+    pub fn render_highlight(&self) -> &'static str {
+        match self.mode {
+            IconMode::Ascii => "> ",
+            IconMode::Emoji | IconMode::NerdFont => "► ",
+        }
+    }
+
+    /// Render the cursor at the end of a text input
+    pub fn render_cursor(&self) -> &'static str {
+        match self.mode {
+            IconMode::Ascii => "|",
+            IconMode::Emoji | IconMode::NerdFont => "▏",
+        }
+    }
+
+    /// Render a value cycled with Space/←→ (e.g. "◂ Send Only ▸")
+    pub fn render_choice(&self, label: &str) -> String {
+        match self.mode {
+            IconMode::Ascii => format!("< {} >", label),
+            IconMode::Emoji | IconMode::NerdFont => format!("◂ {} ▸", label),
+        }
+    }
+
+    /// Render key hints and labels, spelling arrows, ellipses and "≥" out in
+    /// ASCII mode (e.g. "Tab/↑↓:Field" becomes "Tab/Up/Dn:Field", "a → b"
+    /// becomes "a -> b")
+    pub fn render_text(&self, text: &str) -> String {
+        if self.mode != IconMode::Ascii {
+            return text.to_string();
+        }
+        text.replace(" → ", " -> ")
+            .replace("↑↓", "Up/Dn")
+            .replace("←→", "Left/Right")
+            .replace('↑', "Up")
+            .replace('↓', "Dn")
+            .replace('←', "Left")
+            .replace('→', "Right")
+            .replace('…', "...")
+            .replace('≥', ">=")
+    }
+
+    /// Explain the ASCII markers (empty in emoji and Nerd Font modes, whose
+    /// icons speak for themselves)
+    pub fn marker_legend(&self) -> Vec<Span<'static>> {
+        if self.mode != IconMode::Ascii {
+            return vec![];
+        }
+
+        let entries = [
+            (self.sync_folder_icon(), "folder"),
+            (self.folder_icon(), "dir"),
+            (self.file_icon(), "file"),
+            (self.status_icon(StatusType::Synced), "synced"),
+            (self.status_icon(StatusType::OutOfSync), "out of sync"),
+            (self.status_icon(StatusType::Syncing), "syncing"),
+            (self.status_icon(StatusType::LocalOnly), "local only"),
+            (self.status_icon(StatusType::RemoteOnly), "remote only"),
+            (self.status_icon(StatusType::Conflict), "conflict"),
+            (self.ignored_item(false, true)[1].clone(), "ignored"),
+            (self.status_icon(StatusType::Ignored), "ignored, deleted"),
+            (self.status_icon(StatusType::Scanning), "scanning"),
+            (self.status_icon(StatusType::Paused), "paused"),
+            (self.status_icon(StatusType::Error), "error"),
+            (self.status_icon(StatusType::Unknown), "unknown"),
+        ];
+
+        let mut spans = vec![Span::raw("Markers: ")];
+        for (marker, label) in entries {
+            let marker_style = marker.style;
+            spans.push(Span::styled(
+                marker.content.trim_end().to_string(),
+                marker_style,
+            ));
+            spans.push(Span::raw(format!(" {}  ", label)));
+        }
+
+        // No trailing separator after the last entry
+        if let Some(last) = spans.last_mut() {
+            *last = Span::raw(last.content.trim_end().to_string());
+        }
+
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ascii_renderer() -> IconRenderer {
+        IconRenderer::new(IconMode::Ascii, IconTheme::default())
+    }
+
+    fn text(spans: &[Span]) -> String {
+        spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    // ========================================
+    // MODE SELECTION
+    // ========================================
+
+    #[test]
+    fn test_explicit_modes_are_honored() {
+        assert_eq!(IconMode::from_config("emoji"), IconMode::Emoji);
+        assert_eq!(IconMode::from_config("NerdFont"), IconMode::NerdFont);
+        assert_eq!(IconMode::from_config("ascii"), IconMode::Ascii);
+        assert_eq!(IconMode::from_config("bogus"), IconMode::NerdFont);
+    }
+
+    #[test]
+    fn test_detect_picks_ascii_without_unicode() {
+        assert_eq!(
+            IconMode::detect(Some("xterm-256color"), Some("en_US.UTF-8")),
+            IconMode::NerdFont
+        );
+        assert_eq!(
+            IconMode::detect(Some("xterm"), Some("de_DE.utf8")),
+            IconMode::NerdFont
+        );
+        assert_eq!(
+            IconMode::detect(Some("linux"), Some("en_US.UTF-8")),
+            IconMode::Ascii
+        );
+        assert_eq!(IconMode::detect(Some("xterm"), Some("C")), IconMode::Ascii);
+        assert_eq!(
+            IconMode::detect(Some("vt100"), Some("en_US.ISO-8859-1")),
+            IconMode::Ascii
+        );
+        assert_eq!(IconMode::detect(None, None), IconMode::Ascii);
+    }
+
+    // ========================================
+    // ASCII MARKERS
+    // ========================================
+
+    #[test]
+    fn test_ascii_items_are_aligned() {
+        let renderer = ascii_renderer();
+        let states = [
+            SyncState::Synced,
+            SyncState::OutOfSync,
+            SyncState::LocalOnly,
+            SyncState::RemoteOnly,
+            SyncState::Ignored,
+            SyncState::Syncing,
+            SyncState::Conflict,
+            SyncState::Unknown,
+        ];
+
+        let mut rendered: Vec<String> = states
+            .iter()
+            .flat_map(|state| {
+                [
+                    text(&renderer.item_with_sync_state(true, *state)),
+                    text(&renderer.item_with_sync_state(false, *state)),
+                ]
+            })
+            .collect();
+        for is_dir in [true, false] {
+            for exists in [true, false] {
+                rendered.push(text(&renderer.ignored_item(is_dir, exists)));
+            }
+        }
+
+        for marker in &rendered {
+            assert!(marker.is_ascii(), "not ASCII: {:?}", marker);
+            assert_eq!(marker.len(), 3, "misaligned marker: {:?}", marker);
+        }
+    }
+
+    #[test]
+    fn test_ascii_folder_states_are_aligned() {
+        let renderer = ascii_renderer();
+        let states = [
+            FolderState::Loading,
+            FolderState::Paused,
+            FolderState::Syncing,
+            FolderState::OutOfSync,
+            FolderState::Synced,
+            FolderState::Scanning,
+            FolderState::Unknown,
+            FolderState::Error,
+            FolderState::LocalOnly,
+        ];
+
+        for state in states {
+            let marker = text(&renderer.folder_with_status(state));
+            assert!(marker.is_ascii(), "not ASCII: {:?}", marker);
+            assert_eq!(marker.len(), 3, "misaligned marker: {:?}", marker);
+        }
+        assert!(renderer.render_clock().is_ascii());
+        assert!(renderer.render_devices().is_ascii());
+    }
+
+    #[test]
+    fn test_ascii_symbols_and_widgets() {
+        let renderer = ascii_renderer();
+        let symbols = renderer.symbols();
+        let mut rendered = vec![
+            symbols.download,
+            symbols.upload,
+            symbols.modified,
+            symbols.remote_changed,
+            renderer.render_warning(),
+            renderer.render_restart(),
+            renderer.render_highlight(),
+            renderer.render_cursor(),
+        ];
+        for state in [
+            ConnectionIcon::Connected,
+            ConnectionIcon::Connecting,
+            ConnectionIcon::Disconnected,
+            ConnectionIcon::Paused,
+        ] {
+            rendered.push(renderer.render_connection(state));
+        }
+
+        for glyph in rendered {
+            assert!(glyph.is_ascii(), "not ASCII: {:?}", glyph);
+        }
+        assert_eq!(renderer.render_choice("Send Only"), "< Send Only >");
+    }
+
+    #[test]
+    fn test_render_text_spells_out_arrows_in_ascii_mode() {
+        let renderer = ascii_renderer();
+        assert_eq!(
+            renderer.render_text(" Tab/↑↓:Field  Space/←→:Change "),
+            " Tab/Up/Dn:Field  Space/Left/Right:Change "
+        );
+        assert_eq!(renderer.render_text("Alt-↑↓:Move"), "Alt-Up/Dn:Move");
+        assert_eq!(renderer.render_text("  … 3 more"), "  ... 3 more");
+        assert_eq!(renderer.render_text("level ≥ INFO"), "level >= INFO");
+        assert_eq!(renderer.render_text("a → b"), "a -> b");
+
+        let nerd = IconRenderer::new(IconMode::NerdFont, IconTheme::default());
+        assert_eq!(nerd.render_text("Tab/↑↓:Field"), "Tab/↑↓:Field");
+    }
+
+    #[test]
+    fn test_marker_legend_only_in_ascii_mode() {
+        let legend = text(&ascii_renderer().marker_legend());
+        assert!(legend.starts_with("Markers: # folder  + dir  - file  = synced"));
+        assert!(legend.contains("i ignored  x ignored, deleted"));
+        assert!(!legend.ends_with(' '));
+
+        let emoji = IconRenderer::new(IconMode::Emoji, IconTheme::default());
+        assert!(emoji.marker_legend().is_empty());
+    }
 }
@@ -0,0 +1,273 @@
+//! `.stignore` editor rendering
+//!
+//! Multi-line editor for a folder's ignore patterns with per-line validation,
+//! next to a preview of the cached files the current line ignores or un-ignores.
+
+use crate::logic::ignore::{is_comment_or_blank, validate_ignore_line};
+use crate::model::types::{IgnoreDiffLine, IgnoreEditorModal};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Maximum paths listed per section of the match preview
+const MAX_PREVIEW_PATHS: usize = 200;
+
+/// Render the `.stignore` editor modal
+pub fn render_ignore_editor(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    editor: &IgnoreEditorModal,
+) {
+    // Calculate centered modal dimensions (90% width, 80% height)
+    let modal_width = (area.width as f32 * 0.9) as u16;
+    let modal_height = (area.height as f32 * 0.8) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(modal_area);
+
+    render_editor_pane(f, theme, icon_renderer, columns[0], editor);
+    render_preview_pane(f, theme, icon_renderer, columns[1], editor);
+}
+
+/// Render the editable lines with line numbers, validation and cursor
+fn render_editor_pane(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    editor: &IgnoreEditorModal,
+) {
+    let number_width = editor.lines.len().to_string().len().max(2);
+
+    let lines: Vec<Line> = editor
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, text)| {
+            let valid = validate_ignore_line(text).is_ok();
+            let number_style = if !valid {
+                Style::default().fg(theme.error)
+            } else if i == editor.cursor_line {
+                Style::default().fg(theme.label)
+            } else {
+                Style::default().fg(theme.text_muted)
+            };
+            let text_style = if !valid {
+                Style::default().fg(theme.error)
+            } else if is_comment_or_blank(text) {
+                Style::default().fg(theme.text_muted)
+            } else if text.trim_start().starts_with('!') {
+                Style::default().fg(theme.success)
+            } else {
+                Style::default().fg(theme.text)
+            };
+
+            let mut spans = vec![Span::styled(
+                format!("{:>width$} ", i + 1, width = number_width),
+                number_style,
+            )];
+
+            if i == editor.cursor_line {
+                // Reverse-video cursor cell
+                let chars: Vec<char> = text.chars().collect();
+                let col = editor.cursor_col.min(chars.len());
+                let before: String = chars[..col].iter().collect();
+                let at = chars.get(col).map(|c| c.to_string()).unwrap_or(" ".into());
+                let after: String = chars.get(col + 1..).unwrap_or(&[]).iter().collect();
+                spans.push(Span::styled(before, text_style));
+                spans.push(Span::styled(
+                    at,
+                    text_style.add_modifier(Modifier::REVERSED),
+                ));
+                spans.push(Span::styled(after, text_style));
+            } else {
+                spans.push(Span::styled(text.clone(), text_style));
+            }
+
+            Line::from(spans)
+        })
+        .collect();
+
+    // Keep the cursor line visible
+    let viewport_height = area.height.saturating_sub(2) as usize;
+    let scroll = editor
+        .cursor_line
+        .saturating_sub(viewport_height.saturating_sub(1)) as u16;
+
+    let mut title = format!(" .stignore: {} ", editor.folder_label);
+    if editor.is_modified() {
+        title.push_str("[modified] ");
+    }
+
+    let mut block = Block::default()
+        .title(title)
+        .title_bottom(icon_renderer.render_text(
+            " Enter:New line  ^K:Delete  Alt-↑↓:Move  ^T:Comment  ^S:Save  Esc:Close ",
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border));
+
+    if let Some(Err(err)) = editor
+        .lines
+        .get(editor.cursor_line)
+        .map(|line| validate_ignore_line(line))
+    {
+        block = block.title(
+            Line::from(Span::styled(
+                format!(" {} ", err),
+                Style::default().fg(theme.error),
+            ))
+            .right_aligned(),
+        );
+    }
+
+    let widget = Paragraph::new(lines).block(block).scroll((scroll, 0));
+    f.render_widget(widget, area);
+}
+
+/// Render the cached files the current line newly ignores / un-ignores
+fn render_preview_pane(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    editor: &IgnoreEditorModal,
+) {
+    let dim = Style::default().fg(theme.text_muted);
+    let mut lines: Vec<Line> = Vec::new();
+
+    if editor.cached_paths.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No cached files for this folder yet - browse it to populate the preview",
+            dim,
+        )));
+    } else if editor.preview_ignored.is_empty() && editor.preview_unignored.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "This line does not change any cached file",
+            dim,
+        )));
+    }
+
+    let mut section = |label: &str, paths: &[String], marker: &str, color: Color| {
+        if paths.is_empty() {
+            return;
+        }
+        lines.push(Line::from(Span::styled(
+            format!("{} ({})", label, paths.len()),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        for path in paths.iter().take(MAX_PREVIEW_PATHS) {
+            lines.push(Line::from(vec![
+                Span::styled(marker.to_string(), Style::default().fg(color)),
+                Span::raw(path.clone()),
+            ]));
+        }
+        if paths.len() > MAX_PREVIEW_PATHS {
+            lines.push(Line::from(Span::styled(
+                icon_renderer.render_text(&format!("  … {} more", paths.len() - MAX_PREVIEW_PATHS)),
+                dim,
+            )));
+        }
+        lines.push(Line::from(""));
+    };
+
+    section(
+        "Newly ignored",
+        &editor.preview_ignored,
+        "+ ",
+        theme.ignored,
+    );
+    section("Un-ignored", &editor.preview_unignored, "- ", theme.success);
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Line {} matches ({} cached) ",
+                    editor.cursor_line + 1,
+                    editor.cached_paths.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(widget, area);
+}
+
+/// Render the diff shown before saving `.stignore`
+pub fn render_save_ignores_confirmation(f: &mut Frame, theme: &Theme, diff: &[IgnoreDiffLine]) {
+    let area = f.area();
+    let changed = diff
+        .iter()
+        .filter(|line| !matches!(line, IgnoreDiffLine::Unchanged(_)))
+        .count();
+
+    let mut lines: Vec<Line> = diff
+        .iter()
+        .map(|line| match line {
+            IgnoreDiffLine::Unchanged(text) => Line::from(Span::styled(
+                format!("  {}", text),
+                Style::default().fg(theme.text_muted),
+            )),
+            IgnoreDiffLine::Added(text) => Line::from(Span::styled(
+                format!("+ {}", text),
+                Style::default().fg(theme.success),
+            )),
+            IgnoreDiffLine::Removed(text) => Line::from(Span::styled(
+                format!("- {}", text),
+                Style::default().fg(theme.error),
+            )),
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from("Save .stignore? (y/n)"));
+
+    let width = 70.min(area.width);
+    let height = (lines.len() as u16 + 2)
+        .min(area.height.saturating_sub(2))
+        .max(5);
+    let prompt_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    // Keep the question visible when the diff is taller than the dialog
+    let scroll = (lines.len() as u16).saturating_sub(height.saturating_sub(2));
+
+    let prompt = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Confirm Save .stignore ({} changed lines)",
+                    changed
+                ))
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(Style::default().bg(theme.dialog_bg))
+        .scroll((scroll, 0));
+
+    f.render_widget(Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
@@ -1,3 +1,5 @@
+use super::icons::IconRenderer;
+use crate::logic::keymap::Keymap;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 /// Layout information for rendering
@@ -26,9 +28,11 @@ pub fn calculate_layout(
     terminal_size: Rect,
     num_breadcrumb_levels: usize,
     has_breadcrumbs: bool,
-    vim_mode: bool,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
     focus_level: usize,
     can_restore: bool,
+    can_override: bool,
     has_open_command: bool,
     status_height: u16,
     search_visible: bool,
@@ -37,9 +41,11 @@ pub fn calculate_layout(
     // Note: We use search_visible for both parameters since we need visibility for layout
     let legend_height = super::legend::calculate_legend_height(
         terminal_size.width,
-        vim_mode,
+        keymap,
+        icon_renderer,
         focus_level,
         can_restore,
+        can_override,
         has_open_command,
         search_visible, // search_mode (approximation for layout)
         search_visible, // has_search_query (approximation for layout)
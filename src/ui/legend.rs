@@ -1,162 +1,305 @@
+use super::icons::IconRenderer;
+use super::theme::Theme;
+use crate::logic::keymap::{Action, KeyContext, Keymap};
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
+/// Append `key:label` if the key is bound
+fn push_hotkey(spans: &mut Vec<Span<'static>>, theme: &Theme, key: Option<String>, label: &str) {
+    if let Some(key) = key {
+        spans.push(Span::styled(key, Style::default().fg(theme.label)));
+        spans.push(Span::raw(format!(":{}  ", label)));
+    }
+}
+
+/// Keys of several actions joined with "/" (unbound ones left out)
+fn joined_keys(keymap: &Keymap, context: KeyContext, actions: &[Action]) -> Option<String> {
+    let keys: Vec<String> = actions
+        .iter()
+        .filter_map(|action| keymap.key_label(context, *action))
+        .collect();
+    (!keys.is_empty()).then(|| keys.join("/"))
+}
+
 /// Build hotkey spans (extracted for testability)
+///
+/// Keys come from the active keymap, so remapped actions show their new key
+/// and unbound actions are left out.
+#[allow(clippy::too_many_arguments)]
 fn build_hotkey_spans(
-    vim_mode: bool,
+    keymap: &Keymap,
+    theme: &Theme,
     focus_level: usize,
     can_restore: bool,
+    can_override: bool,
     has_open_command: bool,
     search_mode: bool,
     has_search_query: bool,
 ) -> Vec<Span<'static>> {
     let mut hotkey_spans = vec![];
+    let context = if focus_level == 0 {
+        KeyContext::FolderList
+    } else {
+        KeyContext::Breadcrumb
+    };
+    let key = |action: Action| keymap.key_label(context, action);
+    let keys = |actions: &[Action]| joined_keys(keymap, context, actions);
 
     // Navigation keys (different for vim mode)
-    if vim_mode {
-        hotkey_spans.extend(vec![
-            Span::styled("hjkl", Style::default().fg(Color::Yellow)),
-            Span::raw(":Nav  "),
-            Span::styled("gg/G", Style::default().fg(Color::Yellow)),
-            Span::raw(":First/Last  "),
-            Span::styled("^d/^u", Style::default().fg(Color::Yellow)),
-            Span::raw(":½Page  "),
-            Span::styled("^f/^b", Style::default().fg(Color::Yellow)),
-            Span::raw(":FullPage  "),
-        ]);
+    if keymap.vim_mode {
+        // hjkl style: the keys are concatenated
+        let nav: String = [Action::Back, Action::Down, Action::Up, Action::Select]
+            .into_iter()
+            .filter_map(key)
+            .collect();
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            (!nav.is_empty()).then_some(nav),
+            "Nav",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            keys(&[Action::First, Action::Last]),
+            "First/Last",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            keys(&[Action::HalfPageDown, Action::HalfPageUp]),
+            "½Page",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            keys(&[Action::PageDown, Action::PageUp]),
+            "FullPage",
+        );
     } else {
-        hotkey_spans.extend(vec![
-            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
-            Span::raw(":Nav  "),
-            Span::styled("Enter", Style::default().fg(Color::Yellow)),
-            Span::raw(":Open  "),
-            Span::styled("←", Style::default().fg(Color::Yellow)),
-            Span::raw(":Back  "),
-        ]);
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            keys(&[Action::Up, Action::Down]),
+            "Nav",
+        );
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Select), "Open");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Back), "Back");
     }
 
     // Folder-specific actions - only in folder view (focus_level == 0)
     if focus_level == 0 {
-        hotkey_spans.extend(vec![
-            Span::styled("u", Style::default().fg(Color::Yellow)),
-            Span::raw(":Updates  "),
-            Span::styled("c", Style::default().fg(Color::Yellow)),
-            Span::raw(":Change Type  "),
-            Span::styled("p", Style::default().fg(Color::Yellow)),
-            Span::raw(":Pause/Resume  "),
-            Span::styled("o", Style::default().fg(Color::Yellow)),
-            Span::raw(":Open Syncthing Web UI  "),
-        ]);
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::UpdateHistory),
+            "Updates",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::ChangeType),
+            "Change Type",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::FolderSettings),
+            "Settings",
+        );
+        push_hotkey(&mut hotkey_spans, theme, key(Action::NewFolder), "New");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::ShareFolder), "Share");
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::RemoveFolder),
+            "Remove",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::PauseResume),
+            "Pause/Resume",
+        );
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Devices), "Devices");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Pending), "Pending");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Profiles), "Profiles");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Overview), "Overview");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::System), "System");
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::OpenWebUi),
+            "Open Syncthing Web UI",
+        );
     }
 
     // Actions that only apply to breadcrumbs (focus_level > 0), not folders
     if focus_level > 0 {
-        hotkey_spans.extend(vec![
-            Span::styled("c", Style::default().fg(Color::Yellow)),
-            Span::raw(":Copy path  "),
-        ]);
+        push_hotkey(&mut hotkey_spans, theme, key(Action::CopyPath), "Copy path");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::ToggleMark), "Mark");
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::MarkRange),
+            "Mark range",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::MarkMatches),
+            "Mark matches",
+        );
 
         // Search key - contextual based on search state
         if search_mode {
             // Actively typing in search input
-            hotkey_spans.extend(vec![
-                Span::styled("Esc", Style::default().fg(Color::Yellow)),
-                Span::raw(":Exit Search  "),
-            ]);
+            push_hotkey(
+                &mut hotkey_spans,
+                theme,
+                Some("Esc".to_string()),
+                "Exit Search",
+            );
         } else if has_search_query {
             // Search accepted (Enter pressed), showing filtered results
-            hotkey_spans.extend(vec![
-                Span::styled("Esc", Style::default().fg(Color::Yellow)),
-                Span::raw(":Clear Search  "),
-            ]);
+            push_hotkey(
+                &mut hotkey_spans,
+                theme,
+                Some("Esc".to_string()),
+                "Clear Search",
+            );
         } else {
             // No active search, show trigger key
-            let search_key = if vim_mode { "/" } else { "^F" };
-            hotkey_spans.extend(vec![
-                Span::styled(search_key, Style::default().fg(Color::Yellow)),
-                Span::raw(":Search  "),
-            ]);
+            push_hotkey(&mut hotkey_spans, theme, key(Action::Search), "Search");
         }
 
-        hotkey_spans.extend(vec![
-            Span::styled("s", Style::default().fg(Color::Yellow)),
-            Span::raw(":Sort  "),
-            Span::styled("S", Style::default().fg(Color::Yellow)),
-            Span::raw(":Reverse  "),
-            Span::styled("t", Style::default().fg(Color::Yellow)),
-            Span::raw(":Info  "),
-            Span::styled("?", Style::default().fg(Color::Yellow)),
-            Span::raw(":File Info  "),
-        ]);
+        push_hotkey(&mut hotkey_spans, theme, key(Action::CycleSort), "Sort");
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::ReverseSort),
+            "Reverse",
+        );
+        push_hotkey(&mut hotkey_spans, theme, key(Action::CycleDisplay), "Info");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::FileInfo), "File Info");
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Versions), "Versions");
 
         // Open - only show if open_command is configured
         if has_open_command {
-            hotkey_spans.extend(vec![
-                Span::styled("o", Style::default().fg(Color::Yellow)),
-                Span::raw(":Open  "),
-            ]);
+            push_hotkey(&mut hotkey_spans, theme, key(Action::OpenItem), "Open");
         }
 
-        hotkey_spans.extend(vec![
-            Span::styled("i", Style::default().fg(Color::Yellow)),
-            Span::raw(":Ignore  "),
-            Span::styled("I", Style::default().fg(Color::Yellow)),
-            Span::raw(":Ign+Del  "),
-            Span::styled("d", Style::default().fg(Color::Yellow)),
-            Span::raw(":Delete  "),
-        ]);
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::ToggleIgnore),
+            "Ignore",
+        );
+        push_hotkey(
+            &mut hotkey_spans,
+            theme,
+            key(Action::IgnoreDelete),
+            "Ign+Del",
+        );
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Delete), "Delete");
     }
 
-    // Rescan - available in both folder list and breadcrumbs
-    hotkey_spans.extend(vec![
-        Span::styled("r", Style::default().fg(Color::Yellow)),
-        Span::raw(":Rescan  "),
-    ]);
+    // Rescan, conflicts, .stignore, failed items, transfers and logs - available in both folder list and breadcrumbs
+    push_hotkey(&mut hotkey_spans, theme, key(Action::Rescan), "Rescan");
+    push_hotkey(
+        &mut hotkey_spans,
+        theme,
+        key(Action::Conflicts),
+        "Conflicts",
+    );
+    push_hotkey(
+        &mut hotkey_spans,
+        theme,
+        key(Action::EditIgnores),
+        "Edit Ignores",
+    );
+    push_hotkey(&mut hotkey_spans, theme, key(Action::FailedItems), "Failed");
+    push_hotkey(
+        &mut hotkey_spans,
+        theme,
+        key(Action::Transfers),
+        "Transfers",
+    );
+    push_hotkey(
+        &mut hotkey_spans,
+        theme,
+        key(Action::RemoteCompletion),
+        "Remotes",
+    );
+    push_hotkey(&mut hotkey_spans, theme, key(Action::Logs), "Logs");
+    push_hotkey(&mut hotkey_spans, theme, key(Action::RateLimits), "Limits");
 
     // Restore - only show when there are local changes to restore
     if can_restore {
-        hotkey_spans.extend(vec![
-            Span::styled("R", Style::default().fg(Color::Yellow)),
-            Span::raw(":Restore  "),
-        ]);
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Restore), "Restore");
+    }
+
+    // Override - only show when a send-only folder has remote changes
+    if can_override {
+        push_hotkey(&mut hotkey_spans, theme, key(Action::Override), "Override");
     }
 
-    // Quit - always available
-    hotkey_spans.extend(vec![
-        Span::styled("q", Style::default().fg(Color::Yellow)),
-        Span::raw(":Quit"),
-    ]);
+    // Quit - always available unless unbound
+    push_hotkey(&mut hotkey_spans, theme, key(Action::Quit), "Quit");
+
+    // No trailing separator after the last entry
+    if let Some(last) = hotkey_spans.last_mut() {
+        *last = Span::raw(last.content.trim_end().to_string());
+    }
 
     hotkey_spans
 }
 
+/// Legend lines: hotkeys, plus the marker key in ASCII icon mode
+fn build_legend_lines(
+    hotkey_spans: Vec<Span<'static>>,
+    icon_renderer: &IconRenderer,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(hotkey_spans)];
+    let marker_spans = icon_renderer.marker_legend();
+    if !marker_spans.is_empty() {
+        lines.push(Line::from(marker_spans));
+    }
+    lines
+}
+
 /// Build the legend paragraph (reusable for both rendering and height calculation)
+#[allow(clippy::too_many_arguments)]
 pub fn build_legend_paragraph(
-    vim_mode: bool,
+    keymap: &Keymap,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
     focus_level: usize,
     can_restore: bool,
+    can_override: bool,
     has_open_command: bool,
     search_mode: bool,
     has_search_query: bool,
 ) -> Paragraph<'static> {
     let hotkey_spans = build_hotkey_spans(
-        vim_mode,
+        keymap,
+        theme,
         focus_level,
         can_restore,
+        can_override,
         has_open_command,
         search_mode,
         has_search_query,
     );
-    let hotkey_line = Line::from(hotkey_spans);
 
-    Paragraph::new(vec![hotkey_line])
+    Paragraph::new(build_legend_lines(hotkey_spans, icon_renderer))
         .block(Block::default().borders(Borders::ALL).title("Hotkeys"))
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(theme.bar_text))
         .wrap(ratatui::widgets::Wrap { trim: false })
 }
 
@@ -165,17 +308,23 @@ pub fn build_legend_paragraph(
 pub fn render_legend(
     f: &mut Frame,
     area: Rect,
-    vim_mode: bool,
+    keymap: &Keymap,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
     focus_level: usize,
     can_restore: bool,
+    can_override: bool,
     has_open_command: bool,
     search_mode: bool,
     has_search_query: bool,
 ) {
     let legend = build_legend_paragraph(
-        vim_mode,
+        keymap,
+        theme,
+        icon_renderer,
         focus_level,
         can_restore,
+        can_override,
         has_open_command,
         search_mode,
         has_search_query,
@@ -184,30 +333,35 @@ pub fn render_legend(
 }
 
 /// Calculate required height for legend based on terminal width and content
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_legend_height(
     terminal_width: u16,
-    vim_mode: bool,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
     focus_level: usize,
     can_restore: bool,
+    can_override: bool,
     has_open_command: bool,
     search_mode: bool,
     has_search_query: bool,
 ) -> u16 {
     // Build paragraph WITHOUT block borders for accurate line counting
-    // (line_count() doesn't account for borders correctly when block is attached)
+    // (line_count() doesn't account for borders correctly when block is attached).
+    // Colors don't affect wrapping, so the default theme is good enough here.
     let hotkey_spans = build_hotkey_spans(
-        vim_mode,
+        keymap,
+        &Theme::default(),
         focus_level,
         can_restore,
+        can_override,
         has_open_command,
         search_mode,
         has_search_query,
     );
-    let hotkey_line = Line::from(hotkey_spans);
 
     // Create paragraph WITHOUT block for accurate line counting
-    let paragraph_for_counting =
-        Paragraph::new(vec![hotkey_line]).wrap(ratatui::widgets::Wrap { trim: false });
+    let paragraph_for_counting = Paragraph::new(build_legend_lines(hotkey_spans, icon_renderer))
+        .wrap(ratatui::widgets::Wrap { trim: false });
 
     // Calculate available width (subtract left + right borders)
     let available_width = terminal_width.saturating_sub(2);
@@ -234,14 +388,17 @@ mod tests {
 
     #[test]
     fn test_legend_shows_open_web_ui_in_folder_view() {
+        let keymap = Keymap::defaults(false);
         // In folder view (focus_level == 0), legend should show "o:Open Syncthing Web UI"
         let spans = build_hotkey_spans(
-            false, // vim_mode
-            0,     // focus_level (folder view)
-            false, // can_restore
-            true,  // has_open_command
-            false, // search_mode
-            false, // has_search_query
+            &keymap,           // keymap
+            &Theme::default(), // theme
+            0,                 // focus_level (folder view)
+            false,             // can_restore
+            false,             // can_override
+            true,              // has_open_command
+            false,             // search_mode
+            false,             // has_search_query
         );
 
         let text = spans_to_text(&spans);
@@ -254,14 +411,17 @@ mod tests {
 
     #[test]
     fn test_legend_shows_open_in_breadcrumb_view_with_command() {
+        let keymap = Keymap::defaults(false);
         // In breadcrumb view (focus_level > 0) with open_command, legend should show "o:Open"
         let spans = build_hotkey_spans(
-            false, // vim_mode
-            1,     // focus_level (breadcrumb view)
-            false, // can_restore
-            true,  // has_open_command
-            false, // search_mode
-            false, // has_search_query
+            &keymap,           // keymap
+            &Theme::default(), // theme
+            1,                 // focus_level (breadcrumb view)
+            false,             // can_restore
+            false,             // can_override
+            true,              // has_open_command
+            false,             // search_mode
+            false,             // has_search_query
         );
 
         let text = spans_to_text(&spans);
@@ -280,14 +440,17 @@ mod tests {
 
     #[test]
     fn test_legend_hides_open_in_breadcrumb_view_without_command() {
+        let keymap = Keymap::defaults(false);
         // In breadcrumb view without open_command, legend should NOT show 'o' key
         let spans = build_hotkey_spans(
-            false, // vim_mode
-            1,     // focus_level (breadcrumb view)
-            false, // can_restore
-            false, // has_open_command (no command configured)
-            false, // search_mode
-            false, // has_search_query
+            &keymap,           // keymap
+            &Theme::default(), // theme
+            1,                 // focus_level (breadcrumb view)
+            false,             // can_restore
+            false,             // can_override
+            false,             // has_open_command (no command configured)
+            false,             // search_mode
+            false,             // has_search_query
         );
 
         let text = spans_to_text(&spans);
@@ -308,15 +471,18 @@ mod tests {
 
     #[test]
     fn test_legend_always_shows_open_web_ui_in_folder_view_even_without_command() {
+        let keymap = Keymap::defaults(false);
         // In folder view, "o:Open Syncthing Web UI" should ALWAYS be shown (for discoverability)
         // even if open_command is not configured (will show error toast when pressed)
         let spans = build_hotkey_spans(
-            false, // vim_mode
-            0,     // focus_level (folder view)
-            false, // can_restore
-            false, // has_open_command (no command configured)
-            false, // search_mode
-            false, // has_search_query
+            &keymap,           // keymap
+            &Theme::default(), // theme
+            0,                 // focus_level (folder view)
+            false,             // can_restore
+            false,             // can_override
+            false,             // has_open_command (no command configured)
+            false,             // search_mode
+            false,             // has_search_query
         );
 
         let text = spans_to_text(&spans);
@@ -329,14 +495,33 @@ mod tests {
 
     #[test]
     fn test_legend_context_aware_behavior() {
+        let keymap = Keymap::defaults(false);
         // Test that 'o' key behavior changes based on focus_level
 
         // Folder view (focus_level == 0)
-        let folder_spans = build_hotkey_spans(false, 0, false, true, false, false);
+        let folder_spans = build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            0,
+            false,
+            false,
+            true,
+            false,
+            false,
+        );
         let folder_text = spans_to_text(&folder_spans);
 
         // Breadcrumb view (focus_level > 0)
-        let breadcrumb_spans = build_hotkey_spans(false, 1, false, true, false, false);
+        let breadcrumb_spans = build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            1,
+            false,
+            false,
+            true,
+            false,
+            false,
+        );
         let breadcrumb_text = spans_to_text(&breadcrumb_spans);
 
         // Verify they're different
@@ -353,4 +538,277 @@ mod tests {
             "Breadcrumb view should have 'Copy path' option"
         );
     }
+
+    #[test]
+    fn test_legend_shows_devices_only_in_folder_view() {
+        let keymap = Keymap::defaults(false);
+        let folder_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            0,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+        let breadcrumb_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            1,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+
+        assert!(
+            folder_text.contains("D:Devices"),
+            "Folder view should show 'D:Devices', got: {}",
+            folder_text
+        );
+        assert!(
+            !breadcrumb_text.contains("Devices"),
+            "Breadcrumb view should not show 'Devices', got: {}",
+            breadcrumb_text
+        );
+        assert!(
+            folder_text.contains("P:Pending") && !breadcrumb_text.contains("Pending"),
+            "Only folder view should show 'P:Pending', got: {} / {}",
+            folder_text,
+            breadcrumb_text
+        );
+        assert!(
+            folder_text.contains("W:Profiles") && !breadcrumb_text.contains("Profiles"),
+            "Only folder view should show 'W:Profiles', got: {} / {}",
+            folder_text,
+            breadcrumb_text
+        );
+        assert!(
+            folder_text.contains("O:Overview") && !breadcrumb_text.contains("Overview"),
+            "Only folder view should show 'O:Overview', got: {} / {}",
+            folder_text,
+            breadcrumb_text
+        );
+        assert!(
+            folder_text.contains("e:Settings") && !breadcrumb_text.contains("Settings"),
+            "Only folder view should show 'e:Settings', got: {} / {}",
+            folder_text,
+            breadcrumb_text
+        );
+    }
+
+    #[test]
+    fn test_legend_shows_versions_only_in_breadcrumb_view() {
+        let keymap = Keymap::defaults(false);
+        let folder_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            0,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+        let breadcrumb_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            2,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+
+        assert!(
+            breadcrumb_text.contains("V:Versions"),
+            "Breadcrumb view should show 'V:Versions', got: {}",
+            breadcrumb_text
+        );
+        assert!(
+            !folder_text.contains("Versions"),
+            "Folder view should not show 'Versions', got: {}",
+            folder_text
+        );
+    }
+
+    #[test]
+    fn test_legend_shows_marking_only_in_breadcrumb_view() {
+        let keymap = Keymap::defaults(false);
+        let folder_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            0,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+        let breadcrumb_text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            1,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+
+        assert!(
+            breadcrumb_text.contains("Space:Mark") && breadcrumb_text.contains("v:Mark range"),
+            "Breadcrumb view should show marking keys, got: {}",
+            breadcrumb_text
+        );
+        assert!(
+            !folder_text.contains("Mark"),
+            "Folder view should not show marking keys, got: {}",
+            folder_text
+        );
+    }
+
+    #[test]
+    fn test_legend_shows_failed_items_and_logs_in_both_views() {
+        let keymap = Keymap::defaults(false);
+        for focus_level in [0, 1] {
+            let text = spans_to_text(&build_hotkey_spans(
+                &keymap,
+                &Theme::default(),
+                focus_level,
+                false,
+                false,
+                false,
+                false,
+                false,
+            ));
+            assert!(
+                text.contains("F:Failed") && text.contains("L:Logs"),
+                "Legend should show 'F:Failed' and 'L:Logs' at focus level {}, got: {}",
+                focus_level,
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_legend_shows_conflicts_and_ignores_in_both_views() {
+        let keymap = Keymap::defaults(false);
+        for focus_level in [0, 1] {
+            let text = spans_to_text(&build_hotkey_spans(
+                &keymap,
+                &Theme::default(),
+                focus_level,
+                false,
+                false,
+                false,
+                false,
+                false,
+            ));
+            assert!(
+                text.contains("C:Conflicts") && text.contains("E:Edit Ignores"),
+                "Legend should show 'C:Conflicts' and 'E:Edit Ignores' at focus level {}, got: {}",
+                focus_level,
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_legend_shows_override_only_when_available() {
+        let keymap = Keymap::defaults(false);
+        let spans = |can_override| {
+            spans_to_text(&build_hotkey_spans(
+                &keymap,
+                &Theme::default(),
+                0,
+                false,
+                can_override,
+                false,
+                false,
+                false,
+            ))
+        };
+        assert!(spans(true).contains("X:Override"));
+        assert!(!spans(false).contains("Override"));
+    }
+
+    #[test]
+    fn test_legend_reproduces_vim_navigation() {
+        let keymap = Keymap::defaults(true);
+        let text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            1,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+        assert!(
+            text.starts_with("hjkl:Nav  gg/G:First/Last  ^d/^u:½Page  ^f/^b:FullPage"),
+            "got: {}",
+            text
+        );
+        assert!(text.contains("/:Search"), "got: {}", text);
+        assert!(text.ends_with("q:Quit"), "got: {}", text);
+    }
+
+    #[test]
+    fn test_legend_follows_remapped_keys() {
+        let mut keymap = Keymap::defaults(false);
+        keymap.bind(
+            KeyContext::Breadcrumb,
+            Action::Delete,
+            vec![crate::logic::keymap::KeyBinding::parse("x").unwrap()],
+        );
+        keymap.bind(KeyContext::Breadcrumb, Action::Versions, vec![]);
+
+        let text = spans_to_text(&build_hotkey_spans(
+            &keymap,
+            &Theme::default(),
+            1,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ));
+        assert!(text.contains("x:Delete"), "got: {}", text);
+        assert!(!text.contains("d:Delete"), "got: {}", text);
+        assert!(
+            !text.contains("Versions"),
+            "unbound actions are hidden, got: {}",
+            text
+        );
+    }
+
+    #[test]
+    fn test_legend_explains_ascii_markers() {
+        use crate::ui::icons::{IconMode, IconTheme};
+
+        let keymap = Keymap::defaults(false);
+        let height = |mode| {
+            let icon_renderer = IconRenderer::new(mode, IconTheme::default());
+            calculate_legend_height(
+                400,
+                &keymap,
+                &icon_renderer,
+                0,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
+        };
+
+        // One extra line for the marker key, only in ASCII mode
+        assert_eq!(height(IconMode::Ascii), height(IconMode::Emoji) + 1);
+        assert_eq!(height(IconMode::NerdFont), height(IconMode::Emoji));
+    }
 }
@@ -0,0 +1,180 @@
+//! Syncthing log viewer rendering
+//!
+//! Scrollable view of the Syncthing log and reported errors, filtered by
+//! level and search query, with an optional search input at the bottom.
+
+use crate::logic::formatting::format_datetime;
+use crate::logic::keymap::{Action, Keymap};
+use crate::logic::logs::filter_log_lines;
+use crate::model::types::{LogLevel, LogLine, LogViewerModal};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Render the log viewer modal
+pub fn render_log_viewer(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    viewer: &mut LogViewerModal,
+) {
+    // Calculate centered modal dimensions (90% width, 80% height)
+    let modal_width = (area.width as f32 * 0.9) as u16;
+    let modal_height = (area.height as f32 * 0.8) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    let show_search = viewer.search_active || !viewer.search_query.is_empty();
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(if show_search {
+            vec![Constraint::Min(3), Constraint::Length(3)]
+        } else {
+            vec![Constraint::Min(3)]
+        })
+        .split(modal_area);
+
+    let visible = filter_log_lines(&viewer.lines, viewer.min_level, &viewer.search_query);
+
+    // Follow mode pins the view to the newest line
+    let viewport_height = rows[0].height.saturating_sub(2) as usize;
+    let max_scroll = visible.len().saturating_sub(viewport_height);
+    viewer.scroll_offset = if viewer.follow {
+        max_scroll
+    } else {
+        viewer.scroll_offset.min(max_scroll)
+    };
+
+    let lines: Vec<Line> = visible
+        .iter()
+        .skip(viewer.scroll_offset)
+        .take(viewport_height)
+        .map(|line| render_log_line(line, theme, &viewer.search_query))
+        .collect();
+
+    let mut title = icon_renderer.render_text(&format!(
+        " Syncthing Log ({} of {} lines, level ≥ {}) ",
+        visible.len(),
+        viewer.lines.len(),
+        viewer.min_level.label()
+    ));
+    if viewer.follow {
+        title.push_str("[follow] ");
+    }
+
+    let log = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .title_bottom(icon_renderer.render_text(&format!(
+                " {}/PgUp/PgDn:Scroll  {} ",
+                keymap.modal_nav_label(),
+                keymap.modal_hints(&[
+                    (&[Action::ToggleFollow], "Follow"),
+                    (&[Action::CycleLevel], "Level"),
+                    (&[Action::Search], "Search"),
+                    (&[Action::ClearErrors], "Clear errors"),
+                    (&[Action::Close], "Close"),
+                ])
+            )))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(log, rows[0]);
+
+    if show_search {
+        let mut spans = vec![
+            Span::styled("Search: ", Style::default().fg(theme.label)),
+            Span::raw(viewer.search_query.clone()),
+        ];
+        if viewer.search_active {
+            spans.push(Span::styled(
+                " ",
+                Style::default().add_modifier(Modifier::REVERSED),
+            ));
+        }
+
+        let hint = if viewer.search_active {
+            " Enter:Apply  Esc:Clear "
+        } else {
+            " /:Edit "
+        };
+        let search = Paragraph::new(Line::from(spans)).block(
+            Block::default()
+                .title_bottom(hint)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        );
+        f.render_widget(search, rows[1]);
+    }
+}
+
+/// Style for a log level
+fn level_style(level: LogLevel, theme: &Theme) -> Style {
+    match level {
+        LogLevel::Debug => Style::default().fg(theme.text_muted),
+        LogLevel::Info => Style::default().fg(theme.text),
+        LogLevel::Warning => Style::default().fg(theme.warning),
+        LogLevel::Error => Style::default()
+            .fg(theme.error)
+            .add_modifier(Modifier::BOLD),
+    }
+}
+
+/// Render one log line (time, level, message with search matches highlighted)
+fn render_log_line(line: &LogLine, theme: &Theme, query: &str) -> Line<'static> {
+    let style = level_style(line.level, theme);
+    let mut spans = vec![
+        Span::styled(
+            format!("{} ", format_datetime(&line.when)),
+            Style::default().fg(theme.text_muted),
+        ),
+        Span::styled(format!("{:<5} ", line.level.label()), style),
+    ];
+
+    let highlight = Style::default().fg(theme.match_fg).bg(theme.match_bg);
+    let lower = line.message.to_lowercase();
+
+    // Lowercasing can change byte lengths for some scripts; skip highlighting then
+    if query.is_empty() || lower.len() != line.message.len() {
+        spans.push(Span::styled(line.message.clone(), style));
+        return Line::from(spans);
+    }
+
+    let query = query.to_lowercase();
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find(&query) {
+        let start = pos + found;
+        let end = start + query.len();
+        if !line.message.is_char_boundary(start) || !line.message.is_char_boundary(end) {
+            break;
+        }
+        if start > pos {
+            spans.push(Span::styled(line.message[pos..start].to_string(), style));
+        }
+        spans.push(Span::styled(
+            line.message[start..end].to_string(),
+            highlight,
+        ));
+        pos = end;
+    }
+    if pos < line.message.len() {
+        spans.push(Span::styled(line.message[pos..].to_string(), style));
+    }
+
+    Line::from(spans)
+}
@@ -5,27 +5,59 @@
 // - layout: Calculates screen layout (panes, splits, areas)
 // - render: Main orchestration function that coordinates all rendering
 // - system_bar: Renders top system info bar (device name, uptime, transfer rates)
+// - system_menu: Renders the system menu (version, restart, shutdown) and its confirmations
 // - folder_list: Renders the left folder panel
 // - breadcrumb: Renders breadcrumb navigation panels
 // - legend: Renders hotkey legend
 // - search: Renders search input box with query and match count
 // - status_bar: Renders bottom status bar with metrics
 // - dialogs: Renders confirmation dialogs (revert, delete, pattern selection)
+// - conflicts: Renders the sync conflicts modal (side-by-side original vs conflict copy)
+// - failed_items: Renders the failed items modal (paths and pull errors)
+// - fleet: Renders the fleet overview (one row per configured instance)
+// - folder_settings: Renders the folder settings form and the changed-settings confirmation
+// - folder_setup: Renders the new folder form, sharing checklist and folder removal prompt
+// - ignore_editor: Renders the .stignore editor, match preview and save diff
+// - logs: Renders the Syncthing log viewer (level filter, search, follow mode)
+// - devices: Renders the device management modal and add/edit form
+// - pending: Renders the pending invitations inbox and accept-folder form
+// - profiles: Renders the profile switcher (configured Syncthing instances)
+// - rate_limits: Renders the global and per-device bandwidth limit popup
+// - remote_completion: Renders per-device folder completion and the files a device needs
+// - versions: Renders the file versions modal (archived versions + preview)
 // - toast: Renders toast notifications (brief pop-up messages)
+// - transfers: Renders the transfer queue modal (download order, per-file progress)
+// - theme: Semantic color themes (built-in dark/light/high-contrast and theme files)
 
 pub mod breadcrumb;
+pub mod conflicts;
+pub mod devices;
 pub mod dialogs;
+pub mod failed_items;
+pub mod fleet;
 pub mod folder_history;
 pub mod folder_list;
+pub mod folder_settings;
+pub mod folder_setup;
 pub mod icons;
+pub mod ignore_editor;
 pub mod layout;
 pub mod legend;
+pub mod logs;
 pub mod out_of_sync_summary;
+pub mod pending;
+pub mod profiles;
+pub mod rate_limits;
+pub mod remote_completion;
 pub mod render;
 pub mod search;
 pub mod status_bar;
 pub mod system_bar;
+pub mod system_menu;
+pub mod theme;
 pub mod toast;
+pub mod transfers;
+pub mod versions;
 
 // Re-export main render function for convenience
 pub use render::render;
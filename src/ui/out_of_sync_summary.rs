@@ -1,21 +1,23 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem},
 };
 
 use crate::api::Folder;
 use crate::model::types::OutOfSyncSummaryState;
-use crate::ui::icons::IconRenderer;
+use crate::ui::icons::{IconMode, IconRenderer};
+use crate::ui::theme::Theme;
 
 pub fn render_out_of_sync_summary(
     f: &mut Frame,
+    theme: &Theme,
     area: Rect,
     folders: &[Folder],
     summary_state: &OutOfSyncSummaryState,
-    _icon_renderer: &IconRenderer,
+    icon_renderer: &IconRenderer,
 ) {
     // Create centered modal (60% width, auto height)
     let modal_width = (area.width as f32 * 0.6) as u16;
@@ -38,46 +40,67 @@ pub fn render_out_of_sync_summary(
             let breakdown = summary_state.breakdowns.get(&folder.id);
             let is_loading = summary_state.loading.contains(&folder.id);
 
+            // The emoji markers have no ASCII counterpart; the labels suffice
+            let mark = |emoji: &'static str| {
+                if icon_renderer.mode() == IconMode::Ascii {
+                    ""
+                } else {
+                    emoji
+                }
+            };
+
             let mut lines = vec![Line::from(vec![
-                Span::raw("📂 "),
+                Span::raw(mark("📂 ")),
                 Span::styled(display_name, Style::default().add_modifier(Modifier::BOLD)),
             ])];
 
             if is_loading {
                 lines.push(Line::from(Span::styled(
                     "   Loading...",
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme.text_secondary),
                 )));
             } else if let Some(b) = breakdown {
-                let total = b.downloading + b.queued + b.remote_only + b.modified + b.local_only;
+                let total = b.downloading
+                    + b.queued
+                    + b.remote_only
+                    + b.modified
+                    + b.local_only
+                    + b.remote_changed;
 
                 if total == 0 {
                     lines.push(Line::from(Span::styled(
-                        "   ✅ All synced",
-                        Style::default().fg(Color::Green),
+                        format!("   {}All synced", mark("✅ ")),
+                        Style::default().fg(theme.success),
                     )));
                 } else {
                     let mut status_parts = Vec::new();
 
                     if b.downloading > 0 {
-                        status_parts.push(format!("🔄 Downloading: {}", b.downloading));
+                        status_parts.push(format!("{}Downloading: {}", mark("🔄 "), b.downloading));
                     }
                     if b.queued > 0 {
-                        status_parts.push(format!("⏳ Queued: {}", b.queued));
+                        status_parts.push(format!("{}Queued: {}", mark("⏳ "), b.queued));
                     }
                     if b.local_only > 0 {
-                        status_parts.push(format!("💻 Local: {}", b.local_only));
+                        status_parts.push(format!("{}Local: {}", mark("💻 "), b.local_only));
                     }
                     if b.remote_only > 0 {
-                        status_parts.push(format!("☁️ Remote: {}", b.remote_only));
+                        status_parts.push(format!("{}Remote: {}", mark("☁️ "), b.remote_only));
                     }
                     if b.modified > 0 {
-                        status_parts.push(format!("⚠️ Modified: {}", b.modified));
+                        status_parts.push(format!("{}Modified: {}", mark("⚠️ "), b.modified));
+                    }
+                    if b.remote_changed > 0 {
+                        status_parts.push(format!(
+                            "{}Remote changed: {}",
+                            mark("⇅ "),
+                            b.remote_changed
+                        ));
                     }
 
                     lines.push(Line::from(Span::styled(
                         format!("   {}", status_parts.join("  ")),
-                        Style::default().fg(Color::Yellow),
+                        Style::default().fg(theme.label),
                     )));
                 }
             }
@@ -91,9 +114,9 @@ pub fn render_out_of_sync_summary(
             Block::default()
                 .title("Folder Sync Summary")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(theme.border)),
         )
-        .highlight_style(Style::default().bg(Color::DarkGray));
+        .highlight_style(Style::default().bg(theme.selection_bg));
 
     // Clear background behind modal first
     f.render_widget(Clear, modal_area);
@@ -0,0 +1,219 @@
+//! Pending invitations inbox rendering
+//!
+//! Lists device and folder offers waiting to be accepted, plus the form for
+//! choosing a local path and folder type when accepting a folder.
+
+use crate::logic::devices::short_device_id;
+use crate::logic::folder_card::format_folder_type;
+use crate::logic::formatting::format_datetime;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{AcceptFolderForm, PendingInboxModal, PendingInvitation};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the pending invitations inbox
+pub fn render_pending_inbox(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    inbox: &PendingInboxModal,
+) {
+    // Calculate centered modal dimensions (80% width, 60% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.6) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    let items: Vec<ListItem> = if inbox.invitations.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No pending invitations",
+            Style::default().fg(theme.text_secondary),
+        ))]
+    } else {
+        inbox
+            .invitations
+            .iter()
+            .map(|invitation| invitation_list_item(invitation, theme))
+            .collect()
+    };
+
+    let title = format!(" Pending Invitations ({}) ", inbox.invitations.len());
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(format!(
+                    " {} ",
+                    keymap.modal_hints(&[
+                        (&[Action::Accept, Action::Select], "Accept"),
+                        (&[Action::Dismiss], "Dismiss"),
+                        (&[Action::Close], "Close"),
+                    ])
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !inbox.invitations.is_empty() {
+        list_state.select(Some(inbox.selected_index));
+    }
+
+    f.render_widget(Clear, modal_area);
+    f.render_stateful_widget(list, modal_area, &mut list_state);
+
+    if let Some(form) = &inbox.accept_form {
+        render_accept_folder_form(f, theme, icon_renderer, area, form);
+    }
+}
+
+/// Build a single row for the invitation list
+fn invitation_list_item<'a>(invitation: &'a PendingInvitation, theme: &Theme) -> ListItem<'a> {
+    let dim = Style::default().fg(theme.text_muted);
+
+    let mut spans = match invitation {
+        PendingInvitation::Device {
+            device_id,
+            name,
+            address,
+            ..
+        } => {
+            let display_name = if name.is_empty() {
+                short_device_id(device_id)
+            } else {
+                name.as_str()
+            };
+            vec![
+                Span::styled("Device  ", Style::default().fg(theme.accent)),
+                Span::styled(display_name.to_string(), Style::default().fg(theme.text)),
+                Span::styled(format!(" ({})", short_device_id(device_id)), dim),
+                Span::raw("  "),
+                Span::styled(address.clone(), dim),
+            ]
+        }
+        PendingInvitation::Folder {
+            folder_id,
+            label,
+            device_name,
+            ..
+        } => {
+            let display_label = if label.is_empty() {
+                folder_id.as_str()
+            } else {
+                label.as_str()
+            };
+            vec![
+                Span::styled("Folder  ", Style::default().fg(theme.info)),
+                Span::styled(display_label.to_string(), Style::default().fg(theme.text)),
+                Span::styled(format!(" ({})", folder_id), dim),
+                Span::raw("  from "),
+                Span::styled(device_name.clone(), Style::default().fg(theme.text)),
+            ]
+        }
+    };
+
+    spans.push(Span::raw("  "));
+    spans.push(Span::styled(
+        format_datetime(invitation.time()),
+        Style::default().fg(theme.label),
+    ));
+
+    ListItem::new(Line::from(spans))
+}
+
+/// Render the accept-folder form (local path + folder type)
+fn render_accept_folder_form(
+    f: &mut Frame,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    form: &AcceptFolderForm,
+) {
+    let form_width = 76.min(area.width);
+    let form_height = 8;
+    let form_area = Rect {
+        x: (area.width.saturating_sub(form_width)) / 2,
+        y: (area.height.saturating_sub(form_height)) / 2,
+        width: form_width,
+        height: form_height.min(area.height),
+    };
+
+    let label = |text: &'static str| {
+        Span::styled(format!("{:<13}", text), Style::default().fg(theme.label))
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("  "),
+            label("Folder ID:"),
+            Span::styled(
+                form.folder_id.clone(),
+                Style::default().fg(theme.text_secondary),
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw(icon_renderer.render_highlight()),
+            label("Local path:"),
+            Span::styled(
+                format!("{}{}", form.path, icon_renderer.render_cursor()),
+                Style::default()
+                    .fg(theme.text)
+                    .bg(theme.selection_bg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw("  "),
+            label("Folder type:"),
+            Span::styled(
+                icon_renderer.render_choice(&format_folder_type(&form.folder_type)),
+                Style::default().fg(theme.text),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Path as seen on this machine (mapped through path_map)",
+            Style::default().fg(theme.text_secondary),
+        )),
+    ];
+
+    let title = if form.label.is_empty() {
+        format!(" Accept Folder: {} ", form.folder_id)
+    } else {
+        format!(" Accept Folder: {} ", form.label)
+    };
+
+    let widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_bottom(icon_renderer.render_text(" Tab/←→:Type  Enter:Accept  Esc:Cancel "))
+                .border_style(Style::default().fg(theme.dialog_border)),
+        )
+        .style(theme.dialog());
+
+    f.render_widget(Clear, form_area);
+    f.render_widget(widget, form_area);
+}
@@ -0,0 +1,87 @@
+//! Profile switcher rendering
+//!
+//! Lists the configured Syncthing instances with their URLs and marks the one
+//! the app is connected to.
+
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::ProfileSwitcherModal;
+use crate::ui::icons::{ConnectionIcon, IconRenderer};
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+/// Render the profile switcher
+pub fn render_profile_switcher(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    switcher: &ProfileSwitcherModal,
+) {
+    // Small centered modal: one row per profile plus borders
+    let modal_width = ((area.width as f32 * 0.6) as u16).max(40).min(area.width);
+    let modal_height = (switcher.profiles.len() as u16 + 2).min(area.height);
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width,
+        height: modal_height,
+    };
+
+    let dim = Style::default().fg(theme.text_muted);
+    let active_marker = format!(
+        "{} ",
+        icon_renderer.render_connection(ConnectionIcon::Connected)
+    );
+    let blank_marker = " ".repeat(Span::raw(active_marker.as_str()).width());
+    let items: Vec<ListItem> = switcher
+        .profiles
+        .iter()
+        .map(|profile| {
+            let is_active = profile.name == switcher.active;
+            let marker = if is_active {
+                &active_marker
+            } else {
+                &blank_marker
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker.clone(), Style::default().fg(theme.success)),
+                Span::styled(profile.name.clone(), Style::default().fg(theme.text)),
+                Span::raw("  "),
+                Span::styled(profile.base_url.clone(), dim),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(" Profiles ")
+                .title_bottom(format!(
+                    " {}:Connect  {}:Close ",
+                    keymap.modal_label(Action::Select),
+                    keymap.modal_label(Action::Close)
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(switcher.selected_index));
+
+    f.render_widget(Clear, modal_area);
+    f.render_stateful_widget(list, modal_area, &mut list_state);
+}
@@ -0,0 +1,148 @@
+//! Rate limit popup rendering
+//!
+//! Lists the global bandwidth limits and each remote device's limits, with
+//! the presets and the direction they apply to.
+
+use crate::logic::keymap::{Action, Keymap};
+use crate::logic::rate_limits::{RATE_PRESETS, format_limit};
+use crate::model::types::{RateLimitDirection, RateLimitModal};
+use crate::ui::icons::{IconMode, IconRenderer};
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the rate limit popup
+pub fn render_rate_limits(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal: &RateLimitModal,
+) {
+    let modal_width = 64.min(area.width);
+    let modal_height = (modal.rows.len() as u16 + 5).clamp(7, area.height.max(7));
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width,
+        height: modal_height.min(area.height),
+    };
+
+    let symbols = icon_renderer.symbols();
+    let limit_style = |kbps: u64| {
+        if kbps > 0 {
+            Style::default().fg(theme.warning)
+        } else {
+            Style::default().fg(theme.text_muted)
+        }
+    };
+
+    let items: Vec<ListItem> = modal
+        .rows
+        .iter()
+        .map(|row| {
+            let name_style = if row.device_id.is_none() {
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<24}", row.name), name_style),
+                Span::styled(symbols.download, Style::default().fg(theme.label)),
+                Span::styled(
+                    format!("{:<10}", format_limit(row.limits.max_recv_kbps, &symbols)),
+                    limit_style(row.limits.max_recv_kbps),
+                ),
+                Span::styled(symbols.upload, Style::default().fg(theme.label)),
+                Span::styled(
+                    format_limit(row.limits.max_send_kbps, &symbols),
+                    limit_style(row.limits.max_send_kbps),
+                ),
+            ]))
+        })
+        .collect();
+
+    // The words name the direction on their own; drop the arrows in ASCII mode
+    let arrows = |unicode: &'static str| {
+        if icon_renderer.mode() == IconMode::Ascii {
+            ""
+        } else {
+            unicode
+        }
+    };
+    let direction = match modal.direction {
+        RateLimitDirection::Both => format!("{}both", arrows("↓↑ ")),
+        RateLimitDirection::Send => format!("{}send", arrows("↑ ")),
+        RateLimitDirection::Receive => format!("{}receive", arrows("↓ ")),
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" Rate Limits (applies to: {}) ", direction))
+                .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let footer_line = match &modal.custom_input {
+        Some(input) => Line::from(vec![
+            Span::styled(" Custom limit (KiB/s): ", Style::default().fg(theme.label)),
+            Span::styled(
+                format!("{}{}", input, icon_renderer.render_cursor()),
+                Style::default()
+                    .fg(theme.text)
+                    .bg(theme.selection_bg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        None => {
+            let mut spans = vec![Span::raw(" ")];
+            for (i, (label, _)) in RATE_PRESETS.iter().enumerate() {
+                spans.push(Span::styled(
+                    format!("{}", i),
+                    Style::default().fg(theme.label),
+                ));
+                spans.push(Span::raw(format!(":{}  ", label)));
+            }
+            let custom = keymap.modal_label(Action::CustomLimit);
+            if !custom.is_empty() {
+                spans.push(Span::styled(custom, Style::default().fg(theme.label)));
+                spans.push(Span::raw(":Custom"));
+            }
+            Line::from(spans)
+        }
+    };
+    let footer = Paragraph::new(footer_line).block(
+        Block::default()
+            .borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT)
+            .title_bottom(format!(
+                " Tab:Direction  {}:Close ",
+                keymap.modal_label(Action::Close)
+            ))
+            .border_style(Style::default().fg(theme.border)),
+    );
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(modal_area);
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(modal.selected_index));
+
+    f.render_widget(Clear, modal_area);
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+    f.render_widget(footer, chunks[1]);
+}
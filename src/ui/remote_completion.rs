@@ -0,0 +1,255 @@
+//! Remote completion modal rendering
+//!
+//! Lists the devices a folder is shared with, each with its completion
+//! percentage and how much it still needs, above the details of the selected
+//! device. Drilling into a device replaces the list with the files it needs.
+
+use crate::logic::completion::{CompletionStatus, completion_status, format_percent};
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{RemoteCompletionModal, RemoteNeedList};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use crate::utils::format_bytes;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Render the remote completion modal
+pub fn render_remote_completion(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &RemoteCompletionModal,
+) {
+    // Calculate centered modal dimensions (80% width, 70% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.7) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    match &modal_state.remote_need {
+        Some(list) => render_remote_need(
+            f,
+            theme,
+            keymap,
+            icon_renderer,
+            modal_area,
+            &modal_state.folder_label,
+            list,
+        ),
+        None => render_devices(f, theme, keymap, icon_renderer, modal_area, modal_state),
+    }
+}
+
+/// Color of a completion status
+fn status_color(theme: &Theme, status: CompletionStatus) -> Color {
+    match status {
+        CompletionStatus::UpToDate => theme.synced,
+        CompletionStatus::Syncing => theme.syncing,
+        CompletionStatus::Disconnected => theme.warning,
+        CompletionStatus::Paused => theme.paused,
+        CompletionStatus::NotSharing | CompletionStatus::Unknown => theme.unknown,
+    }
+}
+
+fn render_devices(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &RemoteCompletionModal,
+) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(4), Constraint::Length(4)])
+        .split(area);
+
+    let dim = Style::default().fg(theme.text_muted);
+    let name_width = modal_state
+        .devices
+        .iter()
+        .map(|device| device.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let items: Vec<ListItem> = modal_state
+        .devices
+        .iter()
+        .map(|device| {
+            let status = completion_status(device);
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<width$}  ", device.name, width = name_width),
+                    Style::default().fg(theme.text),
+                ),
+                Span::styled(
+                    format!("{:<13}", status.label()),
+                    Style::default().fg(status_color(theme, status)),
+                ),
+            ];
+            if let Some(completion) = &device.completion {
+                spans.push(Span::styled(
+                    format!("{:>5}", format_percent(completion)),
+                    Style::default().fg(theme.text),
+                ));
+                if completion.need_items > 0 || completion.need_deletes > 0 {
+                    spans.push(Span::styled(
+                        format!(
+                            "  needs {} ({} items, {} deletes)",
+                            format_bytes(completion.need_bytes),
+                            completion.need_items,
+                            completion.need_deletes
+                        ),
+                        dim,
+                    ));
+                }
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" Remote Devices: {} ", modal_state.folder_label))
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  {}:Files needed  {}:Close ",
+                    keymap.modal_nav_label(),
+                    keymap.modal_label(Action::Select),
+                    keymap.modal_label(Action::Close)
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.devices.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+    f.render_stateful_widget(list, rows[0], &mut list_state);
+
+    // Details of the selected device
+    let label = Style::default().fg(theme.label);
+    let detail = match modal_state.selected_device() {
+        Some(device) => {
+            let state = match &device.completion {
+                Some(completion) => Line::from(vec![
+                    Span::styled("Has: ", label),
+                    Span::raw(format!(
+                        "{} of {}",
+                        format_bytes(
+                            completion
+                                .global_bytes
+                                .saturating_sub(completion.need_bytes)
+                        ),
+                        format_bytes(completion.global_bytes)
+                    )),
+                    Span::styled("  Connection: ", label),
+                    Span::raw(if device.connected {
+                        "connected"
+                    } else {
+                        "disconnected"
+                    }),
+                ]),
+                None => Line::from(Span::styled("Completion unavailable", dim)),
+            };
+            vec![
+                Line::from(vec![
+                    Span::styled("Device ID: ", label),
+                    Span::styled(device.device_id.clone(), dim),
+                ]),
+                state,
+            ]
+        }
+        None => vec![],
+    };
+
+    let detail = Paragraph::new(detail).block(
+        Block::default()
+            .title(" Device ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(detail, rows[1]);
+}
+
+fn render_remote_need(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    folder_label: &str,
+    list_state: &RemoteNeedList,
+) {
+    let dim = Style::default().fg(theme.text_muted);
+    let items: Vec<ListItem> = list_state
+        .files
+        .iter()
+        .map(|file| {
+            let name = if file.deleted {
+                format!("{} (delete)", file.name)
+            } else {
+                file.name.clone()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>10}  ", format_bytes(file.size)), dim),
+                Span::styled(name, Style::default().fg(theme.text)),
+            ]))
+        })
+        .collect();
+
+    let count = if list_state.has_more {
+        format!("{}+", list_state.files.len())
+    } else {
+        list_state.files.len().to_string()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Needed by {}: {} ({}) ",
+                    list_state.device_name, folder_label, count
+                ))
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  {}:Back ",
+                    keymap.modal_nav_label(),
+                    keymap.modal_label(Action::Close)
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut state = ListState::default();
+    if !list_state.files.is_empty() {
+        state.select(Some(list_state.selected_index));
+    }
+    f.render_stateful_widget(list, area, &mut state);
+}
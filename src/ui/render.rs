@@ -1,4 +1,5 @@
 use crate::App;
+use crate::logic::keymap::{Action, KeyContext};
 use ratatui::Frame;
 use stui::DisplayMode;
 
@@ -27,6 +28,14 @@ pub fn render(f: &mut Frame, app: &mut App) {
         false
     };
 
+    // Check if the current send-only folder has remote changes to override
+    let can_override = app.model.current_folder().is_some_and(|folder| {
+        crate::logic::folder::has_remote_changes(
+            &folder.folder_type,
+            app.model.syncthing.folder_statuses.get(&folder.id),
+        )
+    });
+
     // Calculate status bar data (needed for status height calculation)
     // Clone strings to avoid borrowing issues
     let (
@@ -106,9 +115,11 @@ pub fn render(f: &mut Frame, app: &mut App) {
         size,
         app.model.navigation.breadcrumb_trail.len(),
         has_breadcrumbs,
-        app.model.ui.vim_mode,
+        &app.model.ui.keymap,
+        &app.icon_renderer,
         app.model.navigation.focus_level,
         can_restore,
+        can_override,
         app.open_command.is_some(),
         status_height,
         search_visible,
@@ -119,11 +130,23 @@ pub fn render(f: &mut Frame, app: &mut App) {
     system_bar::render_system_bar(
         f,
         layout_info.system_area,
+        &app.theme,
+        &app.icon_renderer,
+        &app.model.ui.keymap,
         &app.model.syncthing.connection_state,
         app.model.syncthing.system_status.as_ref(),
         app.model.syncthing.device_name.as_deref(),
         (total_files, total_dirs, total_bytes),
         app.model.syncthing.last_transfer_rates,
+        app.unseen_error_count(),
+        app.model.ui.active_profile.as_deref(),
+        crate::logic::rate_limits::rate_limit_indicator(
+            app.model.syncthing.global_rate_limits,
+            app.model.syncthing.rate_limited_devices,
+            &app.icon_renderer.symbols(),
+        )
+        .as_deref(),
+        app.model.syncthing.restart_required,
     );
 
     // Render folders pane if visible
@@ -142,7 +165,10 @@ pub fn render(f: &mut Frame, app: &mut App) {
             &mut temp_state,
             app.model.navigation.focus_level == 0,
             &app.icon_renderer,
+            &app.theme,
             &app.model.syncthing.last_folder_updates,
+            &app.model.syncthing.scan_progress,
+            &app.model.syncthing.remote_completion,
         );
         // Sync back the selection (though folder_list doesn't usually modify it)
         app.model.navigation.folders_state_selection = temp_state.selected();
@@ -172,6 +198,12 @@ pub fn render(f: &mut Frame, app: &mut App) {
         } else {
             level.folder_label.clone()
         };
+        let marked_count = level.marked_items().len();
+        let title = if marked_count == 0 {
+            title
+        } else {
+            format!("{} [{} marked]", title, marked_count)
+        };
 
         let is_focused = app.model.navigation.focus_level == idx + 1;
         // All ancestor breadcrumbs should remain highlighted when drilling deeper
@@ -196,12 +228,14 @@ pub fn render(f: &mut Frame, app: &mut App) {
             level.filtered_items.as_ref(), // Filtered view (if active)
             &level.file_sync_states,
             &level.ignored_exists,
+            &level.marked,
             &mut temp_state,
             &title,
             is_focused,
             is_parent_selected,
             display_mode,
             &app.icon_renderer,
+            &app.theme,
             &level.translated_base_path,
             level.prefix.as_deref(),
         );
@@ -227,10 +261,15 @@ pub fn render(f: &mut Frame, app: &mut App) {
         search::render_search_input(
             f,
             search_area,
+            &app.theme,
             &app.model.ui.search_query,
             app.model.ui.search_mode,
             match_count,
-            app.model.ui.vim_mode,
+            app.model
+                .ui
+                .keymap
+                .key_label(KeyContext::Breadcrumb, Action::Search)
+                .as_deref(),
         );
     }
 
@@ -239,9 +278,12 @@ pub fn render(f: &mut Frame, app: &mut App) {
         legend::render_legend(
             f,
             legend_area,
-            app.model.ui.vim_mode,
+            &app.model.ui.keymap,
+            &app.theme,
+            &app.icon_renderer,
             app.model.navigation.focus_level,
             can_restore,
+            can_override,
             app.open_command.is_some(),
             app.model.ui.search_mode,
             !app.model.ui.search_query.is_empty(),
@@ -253,6 +295,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
         f,
         layout_info.status_area,
         &app.icon_renderer,
+        &app.theme,
         app.model.navigation.focus_level,
         &app.model.syncthing.folders,
         &app.model.syncthing.folder_statuses,
@@ -272,26 +315,289 @@ pub fn render(f: &mut Frame, app: &mut App) {
         app.model.syncthing.connected_device_count,
     );
 
+    // Render device management modal (before dialogs so confirmations appear on top)
+    if let Some(ref modal_state) = app.model.ui.device_details_modal {
+        crate::ui::devices::render_devices_modal(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            modal_state,
+        );
+    }
+
+    // Render file versions modal
+    if let Some(ref mut versions_modal) = app.model.ui.file_versions_modal {
+        crate::ui::versions::render_file_versions_modal(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            versions_modal,
+        );
+    }
+
+    // Render .stignore editor
+    if let Some(ref editor) = app.model.ui.ignore_editor {
+        crate::ui::ignore_editor::render_ignore_editor(
+            f,
+            &app.theme,
+            &app.icon_renderer,
+            size,
+            editor,
+        );
+    }
+
+    // Render folder settings form
+    if let Some(ref form) = app.model.ui.folder_settings {
+        crate::ui::folder_settings::render_folder_settings(
+            f,
+            &app.theme,
+            &app.icon_renderer,
+            size,
+            form,
+        );
+    }
+
+    // Render rate limit popup
+    if let Some(ref modal) = app.model.ui.rate_limits {
+        crate::ui::rate_limits::render_rate_limits(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            modal,
+        );
+    }
+
+    // Render system menu
+    if let Some(ref menu) = app.model.ui.system_menu {
+        crate::ui::system_menu::render_system_menu(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            menu,
+            app.model.syncthing.restart_required,
+        );
+    }
+
+    // Render folder setup dialogs (new folder, sharing, removal)
+    if let Some(ref form) = app.model.ui.new_folder {
+        crate::ui::folder_setup::render_new_folder(f, &app.theme, &app.icon_renderer, size, form);
+    }
+    if let Some(ref modal) = app.model.ui.folder_sharing {
+        crate::ui::folder_setup::render_folder_sharing(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            modal,
+        );
+    }
+    if let Some(ref prompt) = app.model.ui.remove_folder {
+        crate::ui::folder_setup::render_remove_folder(
+            f,
+            &app.theme,
+            &app.icon_renderer,
+            size,
+            prompt,
+        );
+    }
+
+    // Render sync conflicts modal
+    if let Some(ref mut conflicts_modal) = app.model.ui.conflicts_modal {
+        crate::ui::conflicts::render_conflicts_modal(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            conflicts_modal,
+        );
+    }
+
+    // Render failed items modal
+    if let Some(ref failed_items) = app.model.ui.failed_items_modal {
+        crate::ui::failed_items::render_failed_items_modal(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            failed_items,
+        );
+    }
+
+    // Render transfer queue
+    if let Some(ref queue) = app.model.ui.transfer_queue {
+        crate::ui::transfers::render_transfer_queue(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            queue,
+            app.model.syncthing.transfers.get(&queue.folder_id),
+        );
+    }
+
+    // Render remote device completion
+    if let Some(ref remote_completion) = app.model.ui.remote_completion {
+        crate::ui::remote_completion::render_remote_completion(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            remote_completion,
+        );
+    }
+
+    // Render Syncthing log viewer
+    if let Some(ref mut log_viewer) = app.model.ui.log_viewer {
+        crate::ui::logs::render_log_viewer(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            log_viewer,
+        );
+    }
+
+    // Render pending invitations inbox
+    if let Some(ref inbox) = app.model.ui.pending_inbox {
+        crate::ui::pending::render_pending_inbox(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            inbox,
+        );
+    }
+
+    // Render fleet overview
+    if let Some(ref overview) = app.model.ui.fleet_overview {
+        crate::ui::fleet::render_fleet_overview(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            overview,
+        );
+    }
+
+    // Render profile switcher
+    if let Some(ref switcher) = app.model.ui.profile_switcher {
+        crate::ui::profiles::render_profile_switcher(
+            f,
+            &app.theme,
+            &app.model.ui.keymap,
+            &app.icon_renderer,
+            size,
+            switcher,
+        );
+    }
+
     // Render confirmation dialogs if active
     if let Some(action) = &app.model.ui.confirm_action {
         match action {
             crate::model::ConfirmAction::Revert { changed_files, .. } => {
-                dialogs::render_revert_confirmation(f, changed_files);
+                dialogs::render_revert_confirmation(f, &app.theme, changed_files);
+            }
+            crate::model::ConfirmAction::Override {
+                folder_label,
+                changed_files,
+                total,
+                ..
+            } => {
+                dialogs::render_override_confirmation(
+                    f,
+                    &app.theme,
+                    folder_label,
+                    changed_files,
+                    *total,
+                );
             }
             crate::model::ConfirmAction::Delete { name, is_dir, .. } => {
-                dialogs::render_delete_confirmation(f, name, *is_dir);
+                dialogs::render_delete_confirmation(f, &app.theme, name, *is_dir);
             }
             crate::model::ConfirmAction::IgnoreDelete { name, is_dir, .. } => {
                 // Not implemented - would render ignore+delete confirmation
-                dialogs::render_delete_confirmation(f, name, *is_dir);
+                dialogs::render_delete_confirmation(f, &app.theme, name, *is_dir);
             }
             crate::model::ConfirmAction::PauseResume {
                 label, is_paused, ..
             } => {
-                dialogs::render_pause_resume_confirmation(f, label, *is_paused);
+                dialogs::render_pause_resume_confirmation(f, &app.theme, label, *is_paused);
             }
             crate::model::ConfirmAction::Rescan { folder_label, .. } => {
-                dialogs::render_rescan_confirmation(f, folder_label);
+                dialogs::render_rescan_confirmation(f, &app.theme, folder_label);
+            }
+            crate::model::ConfirmAction::PauseResumeDevice {
+                name, is_paused, ..
+            } => {
+                dialogs::render_device_pause_resume_confirmation(f, &app.theme, name, *is_paused);
+            }
+            crate::model::ConfirmAction::RemoveDevice { device_id, name } => {
+                dialogs::render_remove_device_confirmation(f, &app.theme, name, device_id);
+            }
+            crate::model::ConfirmAction::RestoreVersion { versions, .. } => {
+                dialogs::render_restore_version_confirmation(f, &app.theme, versions);
+            }
+            crate::model::ConfirmAction::ResolveConflict {
+                conflict_path,
+                original_path,
+                resolution,
+                ..
+            } => {
+                dialogs::render_resolve_conflict_confirmation(
+                    f,
+                    &app.theme,
+                    conflict_path,
+                    original_path,
+                    *resolution,
+                );
+            }
+            crate::model::ConfirmAction::SaveIgnorePatterns { diff, .. } => {
+                crate::ui::ignore_editor::render_save_ignores_confirmation(f, &app.theme, diff);
+            }
+            crate::model::ConfirmAction::SaveFolderSettings { changes, .. } => {
+                crate::ui::folder_settings::render_save_folder_settings_confirmation(
+                    f,
+                    &app.theme,
+                    &app.icon_renderer,
+                    changes,
+                );
+            }
+            crate::model::ConfirmAction::Batch {
+                operation, items, ..
+            } => {
+                dialogs::render_batch_confirmation(f, &app.theme, *operation, items);
+            }
+            crate::model::ConfirmAction::RestartSyncthing { device_name } => {
+                crate::ui::system_menu::render_system_control_confirmation(
+                    f,
+                    &app.theme,
+                    device_name,
+                    false,
+                );
+            }
+            crate::model::ConfirmAction::ShutdownSyncthing { device_name } => {
+                crate::ui::system_menu::render_system_control_confirmation(
+                    f,
+                    &app.theme,
+                    device_name,
+                    true,
+                );
             }
         }
     }
@@ -305,14 +611,20 @@ pub fn render(f: &mut Frame, app: &mut App) {
             }
             _ => "Unknown error",
         };
-        dialogs::render_setup_help(f, error_message, &app.model.ui.config_path);
+        dialogs::render_setup_help(f, &app.theme, error_message, &app.model.ui.config_path);
     }
 
     if let Some(pattern_state) = &mut app.model.ui.pattern_selection {
         // Create temporary ListState for rendering
         let mut temp_state = ratatui::widgets::ListState::default();
         temp_state.select(pattern_state.selected_index);
-        dialogs::render_pattern_selection(f, &pattern_state.patterns, &mut temp_state);
+        dialogs::render_pattern_selection(
+            f,
+            &app.theme,
+            &app.icon_renderer,
+            &pattern_state.patterns,
+            &mut temp_state,
+        );
         // Sync back the selection
         pattern_state.selected_index = temp_state.selected();
     }
@@ -323,6 +635,8 @@ pub fn render(f: &mut Frame, app: &mut App) {
         temp_state.select(Some(type_state.selected_index));
         dialogs::render_folder_type_selection(
             f,
+            &app.theme,
+            &app.icon_renderer,
             &type_state.folder_label,
             &type_state.current_type,
             &mut temp_state,
@@ -343,6 +657,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
             .map(|s| s.my_id.as_str());
         dialogs::render_file_info(
             f,
+            &app.theme,
             state,
             &app.model.syncthing.devices,
             my_device_id,
@@ -356,6 +671,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
     if let Some(summary_state) = &app.model.ui.out_of_sync_summary {
         out_of_sync_summary::render_out_of_sync_summary(
             f,
+            &app.theme,
             size,
             &app.model.syncthing.folders,
             summary_state,
@@ -367,6 +683,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
     if let Some(ref modal_state) = app.model.ui.folder_history_modal {
         crate::ui::folder_history::render_folder_history_modal(
             f,
+            &app.theme,
             size,
             modal_state,
             &app.icon_renderer,
@@ -375,6 +692,328 @@ pub fn render(f: &mut Frame, app: &mut App) {
 
     // Render toast notification if active
     if let Some((message, _timestamp)) = &app.model.ui.toast_message {
-        toast::render_toast(f, size, message);
+        toast::render_toast(f, size, &app.theme, message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logic::keymap::Keymap;
+    use crate::model::types::*;
+    use crate::ui::icons::{IconMode, IconRenderer, IconTheme};
+    use crate::ui::theme::Theme;
+    use ratatui::{Frame, Terminal, backend::TestBackend};
+    use std::time::Instant;
+
+    /// Draw a modal in ASCII mode and fail on any non-ASCII text
+    ///
+    /// Borders are box-drawing characters, which even the Linux console font
+    /// has, so they are skipped.
+    fn assert_ascii(name: &str, draw: impl FnOnce(&mut Frame, &Theme, &Keymap, &IconRenderer)) {
+        let theme = Theme::default();
+        let keymap = Keymap::defaults(false);
+        let icons = IconRenderer::new(IconMode::Ascii, IconTheme::default());
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+        terminal.draw(|f| draw(f, &theme, &keymap, &icons)).unwrap();
+
+        let stray: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .flat_map(|cell| cell.symbol().chars())
+            .filter(|c| !c.is_ascii() && !('\u{2500}'..='\u{257F}').contains(c))
+            .collect();
+        assert!(stray.is_empty(), "{}: not ASCII: {:?}", name, stray);
+    }
+
+    fn device(name: &str, connected: bool, paused: bool) -> DeviceInfo {
+        DeviceInfo {
+            device_id: "MFZWI3D-BONSGYC-YLTMRWG-C43ENR5-QXGZDMM-FZWI3DP-BONSGYY-LTMRWAD"
+                .to_string(),
+            device_name: name.to_string(),
+            connected,
+            address: "192.168.1.2:22000".to_string(),
+            download_rate: Some(2048.0),
+            upload_rate: Some(1024.0),
+            shared_folder_count: 1,
+            total_folder_count: 2,
+            addresses: vec!["dynamic".to_string()],
+            compression: "metadata".to_string(),
+            introducer: false,
+            paused,
+            in_bytes_total: 0,
+            out_bytes_total: 0,
+        }
+    }
+
+    #[test]
+    fn test_modals_render_ascii_only_in_ascii_mode() {
+        let devices = vec![
+            device("laptop", true, false),
+            device("phone", false, false),
+            device("nas", false, true),
+        ];
+        let mut devices_modal = DeviceDetailsModal {
+            devices,
+            selected_index: 0,
+            refreshed_at: Instant::now(),
+            editor: None,
+        };
+        assert_ascii("devices", |f, theme, keymap, icons| {
+            super::super::devices::render_devices_modal(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &devices_modal,
+            )
+        });
+        devices_modal.editor = Some(DeviceEditorState::new_device());
+        assert_ascii("device editor", |f, theme, keymap, icons| {
+            super::super::devices::render_devices_modal(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &devices_modal,
+            )
+        });
+
+        let rows = vec![
+            FleetRow {
+                profile: "home".to_string(),
+                status: FleetStatus::Reachable(InstanceSummary::default()),
+            },
+            FleetRow {
+                profile: "work".to_string(),
+                status: FleetStatus::Unreachable("connection refused".to_string()),
+            },
+        ];
+        let overview = FleetOverviewModal {
+            rows,
+            selected_index: 0,
+            active: "home".to_string(),
+            polled_at: Instant::now(),
+        };
+        assert_ascii("fleet overview", |f, theme, keymap, icons| {
+            super::super::fleet::render_fleet_overview(f, theme, keymap, icons, f.area(), &overview)
+        });
+
+        let switcher = ProfileSwitcherModal {
+            profiles: vec![
+                ProfileEntry {
+                    name: "home".to_string(),
+                    base_url: "http://localhost:8384".to_string(),
+                },
+                ProfileEntry {
+                    name: "work".to_string(),
+                    base_url: "http://work:8384".to_string(),
+                },
+            ],
+            selected_index: 1,
+            active: "home".to_string(),
+        };
+        assert_ascii("profile switcher", |f, theme, keymap, icons| {
+            super::super::profiles::render_profile_switcher(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &switcher,
+            )
+        });
+
+        let mut viewer = LogViewerModal {
+            lines: vec![LogLine {
+                when: "2024-01-15T10:30:00Z".to_string(),
+                level: LogLevel::Info,
+                message: "Ready to synchronize".to_string(),
+            }],
+            scroll_offset: 0,
+            follow: true,
+            min_level: LogLevel::Info,
+            search_query: String::new(),
+            search_active: false,
+        };
+        assert_ascii("log viewer", |f, theme, keymap, icons| {
+            super::super::logs::render_log_viewer(f, theme, keymap, icons, f.area(), &mut viewer)
+        });
+
+        let mut editor = IgnoreEditorModal::new(
+            "docs".to_string(),
+            "Documents".to_string(),
+            vec!["*.tmp".to_string()],
+            (0..20).map(|i| format!("file{}.tmp", i)).collect(),
+        );
+        editor.preview_ignored = editor.cached_paths.clone();
+        assert_ascii("ignore editor", |f, theme, _, icons| {
+            super::super::ignore_editor::render_ignore_editor(f, theme, icons, f.area(), &editor)
+        });
+
+        let settings = crate::logic::folder_settings::settings_form(
+            "docs",
+            serde_json::json!({"label": "Documents", "fsWatcherEnabled": true}),
+        );
+        assert_ascii("folder settings", |f, theme, _, icons| {
+            super::super::folder_settings::render_folder_settings(
+                f,
+                theme,
+                icons,
+                f.area(),
+                &settings,
+            )
+        });
+        let changes = vec![SettingChange {
+            setting: "Label",
+            before: "Docs".to_string(),
+            after: "Documents".to_string(),
+        }];
+        assert_ascii("folder settings confirmation", |f, theme, _, icons| {
+            super::super::folder_settings::render_save_folder_settings_confirmation(
+                f, theme, icons, &changes,
+            )
+        });
+
+        let new_folder = NewFolderForm {
+            label: "Photos".to_string(),
+            folder_id: "abcde-12345".to_string(),
+            path: "/data/photos".to_string(),
+            path_edited: false,
+            folder_type: "sendreceive".to_string(),
+            devices: vec![DeviceChoice {
+                device_id: "LAPTOP".to_string(),
+                name: "laptop".to_string(),
+                selected: true,
+            }],
+            device_index: 0,
+            focused_field: NewFolderField::Label,
+        };
+        assert_ascii("new folder", |f, theme, _, icons| {
+            super::super::folder_setup::render_new_folder(f, theme, icons, f.area(), &new_folder)
+        });
+
+        let mut inbox = PendingInboxModal {
+            invitations: vec![PendingInvitation::Folder {
+                folder_id: "music".to_string(),
+                label: "Music".to_string(),
+                device_id: "LAPTOP".to_string(),
+                device_name: "laptop".to_string(),
+                time: "2024-01-15T10:30:00Z".to_string(),
+            }],
+            selected_index: 0,
+            accept_form: None,
+        };
+        assert_ascii("pending inbox", |f, theme, keymap, icons| {
+            super::super::pending::render_pending_inbox(f, theme, keymap, icons, f.area(), &inbox)
+        });
+        inbox.accept_form = Some(AcceptFolderForm {
+            folder_id: "music".to_string(),
+            label: "Music".to_string(),
+            device_id: "LAPTOP".to_string(),
+            path: "/data/music".to_string(),
+            folder_type: "sendreceive".to_string(),
+        });
+        assert_ascii("accept folder form", |f, theme, keymap, icons| {
+            super::super::pending::render_pending_inbox(f, theme, keymap, icons, f.area(), &inbox)
+        });
+
+        let mut versions = FileVersionsModal {
+            folder_id: "docs".to_string(),
+            file_paths: vec!["notes.txt".to_string()],
+            versioning_type: "simple".to_string(),
+            versions_root: "/data/docs/.stversions".to_string(),
+            versions: vec![FileVersionEntry {
+                file_path: "notes.txt".to_string(),
+                version_time: "2024-01-15T10:30:00Z".to_string(),
+                mod_time: "2024-01-15T10:29:00Z".to_string(),
+                size: 1024,
+            }],
+            selected_index: 0,
+            marked: std::collections::HashMap::new(),
+            preview: Ok("hello".to_string()),
+            preview_is_binary: false,
+            scroll_offset: 0,
+        };
+        assert_ascii("file versions", |f, theme, keymap, icons| {
+            super::super::versions::render_file_versions_modal(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &mut versions,
+            )
+        });
+
+        let mut conflicts = ConflictsModal {
+            folder_id: "docs".to_string(),
+            folder_label: "Documents".to_string(),
+            conflicts: vec![ConflictEntry {
+                conflict_path: "notes.sync-conflict-20240115-103000-LAPTOP.txt".to_string(),
+                original_path: "notes.txt".to_string(),
+                timestamp: "20240115-103000".to_string(),
+                device_name: "laptop".to_string(),
+                conflict_size: 10,
+                conflict_modified: "2024-01-15T10:30:00Z".to_string(),
+                original_size: Some(12),
+                original_modified: "2024-01-15T10:29:00Z".to_string(),
+            }],
+            selected_index: 0,
+            original_preview: Ok("original".to_string()),
+            conflict_preview: Ok("conflict".to_string()),
+            scroll_offset: 0,
+        };
+        assert_ascii("conflicts", |f, theme, keymap, icons| {
+            super::super::conflicts::render_conflicts_modal(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &mut conflicts,
+            )
+        });
+
+        let rate_limits = RateLimitModal {
+            rows: vec![RateLimitRow {
+                device_id: None,
+                name: "Global".to_string(),
+                limits: crate::api::RateLimits::default(),
+            }],
+            selected_index: 0,
+            direction: RateLimitDirection::Both,
+            custom_input: None,
+        };
+        assert_ascii("rate limits", |f, theme, keymap, icons| {
+            super::super::rate_limits::render_rate_limits(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &rate_limits,
+            )
+        });
+
+        let menu = SystemMenu {
+            version: None,
+            selected_index: 0,
+        };
+        assert_ascii("system menu", |f, theme, keymap, icons| {
+            super::super::system_menu::render_system_menu(
+                f,
+                theme,
+                keymap,
+                icons,
+                f.area(),
+                &menu,
+                true,
+            )
+        });
     }
 }
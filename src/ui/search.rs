@@ -2,10 +2,11 @@
 //!
 //! Renders the search input box with query, match count, and blinking cursor.
 
+use super::theme::Theme;
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
@@ -15,17 +16,19 @@ use ratatui::{
 /// # Arguments
 /// - `f`: Ratatui frame
 /// - `area`: Rectangular area to render in
+/// - `theme`: Color theme
 /// - `query`: Current search query
 /// - `active`: Whether input is actively receiving keystrokes
 /// - `match_count`: Number of matches found (None if not calculated)
-/// - `vim_mode`: Whether vim keybindings are enabled
+/// - `search_key`: Key that starts a search (None if unbound)
 pub fn render_search_input(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
     query: &str,
     active: bool,
     match_count: Option<usize>,
-    vim_mode: bool,
+    search_key: Option<&str>,
 ) {
     // Build title with match count
     let title = if active {
@@ -41,11 +44,17 @@ pub fn render_search_input(
         }
     } else {
         // No search query - show trigger key
-        let search_key = if vim_mode { "/" } else { "Ctrl-F" };
-        format!(" Search ({}) ", search_key)
+        match search_key {
+            Some(key) => format!(" Search ({}) ", key),
+            None => " Search ".to_string(),
+        }
     };
 
-    let border_color = if active { Color::Cyan } else { Color::Gray };
+    let border_color = if active {
+        theme.border
+    } else {
+        theme.border_inactive
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -54,7 +63,7 @@ pub fn render_search_input(
 
     // Build input line with cursor
     let cursor_style = Style::default()
-        .fg(Color::Yellow)
+        .fg(theme.label)
         .add_modifier(Modifier::SLOW_BLINK);
 
     let input_line = if active {
@@ -66,7 +75,7 @@ pub fn render_search_input(
     } else {
         Line::from(vec![Span::styled(
             format!("Match: {}", query),
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.text_secondary),
         )])
     };
 
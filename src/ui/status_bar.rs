@@ -1,10 +1,11 @@
 use crate::api::{Folder, FolderStatus, SyncState};
 use crate::ui::icons::{FolderState, IconRenderer};
+use crate::ui::theme::Theme;
 use crate::utils;
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
@@ -48,6 +49,7 @@ fn map_sync_state_label(sync_state: SyncState) -> &'static str {
         SyncState::RemoteOnly => "Remote Only",
         SyncState::Ignored => "Ignored",
         SyncState::Syncing => "Syncing",
+        SyncState::Conflict => "Conflict",
         SyncState::Unknown => "Unknown",
     }
 }
@@ -100,6 +102,7 @@ pub fn map_folder_state(
 pub fn build_status_paragraph(
     area: Rect,
     icon_renderer: &IconRenderer,
+    theme: &Theme,
     focus_level: usize,
     folders: &[Folder],
     folder_statuses: &HashMap<String, FolderStatus>,
@@ -167,7 +170,7 @@ pub fn build_status_paragraph(
             if part_trimmed == "Ignored" || part_trimmed == "Ignored, not deleted!" {
                 spans.push(Span::styled(
                     part.to_string(),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(theme.error),
                 ));
             } else if let Some(colon_pos) = part.find(':') {
                 // Split on first colon to separate label from value
@@ -175,7 +178,7 @@ pub fn build_status_paragraph(
                 let value = &part[colon_pos + 1..];
                 spans.push(Span::styled(
                     label.to_string(),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.label),
                 ));
                 spans.push(Span::raw(value.to_string()));
             } else {
@@ -187,7 +190,7 @@ pub fn build_status_paragraph(
 
     Paragraph::new(vec![Line::from(status_spans)])
         .block(Block::default().borders(Borders::ALL).title("Status"))
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(theme.bar_text))
         .wrap(Wrap { trim: false })
 }
 
@@ -346,9 +349,12 @@ fn build_status_line(
                         // Has local additions
                         if status.need_total_items > 0 {
                             // Both local additions and remote needs
+                            let symbols = icon_renderer.symbols();
                             format!(
-                                "↓{} ↑{} ({})",
+                                "{}{} {}{} ({})",
+                                symbols.download,
                                 status.need_total_items,
+                                symbols.upload,
                                 status.receive_only_total_items,
                                 utils::format_bytes(
                                     status.need_bytes + status.receive_only_changed_bytes
@@ -417,10 +423,10 @@ fn build_status_line(
             // Determine filter type based on folder
             let filter_desc = if let Some(fid) = &breadcrumb_folder_id {
                 if let Some(folder) = folders.iter().find(|f| &f.id == fid) {
-                    if folder.folder_type == "receiveonly" {
-                        "Filter: Remote + Local"
-                    } else {
-                        "Filter: Remote"
+                    match folder.folder_type.as_str() {
+                        "receiveonly" => "Filter: Remote + Local",
+                        "sendonly" => "Filter: Remote changes",
+                        _ => "Filter: Remote",
                     }
                 } else {
                     "Filter: Active"
@@ -510,6 +516,7 @@ pub fn render_status_bar(
     f: &mut Frame,
     area: Rect,
     icon_renderer: &IconRenderer,
+    theme: &Theme,
     focus_level: usize,
     folders: &[Folder],
     folder_statuses: &HashMap<String, FolderStatus>,
@@ -531,6 +538,7 @@ pub fn render_status_bar(
     let status_bar = build_status_paragraph(
         area,
         icon_renderer,
+        theme,
         focus_level,
         folders,
         folder_statuses,
@@ -607,7 +615,7 @@ pub fn calculate_status_height(
         )
     };
 
-    // Parse status_line and color the labels (same as in build_status_paragraph)
+    // Split into the same spans as build_status_paragraph (colors don't affect wrapping)
     let status_spans: Vec<Span> = if status_line.is_empty() {
         vec![Span::raw("")]
     } else {
@@ -621,17 +629,11 @@ pub fn calculate_status_height(
 
             let part_trimmed = part.trim();
             if part_trimmed == "Ignored" || part_trimmed == "Ignored, not deleted!" {
-                spans.push(Span::styled(
-                    part.to_string(),
-                    Style::default().fg(Color::Red),
-                ));
+                spans.push(Span::raw(part.to_string()));
             } else if let Some(colon_pos) = part.find(':') {
                 let label = &part[..=colon_pos];
                 let value = &part[colon_pos + 1..];
-                spans.push(Span::styled(
-                    label.to_string(),
-                    Style::default().fg(Color::Yellow),
-                ));
+                spans.push(Span::raw(label.to_string()));
                 spans.push(Span::raw(value.to_string()));
             } else {
                 spans.push(Span::raw(part.to_string()));
@@ -1,9 +1,12 @@
 use crate::api::SystemStatus;
+use crate::logic::keymap::{Action, KeyContext, Keymap};
 use crate::model::syncthing::ConnectionState;
+use crate::ui::icons::{ConnectionIcon, IconRenderer};
+use crate::ui::theme::Theme;
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
@@ -20,30 +23,40 @@ fn format_human_size(size: u64) -> String {
 
 /// Format transfer rate (bytes/sec) into human-readable string
 fn format_transfer_rate(bytes_per_sec: f64) -> String {
-    const KB: f64 = 1024.0;
-    const MB: f64 = KB * 1024.0;
-    const GB: f64 = MB * 1024.0;
-
-    if bytes_per_sec < KB {
-        format!("{:.0}B/s", bytes_per_sec)
-    } else if bytes_per_sec < MB {
-        format!("{:.1}K/s", bytes_per_sec / KB)
-    } else if bytes_per_sec < GB {
-        format!("{:.1}M/s", bytes_per_sec / MB)
-    } else {
-        format!("{:.2}G/s", bytes_per_sec / GB)
-    }
+    crate::logic::formatting::format_transfer_rate(bytes_per_sec)
 }
 
 /// Render connection status span based on connection state
-fn render_connection_status(state: &ConnectionState) -> Vec<Span<'_>> {
+fn render_connection_status<'a>(
+    state: &'a ConnectionState,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+) -> Vec<Span<'a>> {
+    let connected = icon_renderer.render_connection(ConnectionIcon::Connected);
+    let connecting = icon_renderer.render_connection(ConnectionIcon::Connecting);
+    let disconnected = icon_renderer.render_connection(ConnectionIcon::Disconnected);
     match state {
         ConnectionState::Connected => {
             vec![
-                Span::styled("🟢 Connected", Style::default().fg(Color::Green)),
+                Span::styled(
+                    format!("{} Connected", connected),
+                    Style::default().fg(theme.success),
+                ),
                 Span::raw(" | "),
             ]
         }
+        ConnectionState::Connecting {
+            restart_requested: Some(_),
+            ..
+        } => {
+            vec![
+                Span::styled(
+                    format!("{} Restarting... ", connecting),
+                    Style::default().fg(theme.warning),
+                ),
+                Span::raw("| "),
+            ]
+        }
         ConnectionState::Connecting {
             attempt,
             next_retry_secs,
@@ -51,37 +64,113 @@ fn render_connection_status(state: &ConnectionState) -> Vec<Span<'_>> {
         } => {
             let text = if *attempt > 1 {
                 format!(
-                    "🟡 Connecting (attempt {}, next: {}s) ",
-                    attempt, next_retry_secs
+                    "{} Connecting (attempt {}, next: {}s) ",
+                    connecting, attempt, next_retry_secs
                 )
             } else {
-                "🟡 Connecting... ".to_string()
+                format!("{} Connecting... ", connecting)
             };
             vec![
-                Span::styled(text, Style::default().fg(Color::Yellow)),
+                Span::styled(text, Style::default().fg(theme.warning)),
                 Span::raw("| "),
             ]
         }
         ConnectionState::Disconnected { message, .. } => {
             // Show raw error message for tech-savvy audience
             vec![
-                Span::styled(format!("🔴 {} ", message), Style::default().fg(Color::Red)),
+                Span::styled(
+                    format!("{} {} ", disconnected, message),
+                    Style::default().fg(theme.error),
+                ),
                 Span::raw("| "),
             ]
         }
     }
 }
 
+/// Build the error badge shown while there are unseen Syncthing errors
+fn error_badge(
+    new_errors: usize,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    keymap: &Keymap,
+) -> Option<Line<'static>> {
+    if new_errors == 0 {
+        return None;
+    }
+
+    let label = if new_errors == 1 { "error" } else { "errors" };
+    let hint = keymap
+        .key_label(KeyContext::FolderList, Action::Logs)
+        .map(|key| format!(" ({}: Logs)", key))
+        .unwrap_or_default();
+    Some(Line::from(Span::styled(
+        format!(
+            " {} {} new {}{} ",
+            icon_renderer.render_warning(),
+            new_errors,
+            label,
+            hint
+        ),
+        Style::default()
+            .fg(theme.banner_fg)
+            .bg(theme.banner_bg)
+            .add_modifier(Modifier::BOLD),
+    )))
+}
+
+/// Build the banner shown while saved config changes wait for a restart
+///
+/// The key hint follows the keymap and is left out when the system menu is
+/// unbound.
+fn restart_badge(
+    restart_required: bool,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    keymap: &Keymap,
+) -> Option<Line<'static>> {
+    if !restart_required {
+        return None;
+    }
+
+    let hint = keymap
+        .key_label(KeyContext::FolderList, Action::System)
+        .map(|key| format!(" ({}: System)", key))
+        .unwrap_or_default();
+    Some(Line::from(Span::styled(
+        format!(
+            " {} Restart required{} ",
+            icon_renderer.render_restart(),
+            hint
+        ),
+        Style::default()
+            .fg(theme.banner_fg)
+            .bg(theme.banner_bg)
+            .add_modifier(Modifier::BOLD),
+    )))
+}
+
 /// Render the system info bar at the top of the screen
+#[allow(clippy::too_many_arguments)]
 pub fn render_system_bar(
     f: &mut Frame,
     area: Rect,
+    theme: &Theme,
+    icon_renderer: &IconRenderer,
+    keymap: &Keymap,
     connection_state: &ConnectionState,
     system_status: Option<&SystemStatus>,
     device_name: Option<&str>,
     local_state_summary: (u64, u64, u64), // (files, dirs, bytes)
     last_transfer_rates: Option<(f64, f64)>, // (download, upload) in bytes/sec
+    new_errors: usize,                    // Errors not yet seen in the log viewer
+    active_profile: Option<&str>,         // Only set when several instances are configured
+    rate_limit: Option<&str>,             // Shown while a bandwidth limit is active
+    restart_required: bool,               // Config changes wait for a restart
 ) {
+    let connected = icon_renderer.render_connection(ConnectionIcon::Connected);
+    let connecting = icon_renderer.render_connection(ConnectionIcon::Connecting);
+    let disconnected = icon_renderer.render_connection(ConnectionIcon::Disconnected);
     let system_line = if let (true, Some(sys_status)) = (
         matches!(connection_state, ConnectionState::Connected),
         system_status,
@@ -90,16 +179,16 @@ pub fn render_system_bar(
         let uptime_str = format_uptime(sys_status.uptime);
         let (total_files, total_dirs, total_bytes) = local_state_summary;
 
-        let mut spans = render_connection_status(connection_state);
+        let mut spans = render_connection_status(connection_state, theme, icon_renderer);
         spans.push(Span::raw(device_name.unwrap_or("Unknown")));
         spans.push(Span::raw(" | "));
-        spans.push(Span::styled("Up:", Style::default().fg(Color::Yellow)));
+        spans.push(Span::styled("Up:", Style::default().fg(theme.label)));
         spans.push(Span::raw(format!(" {}", uptime_str)));
 
         // Add local state (use trimmed size to avoid padding)
         let size_str = format_human_size(total_bytes).trim().to_string();
         spans.push(Span::raw(" | "));
-        spans.push(Span::styled("Local:", Style::default().fg(Color::Yellow)));
+        spans.push(Span::styled("Local:", Style::default().fg(theme.label)));
         spans.push(Span::raw(format!(
             " {} files, {} dirs, {}",
             total_files, total_dirs, size_str
@@ -107,14 +196,29 @@ pub fn render_system_bar(
 
         // Add rates if available (display pre-calculated rates)
         if let Some((in_rate, out_rate)) = last_transfer_rates {
+            let symbols = icon_renderer.symbols();
             spans.push(Span::raw(" | "));
-            spans.push(Span::styled("↓", Style::default().fg(Color::Yellow)));
+            spans.push(Span::styled(
+                symbols.download,
+                Style::default().fg(theme.label),
+            ));
             spans.push(Span::raw(format_transfer_rate(in_rate)));
             spans.push(Span::raw(" "));
-            spans.push(Span::styled("↑", Style::default().fg(Color::Yellow)));
+            spans.push(Span::styled(
+                symbols.upload,
+                Style::default().fg(theme.label),
+            ));
             spans.push(Span::raw(format_transfer_rate(out_rate)));
         }
 
+        if let Some(rate_limit) = rate_limit {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                rate_limit.to_string(),
+                Style::default().fg(theme.warning),
+            ));
+        }
+
         Line::from(spans)
     } else {
         // No system status yet - show connection state and error
@@ -122,34 +226,45 @@ pub fn render_system_bar(
             ConnectionState::Disconnected { message, .. } => {
                 // Show error message (no device name since we're not connected)
                 vec![Span::styled(
-                    format!("🔴 {}", message),
-                    Style::default().fg(Color::Red),
+                    format!("{} {}", disconnected, message),
+                    Style::default().fg(theme.error),
+                )]
+            }
+            ConnectionState::Connecting {
+                restart_requested: Some(_),
+                ..
+            } => {
+                // Errors are expected while Syncthing is down for the restart
+                vec![Span::styled(
+                    format!("{} Restarting Syncthing...", connecting),
+                    Style::default().fg(theme.warning),
                 )]
             }
             ConnectionState::Connecting {
                 attempt,
                 last_error,
                 next_retry_secs,
+                ..
             } => {
                 let mut spans = vec![];
 
                 // Show connecting status
                 let text = if *attempt > 1 {
                     format!(
-                        "🟡 Connecting (attempt {}, next: {}s)",
-                        attempt, next_retry_secs
+                        "{} Connecting (attempt {}, next: {}s)",
+                        connecting, attempt, next_retry_secs
                     )
                 } else {
-                    "🟡 Connecting...".to_string()
+                    format!("{} Connecting...", connecting)
                 };
-                spans.push(Span::styled(text, Style::default().fg(Color::Yellow)));
+                spans.push(Span::styled(text, Style::default().fg(theme.warning)));
 
                 // Show last error if available
                 if let Some(err) = last_error {
                     spans.push(Span::raw(" | "));
                     spans.push(Span::styled(
                         err.clone(),
-                        Style::default().fg(Color::Yellow),
+                        Style::default().fg(theme.warning),
                     ));
                 }
 
@@ -158,8 +273,8 @@ pub fn render_system_bar(
             ConnectionState::Connected => {
                 // Connected but no system status - still loading
                 let mut spans = vec![Span::styled(
-                    "🟢 Connected",
-                    Style::default().fg(Color::Green),
+                    format!("{} Connected", connected),
+                    Style::default().fg(theme.success),
                 )];
 
                 if let Some(name) = device_name {
@@ -175,9 +290,21 @@ pub fn render_system_bar(
         Line::from(spans)
     };
 
+    let title = match active_profile {
+        Some(profile) => format!("System [{}]", profile),
+        None => "System".to_string(),
+    };
+    let mut block = Block::default().borders(Borders::ALL).title(title);
+    if let Some(badge) = error_badge(new_errors, theme, icon_renderer, keymap) {
+        block = block.title(badge.right_aligned());
+    }
+    if let Some(badge) = restart_badge(restart_required, theme, icon_renderer, keymap) {
+        block = block.title(badge.right_aligned());
+    }
+
     let system_widget = Paragraph::new(system_line)
-        .block(Block::default().borders(Borders::ALL).title("System"))
-        .style(Style::default().fg(Color::Gray));
+        .block(block)
+        .style(Style::default().fg(theme.bar_text));
 
     f.render_widget(system_widget, area);
 }
@@ -0,0 +1,178 @@
+//! System menu rendering
+//!
+//! Shows the Syncthing version, OS and architecture, the restart and
+//! shutdown entries, and their confirmation dialogs.
+
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::{SystemMenu, SystemMenuItem};
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the system menu
+pub fn render_system_menu(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    menu: &SystemMenu,
+    restart_required: bool,
+) {
+    let mut info_lines = match &menu.version {
+        Some(version) => {
+            let mut version_spans = vec![
+                Span::styled("Version:  ", Style::default().fg(theme.label)),
+                Span::styled(
+                    version.version.clone(),
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+                ),
+            ];
+            if !version.codename.is_empty() {
+                version_spans.push(Span::styled(
+                    format!(" \"{}\"", version.codename),
+                    Style::default().fg(theme.text_secondary),
+                ));
+            }
+            vec![
+                Line::from(version_spans),
+                Line::from(vec![
+                    Span::styled("Platform: ", Style::default().fg(theme.label)),
+                    Span::raw(format!("{}/{}", version.os, version.arch)),
+                ]),
+            ]
+        }
+        None => vec![Line::from(Span::styled(
+            "Version information unavailable",
+            Style::default().fg(theme.text_muted),
+        ))],
+    };
+    if restart_required {
+        info_lines.push(Line::from(Span::styled(
+            "Config changes are waiting for a restart",
+            Style::default().fg(theme.warning),
+        )));
+    }
+
+    let info_height = info_lines.len() as u16 + 1;
+    let modal_width = 52.min(area.width);
+    let modal_height = (info_height + SystemMenuItem::ALL.len() as u16 + 3).min(area.height);
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width,
+        height: modal_height,
+    };
+
+    let info = Paragraph::new(info_lines).block(
+        Block::default()
+            .title(" System ")
+            .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+            .border_style(Style::default().fg(theme.border)),
+    );
+
+    let items: Vec<ListItem> = SystemMenuItem::ALL
+        .iter()
+        .map(|item| {
+            let key = keymap.modal_label(match item {
+                SystemMenuItem::Restart => Action::Restart,
+                SystemMenuItem::Shutdown => Action::Shutdown,
+            });
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", key), Style::default().fg(theme.label)),
+                Span::styled(item.label(), Style::default().fg(theme.text)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT)
+                .title_bottom(format!(
+                    " {}:Select  {}:Close ",
+                    keymap.modal_label(Action::Select),
+                    keymap.modal_label(Action::Close)
+                ))
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(info_height), Constraint::Min(1)])
+        .split(modal_area);
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(menu.selected_index));
+
+    f.render_widget(Clear, modal_area);
+    f.render_widget(info, chunks[0]);
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+/// Render the restart/shutdown confirmation dialog
+pub fn render_system_control_confirmation(
+    f: &mut Frame,
+    theme: &Theme,
+    device_name: &str,
+    shutdown: bool,
+) {
+    let (title, prompt_text) = if shutdown {
+        (
+            "Confirm Shutdown",
+            format!(
+                "Shut down Syncthing on {}?\n\n\
+                Syncing stops until Syncthing is started again\n\
+                outside stui.\n\n\
+                Continue? (y/n)",
+                device_name
+            ),
+        )
+    } else {
+        (
+            "Confirm Restart",
+            format!(
+                "Restart Syncthing on {}?\n\n\
+                Transfers are interrupted while it restarts;\n\
+                stui reconnects when it is back.\n\n\
+                Continue? (y/n)",
+                device_name
+            ),
+        )
+    };
+
+    let area = f.area();
+    let width = 54.min(area.width);
+    let height = 9.min(area.height);
+    let prompt_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    let prompt = Paragraph::new(prompt_text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme.dialog_danger)),
+        )
+        .style(theme.dialog())
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, prompt_area);
+    f.render_widget(prompt, prompt_area);
+}
@@ -0,0 +1,432 @@
+//! Color themes
+//!
+//! Every color the UI draws with, by meaning rather than by hue. Built-in
+//! themes are `dark` (the original colors), `light` and `high-contrast`; user
+//! themes are YAML files that start from a built-in one and override colors:
+//!
+//! ```yaml
+//! base: light
+//! colors:
+//!   label: "#875f00"
+//!   selection_bg: "254"
+//! ```
+//!
+//! Colors are names (`yellow`, `light blue`, `dark gray`), 256-color indexes
+//! (`"214"`) or hex (`"#rrggbb"`).
+
+use super::icons::IconTheme;
+use anyhow::{Context, Result, anyhow, bail};
+use ratatui::style::{Color, Style};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+macro_rules! theme_colors {
+    ($($(#[$doc:meta])* $field:ident),* $(,)?) => {
+        /// Semantic UI colors
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Theme {
+            $($(#[$doc])* pub $field: Color,)*
+        }
+
+        impl Theme {
+            /// Color of a theme file entry
+            fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+                match name {
+                    $(stringify!($field) => Some(&mut self.$field),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+theme_colors! {
+    // Text
+    /// Primary text (names, paths, values)
+    text,
+    /// Secondary text (legend, hints, empty-list messages)
+    text_secondary,
+    /// De-emphasized text (dates, sizes, descriptions)
+    text_muted,
+    /// Field labels, hotkeys and column headers
+    label,
+    /// Secondary labels (image details, config path, folder rows)
+    info,
+    /// Odd-one-out highlights (device rows, binary file warning)
+    accent,
+
+    // Panes and lists
+    /// Focused pane and modal borders
+    border,
+    /// Border of breadcrumb panes above the focused one
+    border_parent,
+    /// Border of unfocused panes and inactive inputs
+    border_inactive,
+    /// Selected folder text
+    selection_fg,
+    /// Selected row background
+    selection_bg,
+
+    // Status
+    success,
+    warning,
+    error,
+
+    // Dialogs
+    dialog_fg,
+    dialog_bg,
+    /// Border of confirmation prompts and editors
+    dialog_border,
+    /// Border of destructive confirmations
+    dialog_danger,
+
+    // Bars, toasts and search
+    /// Text of the status bar, system bar and legend
+    bar_text,
+    /// Disconnected banner in the system bar
+    banner_fg,
+    banner_bg,
+    toast_success,
+    toast_error,
+    /// Search matches in the log viewer
+    match_fg,
+    match_bg,
+
+    // Icons, sync states and folder cards
+    sync_folder,
+    folder,
+    file,
+    synced,
+    out_of_sync,
+    local_only,
+    remote_only,
+    ignored,
+    syncing,
+    conflict,
+    scanning,
+    unknown,
+    paused,
+}
+
+/// Muted gray of the original design
+const DIM: Color = Color::Rgb(120, 120, 120);
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Names of the built-in themes
+    pub const BUILTIN: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    /// Colors for dark terminals (the original look)
+    pub fn dark() -> Self {
+        Self {
+            text: Color::White,
+            text_secondary: Color::Gray,
+            text_muted: DIM,
+            label: Color::Yellow,
+            info: Color::Cyan,
+            accent: Color::Magenta,
+            border: Color::Cyan,
+            border_parent: Color::Blue,
+            border_inactive: Color::Gray,
+            selection_fg: Color::Cyan,
+            selection_bg: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            dialog_fg: Color::White,
+            dialog_bg: Color::Black,
+            dialog_border: Color::Yellow,
+            dialog_danger: Color::Red,
+            bar_text: Color::Gray,
+            banner_fg: Color::White,
+            banner_bg: Color::Red,
+            toast_success: Color::Green,
+            toast_error: Color::Red,
+            match_fg: Color::Black,
+            match_bg: Color::Yellow,
+            sync_folder: Color::Magenta,
+            folder: Color::Blue,
+            file: Color::Cyan,
+            synced: Color::Green,
+            out_of_sync: Color::Yellow,
+            local_only: Color::Gray,
+            remote_only: Color::White,
+            ignored: Color::Red,
+            syncing: Color::Yellow,
+            conflict: Color::LightRed,
+            scanning: Color::Magenta,
+            unknown: Color::Gray,
+            paused: Color::Gray,
+        }
+    }
+
+    /// Colors for light terminals: no yellow or cyan text
+    pub fn light() -> Self {
+        let dark_yellow = Color::Rgb(135, 95, 0);
+        let dark_green = Color::Rgb(0, 120, 0);
+        let dark_red = Color::Rgb(175, 0, 0);
+        let dark_gray = Color::Rgb(88, 88, 88);
+        Self {
+            text: Color::Black,
+            text_secondary: dark_gray,
+            text_muted: Color::Rgb(118, 118, 118),
+            label: Color::Rgb(0, 70, 160),
+            info: Color::Rgb(0, 110, 130),
+            accent: Color::Rgb(135, 0, 135),
+            border: Color::Rgb(0, 70, 160),
+            border_parent: Color::Rgb(135, 0, 135),
+            border_inactive: Color::Rgb(160, 160, 160),
+            selection_fg: Color::Rgb(0, 70, 160),
+            selection_bg: Color::Rgb(215, 215, 215),
+            success: dark_green,
+            warning: dark_yellow,
+            error: dark_red,
+            dialog_fg: Color::Black,
+            dialog_bg: Color::Rgb(238, 238, 238),
+            dialog_border: dark_yellow,
+            dialog_danger: dark_red,
+            bar_text: dark_gray,
+            banner_fg: Color::White,
+            banner_bg: dark_red,
+            toast_success: dark_green,
+            toast_error: dark_red,
+            match_fg: Color::White,
+            match_bg: Color::Rgb(0, 70, 160),
+            sync_folder: Color::Rgb(135, 0, 135),
+            folder: Color::Rgb(0, 70, 160),
+            file: Color::Rgb(0, 110, 130),
+            synced: dark_green,
+            out_of_sync: dark_yellow,
+            local_only: dark_gray,
+            remote_only: Color::Black,
+            ignored: dark_red,
+            syncing: dark_yellow,
+            conflict: Color::Rgb(215, 0, 95),
+            scanning: Color::Rgb(135, 0, 135),
+            unknown: dark_gray,
+            paused: dark_gray,
+        }
+    }
+
+    /// Bright colors only, nothing dimmed
+    pub fn high_contrast() -> Self {
+        Self {
+            text: Color::White,
+            text_secondary: Color::White,
+            text_muted: Color::Gray,
+            label: Color::LightYellow,
+            info: Color::LightCyan,
+            accent: Color::LightMagenta,
+            border: Color::LightCyan,
+            border_parent: Color::LightMagenta,
+            border_inactive: Color::White,
+            selection_fg: Color::LightYellow,
+            selection_bg: Color::Blue,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            dialog_fg: Color::White,
+            dialog_bg: Color::Black,
+            dialog_border: Color::LightYellow,
+            dialog_danger: Color::LightRed,
+            bar_text: Color::White,
+            banner_fg: Color::White,
+            banner_bg: Color::Red,
+            toast_success: Color::LightGreen,
+            toast_error: Color::LightRed,
+            match_fg: Color::Black,
+            match_bg: Color::LightYellow,
+            sync_folder: Color::LightMagenta,
+            folder: Color::LightBlue,
+            file: Color::LightCyan,
+            synced: Color::LightGreen,
+            out_of_sync: Color::LightYellow,
+            local_only: Color::White,
+            remote_only: Color::White,
+            ignored: Color::LightRed,
+            syncing: Color::LightYellow,
+            conflict: Color::LightRed,
+            scanning: Color::LightMagenta,
+            unknown: Color::White,
+            paused: Color::Gray,
+        }
+    }
+
+    /// Look up a built-in theme by name
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Parse a theme file (a built-in `base` plus color overrides)
+    pub fn from_yaml(text: &str) -> Result<Self> {
+        let file: ThemeFile = serde_yaml::from_str(text)?;
+
+        let base = file.base.as_deref().unwrap_or("dark");
+        let mut theme = Self::builtin(base).ok_or_else(|| {
+            anyhow!(
+                "unknown base theme '{}' (available: {})",
+                base,
+                Self::BUILTIN.join(", ")
+            )
+        })?;
+
+        for (name, value) in &file.colors {
+            let Some(slot) = theme.color_mut(name) else {
+                bail!("colors.{}: unknown color name", name);
+            };
+            *slot = Color::from_str(value)
+                .map_err(|_| anyhow!("colors.{}: invalid color '{}'", name, value))?;
+        }
+
+        Ok(theme)
+    }
+
+    /// Load a theme by built-in name or from a YAML file
+    pub fn load(name_or_path: &str, base_dir: Option<&std::path::Path>) -> Result<Self> {
+        if let Some(theme) = Self::builtin(name_or_path) {
+            return Ok(theme);
+        }
+
+        // Relative paths are relative to the config file
+        let path = std::path::Path::new(name_or_path);
+        let path = match base_dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf(),
+        };
+        let text = std::fs::read_to_string(&path).with_context(|| {
+            format!(
+                "Theme '{}' is not built in ({}) and could not be read from {}",
+                name_or_path,
+                Self::BUILTIN.join(", "),
+                path.display()
+            )
+        })?;
+        Self::from_yaml(&text).with_context(|| format!("Invalid theme file {}", path.display()))
+    }
+
+    /// Icon colors for this theme
+    pub fn icon_theme(&self) -> IconTheme {
+        IconTheme {
+            sync_folder_color: self.sync_folder,
+            folder_color: self.folder,
+            file_color: self.file,
+            synced_color: self.synced,
+            out_of_sync_color: self.out_of_sync,
+            local_only_color: self.local_only,
+            remote_only_color: self.remote_only,
+            ignored_color: self.ignored,
+            syncing_color: self.syncing,
+            conflict_color: self.conflict,
+            scanning_color: self.scanning,
+            unknown_color: self.unknown,
+            error_color: self.error,
+            paused_color: self.paused,
+        }
+    }
+
+    /// Dialog body (text on the dialog background)
+    pub fn dialog(&self) -> Style {
+        Style::default().fg(self.dialog_fg).bg(self.dialog_bg)
+    }
+
+    /// Selected row in lists
+    pub fn selection(&self) -> Style {
+        Style::default().bg(self.selection_bg)
+    }
+}
+
+/// Theme file contents
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Built-in theme to start from (default: dark)
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dark_theme_matches_icon_defaults() {
+        let icons = Theme::dark().icon_theme();
+        let defaults = IconTheme::default();
+        assert_eq!(icons.sync_folder_color, defaults.sync_folder_color);
+        assert_eq!(icons.out_of_sync_color, defaults.out_of_sync_color);
+        assert_eq!(icons.conflict_color, defaults.conflict_color);
+        assert_eq!(icons.paused_color, defaults.paused_color);
+    }
+
+    #[test]
+    fn test_light_theme_avoids_yellow_and_cyan() {
+        let light = Theme::light();
+        for color in [
+            light.text,
+            light.label,
+            light.info,
+            light.border,
+            light.warning,
+            light.file,
+        ] {
+            assert!(
+                !matches!(
+                    color,
+                    Color::Yellow | Color::LightYellow | Color::Cyan | Color::LightCyan
+                ),
+                "{:?} is unreadable on light backgrounds",
+                color
+            );
+        }
+    }
+
+    #[test]
+    fn test_builtin_names() {
+        for name in Theme::BUILTIN {
+            assert!(Theme::builtin(name).is_some(), "{}", name);
+        }
+        assert!(Theme::builtin("solarized").is_none());
+    }
+
+    #[test]
+    fn test_theme_file_overrides_base() {
+        let theme = Theme::from_yaml(
+            "base: light\ncolors:\n  label: \"#112233\"\n  error: light red\n  selection_bg: \"254\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(theme.label, Color::Rgb(0x11, 0x22, 0x33));
+        assert_eq!(theme.error, Color::LightRed);
+        assert_eq!(theme.selection_bg, Color::Indexed(254));
+        assert_eq!(theme.text, Theme::light().text, "rest comes from the base");
+
+        let theme = Theme::from_yaml("colors:\n  border: blue\n").unwrap();
+        assert_eq!(theme.border, Color::Blue);
+        assert_eq!(theme.text, Theme::dark().text, "dark is the default base");
+    }
+
+    #[test]
+    fn test_theme_file_errors() {
+        let err = Theme::from_yaml("colors:\n  lable: red\n").unwrap_err();
+        assert_eq!(err.to_string(), "colors.lable: unknown color name");
+
+        let err = Theme::from_yaml("colors:\n  label: blurple\n").unwrap_err();
+        assert_eq!(err.to_string(), "colors.label: invalid color 'blurple'");
+
+        assert!(Theme::from_yaml("base: solarized\n").is_err());
+        assert!(Theme::from_yaml("colours: {}\n").is_err());
+    }
+}
@@ -1,13 +1,14 @@
+use super::theme::Theme;
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
 /// Render a toast notification (brief pop-up message)
-pub fn render_toast(f: &mut Frame, area: Rect, message: &str) {
+pub fn render_toast(f: &mut Frame, area: Rect, theme: &Theme, message: &str) {
     // Calculate toast dimensions - allow for longer messages
     let max_width = (area.width as usize).min(80); // Max 80 chars wide
     let toast_width = (message.len() + 6).min(max_width) as u16;
@@ -29,9 +30,9 @@ pub fn render_toast(f: &mut Frame, area: Rect, message: &str) {
     // Detect error messages and use different styling
     let is_error = message.starts_with("Error:");
     let (icon, icon_color, border_color) = if is_error {
-        ("✗ ", Color::Red, Color::Red) // Red theme for errors
+        ("✗ ", theme.toast_error, theme.toast_error)
     } else {
-        ("✓ ", Color::Green, Color::Green) // Green theme for success
+        ("✓ ", theme.toast_success, theme.toast_success)
     };
 
     // Create styled toast with icon
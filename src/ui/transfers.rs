@@ -0,0 +1,198 @@
+//! Transfer queue modal rendering
+//!
+//! Lists a folder's needed files in download order. Files being pulled get a
+//! progress bar split into reused, copied and pulled blocks, with rate and
+//! ETA; the selected file's byte breakdown is shown below the list.
+
+use crate::logic::formatting::{format_human_size, format_transfer_rate};
+use crate::logic::keymap::{Action, Keymap};
+use crate::logic::transfers::{
+    FileTransfer, TransferSection, bar_segments, breakdown, eta_secs, format_eta, percent_done,
+};
+use crate::model::types::TransferQueueModal;
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use crate::utils::format_bytes;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+use std::collections::HashMap;
+
+/// Width of the per-file progress bar in cells
+const BAR_WIDTH: usize = 20;
+
+/// Render the transfer queue modal
+pub fn render_transfer_queue(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &TransferQueueModal,
+    transfers: Option<&HashMap<String, FileTransfer>>,
+) {
+    // Calculate centered modal dimensions (80% width, 70% height)
+    let modal_width = (area.width as f32 * 0.8) as u16;
+    let modal_height = (area.height as f32 * 0.7) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(4), Constraint::Length(4)])
+        .split(modal_area);
+
+    let dim = Style::default().fg(theme.text_muted);
+    let items: Vec<ListItem> = modal_state
+        .entries
+        .iter()
+        .map(|entry| {
+            let section_color = match entry.section {
+                TransferSection::InProgress => theme.syncing,
+                TransferSection::Queued => theme.label,
+                TransferSection::Rest => theme.text_muted,
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<12}", entry.section.label()),
+                    Style::default().fg(section_color),
+                ),
+                Span::styled(format_human_size(entry.size), dim),
+                Span::raw("  "),
+            ];
+
+            if let Some(transfer) = transfers.and_then(|t| t.get(&entry.path)) {
+                spans.extend(progress_spans(theme, transfer));
+                spans.push(Span::raw("  "));
+            }
+
+            let name = if entry.deleted {
+                format!("{} (delete)", entry.path)
+            } else {
+                entry.path.clone()
+            };
+            spans.push(Span::styled(name, Style::default().fg(theme.text)));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Transfers: {} ({} of {} needed) ",
+                    modal_state.folder_label,
+                    modal_state.entries.len(),
+                    modal_state.need_total
+                ))
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  {} ",
+                    keymap.modal_nav_label(),
+                    keymap.modal_hints(&[
+                        (&[Action::Prioritize], "Download first"),
+                        (&[Action::Close], "Close"),
+                    ])
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.entries.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+    f.render_stateful_widget(list, rows[0], &mut list_state);
+
+    // Byte breakdown of the selected file
+    let label = Style::default().fg(theme.label);
+    let detail = match modal_state.selected_entry() {
+        Some(entry) => {
+            let progress = match transfers.and_then(|t| t.get(&entry.path)) {
+                Some(transfer) => {
+                    let parts = breakdown(&transfer.progress);
+                    Line::from(vec![
+                        Span::styled("Pulled: ", label),
+                        Span::raw(format_bytes(parts.pulled)),
+                        Span::styled("  Copied: ", label),
+                        Span::raw(format_bytes(parts.copied)),
+                        Span::styled("  Reused: ", label),
+                        Span::raw(format_bytes(parts.reused)),
+                        Span::styled("  Done: ", label),
+                        Span::raw(format!(
+                            "{} of {}",
+                            format_bytes(transfer.progress.bytes_done),
+                            format_bytes(transfer.progress.bytes_total)
+                        )),
+                    ])
+                }
+                None => Line::from(Span::styled(
+                    format!("{} - {}", entry.section.label(), format_bytes(entry.size)),
+                    dim,
+                )),
+            };
+            vec![
+                Line::from(Span::styled(
+                    entry.path.clone(),
+                    Style::default().fg(theme.text),
+                )),
+                progress,
+            ]
+        }
+        None => vec![Line::from(Span::styled("Nothing to download", dim))],
+    };
+
+    let detail = Paragraph::new(detail).block(
+        Block::default()
+            .title(" Progress ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border)),
+    );
+    f.render_widget(detail, rows[1]);
+}
+
+/// Progress bar, percentage, rate and ETA of a file being pulled
+fn progress_spans(theme: &Theme, transfer: &FileTransfer) -> Vec<Span<'static>> {
+    let (reused, copied, pulled) = bar_segments(&transfer.progress, BAR_WIDTH);
+    let remaining = BAR_WIDTH - reused - copied - pulled;
+
+    let mut spans = vec![
+        Span::raw("["),
+        Span::styled("=".repeat(reused), Style::default().fg(theme.synced)),
+        Span::styled("+".repeat(copied), Style::default().fg(theme.info)),
+        Span::styled("#".repeat(pulled), Style::default().fg(theme.syncing)),
+        Span::styled("-".repeat(remaining), Style::default().fg(theme.text_muted)),
+        Span::raw("] "),
+        Span::raw(format!("{:>3}%", percent_done(&transfer.progress))),
+    ];
+
+    if let Some(rate) = transfer.rate {
+        spans.push(Span::styled(
+            format!("  {}", format_transfer_rate(rate)),
+            Style::default().fg(theme.text_secondary),
+        ));
+    }
+    if let Some(eta) = eta_secs(transfer) {
+        spans.push(Span::styled(
+            format!("  ETA {}", format_eta(eta)),
+            Style::default().fg(theme.text_secondary),
+        ));
+    }
+    spans
+}
@@ -0,0 +1,161 @@
+//! File versions modal rendering
+//!
+//! Lists archived versions of one or more files (newest first per file) next
+//! to a preview of the selected version's content. Versions marked for
+//! restore are underlined.
+
+use crate::logic::formatting::format_datetime;
+use crate::logic::keymap::{Action, Keymap};
+use crate::model::types::FileVersionsModal;
+use crate::ui::icons::IconRenderer;
+use crate::ui::theme::Theme;
+use crate::utils;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Render the file versions modal
+pub fn render_file_versions_modal(
+    f: &mut Frame,
+    theme: &Theme,
+    keymap: &Keymap,
+    icon_renderer: &IconRenderer,
+    area: Rect,
+    modal_state: &mut FileVersionsModal,
+) {
+    // Calculate centered modal dimensions (90% width, 80% height)
+    let modal_width = (area.width as f32 * 0.9) as u16;
+    let modal_height = (area.height as f32 * 0.8) as u16;
+
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(modal_width)) / 2,
+        y: (area.height.saturating_sub(modal_height)) / 2,
+        width: modal_width.min(area.width),
+        height: modal_height.min(area.height),
+    };
+
+    f.render_widget(Clear, modal_area);
+
+    // Several files: make room for the file name of each version
+    let multiple_files = modal_state.file_paths.len() > 1;
+    let list_width = if multiple_files { 64 } else { 44 };
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(list_width), Constraint::Min(30)])
+        .split(modal_area);
+
+    // Version list
+    let items: Vec<ListItem> = modal_state
+        .versions
+        .iter()
+        .map(|version| {
+            let mut spans = vec![
+                Span::styled(
+                    format_datetime(&version.version_time),
+                    Style::default().fg(theme.text),
+                ),
+                Span::raw("  "),
+                Span::styled(
+                    utils::format_bytes(version.size),
+                    Style::default().fg(theme.text_muted),
+                ),
+            ];
+            if multiple_files {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(
+                    version.file_path.clone(),
+                    Style::default().fg(theme.text_muted),
+                ));
+            }
+
+            let item = ListItem::new(Line::from(spans));
+            if modal_state.is_marked(version) {
+                item.style(
+                    Style::default()
+                        .fg(theme.label)
+                        .add_modifier(Modifier::UNDERLINED),
+                )
+            } else {
+                item
+            }
+        })
+        .collect();
+
+    let mut title = format!(" Versions ({}) ", modal_state.versions.len());
+    if !modal_state.marked.is_empty() {
+        title.push_str(&format!("[{} marked] ", modal_state.marked.len()));
+    }
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(format!(
+                    " {} ",
+                    keymap.modal_hints(&[
+                        (&[Action::MarkVersion], "Mark"),
+                        (&[Action::RestoreVersion, Action::Select], "Restore"),
+                        (&[Action::Close], "Close"),
+                    ])
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.selection_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(icon_renderer.render_highlight());
+
+    let mut list_state = ListState::default();
+    if !modal_state.versions.is_empty() {
+        list_state.select(Some(modal_state.selected_index));
+    }
+    f.render_stateful_widget(list, columns[0], &mut list_state);
+
+    // Preview of the selected version
+    let content = match &modal_state.preview {
+        Ok(text) => text.clone(),
+        Err(msg) => format!("Error: {}", msg),
+    };
+    let parsed_text = crate::logic::file::parse_ansi_to_text(&content);
+
+    // Clamp scroll offset to the content (write back to prevent drift)
+    let viewport_height = columns[1].height.saturating_sub(2) as usize;
+    let max_scroll = parsed_text.lines.len().saturating_sub(viewport_height);
+    let clamped_scroll = (modal_state.scroll_offset as usize).min(max_scroll) as u16;
+    modal_state.scroll_offset = clamped_scroll;
+
+    let mut title = String::from(" ");
+    if let Some(version) = modal_state.selected_version() {
+        title.push_str(&format!(
+            "{} (modified {}) ",
+            version.file_path,
+            format_datetime(&version.mod_time)
+        ));
+    }
+    if modal_state.preview_is_binary {
+        title.push_str("[binary] ");
+    }
+
+    // NOTE: Don't set .style() on the Paragraph - it would override ANSI colors
+    let preview = Paragraph::new(parsed_text)
+        .block(
+            Block::default()
+                .title(title)
+                .title_bottom(icon_renderer.render_text(&format!(
+                    " {}:Select  PgUp/PgDn:Scroll ",
+                    keymap.modal_nav_label()
+                )))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((clamped_scroll, 0));
+    f.render_widget(preview, columns[1]);
+}
@@ -0,0 +1,169 @@
+//! Record-and-replay tests
+//!
+//! A session recorded through `RecordingProxy` (here in front of the mock
+//! server) must replay through `ReplayServer` into the same invalidations
+//! and API responses, so a recording attached to a bug report reproduces it.
+
+use std::time::Duration;
+use stui::api::SyncthingClient;
+use stui::mock::{
+    Fixture, MockServer, RecordedEntry, Recorder, Recording, RecordingProxy, ReplayServer,
+};
+use stui::services::events::{CacheInvalidation, spawn_event_listener};
+use tokio::sync::mpsc;
+
+/// Collect `count` invalidations from an event listener connected to `base_url`
+async fn listen(base_url: String, api_key: &str, count: usize) -> Vec<String> {
+    let (invalidation_tx, mut invalidation_rx) = mpsc::unbounded_channel();
+    let (event_id_tx, _event_id_rx) = mpsc::unbounded_channel();
+    let listener = spawn_event_listener(
+        base_url,
+        api_key.to_string(),
+        0,
+        invalidation_tx,
+        event_id_tx,
+    );
+
+    let mut seen = Vec::new();
+    while seen.len() < count {
+        let invalidation = tokio::time::timeout(Duration::from_secs(5), invalidation_rx.recv())
+            .await
+            .expect("timed out waiting for invalidation")
+            .expect("channel closed");
+        seen.push(describe(&invalidation));
+    }
+    listener.abort();
+    seen
+}
+
+fn describe(invalidation: &CacheInvalidation) -> String {
+    match invalidation {
+        CacheInvalidation::File {
+            folder_id,
+            file_path,
+            ..
+        } => format!("file {}/{}", folder_id, file_path),
+        CacheInvalidation::Directory {
+            folder_id,
+            dir_path,
+            ..
+        } => format!("dir {}/{}", folder_id, dir_path),
+        CacheInvalidation::ItemStarted {
+            folder_id,
+            file_path,
+            ..
+        } => format!("started {}/{}", folder_id, file_path),
+        CacheInvalidation::ItemFinished {
+            folder_id,
+            file_path,
+            ..
+        } => format!("finished {}/{}", folder_id, file_path),
+        CacheInvalidation::Activity { event_message, .. } => event_message.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_recorded_session_replays_identically() {
+    let fixture = Fixture::from_yaml(
+        r#"
+folders:
+  - id: docs
+    files:
+      - { path: a.txt, size: 5, state: out_of_sync }
+events:
+  - after_ms: 20
+    type: ItemStarted
+    data: { folder: docs, item: a.txt }
+  - after_ms: 60
+    type: ItemFinished
+    data: { folder: docs, item: a.txt, type: file, action: update }
+    update: { folder: docs, files: [{ path: a.txt, size: 6 }] }
+"#,
+    )
+    .unwrap();
+    let server = MockServer::start(fixture).await.unwrap();
+
+    let dir = std::env::temp_dir().join(format!("stui-replay-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("session.jsonl");
+
+    // Record: the listener and a client both go through the proxy
+    let proxy = RecordingProxy::start(&server.url(), Recorder::create(&path).unwrap())
+        .await
+        .unwrap();
+    let live = listen(proxy.url(), server.api_key(), 4).await;
+    let client = SyncthingClient::new(proxy.url(), server.api_key().to_string());
+    let live_status = client.get_folder_status("docs").await.unwrap();
+    drop(proxy);
+
+    let recording = Recording::load(&path).unwrap();
+    assert_eq!(recording.events().count(), 2);
+    assert!(recording.entries.iter().any(|entry| matches!(
+        entry,
+        RecordedEntry::Api { path, status: 200, .. } if path == "/rest/db/status?folder=docs"
+    )));
+    assert!(
+        recording
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].t_ms() <= pair[1].t_ms())
+    );
+
+    // Replay faster than real time: same invalidations, same responses
+    let replay = ReplayServer::start(recording, 20.0).await.unwrap();
+    let replayed = listen(replay.url(), "any-key", 4).await;
+    assert_eq!(replayed, live);
+    assert!(replay.is_finished());
+
+    let client = SyncthingClient::new(replay.url(), "any-key".to_string());
+    let replayed_status = client.get_folder_status("docs").await.unwrap();
+    assert_eq!(replayed_status.sequence, live_status.sequence);
+    assert_eq!(replayed_status.need_total_items, 0);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_attached_recording_replays_deterministically() {
+    let recording = Recording::load(std::path::Path::new(
+        "tests/fixtures/recorded_session.jsonl",
+    ))
+    .unwrap();
+    let replay = ReplayServer::start(recording, 100.0).await.unwrap();
+
+    let seen = listen(replay.url(), "", 4).await;
+    assert_eq!(
+        seen,
+        vec![
+            "started photos/2025/beach.jpg",
+            "finished photos/2025/beach.jpg",
+            "UPDATE file 'beach.jpg'",
+            "file photos/2025/beach.jpg",
+        ]
+    );
+
+    // Responses follow the recorded timeline: once it has played past 950ms
+    // (9.5ms at 100x), the later status is served
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let client = SyncthingClient::new(replay.url(), String::new());
+    let status = client.get_folder_status("photos").await.unwrap();
+    assert_eq!(status.state, "idle");
+
+    // Requests that weren't recorded fail instead of inventing data
+    assert!(client.get_folder_status("docs").await.is_err());
+}
+
+#[tokio::test]
+async fn test_replay_rejects_invalid_speed() {
+    assert!(
+        ReplayServer::start(Recording::default(), 0.0)
+            .await
+            .is_err()
+    );
+    assert!(
+        ReplayServer::start(Recording::default(), f64::NAN)
+            .await
+            .is_err()
+    );
+}
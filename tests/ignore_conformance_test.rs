@@ -0,0 +1,223 @@
+//! Conformance tests for the `.stignore` matcher
+//!
+//! Every case comes from the examples in Syncthing's ignore documentation
+//! (https://docs.syncthing.net/users/ignoring.html). Receive-only folders
+//! show ignored icons based on this matcher, so it must agree with Syncthing.
+
+use stui::logic::ignore::IgnoreMatcher;
+
+fn matcher(lines: &[&str]) -> IgnoreMatcher {
+    let lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    IgnoreMatcher::new(&lines)
+}
+
+/// Assert which paths a single pattern ignores
+fn assert_pattern(pattern: &str, ignored: &[&str], not_ignored: &[&str]) {
+    let m = matcher(&[pattern]);
+    for path in ignored {
+        assert!(m.is_ignored(path), "{:?} should ignore {:?}", pattern, path);
+    }
+    for path in not_ignored {
+        assert!(
+            !m.is_ignored(path),
+            "{:?} should not ignore {:?}",
+            pattern,
+            path
+        );
+    }
+}
+
+// ========================================
+// PATTERN SYNTAX
+// ========================================
+
+#[test]
+fn test_plain_name_matches_at_any_depth() {
+    // "foo" matches a file or directory named foo anywhere, and its contents
+    assert_pattern(
+        "foo",
+        &["foo", "subdir/foo", "foo/bar", "subdir/foo/bar"],
+        &["foofoo", "subdir/foofoo", "barfoo"],
+    );
+}
+
+#[test]
+fn test_star_stays_within_a_component() {
+    // "te*ne" matches telephone and subdir/telephone, but not tele/phone
+    assert_pattern("te*ne", &["telephone", "subdir/telephone"], &["tele/phone"]);
+}
+
+#[test]
+fn test_double_star_crosses_directories() {
+    // "te**ne" matches telephone, subdir/telephone and tele/sub/dir/phone
+    assert_pattern(
+        "te**ne",
+        &["telephone", "subdir/telephone", "tele/sub/dir/phone"],
+        &["telephones"],
+    );
+}
+
+#[test]
+fn test_question_mark_is_one_character() {
+    // "te??st" matches tebest but not teb/st or test
+    assert_pattern("te??st", &["tebest", "subdir/tebest"], &["teb/st", "test"]);
+}
+
+#[test]
+fn test_character_range() {
+    // "[a-z]" matches a single lowercase letter
+    assert_pattern("[a-z]", &["a", "z", "dir/q"], &["A", "aa", "1"]);
+    assert_pattern("x[0-9].txt", &["x1.txt"], &["xa.txt", "x10.txt"]);
+}
+
+#[test]
+fn test_alternatives() {
+    // "{banana,pineapple}" matches either word
+    assert_pattern(
+        "{banana,pineapple}",
+        &["banana", "pineapple", "fruit/banana"],
+        &["apple", "bananapineapple"],
+    );
+    assert_pattern("*.{jpg,png}", &["a.jpg", "b/c.png"], &["a.gif"]);
+}
+
+#[test]
+fn test_backslash_escapes() {
+    // "\{banana\}" matches the literal name {banana}
+    assert_pattern("\\{banana\\}", &["{banana}"], &["banana"]);
+    assert_pattern("\\*.txt", &["*.txt"], &["a.txt"]);
+}
+
+#[test]
+fn test_leading_slash_anchors_to_root() {
+    // "/foo" matches foo in the folder root only
+    assert_pattern(
+        "/foo",
+        &["foo", "foo/bar"],
+        &["subdir/foo", "subdir/foo/bar"],
+    );
+}
+
+#[test]
+fn test_trailing_slash_matches_directory_contents() {
+    assert_pattern("cache/", &["cache/data", "sub/cache/data"], &["cachefile"]);
+}
+
+#[test]
+fn test_case_insensitive_prefix() {
+    // "(?i)test" matches test, TEST and tEsT
+    assert_pattern(
+        "(?i)test",
+        &["test", "TEST", "tEsT", "dir/Test"],
+        &["tests"],
+    );
+    assert_pattern("test", &["test"], &["TEST"]);
+}
+
+#[test]
+fn test_comments_blank_lines_and_includes_are_skipped() {
+    let m = matcher(&["// foo", "", "   ", "#include .stglobalignore"]);
+    assert!(m.match_path("foo").is_none());
+    assert!(m.match_path(".stglobalignore").is_none());
+}
+
+// ========================================
+// ORDERING AND NEGATION
+// ========================================
+
+#[test]
+fn test_first_match_wins() {
+    // A negation only helps when it comes before the pattern it overrides
+    let m = matcher(&["!important.log", "*.log"]);
+    assert!(!m.is_ignored("important.log"));
+    assert!(m.is_ignored("debug.log"));
+
+    let m = matcher(&["*.log", "!important.log"]);
+    assert!(m.is_ignored("important.log"));
+}
+
+#[test]
+fn test_negated_case_insensitive_pattern() {
+    // "(?i)!picture*.png" keeps Picture1.PNG while "*.png" ignores the rest
+    let m = matcher(&["(?i)!picture*.png", "*.png"]);
+    assert!(!m.is_ignored("Picture1.PNG"));
+    assert!(!m.is_ignored("pictures/picture2.png"));
+    assert!(m.is_ignored("screenshot.png"));
+}
+
+#[test]
+fn test_prefixes_in_any_order() {
+    let m = matcher(&["!(?i)KEEP.txt", "(?d)(?i)*.TXT"]);
+    assert!(!m.is_ignored("keep.txt"));
+
+    let verdict = m.match_path("other.txt").unwrap();
+    assert!(verdict.ignored && verdict.deletable);
+}
+
+// ========================================
+// DOCUMENTATION EXAMPLE
+// ========================================
+
+/// The `.stignore` from Syncthing's documentation example
+const DOC_EXAMPLE: [&str; 7] = [
+    "(?d).DS_Store",
+    "!frobble",
+    "!quuz",
+    "foo",
+    "*2",
+    "qu*",
+    "(?i)my pictures",
+];
+
+#[test]
+fn test_documentation_example_tree() {
+    let m = matcher(&DOC_EXAMPLE);
+
+    // .DS_Store is ignored and may be deleted to remove its directory
+    let ds_store = m.match_path(".DS_Store").unwrap();
+    assert!(ds_store.ignored && ds_store.deletable);
+
+    // foo is ignored, foofoo is not
+    assert!(m.is_ignored("foo"));
+    assert!(!m.is_ignored("foofoo"));
+
+    // bar/baz is synced, bar/quux is ignored by qu*, bar/quuz is kept by !quuz
+    assert!(!m.is_ignored("bar/baz"));
+    assert!(m.is_ignored("bar/quux"));
+    assert!(!m.is_ignored("bar/quuz"));
+
+    // bar2 and everything in it is ignored by *2
+    assert!(m.is_ignored("bar2"));
+    assert!(m.is_ignored("bar2/baz"));
+
+    // "My Pictures" is ignored case-insensitively, including its contents
+    assert!(m.is_ignored("My Pictures"));
+    assert!(m.is_ignored("My Pictures/Img15.PNG"));
+}
+
+#[test]
+fn test_deciding_pattern_and_line_are_reported() {
+    let m = matcher(&DOC_EXAMPLE);
+
+    let verdict = m.match_path("bar/quux").unwrap();
+    assert_eq!((verdict.line, verdict.pattern.as_str()), (6, "qu*"));
+    assert!(!verdict.deletable);
+
+    let verdict = m.match_path("bar/quuz").unwrap();
+    assert!(!verdict.ignored);
+    assert_eq!((verdict.line, verdict.pattern.as_str()), (3, "!quuz"));
+
+    let verdict = m.match_path("My Pictures/Img15.PNG").unwrap();
+    assert_eq!(verdict.line, 7);
+
+    assert!(m.match_path("bar/baz").is_none());
+}
+
+#[test]
+fn test_matching_lines_lists_every_match_in_order() {
+    let m = matcher(&DOC_EXAMPLE);
+
+    // "quz2" matches both *2 (line 5) and qu* (line 6)
+    let lines: Vec<usize> = m.matching_lines("quz2").iter().map(|v| v.line).collect();
+    assert_eq!(lines, vec![5, 6]);
+}
@@ -0,0 +1,700 @@
+//! End-to-end tests against the mock Syncthing server
+//!
+//! These exercise the real HTTP stack: SyncthingClient, the API service queue,
+//! the event listener and cache invalidation, all talking to `stui::mock`.
+
+use std::time::Duration;
+use stui::api::{Folder, RateLimits, SyncState, SyncthingClient};
+use stui::cache::CacheDb;
+use stui::logic::folder_setup::apply_sharing;
+use stui::logic::transfers::{TransferSection, queue_entries};
+use stui::mock::{FileFixture, FileState, FileUpdate, Fixture, MockServer};
+use stui::model::DeviceChoice;
+use stui::services::api::{ApiRequest, ApiResponse, Priority, spawn_api_service};
+use stui::services::events::{CacheInvalidation, spawn_event_listener};
+use tokio::sync::mpsc;
+
+const NAS: &str = "NAS0000-AAAAAAA-BBBBBBB-CCCCCCC-DDDDDDD-EEEEEEE-FFFFFFF-GGGGGGG";
+
+fn example_fixture() -> Fixture {
+    Fixture::load(std::path::Path::new("tests/fixtures/mock_syncthing.yaml")).unwrap()
+}
+
+async fn start(fixture: Fixture) -> (MockServer, SyncthingClient) {
+    let server = MockServer::start(fixture).await.unwrap();
+    let client = SyncthingClient::new(server.url(), server.api_key().to_string());
+    (server, client)
+}
+
+/// Wait for the next message on a channel, failing the test after 5 seconds
+async fn recv<T>(rx: &mut mpsc::UnboundedReceiver<T>) -> T {
+    tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("timed out waiting for message")
+        .expect("channel closed")
+}
+
+// ============================================================================
+// CLIENT ENDPOINTS
+// ============================================================================
+
+#[tokio::test]
+async fn test_config_and_system_endpoints() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let folders = client.get_folders().await.unwrap();
+    let ids: Vec<&str> = folders.iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["docs", "photos"]);
+    assert_eq!(folders[0].label.as_deref(), Some("Documents"));
+
+    assert_eq!(client.get_device_name().await.unwrap(), "laptop");
+    assert_eq!(client.get_system_status().await.unwrap().uptime, 3600);
+
+    let stats = client.get_connection_stats().await.unwrap();
+    assert_eq!(stats.total.in_bytes_total, 1048576);
+
+    let connections = client.get_system_connections().await.unwrap();
+    assert!(connections.connections[NAS].connected);
+    assert_eq!(connections.connections.len(), 2);
+}
+
+#[tokio::test]
+async fn test_browse_lists_global_tree() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let mut root: Vec<String> = client
+        .browse_folder("docs", None)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|item| item.name)
+        .collect();
+    root.sort();
+    assert_eq!(root, vec!["archive", "notes", "readme.md", "scratch.tmp"]);
+
+    let notes = client.browse_folder("docs", Some("notes/")).await.unwrap();
+    assert_eq!(notes.len(), 3);
+    assert!(notes.iter().all(|i| i.item_type == "FILE_INFO_TYPE_FILE"));
+
+    // Local-only files aren't in the global tree
+    let photos = client.browse_folder("photos", Some("2025/")).await.unwrap();
+    assert_eq!(photos.len(), 1);
+    assert_eq!(photos[0].name, "beach.jpg");
+
+    assert!(client.browse_folder("nope", None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_file_info_matches_fixture_states() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let state = |folder: &'static str, path: &'static str| {
+        let client = client.clone();
+        async move {
+            client
+                .get_file_info(folder, path)
+                .await
+                .unwrap()
+                .determine_sync_state()
+        }
+    };
+
+    assert_eq!(state("docs", "readme.md").await, SyncState::Synced);
+    assert_eq!(state("docs", "notes").await, SyncState::Synced);
+    assert_eq!(state("docs", "notes/todo.txt").await, SyncState::OutOfSync);
+    assert_eq!(state("docs", "notes/big.iso").await, SyncState::OutOfSync);
+    assert_eq!(
+        state("docs", "notes/new-from-nas.txt").await,
+        SyncState::RemoteOnly
+    );
+    assert_eq!(state("docs", "scratch.tmp").await, SyncState::Ignored);
+    assert_eq!(
+        state("photos", "2025/edited.jpg").await,
+        SyncState::LocalOnly
+    );
+}
+
+#[tokio::test]
+async fn test_status_need_and_local_changed() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let status = client.get_folder_status("docs").await.unwrap();
+    assert_eq!(status.state, "idle");
+    assert_eq!(status.sequence, 6);
+    assert_eq!(status.need_total_items, 3);
+
+    let need = client.get_needed_files("docs", None, None).await.unwrap();
+    let names = |files: &[stui::api::FileInfo]| -> Vec<String> {
+        files.iter().map(|f| f.name.clone()).collect()
+    };
+    assert_eq!(names(&need.progress), vec!["notes/big.iso"]);
+    assert_eq!(
+        names(&need.rest),
+        vec!["notes/new-from-nas.txt", "notes/todo.txt"]
+    );
+
+    let paged = client
+        .get_needed_files("docs", Some(2), Some(2))
+        .await
+        .unwrap();
+    assert!(paged.progress.is_empty());
+    assert_eq!(names(&paged.rest), vec!["notes/todo.txt"]);
+
+    let changed = client.get_local_changed_files("photos").await.unwrap();
+    assert_eq!(changed, vec!["2025/edited.jpg"]);
+
+    let photos = client.get_folder_status("photos").await.unwrap();
+    assert_eq!(photos.receive_only_total_items, 1);
+}
+
+#[tokio::test]
+async fn test_prio_moves_file_to_front_of_queue() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let need = client
+        .prioritize_file("docs", "notes/todo.txt")
+        .await
+        .unwrap();
+    let order: Vec<(TransferSection, String)> = queue_entries(&need)
+        .into_iter()
+        .map(|entry| (entry.section, entry.path))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            (TransferSection::InProgress, "notes/big.iso".to_string()),
+            (TransferSection::Queued, "notes/todo.txt".to_string()),
+            (TransferSection::Rest, "notes/new-from-nas.txt".to_string()),
+        ]
+    );
+
+    // The most recently prioritized file goes first, and the order sticks
+    client
+        .prioritize_file("docs", "notes/new-from-nas.txt")
+        .await
+        .unwrap();
+    let need = client.get_needed_files("docs", None, None).await.unwrap();
+    let queued: Vec<&str> = need.queued.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(queued, vec!["notes/new-from-nas.txt", "notes/todo.txt"]);
+
+    assert!(client.prioritize_file("nope", "a.txt").await.is_err());
+}
+
+#[tokio::test]
+async fn test_remote_device_completion_and_need() {
+    let fixture = Fixture::from_yaml(&format!(
+        r#"
+devices:
+  - {{ id: {NAS}, name: nas }}
+  - {{ id: PHONE00-ID, name: phone }}
+folders:
+  - id: docs
+    devices: [{NAS}]
+    files:
+      - {{ path: readme.md, size: 600 }}
+      - {{ path: notes/new.txt, size: 300, state: local_only }}
+      - {{ path: notes/draft.txt, size: 100, state: local_only }}
+"#
+    ))
+    .unwrap();
+    let (_server, client) = start(fixture).await;
+
+    let completion = client.get_completion("docs", NAS).await.unwrap();
+    assert_eq!(completion.remote_state, "valid");
+    assert_eq!(completion.need_bytes, 400);
+    assert_eq!(completion.need_items, 2);
+    assert_eq!(completion.completion, 60.0);
+
+    let names = |files: Vec<stui::api::FileInfo>| -> Vec<String> {
+        files.into_iter().map(|f| f.name).collect()
+    };
+    let need = client.get_remote_need("docs", NAS, 1, 100).await.unwrap();
+    assert_eq!(names(need), vec!["notes/draft.txt", "notes/new.txt"]);
+    let second_page = client.get_remote_need("docs", NAS, 2, 1).await.unwrap();
+    assert_eq!(names(second_page), vec!["notes/new.txt"]);
+
+    // A device the folder isn't shared with needs nothing
+    let phone = client.get_completion("docs", "PHONE00-ID").await.unwrap();
+    assert_eq!(phone.remote_state, "notSharing");
+    assert_eq!(phone.need_bytes, 0);
+    assert!(
+        client
+            .get_remote_need("docs", "PHONE00-ID", 1, 100)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert!(client.get_completion("nope", NAS).await.is_err());
+}
+
+#[tokio::test]
+async fn test_override_keeps_local_version_of_send_only_folder() {
+    let fixture = Fixture::from_yaml(
+        r#"
+folders:
+  - id: docs
+    type: sendonly
+    files:
+      - { path: readme.md }
+      - { path: edited.txt, state: out_of_sync }
+      - { path: from-remote.txt, state: remote_only }
+  - id: shared
+    files:
+      - { path: todo.txt, state: out_of_sync }
+"#,
+    )
+    .unwrap();
+    let (_server, client) = start(fixture).await;
+
+    assert_eq!(
+        client
+            .get_folder_status("docs")
+            .await
+            .unwrap()
+            .need_total_items,
+        2
+    );
+    client.override_folder("docs").await.unwrap();
+    assert_eq!(
+        client
+            .get_folder_status("docs")
+            .await
+            .unwrap()
+            .need_total_items,
+        0
+    );
+    let need = client.get_needed_files("docs", None, None).await.unwrap();
+    assert!(need.rest.is_empty() && need.progress.is_empty());
+
+    // Folders that aren't send-only are left alone
+    client.override_folder("shared").await.unwrap();
+    assert_eq!(
+        client
+            .get_folder_status("shared")
+            .await
+            .unwrap()
+            .need_total_items,
+        1
+    );
+    assert!(client.override_folder("nope").await.is_err());
+}
+
+#[tokio::test]
+async fn test_folder_config_round_trip_keeps_unknown_fields() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let mut config = client.get_folder_config("docs").await.unwrap();
+    assert_eq!(config["rescanIntervalS"], 3600);
+    config["label"] = "Work Docs".into();
+    config["rescanIntervalS"] = 60.into();
+    config["futureSetting"] = serde_json::json!({ "enabled": true });
+    client.update_folder_config("docs", &config).await.unwrap();
+
+    let saved = client.get_folder_config("docs").await.unwrap();
+    assert_eq!(saved["rescanIntervalS"], 60);
+    assert_eq!(saved["futureSetting"]["enabled"], true);
+    let folders = client.get_folders().await.unwrap();
+    assert_eq!(folders[0].label.as_deref(), Some("Work Docs"));
+
+    assert!(client.get_folder_config("nope").await.is_err());
+}
+
+#[tokio::test]
+async fn test_add_share_and_remove_folder() {
+    let (_server, client) = start(example_fixture()).await;
+
+    let folder = Folder {
+        id: "music-12345".to_string(),
+        label: Some("Music".to_string()),
+        path: "/data/music".to_string(),
+        paused: false,
+        folder_type: "sendonly".to_string(),
+        devices: vec![],
+    };
+    client.add_folder(&folder).await.unwrap();
+    assert!(client.add_folder(&folder).await.is_err());
+
+    let choices = vec![DeviceChoice {
+        device_id: NAS.to_string(),
+        name: "nas".to_string(),
+        selected: true,
+    }];
+    let config = client.get_folder_config("music-12345").await.unwrap();
+    let shared = apply_sharing(&config, &choices);
+    client
+        .update_folder_config("music-12345", &shared)
+        .await
+        .unwrap();
+
+    let folders = client.get_folders().await.unwrap();
+    let added = folders.iter().find(|f| f.id == "music-12345").unwrap();
+    assert_eq!(added.path, "/data/music");
+    assert_eq!(added.folder_type, "sendonly");
+    let devices: Vec<&str> = added.devices.iter().map(|d| d.device_id.as_str()).collect();
+    assert_eq!(devices, vec![NAS]);
+
+    client.remove_folder("music-12345").await.unwrap();
+    let folders = client.get_folders().await.unwrap();
+    assert!(folders.iter().all(|f| f.id != "music-12345"));
+    assert!(client.remove_folder("music-12345").await.is_err());
+}
+
+#[tokio::test]
+async fn test_global_and_device_rate_limits() {
+    let (_server, client) = start(example_fixture()).await;
+
+    assert!(!client.get_global_rate_limits().await.unwrap().is_limited());
+
+    let one_mb = RateLimits {
+        max_send_kbps: 1024,
+        max_recv_kbps: 1024,
+    };
+    client.set_global_rate_limits(&one_mb).await.unwrap();
+    assert_eq!(client.get_global_rate_limits().await.unwrap(), one_mb);
+
+    let upload_only = RateLimits {
+        max_send_kbps: 512,
+        max_recv_kbps: 0,
+    };
+    client
+        .set_device_rate_limits(NAS, &upload_only)
+        .await
+        .unwrap();
+    let devices = client.get_config_devices().await.unwrap();
+    let nas = devices.iter().find(|d| d.device_id == NAS).unwrap();
+    assert_eq!(nas.rate_limits(), upload_only);
+    // Other device settings are left alone
+    assert_eq!(nas.name, "nas");
+
+    assert!(
+        client
+            .set_device_rate_limits("UNKNOWN", &upload_only)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_system_version_and_restart() {
+    let (server, client) = start(example_fixture()).await;
+
+    let version = client.get_system_version().await.unwrap();
+    assert_eq!(version.version, "v1.27.0");
+    assert_eq!(format!("{}/{}", version.os, version.arch), "linux/amd64");
+    assert_eq!(version.codename, "Gold Grasshopper");
+
+    // Bandwidth limits apply without a restart, other options don't
+    assert!(!client.get_restart_required().await.unwrap());
+    client
+        .set_global_rate_limits(&RateLimits {
+            max_send_kbps: 1024,
+            max_recv_kbps: 0,
+        })
+        .await
+        .unwrap();
+    assert!(!client.get_restart_required().await.unwrap());
+    reqwest::Client::new()
+        .patch(format!("{}/rest/config/options", server.url()))
+        .header("X-API-Key", server.api_key())
+        .json(&serde_json::json!({ "listenAddresses": ["default"] }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    assert!(client.get_restart_required().await.unwrap());
+
+    client.restart().await.unwrap();
+    assert!(!client.get_restart_required().await.unwrap());
+    assert_eq!(client.get_system_status().await.unwrap().uptime, 0);
+    assert!(
+        server
+            .requests()
+            .contains(&"POST /rest/system/restart".to_string())
+    );
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_ignores_round_trip() {
+    let (_server, client) = start(example_fixture()).await;
+
+    assert_eq!(
+        client.get_ignore_patterns("docs").await.unwrap(),
+        vec!["*.tmp", "/build"]
+    );
+
+    client
+        .set_ignore_patterns("docs", vec!["*.bak".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(
+        client.get_ignore_patterns("docs").await.unwrap(),
+        vec!["*.bak"]
+    );
+}
+
+#[tokio::test]
+async fn test_rescan_paths_sends_one_sub_per_path() {
+    let server = MockServer::start(example_fixture()).await.unwrap();
+    let client = SyncthingClient::new(server.url(), server.api_key().to_string());
+
+    client
+        .rescan_paths("docs", &["notes/a b.txt".to_string(), "photos".to_string()])
+        .await
+        .unwrap();
+    assert!(
+        server.requests().contains(
+            &"POST /rest/db/scan?folder=docs&sub=notes%2Fa%20b.txt&sub=photos".to_string()
+        )
+    );
+
+    assert!(client.rescan_paths("missing", &[]).await.is_err());
+}
+
+#[tokio::test]
+async fn test_wrong_api_key_is_rejected() {
+    let server = MockServer::start(example_fixture()).await.unwrap();
+    let client = SyncthingClient::new(server.url(), "wrong".to_string());
+
+    assert!(client.get_folders().await.is_err());
+    assert!(
+        server
+            .requests()
+            .contains(&"GET /rest/system/config".to_string())
+    );
+}
+
+// ============================================================================
+// SERVICES
+// ============================================================================
+
+#[tokio::test]
+async fn test_api_service_round_trip() {
+    let (_server, client) = start(example_fixture()).await;
+    let (request_tx, mut response_rx) = spawn_api_service(client);
+
+    request_tx
+        .send(ApiRequest::BrowseFolder {
+            folder_id: "docs".to_string(),
+            prefix: Some("notes/".to_string()),
+            priority: Priority::High,
+        })
+        .unwrap();
+
+    match recv(&mut response_rx).await {
+        ApiResponse::BrowseResult {
+            folder_id, items, ..
+        } => {
+            assert_eq!(folder_id, "docs");
+            assert_eq!(items.unwrap().len(), 3);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    request_tx
+        .send(ApiRequest::GetFolderStatus {
+            folder_id: "photos".to_string(),
+        })
+        .unwrap();
+
+    match recv(&mut response_rx).await {
+        ApiResponse::FolderStatusResult { folder_id, status } => {
+            assert_eq!(folder_id, "photos");
+            assert_eq!(status.unwrap().sequence, 2);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_emitted_event_invalidates_stale_browse_cache() {
+    let (server, client) = start(example_fixture()).await;
+    let cache = CacheDb::new_in_memory().unwrap();
+
+    // Populate the cache the way the app does on first browse
+    let sequence = client.get_folder_status("docs").await.unwrap().sequence;
+    let items = client.browse_folder("docs", Some("notes/")).await.unwrap();
+    cache
+        .save_browse_items("docs", Some("notes/"), &items, sequence)
+        .unwrap();
+
+    let (invalidation_tx, mut invalidation_rx) = mpsc::unbounded_channel();
+    let (event_id_tx, mut event_id_rx) = mpsc::unbounded_channel();
+    let listener = spawn_event_listener(
+        server.url(),
+        server.api_key().to_string(),
+        0,
+        invalidation_tx,
+        event_id_tx,
+    );
+
+    // A new file arrives and Syncthing reports the index change
+    server.update_files(&FileUpdate {
+        folder: "docs".to_string(),
+        files: vec![FileFixture::new("notes/added.txt", FileState::Synced)],
+        remove: vec![],
+    });
+    let event_id = server.emit(
+        "LocalIndexUpdated",
+        serde_json::json!({ "folder": "docs", "filenames": ["notes/added.txt"] }),
+    );
+
+    match recv(&mut invalidation_rx).await {
+        CacheInvalidation::File {
+            folder_id,
+            file_path,
+            ..
+        } => {
+            assert_eq!(folder_id, "docs");
+            assert_eq!(file_path, "notes/added.txt");
+        }
+        other => panic!("unexpected invalidation: {:?}", other),
+    }
+    assert_eq!(recv(&mut event_id_rx).await, event_id);
+
+    cache
+        .invalidate_single_file("docs", "notes/added.txt")
+        .unwrap();
+
+    // The folder sequence moved on, so the cached listing is stale and a refetch sees the file
+    let new_sequence = client.get_folder_status("docs").await.unwrap().sequence;
+    assert!(new_sequence > sequence);
+    assert!(
+        cache
+            .get_browse_items("docs", Some("notes/"), new_sequence)
+            .unwrap()
+            .is_none()
+    );
+    let refreshed = client.browse_folder("docs", Some("notes/")).await.unwrap();
+    assert!(refreshed.iter().any(|item| item.name == "added.txt"));
+
+    listener.abort();
+}
+
+#[tokio::test]
+async fn test_scripted_events_are_delivered_in_order() {
+    let fixture = Fixture::from_yaml(
+        r#"
+folders:
+  - id: docs
+    files: [{ path: a.txt, state: out_of_sync }]
+events:
+  - after_ms: 20
+    type: ItemStarted
+    data: { folder: docs, item: a.txt }
+  - after_ms: 40
+    type: RemoteIndexUpdated
+    data: { folder: docs }
+    update:
+      folder: docs
+      files: [{ path: a.txt }]
+"#,
+    )
+    .unwrap();
+    let (server, client) = start(fixture).await;
+
+    let (invalidation_tx, mut invalidation_rx) = mpsc::unbounded_channel();
+    let (event_id_tx, _event_id_rx) = mpsc::unbounded_channel();
+    let listener = spawn_event_listener(
+        server.url(),
+        server.api_key().to_string(),
+        0,
+        invalidation_tx,
+        event_id_tx,
+    );
+
+    assert!(matches!(
+        recv(&mut invalidation_rx).await,
+        CacheInvalidation::ItemStarted { file_path, .. } if file_path == "a.txt"
+    ));
+    assert!(matches!(
+        recv(&mut invalidation_rx).await,
+        CacheInvalidation::Directory { dir_path, .. } if dir_path.is_empty()
+    ));
+
+    // The update was applied before the event went out
+    let details = client.get_file_info("docs", "a.txt").await.unwrap();
+    assert_eq!(details.determine_sync_state(), SyncState::Synced);
+
+    listener.abort();
+}
+
+#[tokio::test]
+async fn test_state_events_reach_the_listener() {
+    let (server, _client) = start(example_fixture()).await;
+
+    let (invalidation_tx, mut invalidation_rx) = mpsc::unbounded_channel();
+    let (event_id_tx, _event_id_rx) = mpsc::unbounded_channel();
+    let listener = spawn_event_listener(
+        server.url(),
+        server.api_key().to_string(),
+        0,
+        invalidation_tx,
+        event_id_tx,
+    );
+
+    // FolderSummary carries the same object as /rest/db/status
+    let summary: serde_json::Value = reqwest::Client::new()
+        .get(format!("{}/rest/db/status?folder=docs", server.url()))
+        .header("X-API-Key", server.api_key())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    server.emit(
+        "StateChanged",
+        serde_json::json!({ "folder": "docs", "from": "idle", "to": "scanning" }),
+    );
+    server.emit(
+        "FolderSummary",
+        serde_json::json!({ "folder": "docs", "summary": summary }),
+    );
+    server.emit(
+        "DeviceDisconnected",
+        serde_json::json!({ "id": NAS, "error": "reading: EOF" }),
+    );
+    server.emit("ConfigSaved", serde_json::json!({ "version": 37 }));
+    server.emit(
+        "DownloadProgress",
+        serde_json::json!({ "docs": { "notes/big.iso": {
+            "total": 5600, "pulling": 4, "copiedFromOrigin": 0, "reused": 1000,
+            "copiedFromElsewhere": 0, "pulled": 2000,
+            "bytesTotal": 734003200u64, "bytesDone": 393216000u64
+        }}}),
+    );
+
+    assert!(matches!(
+        recv(&mut invalidation_rx).await,
+        CacheInvalidation::FolderState { folder_id, state } if folder_id == "docs" && state == "scanning"
+    ));
+    match recv(&mut invalidation_rx).await {
+        CacheInvalidation::FolderSummary { folder_id, status } => {
+            assert_eq!(folder_id, "docs");
+            assert_eq!(status.sequence, summary["sequence"].as_u64().unwrap());
+            assert!(status.need_total_items > 0);
+        }
+        other => panic!("unexpected invalidation: {:?}", other),
+    }
+    assert!(matches!(
+        recv(&mut invalidation_rx).await,
+        CacheInvalidation::DeviceConnection { device_id, connected: false } if device_id == NAS
+    ));
+    assert!(matches!(
+        recv(&mut invalidation_rx).await,
+        CacheInvalidation::ConfigSaved
+    ));
+    match recv(&mut invalidation_rx).await {
+        CacheInvalidation::DownloadProgress { folders, .. } => {
+            let progress = &folders["docs"]["notes/big.iso"];
+            assert_eq!(progress.reused, 1000);
+            assert_eq!(progress.bytes_done, 393216000);
+        }
+        other => panic!("unexpected invalidation: {:?}", other),
+    }
+
+    listener.abort();
+}
@@ -84,7 +84,7 @@ fn test_new_breadcrumb_inherits_filter() {
 #[test]
 fn test_filter_only_shows_out_of_sync() {
     // Simulate directory with mixed sync states
-    let all_items = vec![
+    let all_items = [
         BrowseItem {
             name: "synced.txt".to_string(),
             item_type: "file".to_string(), // Synced file
@@ -106,7 +106,7 @@ fn test_filter_only_shows_out_of_sync() {
     ];
 
     // Out-of-sync items from cache (would come from GetNeededFiles API)
-    let out_of_sync_items = vec!["out-of-sync.txt"];
+    let out_of_sync_items = ["out-of-sync.txt"];
 
     // Filter should only keep items in out_of_sync_items list
     let filtered: Vec<_> = all_items
@@ -123,6 +123,7 @@ fn test_filter_only_shows_out_of_sync() {
 
 /// Test: Directory should be shown if ANY child is out-of-sync
 #[test]
+#[allow(clippy::const_is_empty)]
 fn test_directory_shown_if_child_out_of_sync() {
     // Simulate root level with directories
     let current_path = "";
@@ -142,7 +143,7 @@ fn test_directory_shown_if_child_out_of_sync() {
     ];
 
     // Out-of-sync files (nested in Messages/)
-    let out_of_sync_paths = vec!["Messages/foo"];
+    let out_of_sync_paths = ["Messages/foo"];
 
     // Check each directory
     for item in &items {
@@ -185,7 +186,7 @@ fn test_subdirectory_filter_application() {
 
     // Simulate being inside Messages/ directory
     let current_path = "Messages/";
-    let items_in_messages = vec![
+    let items_in_messages = [
         BrowseItem {
             name: "foo".to_string(),
             item_type: "file".to_string(), // Out-of-sync file
@@ -207,7 +208,7 @@ fn test_subdirectory_filter_application() {
     ];
 
     // Out-of-sync paths (full paths from cache)
-    let out_of_sync_paths = vec!["Messages/foo"];
+    let out_of_sync_paths = ["Messages/foo"];
 
     // Filter logic: keep only items that match out_of_sync_paths
     let filtered: Vec<_> = items_in_messages